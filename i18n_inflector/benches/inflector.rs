@@ -30,6 +30,13 @@ fn bench_pluralize_en(c: &mut Criterion) {
     });
 }
 
+fn bench_pluralize_best_en(c: &mut Criterion) {
+    let rules = language_rules("en").expect("supported locale");
+    c.bench_function("pluralize_best_en", |b| {
+        b.iter(|| rules.pluralize_best(black_box("category")));
+    });
+}
+
 fn bench_singularize_es(c: &mut Criterion) {
     let rules = language_rules("es").expect("supported locale");
     c.bench_function("singularize_es", |b| {
@@ -105,6 +112,7 @@ criterion_group!(
     bench_pluralize_no_change,
     bench_singularize_en,
     bench_pluralize_en,
+    bench_pluralize_best_en,
     bench_singularize_es,
     bench_pluralize_es,
     bench_singularize_de,
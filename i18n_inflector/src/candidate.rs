@@ -0,0 +1,124 @@
+//! Ranked inflection candidates.
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+/// How confident the rules are that a [`Candidate`] is the correct inflection.
+///
+/// Variants are ordered from least to most confident.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Confidence {
+    /// An alternative form produced by the suffix rules.
+    Possible,
+    /// The preferred form produced by the suffix rules.
+    Likely,
+    /// A form taken from an irregular, uncountable or user-supplied entry.
+    Certain,
+}
+
+/// An inflected form together with the confidence of the rules that produced it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Candidate<'a> {
+    value: Cow<'a, str>,
+    confidence: Confidence,
+}
+
+impl<'a> Candidate<'a> {
+    /// Creates a new candidate.
+    #[must_use]
+    pub fn new<V: Into<Cow<'a, str>>>(value: V, confidence: Confidence) -> Self {
+        Candidate {
+            value: value.into(),
+            confidence,
+        }
+    }
+
+    /// Returns the inflected form.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the confidence of the rules that produced this candidate.
+    #[must_use]
+    pub fn confidence(&self) -> Confidence {
+        self.confidence
+    }
+
+    /// Consumes the candidate and returns the inflected form.
+    #[must_use]
+    pub fn into_value(self) -> Cow<'a, str> {
+        self.value
+    }
+}
+
+impl PartialEq<str> for Candidate<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.value == other
+    }
+}
+
+impl PartialEq<&str> for Candidate<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.value == *other
+    }
+}
+
+/// Ranks an ordered list of forms produced by suffix rules.
+///
+/// The first form is [`Confidence::Likely`], the remaining forms are [`Confidence::Possible`], and
+/// duplicates are removed while keeping the first occurrence.
+#[must_use]
+pub(crate) fn rank(values: Vec<Cow<'_, str>>) -> Vec<Candidate<'_>> {
+    let mut candidates: Vec<Candidate<'_>> = Vec::with_capacity(values.len());
+    for value in values {
+        if candidates.iter().any(|candidate| candidate.value == value) {
+            continue;
+        }
+        let confidence = if candidates.is_empty() {
+            Confidence::Likely
+        } else {
+            Confidence::Possible
+        };
+        candidates.push(Candidate::new(value, confidence));
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use alloc::vec;
+
+    #[test]
+    fn test_candidate() {
+        let candidate = Candidate::new("users", Confidence::Likely);
+        assert_eq!(candidate.value(), "users");
+        assert_eq!(candidate.confidence(), Confidence::Likely);
+        assert_eq!(candidate, "users");
+        assert_eq!(candidate.into_value(), "users");
+
+        let candidate = Candidate::new(String::from("users"), Confidence::Certain);
+        assert_eq!(candidate.confidence(), Confidence::Certain);
+    }
+
+    #[test]
+    fn test_confidence_order() {
+        assert!(Confidence::Certain > Confidence::Likely);
+        assert!(Confidence::Likely > Confidence::Possible);
+    }
+
+    #[test]
+    fn test_rank() {
+        let candidates = rank(vec!["boxes".into(), "boxs".into(), "boxes".into()]);
+        assert_eq!(
+            candidates,
+            vec![
+                Candidate::new("boxes", Confidence::Likely),
+                Candidate::new("boxs", Confidence::Possible),
+            ]
+        );
+        assert!(rank(Vec::new()).is_empty());
+    }
+}
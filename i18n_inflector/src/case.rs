@@ -0,0 +1,341 @@
+//! Case folding utilities.
+//!
+//! Language rules are written against lowercase words. These helpers fold the input to lowercase
+//! before the rules run and re-apply the input's casing pattern to the output, so that `Children`
+//! becomes `Child` and `USERS` becomes `USER`.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::candidate::Candidate;
+
+/// The casing pattern of a word.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Casing {
+    /// All cased characters are lowercase (`users`); also used for words without cased characters.
+    Lower,
+    /// All cased characters are uppercase (`USERS`).
+    Upper,
+    /// The first cased character is uppercase and the rest are lowercase (`Users`).
+    Title,
+    /// Any other combination (`iPhones`, `McDonalds`).
+    Mixed,
+}
+
+/// Returns `true` if the language uses the Turkic dotted / dotless `i` casing rules.
+#[inline]
+#[must_use]
+pub(crate) fn is_turkic(language: &str) -> bool {
+    matches!(language, "tr" | "az")
+}
+
+/// Determines the casing pattern of a word.
+#[must_use]
+pub(crate) fn casing(word: &str) -> Casing {
+    let mut upper = 0usize;
+    let mut lower = 0usize;
+    let mut first_upper = false;
+    let mut upper_after_first = false;
+
+    for ch in word.chars() {
+        if ch.is_uppercase() {
+            if upper == 0 && lower == 0 {
+                first_upper = true;
+            } else {
+                upper_after_first = true;
+            }
+            upper += 1;
+        } else if ch.is_lowercase() {
+            lower += 1;
+        }
+    }
+
+    if upper == 0 {
+        Casing::Lower
+    } else if first_upper && !upper_after_first {
+        Casing::Title
+    } else if lower == 0 {
+        Casing::Upper
+    } else {
+        Casing::Mixed
+    }
+}
+
+/// Pushes the lowercase form of `ch` onto `buffer`, honoring Turkic casing when requested.
+#[inline]
+fn push_lower(buffer: &mut String, ch: char, turkic: bool) {
+    match ch {
+        'I' if turkic => buffer.push('ı'),
+        'İ' if turkic => buffer.push('i'),
+        _ => buffer.extend(ch.to_lowercase()),
+    }
+}
+
+/// Pushes the uppercase form of `ch` onto `buffer`, honoring Turkic casing when requested.
+#[inline]
+fn push_upper(buffer: &mut String, ch: char, turkic: bool) {
+    match ch {
+        'i' if turkic => buffer.push('İ'),
+        'ı' if turkic => buffer.push('I'),
+        _ => buffer.extend(ch.to_uppercase()),
+    }
+}
+
+/// Folds a word to lowercase using Unicode case mapping.
+///
+/// For Turkish and Azerbaijani, `I` folds to dotless `ı` and `İ` folds to `i`; all other languages
+/// use [`str::to_lowercase`], which also handles the Greek final sigma.
+#[must_use]
+pub(crate) fn to_lower(language: &str, word: &str) -> String {
+    if !is_turkic(language) {
+        return word.to_lowercase();
+    }
+    let mut buffer = String::with_capacity(word.len());
+    for ch in word.chars() {
+        push_lower(&mut buffer, ch, true);
+    }
+    buffer
+}
+
+/// Re-applies the casing pattern of `original` to the lowercase `word`.
+#[must_use]
+pub(crate) fn apply_casing(language: &str, original: &str, casing: Casing, word: &str) -> String {
+    let turkic = is_turkic(language);
+    let mut buffer = String::with_capacity(word.len());
+    match casing {
+        Casing::Lower => buffer.push_str(word),
+        Casing::Upper => {
+            for ch in word.chars() {
+                push_upper(&mut buffer, ch, turkic);
+            }
+        }
+        Casing::Title => {
+            let mut first = true;
+            for ch in word.chars() {
+                if first && (ch.is_lowercase() || ch.is_uppercase()) {
+                    push_upper(&mut buffer, ch, turkic);
+                    first = false;
+                } else {
+                    buffer.push(ch);
+                }
+            }
+        }
+        Casing::Mixed => {
+            // Copy the case of the original character at the same position; characters past the
+            // end of the original take the case of its last cased character.
+            let mut original_chars = original.chars();
+            let mut last_upper = false;
+            for ch in word.chars() {
+                if let Some(original_ch) = original_chars.next() {
+                    if original_ch.is_uppercase() {
+                        last_upper = true;
+                    } else if original_ch.is_lowercase() {
+                        last_upper = false;
+                    }
+                    if original_ch.is_uppercase() {
+                        push_upper(&mut buffer, ch, turkic);
+                    } else {
+                        buffer.push(ch);
+                    }
+                } else if last_upper {
+                    push_upper(&mut buffer, ch, turkic);
+                } else {
+                    buffer.push(ch);
+                }
+            }
+        }
+    }
+    buffer
+}
+
+/// Restores the casing of `original` on an inflected `word`, borrowing `original` when the
+/// inflection left it unchanged.
+fn restore<'a>(language: &str, original: &'a str, casing: Casing, word: &str) -> Cow<'a, str> {
+    let cased = apply_casing(language, original, casing, word);
+    if cased == original {
+        Cow::Borrowed(original)
+    } else {
+        Cow::Owned(cased)
+    }
+}
+
+/// Runs a single-form inflection function (singularization, count forms) case-insensitively and
+/// re-applies the input's casing.
+pub(crate) fn inflect_cased<'a, F>(language: &str, name: &'a str, inflect: F) -> Cow<'a, str>
+where
+    F: for<'b> FnOnce(&'b str) -> Cow<'b, str>,
+{
+    let casing = casing(name);
+    if casing == Casing::Lower {
+        return inflect(name);
+    }
+    let folded = to_lower(language, name);
+    let result = inflect(&folded);
+    restore(language, name, casing, &result)
+}
+
+/// Runs a pluralization function case-insensitively and re-applies the input's casing to every
+/// candidate.
+pub(crate) fn pluralize_cased<'a, F>(
+    language: &str,
+    name: &'a str,
+    pluralize: F,
+) -> Vec<Cow<'a, str>>
+where
+    F: for<'b> FnOnce(&'b str) -> Vec<Cow<'b, str>>,
+{
+    let casing = casing(name);
+    if casing == Casing::Lower {
+        return pluralize(name);
+    }
+    let folded = to_lower(language, name);
+    pluralize(&folded)
+        .iter()
+        .map(|candidate| restore(language, name, casing, candidate))
+        .collect()
+}
+
+/// Runs a function producing ranked candidates case-insensitively and re-applies the input's
+/// casing to every candidate.
+pub(crate) fn candidates_cased<'a, F>(
+    language: &str,
+    name: &'a str,
+    candidates: F,
+) -> Vec<Candidate<'a>>
+where
+    F: for<'b> FnOnce(&'b str) -> Vec<Candidate<'b>>,
+{
+    let casing = casing(name);
+    if casing == Casing::Lower {
+        return candidates(name);
+    }
+    let folded = to_lower(language, name);
+    candidates(&folded)
+        .iter()
+        .map(|candidate| {
+            Candidate::new(
+                restore(language, name, casing, candidate.value()),
+                candidate.confidence(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candidate::Confidence;
+    use alloc::vec;
+
+    fn identity(name: &str) -> Cow<'_, str> {
+        Cow::Borrowed(name)
+    }
+
+    fn strip_s(name: &str) -> Cow<'_, str> {
+        Cow::Borrowed(name.strip_suffix('s').unwrap_or(name))
+    }
+
+    fn append_s(name: &str) -> Vec<Cow<'_, str>> {
+        vec![Cow::Owned(alloc::format!("{name}s"))]
+    }
+
+    fn ranked(name: &str) -> Vec<Candidate<'_>> {
+        vec![
+            Candidate::new(alloc::format!("{name}s"), Confidence::Likely),
+            Candidate::new(name, Confidence::Possible),
+        ]
+    }
+
+    #[test]
+    fn test_casing() {
+        assert_eq!(casing("users"), Casing::Lower);
+        assert_eq!(casing("USERS"), Casing::Upper);
+        assert_eq!(casing("Users"), Casing::Title);
+        assert_eq!(casing("iPhones"), Casing::Mixed);
+        assert_eq!(casing("McDonalds"), Casing::Mixed);
+        assert_eq!(casing("U"), Casing::Title);
+        assert_eq!(casing(""), Casing::Lower);
+        assert_eq!(casing("123"), Casing::Lower);
+        assert_eq!(casing("Ελληνικά"), Casing::Title);
+    }
+
+    #[test]
+    fn test_to_lower() {
+        assert_eq!(to_lower("en", "USERS"), "users");
+        assert_eq!(to_lower("en", "Häuser"), "häuser");
+        assert_eq!(to_lower("el", "ΧΡΉΣΤΕΣ"), "χρήστες");
+    }
+
+    #[test]
+    fn test_to_lower_turkic() {
+        assert_eq!(to_lower("tr", "KIZLAR"), "kızlar");
+        assert_eq!(to_lower("tr", "İLLER"), "iller");
+        assert_eq!(to_lower("az", "İNSANLAR"), "insanlar");
+        assert_eq!(to_lower("en", "KIZLAR"), "kizlar");
+    }
+
+    #[test]
+    fn test_apply_casing() {
+        assert_eq!(apply_casing("en", "USERS", Casing::Upper, "user"), "USER");
+        assert_eq!(apply_casing("en", "Users", Casing::Title, "user"), "User");
+        assert_eq!(
+            apply_casing("en", "McDonalds", Casing::Mixed, "mcdonald"),
+            "McDonald"
+        );
+        assert_eq!(
+            apply_casing("en", "iPhone", Casing::Mixed, "iphones"),
+            "iPhones"
+        );
+        assert_eq!(
+            apply_casing("en", "ABCd", Casing::Mixed, "abcdes"),
+            "ABCdes"
+        );
+        assert_eq!(apply_casing("en", "users", Casing::Lower, "user"), "user");
+    }
+
+    #[test]
+    fn test_apply_casing_turkic() {
+        assert_eq!(apply_casing("tr", "KIZLAR", Casing::Upper, "kız"), "KIZ");
+        assert_eq!(apply_casing("tr", "İLLER", Casing::Upper, "il"), "İL");
+        assert_eq!(apply_casing("tr", "İller", Casing::Title, "il"), "İl");
+        assert_eq!(apply_casing("en", "ILLS", Casing::Upper, "ill"), "ILL");
+    }
+
+    #[test]
+    fn test_inflect_cased() {
+        assert!(matches!(
+            inflect_cased("en", "users", strip_s),
+            Cow::Borrowed("user")
+        ));
+        assert_eq!(inflect_cased("en", "USERS", strip_s), "USER");
+        assert_eq!(inflect_cased("en", "Users", strip_s), "User");
+        assert!(matches!(
+            inflect_cased("en", "User", identity),
+            Cow::Borrowed("User")
+        ));
+    }
+
+    #[test]
+    fn test_pluralize_cased() {
+        assert_eq!(pluralize_cased("en", "user", append_s), vec!["users"]);
+        assert_eq!(pluralize_cased("en", "USER", append_s), vec!["USERS"]);
+        assert_eq!(pluralize_cased("en", "User", append_s), vec!["Users"]);
+    }
+
+    #[test]
+    fn test_candidates_cased() {
+        assert_eq!(
+            candidates_cased("en", "user", ranked),
+            vec!["users", "user"]
+        );
+        let result = candidates_cased("en", "User", ranked);
+        assert_eq!(result, vec!["Users", "User"]);
+        assert_eq!(result[0].confidence(), Confidence::Likely);
+        assert_eq!(result[1].confidence(), Confidence::Possible);
+        assert!(matches!(
+            result[1].clone().into_value(),
+            Cow::Borrowed("User")
+        ));
+    }
+}
@@ -0,0 +1,396 @@
+//! Catalogue of the supported languages.
+//!
+//! Lists every language in the registry together with its English name, endonym and scripts, and
+//! states how far its rules can be trusted: some languages have rules of their own, others borrow
+//! the rules of a related language, and languages without a morphological plural return words
+//! unchanged.
+
+use crate::error::{Error, Result};
+use crate::language_rules::LanguageRuleSet;
+use crate::locale::LanguageTag;
+use crate::registry;
+
+use QualityTier::{Delegated, Identity, Native};
+
+/// How closely the rules of a language model its inflection.
+///
+/// Variants are ordered from least to most trustworthy.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum QualityTier {
+    /// The language does not mark plurality on nouns, or its rules are not implemented, and words
+    /// are returned unchanged.
+    Identity,
+    /// The language borrows the rules of a related language (Galician uses the Portuguese rules),
+    /// which only approximate its inflection.
+    Delegated,
+    /// The language has rules of its own.
+    Native,
+}
+
+/// Metadata about a supported language.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_inflector::{language_info, supported_languages, QualityTier};
+///
+/// # fn main() -> i18n_inflector::Result<()> {
+/// let info = language_info("ko-KR")?;
+/// assert_eq!(info.name(), "Korean");
+/// assert_eq!(info.endonym(), "한국어");
+/// assert_eq!(info.delegate(), Some("ja"));
+/// assert_eq!(info.tier(), QualityTier::Identity);
+///
+/// let native = supported_languages()
+///     .iter()
+///     .filter(|info| info.tier() == QualityTier::Native);
+/// assert!(native.map(|info| info.code()).any(|code| code == "sv"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LanguageInfo {
+    code: &'static str,
+    name: &'static str,
+    endonym: &'static str,
+    scripts: &'static [&'static str],
+    delegate: Option<&'static str>,
+    tier: QualityTier,
+}
+
+impl LanguageInfo {
+    /// Returns the ISO 639-1 language code.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the English name of the language.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the name of the language in the language itself.
+    #[must_use]
+    pub fn endonym(&self) -> &'static str {
+        self.endonym
+    }
+
+    /// Returns the ISO 15924 codes of the scripts the language is written in, most common first.
+    ///
+    /// Delegated rules may only handle the scripts of the language they are borrowed from.
+    #[must_use]
+    pub fn scripts(&self) -> &'static [&'static str] {
+        self.scripts
+    }
+
+    /// Returns the code of the language whose rules are used, or `None` if the language has rules
+    /// of its own.
+    #[must_use]
+    pub fn delegate(&self) -> Option<&'static str> {
+        self.delegate
+    }
+
+    /// Returns how closely the rules model the inflection of the language.
+    #[must_use]
+    pub fn tier(&self) -> QualityTier {
+        self.tier
+    }
+
+    /// Returns the rule set of the language.
+    #[must_use]
+    pub fn rules(&self) -> &'static LanguageRuleSet {
+        registry::LANGUAGE_RULES_MAP[self.code]
+    }
+}
+
+/// Builds the catalogue from `code => (name, endonym, [scripts], delegate, tier)` entries.
+macro_rules! catalogue {
+    ($(
+        $code:literal => (
+            $name:literal, $endonym:literal, [$($script:literal),+], $delegate:expr, $tier:ident
+        ),
+    )*) => {
+        &[$(LanguageInfo {
+            code: $code,
+            name: $name,
+            endonym: $endonym,
+            scripts: &[$($script),+],
+            delegate: $delegate,
+            tier: $tier,
+        }),*]
+    };
+}
+
+/// All supported languages, sorted by code.
+static CATALOGUE: &[LanguageInfo] = catalogue! {
+    "aa" => ("Afar", "Qafaraf", ["Latn"], Some("ja"), Identity),
+    "ab" => ("Abkhaz", "аԥсуа бызшәа", ["Cyrl"], Some("ja"), Identity),
+    "ae" => ("Avestan", "avesta", ["Avst"], Some("ja"), Identity),
+    "af" => ("Afrikaans", "Afrikaans", ["Latn"], None, Native),
+    "ak" => ("Akan", "Akan", ["Latn"], Some("ja"), Identity),
+    "am" => ("Amharic", "አማርኛ", ["Ethi"], None, Native),
+    "an" => ("Aragonese", "aragonés", ["Latn"], Some("es"), Delegated),
+    "ar" => ("Arabic", "العربية", ["Arab"], None, Native),
+    "as" => ("Assamese", "অসমীয়া", ["Beng"], Some("hi"), Delegated),
+    "av" => ("Avar", "авар мацӀ", ["Cyrl"], Some("ja"), Identity),
+    "ay" => ("Aymara", "aymar aru", ["Latn"], None, Native),
+    "az" => ("Azerbaijani", "azərbaycan dili", ["Latn", "Arab"], Some("tr"), Delegated),
+    "ba" => ("Bashkir", "башҡорт теле", ["Cyrl"], Some("tr"), Delegated),
+    "be" => ("Belarusian", "беларуская мова", ["Cyrl", "Latn"], None, Native),
+    "bg" => ("Bulgarian", "български език", ["Cyrl"], Some("hr"), Delegated),
+    "bi" => ("Bislama", "Bislama", ["Latn"], Some("ja"), Identity),
+    "bm" => ("Bambara", "bamanankan", ["Latn", "Nkoo"], Some("ja"), Identity),
+    "bn" => ("Bengali", "বাংলা", ["Beng"], None, Native),
+    "bo" => ("Tibetan", "བོད་ཡིག", ["Tibt"], Some("ja"), Identity),
+    "br" => ("Breton", "brezhoneg", ["Latn"], None, Native),
+    "bs" => ("Bosnian", "bosanski jezik", ["Latn", "Cyrl"], Some("hr"), Delegated),
+    "ca" => ("Catalan", "català", ["Latn"], None, Native),
+    "ce" => ("Chechen", "нохчийн мотт", ["Cyrl"], Some("ja"), Identity),
+    "ch" => ("Chamorro", "Chamoru", ["Latn"], Some("ja"), Identity),
+    "co" => ("Corsican", "corsu", ["Latn"], Some("it"), Delegated),
+    "cs" => ("Czech", "čeština", ["Latn"], None, Native),
+    "cu" => ("Church Slavonic", "ѩзыкъ словѣньскъ", ["Cyrs", "Glag"], Some("ja"), Identity),
+    "cv" => ("Chuvash", "чӑваш чӗлхи", ["Cyrl"], Some("tr"), Delegated),
+    "cy" => ("Welsh", "Cymraeg", ["Latn"], None, Native),
+    "da" => ("Danish", "dansk", ["Latn"], None, Native),
+    "de" => ("German", "Deutsch", ["Latn"], None, Native),
+    "dv" => ("Divehi", "ދިވެހި", ["Thaa"], Some("hi"), Delegated),
+    "dz" => ("Dzongkha", "རྫོང་ཁ", ["Tibt"], Some("ja"), Identity),
+    "ee" => ("Ewe", "Eʋegbe", ["Latn"], Some("ja"), Identity),
+    "el" => ("Greek", "ελληνικά", ["Grek"], None, Native),
+    "en" => ("English", "English", ["Latn"], None, Native),
+    "eo" => ("Esperanto", "Esperanto", ["Latn"], None, Native),
+    "es" => ("Spanish", "español", ["Latn"], None, Native),
+    "et" => ("Estonian", "eesti keel", ["Latn"], None, Native),
+    "eu" => ("Basque", "euskara", ["Latn"], None, Native),
+    "fa" => ("Persian", "فارسی", ["Arab"], None, Native),
+    "ff" => ("Fula", "Fulfulde", ["Latn", "Adlm"], Some("ja"), Identity),
+    "fi" => ("Finnish", "suomi", ["Latn"], None, Native),
+    "fj" => ("Fijian", "vosa Vakaviti", ["Latn"], Some("ja"), Identity),
+    "fo" => ("Faroese", "føroyskt", ["Latn"], Some("is"), Delegated),
+    "fr" => ("French", "français", ["Latn"], None, Native),
+    "fy" => ("Western Frisian", "Frysk", ["Latn"], Some("nl"), Delegated),
+    "ga" => ("Irish", "Gaeilge", ["Latn"], None, Native),
+    "gd" => ("Scottish Gaelic", "Gàidhlig", ["Latn"], Some("ga"), Delegated),
+    "gl" => ("Galician", "galego", ["Latn"], Some("pt"), Delegated),
+    "gn" => ("Guarani", "avañe'ẽ", ["Latn"], None, Native),
+    "gu" => ("Gujarati", "ગુજરાતી", ["Gujr"], None, Native),
+    "gv" => ("Manx", "Gaelg", ["Latn"], Some("ga"), Delegated),
+    "ha" => ("Hausa", "Hausa", ["Latn", "Arab"], None, Native),
+    "he" => ("Hebrew", "עברית", ["Hebr"], Some("ar"), Delegated),
+    "hi" => ("Hindi", "हिन्दी", ["Deva"], None, Native),
+    "ho" => ("Hiri Motu", "Hiri Motu", ["Latn"], Some("ja"), Identity),
+    "hr" => ("Croatian", "hrvatski jezik", ["Latn"], None, Native),
+    "ht" => ("Haitian Creole", "Kreyòl ayisyen", ["Latn"], Some("ja"), Identity),
+    "hu" => ("Hungarian", "magyar", ["Latn"], None, Native),
+    "hy" => ("Armenian", "հայերեն", ["Armn"], None, Native),
+    "ia" => ("Interlingua", "Interlingua", ["Latn"], None, Native),
+    "id" => ("Indonesian", "Bahasa Indonesia", ["Latn"], Some("ja"), Identity),
+    "ie" => ("Interlingue", "Interlingue", ["Latn"], Some("ia"), Delegated),
+    "ig" => ("Igbo", "Asụsụ Igbo", ["Latn"], Some("ja"), Identity),
+    "ii" => ("Sichuan Yi", "ꆈꌠꉙ", ["Yiii"], Some("ja"), Identity),
+    "ik" => ("Inupiaq", "Iñupiatun", ["Latn"], Some("ja"), Identity),
+    "is" => ("Icelandic", "íslenska", ["Latn"], None, Native),
+    "it" => ("Italian", "italiano", ["Latn"], None, Native),
+    "iu" => ("Inuktitut", "ᐃᓄᒃᑎᑐᑦ", ["Cans", "Latn"], Some("ja"), Identity),
+    "ja" => ("Japanese", "日本語", ["Jpan"], None, Identity),
+    "jv" => ("Javanese", "basa Jawa", ["Latn", "Java"], Some("ja"), Identity),
+    "ka" => ("Georgian", "ქართული", ["Geor"], Some("ja"), Identity),
+    "kg" => ("Kongo", "Kikongo", ["Latn"], Some("sw"), Delegated),
+    "ki" => ("Kikuyu", "Gĩkũyũ", ["Latn"], Some("sw"), Delegated),
+    "kj" => ("Kuanyama", "Oshikwanyama", ["Latn"], Some("sw"), Delegated),
+    "kk" => ("Kazakh", "қазақ тілі", ["Cyrl", "Latn"], Some("tr"), Delegated),
+    "km" => ("Khmer", "ខ្មែរ", ["Khmr"], Some("ja"), Identity),
+    "kn" => ("Kannada", "ಕನ್ನಡ", ["Knda"], None, Native),
+    "ko" => ("Korean", "한국어", ["Kore"], Some("ja"), Identity),
+    "ku" => ("Kurdish", "Kurdî", ["Latn", "Arab"], None, Native),
+    "kv" => ("Komi", "коми кыв", ["Cyrl"], Some("ja"), Identity),
+    "kw" => ("Cornish", "Kernewek", ["Latn"], Some("cy"), Delegated),
+    "ky" => ("Kyrgyz", "кыргызча", ["Cyrl"], Some("tr"), Delegated),
+    "la" => ("Latin", "latine", ["Latn"], None, Native),
+    "lb" => ("Luxembourgish", "Lëtzebuergesch", ["Latn"], Some("de"), Delegated),
+    "lg" => ("Luganda", "Luganda", ["Latn"], Some("sw"), Delegated),
+    "li" => ("Limburgish", "Limburgs", ["Latn"], Some("nl"), Delegated),
+    "lo" => ("Lao", "ລາວ", ["Laoo"], Some("ja"), Identity),
+    "lt" => ("Lithuanian", "lietuvių kalba", ["Latn"], None, Native),
+    "lu" => ("Luba-Katanga", "Kiluba", ["Latn"], Some("sw"), Delegated),
+    "lv" => ("Latvian", "latviešu valoda", ["Latn"], None, Native),
+    "mg" => ("Malagasy", "fiteny malagasy", ["Latn"], Some("ja"), Identity),
+    "mi" => ("Māori", "te reo Māori", ["Latn"], Some("ja"), Identity),
+    "mk" => ("Macedonian", "македонски јазик", ["Cyrl"], Some("hr"), Delegated),
+    "ml" => ("Malayalam", "മലയാളം", ["Mlym"], None, Native),
+    "mn" => ("Mongolian", "монгол хэл", ["Cyrl", "Mong"], None, Native),
+    "mr" => ("Marathi", "मराठी", ["Deva"], Some("hi"), Delegated),
+    "ms" => ("Malay", "bahasa Melayu", ["Latn", "Arab"], Some("ja"), Identity),
+    "mt" => ("Maltese", "Malti", ["Latn"], None, Native),
+    "my" => ("Burmese", "မြန်မာဘာသာ", ["Mymr"], Some("ja"), Identity),
+    "nb" => ("Norwegian Bokmål", "norsk bokmål", ["Latn"], None, Native),
+    "nd" => ("Northern Ndebele", "isiNdebele", ["Latn"], Some("zu"), Delegated),
+    "ne" => ("Nepali", "नेपाली", ["Deva"], Some("hi"), Delegated),
+    "nl" => ("Dutch", "Nederlands", ["Latn"], None, Native),
+    "nn" => ("Norwegian Nynorsk", "norsk nynorsk", ["Latn"], None, Native),
+    "no" => ("Norwegian", "norsk", ["Latn"], Some("nb"), Delegated),
+    "nr" => ("Southern Ndebele", "isiNdebele", ["Latn"], Some("zu"), Delegated),
+    "nv" => ("Navajo", "Diné bizaad", ["Latn"], Some("ja"), Identity),
+    "ny" => ("Chichewa", "Chichewa", ["Latn"], Some("sw"), Delegated),
+    "oc" => ("Occitan", "occitan", ["Latn"], None, Native),
+    "oj" => ("Ojibwe", "ᐊᓂᔑᓈᐯᒧᐎᓐ", ["Cans", "Latn"], Some("ja"), Identity),
+    "om" => ("Oromo", "Afaan Oromoo", ["Latn"], Some("so"), Delegated),
+    "or" => ("Odia", "ଓଡ଼ିଆ", ["Orya"], Some("hi"), Delegated),
+    "os" => ("Ossetian", "ирон æвзаг", ["Cyrl"], Some("fa"), Delegated),
+    "pa" => ("Punjabi", "ਪੰਜਾਬੀ", ["Guru", "Arab"], Some("hi"), Delegated),
+    "pi" => ("Pali", "pāli", ["Deva", "Latn"], Some("ja"), Identity),
+    "pl" => ("Polish", "polski", ["Latn"], None, Native),
+    "ps" => ("Pashto", "پښتو", ["Arab"], None, Native),
+    "pt" => ("Portuguese", "português", ["Latn"], None, Native),
+    "qu" => ("Quechua", "Runa Simi", ["Latn"], None, Native),
+    "rm" => ("Romansh", "rumantsch", ["Latn"], None, Native),
+    "ro" => ("Romanian", "română", ["Latn"], None, Native),
+    "ru" => ("Russian", "русский", ["Cyrl"], None, Native),
+    "rw" => ("Kinyarwanda", "Ikinyarwanda", ["Latn"], Some("sw"), Delegated),
+    "sa" => ("Sanskrit", "संस्कृतम्", ["Deva"], Some("ja"), Identity),
+    "sc" => ("Sardinian", "sardu", ["Latn"], Some("it"), Delegated),
+    "sd" => ("Sindhi", "سنڌي", ["Arab", "Deva"], Some("hi"), Delegated),
+    "se" => ("Northern Sami", "davvisámegiella", ["Latn"], Some("ja"), Identity),
+    "sg" => ("Sango", "yângâ tî sängö", ["Latn"], Some("ja"), Identity),
+    "si" => ("Sinhala", "සිංහල", ["Sinh"], Some("hi"), Delegated),
+    "sk" => ("Slovak", "slovenčina", ["Latn"], None, Native),
+    "sl" => ("Slovenian", "slovenščina", ["Latn"], Some("hr"), Delegated),
+    "sm" => ("Samoan", "gagana Samoa", ["Latn"], Some("ja"), Identity),
+    "sn" => ("Shona", "chiShona", ["Latn"], Some("sw"), Delegated),
+    "so" => ("Somali", "Soomaaliga", ["Latn"], None, Native),
+    "sq" => ("Albanian", "shqip", ["Latn"], None, Native),
+    "sr" => ("Serbian", "српски", ["Cyrl", "Latn"], None, Native),
+    "ss" => ("Swati", "siSwati", ["Latn"], Some("zu"), Delegated),
+    "st" => ("Southern Sotho", "Sesotho", ["Latn"], Some("zu"), Delegated),
+    "su" => ("Sundanese", "basa Sunda", ["Latn"], Some("ja"), Identity),
+    "sv" => ("Swedish", "svenska", ["Latn"], None, Native),
+    "sw" => ("Swahili", "Kiswahili", ["Latn"], None, Native),
+    "ta" => ("Tamil", "தமிழ்", ["Taml"], None, Native),
+    "te" => ("Telugu", "తెలుగు", ["Telu"], None, Native),
+    "tg" => ("Tajik", "тоҷикӣ", ["Cyrl"], Some("fa"), Delegated),
+    "th" => ("Thai", "ไทย", ["Thai"], Some("ja"), Identity),
+    "ti" => ("Tigrinya", "ትግርኛ", ["Ethi"], Some("am"), Delegated),
+    "tk" => ("Turkmen", "Türkmençe", ["Latn"], Some("tr"), Delegated),
+    "tl" => ("Tagalog", "Tagalog", ["Latn"], Some("ja"), Identity),
+    "tn" => ("Tswana", "Setswana", ["Latn"], Some("zu"), Delegated),
+    "tr" => ("Turkish", "Türkçe", ["Latn"], None, Native),
+    "ts" => ("Tsonga", "Xitsonga", ["Latn"], Some("zu"), Delegated),
+    "tt" => ("Tatar", "татар теле", ["Cyrl"], Some("tr"), Delegated),
+    "ug" => ("Uyghur", "ئۇيغۇرچە", ["Arab"], Some("tr"), Delegated),
+    "uk" => ("Ukrainian", "українська", ["Cyrl"], None, Native),
+    "ur" => ("Urdu", "اردو", ["Arab"], Some("hi"), Delegated),
+    "uz" => ("Uzbek", "oʻzbekcha", ["Latn", "Cyrl"], Some("tr"), Delegated),
+    "ve" => ("Venda", "Tshivenḓa", ["Latn"], Some("zu"), Delegated),
+    "vi" => ("Vietnamese", "Tiếng Việt", ["Latn"], Some("ja"), Identity),
+    "wa" => ("Walloon", "walon", ["Latn"], None, Native),
+    "wo" => ("Wolof", "Wolof", ["Latn"], Some("ja"), Identity),
+    "xh" => ("Xhosa", "isiXhosa", ["Latn"], Some("zu"), Delegated),
+    "yi" => ("Yiddish", "ייִדיש", ["Hebr"], Some("ar"), Delegated),
+    "yo" => ("Yoruba", "Èdè Yorùbá", ["Latn"], Some("ja"), Identity),
+    "zh" => ("Chinese", "中文", ["Hans", "Hant"], Some("ja"), Identity),
+    "zu" => ("Zulu", "isiZulu", ["Latn"], None, Native),
+};
+
+/// Returns all supported languages, sorted by code.
+pub(crate) fn all() -> &'static [LanguageInfo] {
+    CATALOGUE
+}
+
+/// Returns the catalogue entry of the language of `locale`.
+pub(crate) fn lookup(locale: &str) -> Result<&'static LanguageInfo> {
+    let tag = LanguageTag::parse(locale)?;
+    CATALOGUE
+        .binary_search_by_key(&tag.language(), |info| info.code)
+        .map(|index| &CATALOGUE[index])
+        .map_err(|_| Error::UnsupportedLanguage {
+            locale: locale.into(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language_rules::LanguageRules;
+
+    #[test]
+    fn test_catalogue_matches_registry() {
+        assert_eq!(CATALOGUE.len(), registry::LANGUAGE_RULES_MAP.len());
+        assert!(CATALOGUE.windows(2).all(|pair| pair[0].code < pair[1].code));
+        for info in CATALOGUE {
+            assert_eq!(info.rules().language(), info.code);
+        }
+    }
+
+    #[test]
+    fn test_scripts() {
+        for info in CATALOGUE {
+            assert!(!info.scripts.is_empty(), "{}", info.code);
+            for script in info.scripts {
+                let tag = alloc::format!("{}-{script}", info.code);
+                assert!(LanguageTag::parse(&tag).is_ok(), "{tag}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_tiers() {
+        let words = [
+            "users",
+            "user",
+            "gatti",
+            "casas",
+            "книги",
+            "Häuser",
+            "kitaplar",
+        ];
+        for info in CATALOGUE {
+            let rules = info.rules();
+            let identity = words
+                .iter()
+                .all(|word| rules.singularize(word) == *word && rules.pluralize(word) == [*word]);
+            assert_eq!(info.tier == Identity, identity, "{}", info.code);
+            assert_eq!(
+                info.tier == Delegated,
+                info.delegate.is_some_and(|delegate| delegate != "ja"),
+                "{}",
+                info.code
+            );
+            if let Some(delegate) = info.delegate {
+                let delegate = registry::LANGUAGE_RULES_MAP[delegate];
+                for word in words {
+                    assert_eq!(
+                        rules.singularize(word),
+                        delegate.singularize(word),
+                        "{}",
+                        info.code
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        let info = lookup("gl-ES").unwrap();
+        assert_eq!(info.name(), "Galician");
+        assert_eq!(info.delegate(), Some("pt"));
+        assert_eq!(info.tier(), Delegated);
+        assert_eq!(lookup("sv").unwrap().tier(), Native);
+        assert_eq!(lookup("swe").unwrap().endonym(), "svenska");
+        assert_eq!(lookup("ur").unwrap().delegate(), Some("hi"));
+        assert_eq!(lookup("ka").unwrap().tier(), Identity);
+        assert_eq!(
+            lookup("xx").unwrap_err(),
+            Error::UnsupportedLanguage {
+                locale: "xx".into()
+            }
+        );
+        assert!(matches!(
+            lookup("en-"),
+            Err(Error::MalformedLocaleTag { .. })
+        ));
+    }
+}
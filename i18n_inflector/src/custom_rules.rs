@@ -0,0 +1,688 @@
+//! User-extensible rule sets.
+//!
+//! A [`CustomRuleSet`] wraps a built-in [`LanguageRuleSet`] and layers user-supplied irregulars,
+//! uncountables and suffix rules on top of it. User entries always take priority over the
+//! built-in rules, and suffix rules added later take priority over those added earlier.
+
+use alloc::borrow::Cow;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::candidate::{Candidate, Confidence};
+use crate::case;
+use crate::error::{Error, Result, RuleLocation};
+use crate::explain::{self, Explanation, Rule, RuleOrigin, Step};
+use crate::identifier::HeadPosition;
+use crate::language_rules::{self, LanguageRuleSet, LanguageRules};
+use crate::plural_rules::{PluralCategory, PluralOperands};
+
+/// A suffix replacement rule: words ending in `suffix` have it replaced by `replacement`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct SuffixRule {
+    suffix: String,
+    replacement: String,
+}
+
+impl SuffixRule {
+    /// Applies the rule to a word, returning `None` if the word does not end in the suffix.
+    fn apply(&self, word: &str) -> Option<String> {
+        word.strip_suffix(self.suffix.as_str())
+            .map(|stem| format!("{stem}{}", self.replacement))
+    }
+}
+
+/// An owned rule set that layers user-supplied rules on top of a built-in [`LanguageRuleSet`].
+///
+/// Lookups are performed in the following order, and the first match wins:
+///
+/// 1. user uncountables
+/// 2. user irregulars
+/// 3. user suffix rules, most recently added first
+/// 4. the built-in rule set
+///
+/// # Examples
+///
+/// ```
+/// use i18n_inflector::{language_rules, CustomRuleSet, LanguageRules};
+///
+/// # fn main() -> i18n_inflector::Result<()> {
+/// let rules = CustomRuleSet::builder(language_rules("en")?)
+///     .irregular("schema", "schemata")
+///     .irregular("datum", "data")
+///     .uncountable("metadata")
+///     .build()?;
+///
+/// assert_eq!(rules.pluralize("schema"), vec!["schemata"]);
+/// assert_eq!(rules.singularize("Data"), "Datum");
+/// assert_eq!(rules.singularize("metadata"), "metadata");
+/// // Everything else falls through to the built-in English rules
+/// assert_eq!(rules.singularize("users"), "user");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CustomRuleSet {
+    base: &'static LanguageRuleSet,
+    /// Irregular singular -> plural mappings.
+    plurals: BTreeMap<String, String>,
+    /// Irregular plural -> singular mappings.
+    singulars: BTreeMap<String, String>,
+    uncountables: BTreeSet<String>,
+    /// Suffix rules applied when pluralizing, in the order they were added.
+    plural_rules: Vec<SuffixRule>,
+    /// Suffix rules applied when singularizing, in the order they were added.
+    singular_rules: Vec<SuffixRule>,
+    head_position: Option<HeadPosition>,
+}
+
+impl CustomRuleSet {
+    /// Returns a builder for a custom rule set layered on top of `base`.
+    #[must_use]
+    pub fn builder(base: &'static LanguageRuleSet) -> CustomRuleSetBuilder {
+        CustomRuleSetBuilder {
+            base,
+            irregulars: Vec::new(),
+            uncountables: Vec::new(),
+            plural_rules: Vec::new(),
+            singular_rules: Vec::new(),
+            head_position: None,
+        }
+    }
+
+    /// Returns the built-in rule set this rule set is layered on.
+    #[must_use]
+    pub fn base(&self) -> &'static LanguageRuleSet {
+        self.base
+    }
+
+    /// Returns the user-supplied singular of a lowercase word, if any.
+    fn user_singular<'a>(&self, word: &'a str) -> Option<Cow<'a, str>> {
+        if self.uncountables.contains(word) || self.plurals.contains_key(word) {
+            return Some(Cow::Borrowed(word));
+        }
+        if let Some(singular) = self.singulars.get(word) {
+            return Some(Cow::Owned(singular.clone()));
+        }
+        self.singular_rules
+            .iter()
+            .rev()
+            .find_map(|rule| rule.apply(word))
+            .map(Cow::Owned)
+    }
+
+    /// Singularizes a lowercase word.
+    fn singularize_lower<'a>(&self, word: &'a str) -> Cow<'a, str> {
+        self.user_singular(word)
+            .unwrap_or_else(|| self.base.singularize(word))
+    }
+
+    /// Returns the ranked singular candidates of a lowercase word.
+    fn singularize_candidates_lower<'a>(&self, word: &'a str) -> Vec<Candidate<'a>> {
+        match self.user_singular(word) {
+            Some(singular) => vec![Candidate::new(singular, Confidence::Certain)],
+            None => self.base.singularize_candidates(word),
+        }
+    }
+
+    /// Records the user rules consulted for a lowercase word and returns the form given by the
+    /// one that fired, if any. `inflected` maps words to their irregular form in the direction of
+    /// the inflection, and `kept` holds irregular forms that already are in that number.
+    fn trace_user_rules<'a>(
+        &self,
+        word: &'a str,
+        inflected: &BTreeMap<String, String>,
+        kept: &BTreeMap<String, String>,
+        rules: &[SuffixRule],
+        steps: &mut Vec<Step>,
+    ) -> Option<Cow<'a, str>> {
+        if !self.uncountables.is_empty() {
+            let matched = self.uncountables.contains(word);
+            steps.push(Step::new(Rule::Uncountable, RuleOrigin::User, matched));
+            if matched {
+                return Some(Cow::Borrowed(word));
+            }
+        }
+        if !inflected.is_empty() {
+            let form = if kept.contains_key(word) {
+                Some(Cow::Borrowed(word))
+            } else {
+                inflected.get(word).map(|form| Cow::Owned(form.clone()))
+            };
+            steps.push(Step::new(Rule::Irregular, RuleOrigin::User, form.is_some()));
+            if form.is_some() {
+                return form;
+            }
+        }
+        for rule in rules.iter().rev() {
+            let form = rule.apply(word);
+            let suffix_rule = Rule::Suffix {
+                suffix: rule.suffix.clone(),
+                replacement: rule.replacement.clone(),
+            };
+            steps.push(Step::new(suffix_rule, RuleOrigin::User, form.is_some()));
+            if let Some(form) = form {
+                return Some(Cow::Owned(form));
+            }
+        }
+        None
+    }
+
+    /// Returns the user-supplied plural of a lowercase word, if any.
+    fn user_plural<'a>(&self, word: &'a str) -> Option<Cow<'a, str>> {
+        if self.uncountables.contains(word) || self.singulars.contains_key(word) {
+            return Some(Cow::Borrowed(word));
+        }
+        if let Some(plural) = self.plurals.get(word) {
+            return Some(Cow::Owned(plural.clone()));
+        }
+        self.plural_rules
+            .iter()
+            .rev()
+            .find_map(|rule| rule.apply(word))
+            .map(Cow::Owned)
+    }
+
+    /// Returns the plural forms of a lowercase word.
+    fn pluralize_lower<'a>(&self, word: &'a str) -> Vec<Cow<'a, str>> {
+        match self.user_plural(word) {
+            Some(plural) => vec![plural],
+            None => self.base.pluralize(word),
+        }
+    }
+
+    /// Returns the ranked plural candidates of a lowercase word.
+    fn pluralize_candidates_lower<'a>(&self, word: &'a str) -> Vec<Candidate<'a>> {
+        match self.user_plural(word) {
+            Some(plural) => vec![Candidate::new(plural, Confidence::Certain)],
+            None => self.base.pluralize_candidates(word),
+        }
+    }
+}
+
+impl LanguageRules for CustomRuleSet {
+    fn language(&self) -> &'static str {
+        self.base.language()
+    }
+
+    fn locale(&self) -> &'static str {
+        self.base.locale()
+    }
+
+    fn singularize<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        case::inflect_cased(self.language(), name, |word| self.singularize_lower(word))
+    }
+
+    fn singularize_candidates<'a>(&self, name: &'a str) -> Vec<Candidate<'a>> {
+        case::candidates_cased(self.language(), name, |word| {
+            self.singularize_candidates_lower(word)
+        })
+    }
+
+    fn pluralize<'a>(&self, name: &'a str) -> Vec<Cow<'a, str>> {
+        case::pluralize_cased(self.language(), name, |word| self.pluralize_lower(word))
+    }
+
+    fn pluralize_candidates<'a>(&self, name: &'a str) -> Vec<Candidate<'a>> {
+        case::candidates_cased(self.language(), name, |word| {
+            self.pluralize_candidates_lower(word)
+        })
+    }
+
+    fn explain_singularize<'a>(&self, name: &'a str) -> Explanation<Cow<'a, str>> {
+        let mut steps = Vec::new();
+        let output = case::inflect_cased(self.language(), name, |word| {
+            let user = self.trace_user_rules(
+                word,
+                &self.singulars,
+                &self.plurals,
+                &self.singular_rules,
+                &mut steps,
+            );
+            user.unwrap_or_else(|| explain::trace_singularize(self.base, word, &mut steps))
+        });
+        Explanation::new(output, steps)
+    }
+
+    fn explain_pluralize<'a>(&self, name: &'a str) -> Explanation<Vec<Cow<'a, str>>> {
+        let mut steps = Vec::new();
+        let output = case::pluralize_cased(self.language(), name, |word| {
+            let user = self.trace_user_rules(
+                word,
+                &self.plurals,
+                &self.singulars,
+                &self.plural_rules,
+                &mut steps,
+            );
+            match user {
+                Some(plural) => vec![plural],
+                None => explain::trace_pluralize(self.base, word, &mut steps),
+            }
+        });
+        Explanation::new(output, steps)
+    }
+
+    fn inflect_for_count<'a, N: Into<PluralOperands>>(&self, word: &'a str, n: N) -> Cow<'a, str> {
+        let category = self.plural_category(n);
+        case::inflect_cased(self.language(), word, |word| {
+            let singular = self.singularize_lower(word);
+            language_rules::inflect_derived(singular, |singular| match self.user_plural(singular) {
+                Some(plural) if category != PluralCategory::One => plural,
+                Some(_) => Cow::Borrowed(singular),
+                None => self.base.count_form(singular, category),
+            })
+        })
+    }
+
+    fn head_position(&self) -> HeadPosition {
+        self.head_position
+            .unwrap_or_else(|| self.base.head_position())
+    }
+}
+
+/// A builder for [`CustomRuleSet`].
+#[derive(Clone, Debug)]
+pub struct CustomRuleSetBuilder {
+    base: &'static LanguageRuleSet,
+    irregulars: Vec<(String, String)>,
+    uncountables: Vec<String>,
+    plural_rules: Vec<(String, String)>,
+    singular_rules: Vec<(String, String)>,
+    head_position: Option<HeadPosition>,
+}
+
+impl CustomRuleSetBuilder {
+    /// Adds an irregular noun that is used in both directions (e.g. `schema` / `schemata`).
+    #[must_use]
+    pub fn irregular<S: AsRef<str>, P: AsRef<str>>(mut self, singular: S, plural: P) -> Self {
+        self.irregulars
+            .push((singular.as_ref().into(), plural.as_ref().into()));
+        self
+    }
+
+    /// Adds a word that has no distinct singular and plural forms (e.g. `metadata`).
+    #[must_use]
+    pub fn uncountable<S: AsRef<str>>(mut self, word: S) -> Self {
+        self.uncountables.push(word.as_ref().into());
+        self
+    }
+
+    /// Adds a pluralization rule that replaces the `suffix` of a singular word with
+    /// `replacement` (e.g. `("um", "a")` for `datum` -> `data`).
+    #[must_use]
+    pub fn plural_rule<S: AsRef<str>, R: AsRef<str>>(mut self, suffix: S, replacement: R) -> Self {
+        self.plural_rules
+            .push((suffix.as_ref().into(), replacement.as_ref().into()));
+        self
+    }
+
+    /// Adds a singularization rule that replaces the `suffix` of a plural word with
+    /// `replacement` (e.g. `("a", "um")` for `data` -> `datum`).
+    #[must_use]
+    pub fn singular_rule<S: AsRef<str>, R: AsRef<str>>(
+        mut self,
+        suffix: S,
+        replacement: R,
+    ) -> Self {
+        self.singular_rules
+            .push((suffix.as_ref().into(), replacement.as_ref().into()));
+        self
+    }
+
+    /// Overrides the position of the head noun in compound identifiers.
+    #[must_use]
+    pub fn head_position(mut self, position: HeadPosition) -> Self {
+        self.head_position = Some(position);
+        self
+    }
+
+    /// Builds the [`CustomRuleSet`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RuleDefinition`] if an irregular or uncountable word is empty or if a
+    /// suffix rule has an empty suffix, and [`Error::DuplicateRegistration`] if a singular or
+    /// plural is given two different irregular forms.
+    pub fn build(self) -> Result<CustomRuleSet> {
+        let language = self.base.language();
+        let lower = |word: &str| case::to_lower(language, word.trim());
+
+        let mut plurals = BTreeMap::new();
+        let mut singulars = BTreeMap::new();
+        for (index, (raw_singular, raw_plural)) in self.irregulars.iter().enumerate() {
+            let location = RuleLocation::Irregular(index);
+            let (singular, plural) = (lower(raw_singular), lower(raw_plural));
+            if singular.is_empty() || plural.is_empty() {
+                return Err(Error::RuleDefinition {
+                    location,
+                    rule: format!("{raw_singular:?} -> {raw_plural:?}"),
+                });
+            }
+            for (map, key, value) in [
+                (&plurals, &singular, &plural),
+                (&singulars, &plural, &singular),
+            ] {
+                if map.get(key).is_some_and(|existing| existing != value) {
+                    return Err(Error::DuplicateRegistration {
+                        location,
+                        word: key.clone(),
+                    });
+                }
+            }
+            plurals.insert(singular.clone(), plural.clone());
+            singulars.insert(plural, singular);
+        }
+
+        let mut uncountables = BTreeSet::new();
+        for (index, word) in self.uncountables.iter().enumerate() {
+            let lowered = lower(word);
+            if lowered.is_empty() {
+                return Err(Error::RuleDefinition {
+                    location: RuleLocation::Uncountable(index),
+                    rule: format!("{word:?}"),
+                });
+            }
+            uncountables.insert(lowered);
+        }
+
+        let suffix_rules = |rules: &[(String, String)],
+                            location: fn(usize) -> RuleLocation|
+         -> Result<Vec<SuffixRule>> {
+            rules
+                .iter()
+                .enumerate()
+                .map(|(index, (suffix, replacement))| {
+                    let lowered = lower(suffix);
+                    if lowered.is_empty() {
+                        return Err(Error::RuleDefinition {
+                            location: location(index),
+                            rule: format!("{suffix:?} -> {replacement:?}"),
+                        });
+                    }
+                    Ok(SuffixRule {
+                        suffix: lowered,
+                        replacement: lower(replacement),
+                    })
+                })
+                .collect()
+        };
+
+        Ok(CustomRuleSet {
+            base: self.base,
+            plurals,
+            singulars,
+            uncountables,
+            plural_rules: suffix_rules(&self.plural_rules, RuleLocation::PluralRule)?,
+            singular_rules: suffix_rules(&self.singular_rules, RuleLocation::SingularRule)?,
+            head_position: self.head_position,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::languages;
+    use alloc::string::ToString;
+
+    fn english() -> CustomRuleSetBuilder {
+        CustomRuleSet::builder(&languages::en::RULES)
+    }
+
+    #[test]
+    fn test_language() {
+        let rules = english().build().unwrap();
+        assert_eq!(rules.language(), "en");
+        assert_eq!(rules.base().language(), "en");
+    }
+
+    #[test]
+    fn test_falls_through_to_base() {
+        let rules = english().build().unwrap();
+        assert_eq!(rules.singularize("users"), "user");
+        assert_eq!(rules.singularize("children"), "child");
+        assert_eq!(rules.pluralize("child"), vec!["children"]);
+    }
+
+    #[test]
+    fn test_irregular() {
+        let rules = english()
+            .irregular("schema", "schemata")
+            .irregular("datum", "data")
+            .build()
+            .unwrap();
+        assert_eq!(rules.singularize("schemata"), "schema");
+        assert_eq!(rules.singularize("data"), "datum");
+        assert_eq!(rules.pluralize("schema"), vec!["schemata"]);
+        assert_eq!(rules.pluralize("datum"), vec!["data"]);
+        // Known forms are left unchanged
+        assert_eq!(rules.singularize("schema"), "schema");
+        assert_eq!(rules.pluralize("schemata"), vec!["schemata"]);
+    }
+
+    #[test]
+    fn test_irregular_overrides_base() {
+        let rules = english().irregular("person", "persons").build().unwrap();
+        assert_eq!(rules.pluralize("person"), vec!["persons"]);
+        assert_eq!(rules.singularize("persons"), "person");
+    }
+
+    #[test]
+    fn test_uncountable() {
+        let rules = english()
+            .uncountable("news")
+            .uncountable("Equipment")
+            .build()
+            .unwrap();
+        assert_eq!(rules.singularize("news"), "news");
+        assert_eq!(rules.pluralize("equipment"), vec!["equipment"]);
+    }
+
+    #[test]
+    fn test_suffix_rules() {
+        let rules = english()
+            .plural_rule("um", "a")
+            .singular_rule("a", "um")
+            .plural_rule("ium", "ia")
+            .build()
+            .unwrap();
+        assert_eq!(rules.pluralize("medium"), vec!["media"]);
+        assert_eq!(rules.pluralize("stadium"), vec!["stadia"]);
+        assert_eq!(rules.singularize("media"), "medium");
+        assert_eq!(rules.singularize("users"), "user");
+    }
+
+    #[test]
+    fn test_pluralize_candidates() {
+        let rules = english()
+            .irregular("schema", "schemata")
+            .plural_rule("um", "a")
+            .build()
+            .unwrap();
+        assert_eq!(
+            rules.pluralize_candidates("Schema"),
+            vec![Candidate::new("Schemata", Confidence::Certain)]
+        );
+        assert_eq!(
+            rules.pluralize_candidates("medium"),
+            vec![Candidate::new("media", Confidence::Certain)]
+        );
+        assert_eq!(rules.pluralize_best("user"), "users");
+        assert_eq!(rules.pluralize_best("NEWS"), "NEWS");
+    }
+
+    #[test]
+    fn test_singularize_candidates() {
+        let rules = english()
+            .irregular("schema", "schemata")
+            .singular_rule("a", "um")
+            .build()
+            .unwrap();
+        assert_eq!(
+            rules.singularize_candidates("Schemata"),
+            vec![Candidate::new("Schema", Confidence::Certain)]
+        );
+        assert_eq!(
+            rules.singularize_candidates("media"),
+            vec![Candidate::new("medium", Confidence::Certain)]
+        );
+        assert_eq!(
+            rules.singularize_candidates("users"),
+            vec![Candidate::new("user", Confidence::Likely)]
+        );
+    }
+
+    #[test]
+    fn test_preserves_case() {
+        let rules = english().irregular("schema", "schemata").build().unwrap();
+        assert_eq!(rules.singularize("Schemata"), "Schema");
+        assert_eq!(rules.pluralize("SCHEMA"), vec!["SCHEMATA"]);
+    }
+
+    #[test]
+    fn test_identifier() {
+        let rules = english().irregular("schema", "schemata").build().unwrap();
+        assert_eq!(rules.head_position(), HeadPosition::Last);
+        assert_eq!(rules.singularize_identifier("db_schemata"), "db_schema");
+
+        let rules = english()
+            .head_position(HeadPosition::First)
+            .build()
+            .unwrap();
+        assert_eq!(rules.head_position(), HeadPosition::First);
+        assert_eq!(
+            rules.singularize_identifier("users_archive"),
+            "user_archive"
+        );
+    }
+
+    #[test]
+    fn test_inflect_for_count() {
+        let rules = english()
+            .irregular("schema", "schemata")
+            .uncountable("metadata")
+            .build()
+            .unwrap();
+        assert_eq!(rules.inflect_for_count("schemata", 1), "schema");
+        assert_eq!(rules.inflect_for_count("Schema", 2), "Schemata");
+        assert_eq!(rules.inflect_for_count("metadata", 2), "metadata");
+        assert_eq!(rules.inflect_for_count("users", 1), "user");
+        assert_eq!(rules.inflect_for_count("user", 0), "users");
+
+        let rules = CustomRuleSet::builder(crate::language_rules("ru").unwrap())
+            .irregular("человек", "люди")
+            .build()
+            .unwrap();
+        assert_eq!(rules.inflect_for_count("файл", 5), "файлов");
+        assert_eq!(rules.inflect_for_count("человек", 1), "человек");
+    }
+
+    #[test]
+    fn test_explain() {
+        let rules = english()
+            .uncountable("metadata")
+            .irregular("schema", "schemata")
+            .singular_rule("a", "um")
+            .singular_rule("ae", "a")
+            .build()
+            .unwrap();
+
+        let explanation = rules.explain_singularize("Criteria");
+        assert_eq!(explanation.output(), "Criterium");
+        let steps: Vec<_> = explanation
+            .steps()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                "user uncountable word (rejected)",
+                "user irregular noun (rejected)",
+                r#"user suffix rule "ae" -> "a" (rejected)"#,
+                r#"user suffix rule "a" -> "um" (matched)"#,
+            ]
+        );
+
+        let explanation = rules.explain_pluralize("schema");
+        assert_eq!(explanation.output(), &vec!["schemata"]);
+        assert_eq!(explanation.matched().unwrap().origin(), RuleOrigin::User);
+        assert_eq!(explanation.rule(), Some(&Rule::Irregular));
+
+        // Words without a user rule are explained by the built-in rules
+        let explanation = rules.explain_singularize("users");
+        assert_eq!(explanation.output(), "user");
+        let step = explanation.matched().unwrap();
+        assert_eq!(step.origin(), RuleOrigin::BuiltIn);
+        assert_eq!(
+            step.to_string(),
+            r#"built-in suffix rule "s" -> "" (matched)"#
+        );
+        assert!(
+            explanation
+                .rejected()
+                .any(|step| step.origin() == RuleOrigin::User)
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let error = english().irregular("", "data").build().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"invalid irregular rule at index 0: "" -> "data""#
+        );
+        assert_eq!(
+            english()
+                .uncountable("data")
+                .uncountable(" ")
+                .build()
+                .unwrap_err(),
+            Error::RuleDefinition {
+                location: RuleLocation::Uncountable(1),
+                rule: r#"" ""#.into(),
+            }
+        );
+        assert!(english().plural_rule("", "s").build().is_err());
+        assert!(matches!(
+            english().singular_rule("", "").build(),
+            Err(Error::RuleDefinition {
+                location: RuleLocation::SingularRule(0),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_irregulars() {
+        let error = english()
+            .irregular("schema", "schemata")
+            .irregular("Schema", "schemas")
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            Error::DuplicateRegistration {
+                location: RuleLocation::Irregular(1),
+                word: "schema".into(),
+            }
+        );
+        assert!(matches!(
+            english()
+                .irregular("datum", "data")
+                .irregular("date", "data")
+                .build(),
+            Err(Error::DuplicateRegistration { word, .. }) if word == "data"
+        ));
+        // Repeating the same irregular is harmless
+        assert!(
+            english()
+                .irregular("schema", "schemata")
+                .irregular("schema", "schemata")
+                .build()
+                .is_ok()
+        );
+    }
+}
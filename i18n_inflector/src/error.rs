@@ -1,38 +1,154 @@
-use alloc::string::{String, ToString};
+use alloc::string::String;
+use core::fmt;
 
 /// Result type for inflector operations.
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
-/// An error returned when an unsupported locale is provided.
+/// An error returned by inflector operations.
+///
+/// Each variant carries the input that caused it, so that callers can match on the kind of
+/// failure instead of parsing the message. New variants may be added in minor releases.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_inflector::{language_rules, Error};
+///
+/// match language_rules("xx") {
+///     Err(Error::UnsupportedLanguage { locale }) => assert_eq!(locale, "xx"),
+///     _ => unreachable!(),
+/// }
+/// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Error {
-    message: String,
+#[non_exhaustive]
+pub enum Error {
+    /// The locale is a well-formed language tag, but no rule set exists for its language.
+    UnsupportedLanguage {
+        /// The locale as passed by the caller.
+        locale: String,
+    },
+    /// The locale is not a well-formed BCP 47 language tag.
+    MalformedLocaleTag {
+        /// The tag as passed by the caller.
+        tag: String,
+    },
+    /// The script subtag of a language tag is not an ISO 15924 script code.
+    UnknownScript {
+        /// The tag as passed by the caller.
+        tag: String,
+        /// The offending script subtag.
+        script: String,
+    },
+    /// A user-supplied rule is invalid, such as an irregular with an empty word or a suffix rule
+    /// with an empty suffix.
+    RuleDefinition {
+        /// The position of the rule in the builder.
+        location: RuleLocation,
+        /// The rule as passed by the caller.
+        rule: String,
+    },
+    /// A word is registered twice with conflicting rules.
+    DuplicateRegistration {
+        /// The position of the second registration in the builder.
+        location: RuleLocation,
+        /// The word registered twice.
+        word: String,
+    },
+    /// A string is not a valid decimal number for plural category selection.
+    InvalidPluralOperand {
+        /// The string as passed by the caller.
+        value: String,
+    },
 }
 
-impl Error {
-    pub(crate) fn new<S: AsRef<str>>(message: S) -> Error {
-        Error {
-            message: message.as_ref().to_string(),
-        }
-    }
+/// The position of a user-supplied rule in a
+/// [`CustomRuleSetBuilder`](crate::CustomRuleSetBuilder), as the zero-based index among rules of
+/// the same kind in the order they were added.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum RuleLocation {
+    /// An irregular noun added with `irregular`.
+    Irregular(usize),
+    /// An uncountable word added with `uncountable`.
+    Uncountable(usize),
+    /// A suffix rule added with `plural_rule`.
+    PluralRule(usize),
+    /// A suffix rule added with `singular_rule`.
+    SingularRule(usize),
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
-impl core::fmt::Display for Error {
-    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "{}", self.message)
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnsupportedLanguage { locale } => write!(f, "unsupported locale: {locale}"),
+            Error::MalformedLocaleTag { tag } => write!(f, "malformed locale tag: {tag:?}"),
+            Error::UnknownScript { tag, script } => {
+                write!(f, "unknown script {script:?} in locale tag {tag:?}")
+            }
+            Error::RuleDefinition { location, rule } => write!(f, "invalid {location}: {rule}"),
+            Error::DuplicateRegistration { location, word } => {
+                write!(f, "duplicate registration of {word:?} in {location}")
+            }
+            Error::InvalidPluralOperand { value } => {
+                write!(f, "invalid plural operand: {value:?}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for RuleLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (kind, index) = match self {
+            RuleLocation::Irregular(index) => ("irregular", index),
+            RuleLocation::Uncountable(index) => ("uncountable", index),
+            RuleLocation::PluralRule(index) => ("plural", index),
+            RuleLocation::SingularRule(index) => ("singular", index),
+        };
+        write!(f, "{kind} rule at index {index}")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn test_error() {
-        let error = Error::new("test");
-        assert_eq!(error.to_string(), "test");
+        let error = Error::UnsupportedLanguage {
+            locale: "xx".into(),
+        };
+        assert_eq!(error.to_string(), "unsupported locale: xx");
+        let error = Error::UnknownScript {
+            tag: "en-Abcd".into(),
+            script: "Abcd".into(),
+        };
+        assert_eq!(
+            error.to_string(),
+            r#"unknown script "Abcd" in locale tag "en-Abcd""#
+        );
+    }
+
+    #[test]
+    fn test_rule_location() {
+        let error = Error::RuleDefinition {
+            location: RuleLocation::PluralRule(2),
+            rule: r#""" -> "s""#.into(),
+        };
+        assert_eq!(
+            error.to_string(),
+            r#"invalid plural rule at index 2: "" -> "s""#
+        );
+        let error = Error::DuplicateRegistration {
+            location: RuleLocation::Irregular(1),
+            word: "person".into(),
+        };
+        assert_eq!(
+            error.to_string(),
+            r#"duplicate registration of "person" in irregular rule at index 1"#
+        );
     }
 }
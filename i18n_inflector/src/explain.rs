@@ -0,0 +1,454 @@
+//! Explanations of how a word was inflected.
+//!
+//! [`LanguageRules::explain_singularize`](crate::LanguageRules::explain_singularize) and
+//! [`LanguageRules::explain_pluralize`](crate::LanguageRules::explain_pluralize) return the
+//! inflected form together with the rules that were tried, in the order they were consulted, so
+//! that a surprising result can be traced to the uncountable, irregular or suffix rule behind it.
+//! Languages whose suffix rules are ordered tables (English) report the entry that fired and the
+//! entries tried before it. Suffix rules written as code do not report themselves, so a word
+//! changed by one is explained as [`Rule::Unreported`] rather than with a guessed suffix.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::language_rules::LanguageRuleSet;
+
+/// A rule consulted while inflecting a word.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Rule {
+    /// The word is listed as uncountable (`news`, `sheep`).
+    Uncountable,
+    /// Words ending in `suffix` are uncountable (`-ware`).
+    UncountableSuffix {
+        /// The suffix of uncountable words.
+        suffix: String,
+    },
+    /// Singular nouns ending in `suffix` have an identical plural (French `-s`). Only consulted
+    /// when pluralizing.
+    InvariantSuffix {
+        /// The suffix of invariant nouns.
+        suffix: String,
+    },
+    /// The word is an irregular noun (`children` -> `child`), or the singular of one when
+    /// singularizing.
+    Irregular,
+    /// The word ends in `suffix`, which is replaced by `replacement`.
+    Suffix {
+        /// The suffix matched at the end of the word.
+        suffix: String,
+        /// The text that replaces the suffix.
+        replacement: String,
+    },
+    /// The word already is in the requested number and is left unchanged (`users` when
+    /// pluralizing).
+    Unchanged,
+    /// The word was changed by a rule that does not report itself, such as a built-in suffix
+    /// rule written as code.
+    Unreported,
+}
+
+/// Where a [`Rule`] comes from.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RuleOrigin {
+    /// The built-in rules of the language.
+    BuiltIn,
+    /// An entry added to a [`CustomRuleSet`](crate::CustomRuleSet).
+    User,
+}
+
+/// A rule that was tried while inflecting a word, and whether it fired.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Step {
+    rule: Rule,
+    origin: RuleOrigin,
+    matched: bool,
+}
+
+impl Step {
+    /// Creates a new step.
+    pub(crate) fn new(rule: Rule, origin: RuleOrigin, matched: bool) -> Self {
+        Step {
+            rule,
+            origin,
+            matched,
+        }
+    }
+
+    /// Returns the rule that was tried.
+    #[must_use]
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// Returns where the rule comes from.
+    #[must_use]
+    pub fn origin(&self) -> RuleOrigin {
+        self.origin
+    }
+
+    /// Returns `true` if the rule fired, and `false` if it was tried and rejected.
+    #[must_use]
+    pub fn matched(&self) -> bool {
+        self.matched
+    }
+}
+
+/// The result of an inflection together with a trace of the rules behind it.
+///
+/// `T` is `Cow<str>` for singularization and `Vec<Cow<str>>` for pluralization.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_inflector::{language_rules, LanguageRules, Rule, RuleOrigin};
+///
+/// # fn main() -> i18n_inflector::Result<()> {
+/// let explanation = language_rules("en")?.explain_singularize("Children");
+/// assert_eq!(explanation.output(), "Child");
+/// assert_eq!(explanation.rule(), Some(&Rule::Irregular));
+///
+/// let explanation = language_rules("en")?.explain_singularize("Boxes");
+/// assert_eq!(explanation.output(), "Box");
+/// let step = explanation.matched().unwrap();
+/// assert_eq!(step.origin(), RuleOrigin::BuiltIn);
+/// assert_eq!(step.to_string(), r#"built-in suffix rule "xes" -> "x" (matched)"#);
+/// // The English uncountables, irregulars and earlier suffix rules were tried first
+/// assert!(explanation.rejected().any(|step| step.rule() == &Rule::Irregular));
+///
+/// // German suffix rules are written as code and do not report themselves
+/// let explanation = language_rules("de")?.explain_singularize("Kunden");
+/// assert_eq!(explanation.output(), "Kunde");
+/// assert_eq!(explanation.rule(), Some(&Rule::Unreported));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Explanation<T> {
+    output: T,
+    steps: Vec<Step>,
+}
+
+impl<T> Explanation<T> {
+    /// Creates a new explanation.
+    pub(crate) fn new(output: T, steps: Vec<Step>) -> Self {
+        Explanation { output, steps }
+    }
+
+    /// Returns the inflected output, as returned by the inflection method itself.
+    #[must_use]
+    pub fn output(&self) -> &T {
+        &self.output
+    }
+
+    /// Consumes the explanation and returns the inflected output.
+    #[must_use]
+    pub fn into_output(self) -> T {
+        self.output
+    }
+
+    /// Returns every rule that was tried, in the order it was consulted. The last step is the one
+    /// that fired, if any.
+    #[must_use]
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Returns the step of the rule that produced the output, or `None` if no rule changed the
+    /// word.
+    #[must_use]
+    pub fn matched(&self) -> Option<&Step> {
+        self.steps.iter().find(|step| step.matched)
+    }
+
+    /// Returns the rule that produced the output, or `None` if no rule changed the word.
+    #[must_use]
+    pub fn rule(&self) -> Option<&Rule> {
+        self.matched().map(Step::rule)
+    }
+
+    /// Returns the rules that were tried and rejected before the output was produced.
+    pub fn rejected(&self) -> impl Iterator<Item = &Step> {
+        self.steps.iter().filter(|step| !step.matched)
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Uncountable => write!(f, "uncountable word"),
+            Rule::UncountableSuffix { suffix } => write!(f, "uncountable suffix {suffix:?}"),
+            Rule::InvariantSuffix { suffix } => write!(f, "invariant suffix {suffix:?}"),
+            Rule::Irregular => write!(f, "irregular noun"),
+            Rule::Suffix {
+                suffix,
+                replacement,
+            } => write!(f, "suffix rule {suffix:?} -> {replacement:?}"),
+            Rule::Unchanged => write!(f, "unchanged word"),
+            Rule::Unreported => write!(f, "unreported rule"),
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let origin = match self.origin {
+            RuleOrigin::BuiltIn => "built-in",
+            RuleOrigin::User => "user",
+        };
+        let outcome = if self.matched { "matched" } else { "rejected" };
+        write!(f, "{origin} {} ({outcome})", self.rule)
+    }
+}
+
+/// Records the lexicon lookups of `rules` for the lowercase `word` and returns `true` if one of
+/// them fired. Invariant suffixes are only consulted when `pluralizing`, and an empty list of
+/// uncountables is not reported.
+fn trace_lexicon(
+    rules: &LanguageRuleSet,
+    word: &str,
+    pluralizing: bool,
+    steps: &mut Vec<Step>,
+) -> bool {
+    let lexicon = rules.lexicon;
+    if !lexicon.uncountables.is_empty() {
+        let uncountable = lexicon.uncountables.contains(word);
+        steps.push(Step::new(
+            Rule::Uncountable,
+            RuleOrigin::BuiltIn,
+            uncountable,
+        ));
+        if uncountable {
+            return true;
+        }
+    }
+    let uncountable_suffixes = lexicon
+        .uncountable_suffixes
+        .iter()
+        .map(|suffix| (*suffix, false));
+    let invariant_suffixes = lexicon
+        .invariant_suffixes
+        .iter()
+        .filter(|_| pluralizing)
+        .map(|suffix| (*suffix, true));
+    for (suffix, invariant) in uncountable_suffixes.chain(invariant_suffixes) {
+        let matched = word.ends_with(suffix);
+        let suffix = String::from(suffix);
+        let rule = if invariant {
+            Rule::InvariantSuffix { suffix }
+        } else {
+            Rule::UncountableSuffix { suffix }
+        };
+        steps.push(Step::new(rule, RuleOrigin::BuiltIn, matched));
+        if matched {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns `true` if `form` is `word` with an irregular ending replaced by its form in
+/// `irregular`, either the whole word or the last element of a compound (`firemen` ->
+/// `fireman`). An irregular form kept as it is only counts for the whole word, since many
+/// regular nouns end in one (`human`).
+fn is_irregular<F>(word: &str, form: &str, irregular: F) -> bool
+where
+    F: Fn(&str) -> Option<&'static str>,
+{
+    word.char_indices().any(|(index, _)| {
+        let ending = &word[index..];
+        irregular(ending).is_some_and(|irregular| {
+            (index == 0 || irregular != ending)
+                && form.strip_prefix(&word[..index]) == Some(irregular)
+        })
+    })
+}
+
+/// Records the entries of the ordered suffix table `rules` tried on a word up to `matched`, the
+/// index of the entry that fired: the entries before it as rejected and the entry itself as
+/// matched. Every entry is rejected if none fired.
+pub(crate) fn trace_table(rules: &[(&str, &str)], matched: Option<usize>, steps: &mut Vec<Step>) {
+    let tried = matched.map_or(rules.len(), |index| index + 1);
+    for (index, &(suffix, replacement)) in rules[..tried].iter().enumerate() {
+        let rule = Rule::Suffix {
+            suffix: suffix.into(),
+            replacement: replacement.into(),
+        };
+        steps.push(Step::new(rule, RuleOrigin::BuiltIn, matched == Some(index)));
+    }
+}
+
+/// Singularizes the lowercase `word` with the built-in `rules` and records the rules behind the
+/// result.
+///
+/// Languages that trace their own rules report their irregular lookup and the suffix table
+/// entries they tried. For other languages, the irregular table is reported if the rule set
+/// registers one, and a change made by the suffix rules as [`Rule::Unreported`].
+pub(crate) fn trace_singularize<'a>(
+    rules: &LanguageRuleSet,
+    word: &'a str,
+    steps: &mut Vec<Step>,
+) -> Cow<'a, str> {
+    if trace_lexicon(rules, word, false, steps) {
+        return Cow::Borrowed(word);
+    }
+    if let Some(explain_singularize) = rules.explain_singularize_fn {
+        return explain_singularize(word, steps);
+    }
+    let singular = (rules.singularize_fn)(word);
+    if let Some(irregulars) = rules.irregulars {
+        let matched = is_irregular(word, &singular, |ending| irregulars.singular(ending));
+        steps.push(Step::new(Rule::Irregular, RuleOrigin::BuiltIn, matched));
+        if matched {
+            return singular;
+        }
+    }
+    steps.extend(unreported_steps(word, Some(&singular)));
+    singular
+}
+
+/// Pluralizes the lowercase `word` with the built-in `rules` and records the rules behind the
+/// most likely plural, like [`trace_singularize`].
+pub(crate) fn trace_pluralize<'a>(
+    rules: &LanguageRuleSet,
+    word: &'a str,
+    steps: &mut Vec<Step>,
+) -> Vec<Cow<'a, str>> {
+    if trace_lexicon(rules, word, true, steps) {
+        return vec![Cow::Borrowed(word)];
+    }
+    if let Some(explain_pluralize) = rules.explain_pluralize_fn {
+        return explain_pluralize(word, steps);
+    }
+    let plurals = (rules.pluralize_fn)(word);
+    let Some(plural) = plurals.first() else {
+        return plurals;
+    };
+    if let Some(irregulars) = rules.irregulars {
+        let matched = is_irregular(word, plural, |ending| irregulars.plural(ending));
+        steps.push(Step::new(Rule::Irregular, RuleOrigin::BuiltIn, matched));
+        if matched {
+            return plurals;
+        }
+    }
+    steps.extend(unreported_steps(word, Some(plural)));
+    plurals
+}
+
+/// Returns the steps explaining `output` for rule sets that do not trace their rules: a single
+/// [`Rule::Unreported`] step, or none if the word is unchanged.
+pub(crate) fn unreported_steps(word: &str, output: Option<&str>) -> Vec<Step> {
+    output
+        .filter(|output| output != &word)
+        .map(|_| Step::new(Rule::Unreported, RuleOrigin::BuiltIn, true))
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_unreported_steps() {
+        let steps = unreported_steps("kunden", Some("kunde"));
+        assert_eq!(
+            steps,
+            vec![Step::new(Rule::Unreported, RuleOrigin::BuiltIn, true)]
+        );
+        assert!(unreported_steps("kunde", Some("kunde")).is_empty());
+        assert!(unreported_steps("kunde", None).is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        let step = Step::new(
+            Rule::Suffix {
+                suffix: "a".into(),
+                replacement: "um".into(),
+            },
+            RuleOrigin::User,
+            false,
+        );
+        assert_eq!(
+            step.to_string(),
+            r#"user suffix rule "a" -> "um" (rejected)"#
+        );
+        let step = Step::new(Rule::Irregular, RuleOrigin::BuiltIn, true);
+        assert_eq!(step.to_string(), "built-in irregular noun (matched)");
+        let step = Step::new(Rule::Unreported, RuleOrigin::BuiltIn, true);
+        assert_eq!(step.to_string(), "built-in unreported rule (matched)");
+        let step = Step::new(Rule::Unchanged, RuleOrigin::BuiltIn, true);
+        assert_eq!(step.to_string(), "built-in unchanged word (matched)");
+    }
+
+    #[test]
+    fn test_trace_table() {
+        let rules = [("ies", "y"), ("ss", "ss"), ("s", "")];
+        let rule = |suffix: &str, replacement: &str, matched| {
+            let rule = Rule::Suffix {
+                suffix: suffix.into(),
+                replacement: replacement.into(),
+            };
+            Step::new(rule, RuleOrigin::BuiltIn, matched)
+        };
+        let mut steps = Vec::new();
+        trace_table(&rules, Some(1), &mut steps);
+        assert_eq!(steps, [rule("ies", "y", false), rule("ss", "ss", true)]);
+        steps.clear();
+        trace_table(&rules, None, &mut steps);
+        assert_eq!(steps.len(), 3);
+        assert!(steps.iter().all(|step| !step.matched()));
+    }
+
+    #[test]
+    fn test_is_irregular() {
+        let singular = |word: &str| match word {
+            "men" | "man" => Some("man"),
+            _ => None,
+        };
+        assert!(is_irregular("men", "man", singular));
+        assert!(is_irregular("firemen", "fireman", singular));
+        assert!(is_irregular("man", "man", singular));
+        assert!(!is_irregular("human", "human", singular));
+        assert!(!is_irregular("firemen", "firemen", singular));
+    }
+
+    #[test]
+    fn test_trace_singularize() {
+        let rules = &crate::languages::de::RULES;
+        let mut steps = Vec::new();
+        assert_eq!(trace_singularize(rules, "bäume", &mut steps), "baum");
+        assert_eq!(steps.last().unwrap().rule(), &Rule::Irregular);
+        steps.clear();
+        assert_eq!(trace_singularize(rules, "kunden", &mut steps), "kunde");
+        let last = steps.last().unwrap();
+        assert_eq!(
+            last,
+            &Step::new(Rule::Unreported, RuleOrigin::BuiltIn, true)
+        );
+        steps.clear();
+        assert_eq!(trace_singularize(rules, "lehrer", &mut steps), "lehrer");
+        assert!(steps.iter().all(|step| !step.matched()));
+    }
+
+    #[test]
+    fn test_trace_pluralize() {
+        let rules = &crate::languages::en::RULES;
+        let mut steps = Vec::new();
+        assert_eq!(trace_pluralize(rules, "box", &mut steps), ["boxes"]);
+        let suffix = Rule::Suffix {
+            suffix: "x".into(),
+            replacement: "xes".into(),
+        };
+        assert_eq!(
+            steps.last().unwrap(),
+            &Step::new(suffix, RuleOrigin::BuiltIn, true)
+        );
+        assert!(steps[..steps.len() - 1].iter().all(|step| !step.matched()));
+    }
+}
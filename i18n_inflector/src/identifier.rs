@@ -0,0 +1,266 @@
+//! Inflection of compound identifiers such as `user_accounts`, `orderLineItems`,
+//! `OrderLineItems` and `order-line-items`.
+//!
+//! An identifier is split into words at separators (`_`, `-`, whitespace, ...) and at case
+//! boundaries. Only the head noun is inflected; every other character of the identifier is left
+//! untouched, so the original naming convention is preserved.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::language_rules::LanguageRules;
+
+/// The position of the head noun in a compound identifier.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HeadPosition {
+    /// The head is the first word (head-initial languages, e.g. French `prix_produits`).
+    First,
+    /// The head is the last word (head-final languages, e.g. English `user_accounts`).
+    Last,
+}
+
+/// Returns the default head position for compound identifiers in the given language.
+///
+/// Romance, Celtic, Semitic and Bantu languages place the head noun first; all other languages
+/// place it last.
+#[must_use]
+pub(crate) fn head_position(language: &str) -> HeadPosition {
+    match language {
+        "an" | "ar" | "br" | "ca" | "co" | "cy" | "es" | "fa" | "fr" | "ga" | "gd" | "gl"
+        | "gv" | "he" | "ia" | "ie" | "it" | "kg" | "ki" | "kj" | "kw" | "la" | "lg" | "lu"
+        | "nd" | "nr" | "ny" | "oc" | "ps" | "pt" | "rm" | "ro" | "rw" | "sc" | "sn" | "sq"
+        | "ss" | "st" | "sw" | "tg" | "tn" | "ts" | "ve" | "wa" | "xh" | "zu" => {
+            HeadPosition::First
+        }
+        _ => HeadPosition::Last,
+    }
+}
+
+/// Splits an identifier into the byte ranges of its words.
+///
+/// Words are separated by any non-alphanumeric character and by case boundaries (`userAccounts`,
+/// `HTTPServer`). A trailing lowercase `s` stays attached to an acronym (`IDs`), and runs of digits
+/// are returned as separate words.
+#[must_use]
+pub(crate) fn split_words(identifier: &str) -> Vec<Range<usize>> {
+    let chars: Vec<(usize, char)> = identifier.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start: Option<usize> = None;
+
+    for (position, &(index, ch)) in chars.iter().enumerate() {
+        if !ch.is_alphanumeric() {
+            if let Some(word_start) = start.take() {
+                words.push(word_start..index);
+            }
+            continue;
+        }
+
+        if let Some(word_start) = start {
+            let prev = chars[position - 1].1;
+            let next = chars.get(position + 1).map(|&(_, next)| next);
+            let acronym_plural = next == Some('s')
+                && !chars
+                    .get(position + 2)
+                    .is_some_and(|&(_, after)| after.is_lowercase());
+            let boundary = (prev.is_numeric() != ch.is_numeric())
+                || (ch.is_uppercase() && !prev.is_uppercase() && !prev.is_numeric())
+                || (ch.is_uppercase()
+                    && prev.is_uppercase()
+                    && next.is_some_and(char::is_lowercase)
+                    && !acronym_plural);
+            if boundary {
+                words.push(word_start..index);
+                start = Some(index);
+            }
+        } else {
+            start = Some(index);
+        }
+    }
+    if let Some(word_start) = start {
+        words.push(word_start..identifier.len());
+    }
+    words
+}
+
+/// Returns the byte range of the head noun of an identifier, if it contains any letters.
+fn head_word(identifier: &str, position: HeadPosition) -> Option<Range<usize>> {
+    let mut words = split_words(identifier)
+        .into_iter()
+        .filter(|range| identifier[range.clone()].chars().any(char::is_alphabetic));
+    match position {
+        HeadPosition::First => words.next(),
+        HeadPosition::Last => words.next_back(),
+    }
+}
+
+/// Replaces the `range` of `identifier` with `word`, borrowing the identifier when unchanged.
+fn splice<'a>(identifier: &'a str, range: &Range<usize>, word: &str) -> Cow<'a, str> {
+    if &identifier[range.clone()] == word {
+        return Cow::Borrowed(identifier);
+    }
+    let mut result = String::with_capacity(identifier.len() + word.len());
+    result.push_str(&identifier[..range.start]);
+    result.push_str(word);
+    result.push_str(&identifier[range.end..]);
+    Cow::Owned(result)
+}
+
+/// Singularizes the head noun of a compound identifier.
+pub(crate) fn singularize_identifier<'a, R>(
+    rules: &R,
+    identifier: &'a str,
+    position: HeadPosition,
+) -> Cow<'a, str>
+where
+    R: LanguageRules + ?Sized,
+{
+    let Some(range) = head_word(identifier, position) else {
+        return Cow::Borrowed(identifier);
+    };
+    let singular = rules.singularize(&identifier[range.clone()]);
+    splice(identifier, &range, &singular)
+}
+
+/// Returns the possible plural forms of a compound identifier by pluralizing its head noun.
+pub(crate) fn pluralize_identifier<'a, R>(
+    rules: &R,
+    identifier: &'a str,
+    position: HeadPosition,
+) -> Vec<Cow<'a, str>>
+where
+    R: LanguageRules + ?Sized,
+{
+    let Some(range) = head_word(identifier, position) else {
+        return alloc::vec![Cow::Borrowed(identifier)];
+    };
+    rules
+        .pluralize(&identifier[range.clone()])
+        .iter()
+        .map(|plural| splice(identifier, &range, plural))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::languages;
+    use alloc::vec;
+
+    fn words(identifier: &str) -> Vec<&str> {
+        split_words(identifier)
+            .into_iter()
+            .map(|range| &identifier[range])
+            .collect()
+    }
+
+    #[test]
+    fn test_split_words_separators() {
+        assert_eq!(words("user_accounts"), vec!["user", "accounts"]);
+        assert_eq!(words("order-line-items"), vec!["order", "line", "items"]);
+        assert_eq!(words("ORDER_LINE_ITEMS"), vec!["ORDER", "LINE", "ITEMS"]);
+        assert_eq!(words("__users__"), vec!["users"]);
+        assert_eq!(words("user accounts"), vec!["user", "accounts"]);
+    }
+
+    #[test]
+    fn test_split_words_case_boundaries() {
+        assert_eq!(words("orderLineItems"), vec!["order", "Line", "Items"]);
+        assert_eq!(words("OrderLineItems"), vec!["Order", "Line", "Items"]);
+        assert_eq!(words("HTTPServers"), vec!["HTTP", "Servers"]);
+        assert_eq!(words("userIDs"), vec!["user", "IDs"]);
+    }
+
+    #[test]
+    fn test_split_words_digits() {
+        assert_eq!(words("users2"), vec!["users", "2"]);
+        assert_eq!(words("v2Users"), vec!["v", "2", "Users"]);
+    }
+
+    #[test]
+    fn test_split_words_uncased_scripts() {
+        assert_eq!(words("ユーザー_アカウント"), vec!["ユーザー", "アカウント"]);
+    }
+
+    #[test]
+    fn test_split_words_empty() {
+        assert!(words("").is_empty());
+        assert!(words("__").is_empty());
+    }
+
+    #[test]
+    fn test_head_position() {
+        assert_eq!(head_position("en"), HeadPosition::Last);
+        assert_eq!(head_position("de"), HeadPosition::Last);
+        assert_eq!(head_position("fr"), HeadPosition::First);
+        assert_eq!(head_position("es"), HeadPosition::First);
+    }
+
+    #[test]
+    fn test_singularize_identifier() {
+        let rules = &languages::en::RULES;
+        let last = HeadPosition::Last;
+        assert_eq!(
+            singularize_identifier(rules, "user_accounts", last),
+            "user_account"
+        );
+        assert_eq!(
+            singularize_identifier(rules, "UserCategories", last),
+            "UserCategory"
+        );
+        assert_eq!(
+            singularize_identifier(rules, "orderLineItems", last),
+            "orderLineItem"
+        );
+        assert_eq!(
+            singularize_identifier(rules, "order-line-items", last),
+            "order-line-item"
+        );
+        assert_eq!(
+            singularize_identifier(rules, "ORDER_LINE_ITEMS", last),
+            "ORDER_LINE_ITEM"
+        );
+        assert_eq!(singularize_identifier(rules, "users2", last), "user2");
+    }
+
+    #[test]
+    fn test_singularize_identifier_head_first() {
+        let rules = &languages::fr::RULES;
+        assert_eq!(
+            singularize_identifier(rules, "categories_produit", HeadPosition::First),
+            "categorie_produit"
+        );
+    }
+
+    #[test]
+    fn test_singularize_identifier_unchanged() {
+        let rules = &languages::en::RULES;
+        assert!(matches!(
+            singularize_identifier(rules, "user_account", HeadPosition::Last),
+            Cow::Borrowed("user_account")
+        ));
+        assert!(matches!(
+            singularize_identifier(rules, "__", HeadPosition::Last),
+            Cow::Borrowed("__")
+        ));
+    }
+
+    #[test]
+    fn test_pluralize_identifier() {
+        let rules = &languages::en::RULES;
+        let result = pluralize_identifier(rules, "UserCategory", HeadPosition::Last);
+        assert!(result.iter().any(|v| v == "UserCategories"));
+        let result = pluralize_identifier(rules, "user_child", HeadPosition::Last);
+        assert_eq!(result, vec!["user_children"]);
+        let result = pluralize_identifier(rules, "42", HeadPosition::Last);
+        assert_eq!(result, vec!["42"]);
+    }
+
+    #[test]
+    fn test_pluralize_identifier_head_first() {
+        let rules = &languages::fr::RULES;
+        let result = pluralize_identifier(rules, "journal_ventes", HeadPosition::First);
+        assert!(result.iter().any(|v| v == "journaux_ventes"));
+    }
+}
@@ -1,6 +1,14 @@
 //! The `LanguageRules` trait standardizes the interface between the core engine and locale modules.
 
+use crate::candidate::{self, Candidate, Confidence};
+use crate::case;
+use crate::explain::{self, Explanation, Step};
+use crate::identifier::{self, HeadPosition};
+use crate::lexicon::{Irregulars, Lexicon};
+use crate::number::{self, Number};
+use crate::plural_rules::{self, PluralCategory, PluralOperands};
 use alloc::borrow::Cow;
+use alloc::vec;
 use alloc::vec::Vec;
 
 /// Function type for singularization.
@@ -9,6 +17,44 @@ pub type SingularizeFn = for<'a> fn(&'a str) -> Cow<'a, str>;
 /// Function type for pluralization.
 pub type PluralizeFn = for<'a> fn(&'a str) -> Vec<Cow<'a, str>>;
 
+/// Function type for ranked singularization candidates.
+///
+/// Returns the plausible singular forms of a lowercase word, most likely first.
+pub type SingularizeCandidatesFn = for<'a> fn(&'a str) -> Vec<Cow<'a, str>>;
+
+/// Function type for count-driven forms.
+///
+/// Receives a lowercase singular noun and the plural category of a number, and returns the form
+/// the number requires, or `None` to use the singular for [`PluralCategory::One`] and the most
+/// likely plural otherwise.
+pub type CountFormFn = for<'a> fn(&'a str, PluralCategory) -> Option<Cow<'a, str>>;
+
+/// Function type for grammatical number detection.
+///
+/// Receives a lowercase noun and returns its number when the language's own word lists or
+/// inflection tables decide it, or `None` to fall back to the suffix rules.
+pub type NumberFn = fn(&str) -> Option<Number>;
+
+/// Function type for traced singularization.
+///
+/// Singularizes a lowercase word like a [`SingularizeFn`] and records the irregular lookup and
+/// the suffix rules it tried.
+pub(crate) type ExplainSingularizeFn = for<'a> fn(&'a str, &mut Vec<Step>) -> Cow<'a, str>;
+
+/// Function type for traced pluralization, the counterpart of [`ExplainSingularizeFn`].
+pub(crate) type ExplainPluralizeFn = for<'a> fn(&'a str, &mut Vec<Step>) -> Vec<Cow<'a, str>>;
+
+/// Applies `inflect` to a form derived from the input, keeping the input's lifetime.
+pub(crate) fn inflect_derived<'a, F>(derived: Cow<'a, str>, inflect: F) -> Cow<'a, str>
+where
+    F: for<'b> FnOnce(&'b str) -> Cow<'b, str>,
+{
+    match derived {
+        Cow::Borrowed(word) => inflect(word),
+        Cow::Owned(word) => Cow::Owned(inflect(&word).into_owned()),
+    }
+}
+
 /// A standardized interface for language-specific inflection rules.
 ///
 /// Each language module implements this trait to provide singularization
@@ -17,11 +63,137 @@ pub trait LanguageRules {
     /// Returns the ISO 639-1 two-letter language code for this language.
     fn language(&self) -> &'static str;
 
+    /// Returns the BCP 47 tag this rule set was registered for, such as `pt-PT` or `de-CH`.
+    ///
+    /// This is the language code for rule sets that apply to a whole language.
+    fn locale(&self) -> &'static str {
+        self.language()
+    }
+
     /// Converts a potentially plural word to its singular form.
+    ///
+    /// Uncountable nouns are returned unchanged. Matching is case-insensitive and the casing
+    /// pattern of the input (lower, UPPER, Title or mixed) is re-applied to the output.
     fn singularize<'a>(&self, name: &'a str) -> Cow<'a, str>;
 
+    /// Returns the possible singular forms for a word, ranked from most to least likely.
+    ///
+    /// Plural endings are often ambiguous (German `Kunden` may be `Kunde` or `Kund`, Italian
+    /// `cani` may be `cano` or `cane`), so callers can check the candidates against their own
+    /// lexicon or schema. Uncountable nouns are [`Confidence::Certain`]; otherwise the form
+    /// returned by [`singularize`](Self::singularize) is [`Confidence::Likely`] and the
+    /// alternatives are [`Confidence::Possible`].
+    fn singularize_candidates<'a>(&self, name: &'a str) -> Vec<Candidate<'a>> {
+        candidate::rank(vec![self.singularize(name)])
+    }
+
     /// Returns a list of possible plural forms for a word.
+    ///
+    /// Uncountable and invariant nouns are returned unchanged. Matching is case-insensitive and the
+    /// casing pattern of the input is re-applied to every candidate.
     fn pluralize<'a>(&self, name: &'a str) -> Vec<Cow<'a, str>>;
+
+    /// Returns the possible plural forms for a word, ranked from most to least likely.
+    ///
+    /// Forms taken from uncountable, irregular or user-supplied entries are
+    /// [`Confidence::Certain`]; otherwise the form preferred by the language's suffix rules is
+    /// [`Confidence::Likely`] and the alternatives are [`Confidence::Possible`].
+    fn pluralize_candidates<'a>(&self, name: &'a str) -> Vec<Candidate<'a>> {
+        candidate::rank(self.pluralize(name))
+    }
+
+    /// Singularizes a word and explains which rule produced the result.
+    ///
+    /// The trace lists the rules that were tried in the order they were consulted (uncountables,
+    /// irregulars and suffix rules), each marked as matched or rejected. Suffix rules kept in
+    /// ordered tables are reported with their suffix and replacement, and those written as code
+    /// as [`Rule::Unreported`](crate::Rule::Unreported), as is any change made by this default
+    /// implementation, which does not trace any rule. The output is the same as that of
+    /// [`singularize`](Self::singularize).
+    fn explain_singularize<'a>(&self, name: &'a str) -> Explanation<Cow<'a, str>> {
+        let output = self.singularize(name);
+        let steps = explain::unreported_steps(name, Some(&output));
+        Explanation::new(output, steps)
+    }
+
+    /// Pluralizes a word and explains which rule produced the most likely plural.
+    ///
+    /// The output is the same as that of [`pluralize`](Self::pluralize); see
+    /// [`explain_singularize`](Self::explain_singularize) for the trace.
+    fn explain_pluralize<'a>(&self, name: &'a str) -> Explanation<Vec<Cow<'a, str>>> {
+        let output = self.pluralize(name);
+        let steps = explain::unreported_steps(name, output.first().map(|plural| &**plural));
+        Explanation::new(output, steps)
+    }
+
+    /// Returns the most likely plural form for a word.
+    fn pluralize_best<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        self.pluralize_candidates(name)
+            .into_iter()
+            .next()
+            .map_or(Cow::Borrowed(name), Candidate::into_value)
+    }
+
+    /// Detects whether a noun of unknown number is singular, plural, ambiguous or invariant.
+    ///
+    /// The word is checked against the language's uncountables, irregulars and suffix rules in
+    /// both directions, so that singular nouns such as `status` or `bus` are not reported as
+    /// plural just because singularization would strip their final `s`. Languages that count
+    /// nouns in the genitive plural also recognize it (Russian `5 машин`, Polish `5 zadań`).
+    fn number_of(&self, word: &str) -> Number {
+        number::number_of(self, word)
+    }
+
+    /// Returns the CLDR cardinal plural category of `n` in this language.
+    fn plural_category<N: Into<PluralOperands>>(&self, n: N) -> PluralCategory
+    where
+        Self: Sized,
+    {
+        plural_rules::cardinal(self.locale(), &n.into()).unwrap_or(PluralCategory::Other)
+    }
+
+    /// Returns the CLDR ordinal plural category of `n` in this language.
+    fn ordinal_category<N: Into<PluralOperands>>(&self, n: N) -> PluralCategory
+    where
+        Self: Sized,
+    {
+        plural_rules::ordinal(self.locale(), &n.into()).unwrap_or(PluralCategory::Other)
+    }
+
+    /// Returns the form of a noun required by the number `n`.
+    ///
+    /// The word may be given in its singular or plural form. The CLDR plural category of `n`
+    /// selects the form, so that languages with several plural forms get the correct one, e.g.
+    /// Russian `1 файл`, `2 файла`, `5 файлов` or Polish `1 plik`, `2 pliki`, `5 plików`.
+    fn inflect_for_count<'a, N: Into<PluralOperands>>(&self, word: &'a str, n: N) -> Cow<'a, str>
+    where
+        Self: Sized,
+    {
+        let singular = self.singularize(word);
+        if self.plural_category(n) == PluralCategory::One {
+            return singular;
+        }
+        inflect_derived(singular, |singular| self.pluralize_best(singular))
+    }
+
+    /// Returns the position of the head noun in compound identifiers for this language.
+    ///
+    /// Defaults to [`HeadPosition::Last`].
+    fn head_position(&self) -> HeadPosition {
+        HeadPosition::Last
+    }
+
+    /// Singularizes a compound identifier (`snake_case`, `camelCase`, `PascalCase`, `kebab-case`,
+    /// ...) by singularizing only its head noun and keeping the original naming convention.
+    fn singularize_identifier<'a>(&self, identifier: &'a str) -> Cow<'a, str> {
+        identifier::singularize_identifier(self, identifier, self.head_position())
+    }
+
+    /// Returns the possible plural forms of a compound identifier by pluralizing only its head
+    /// noun and keeping the original naming convention.
+    fn pluralize_identifier<'a>(&self, identifier: &'a str) -> Vec<Cow<'a, str>> {
+        identifier::pluralize_identifier(self, identifier, self.head_position())
+    }
 }
 
 /// A concrete implementation of [`LanguageRules`] backed by function pointers.
@@ -32,10 +204,83 @@ pub trait LanguageRules {
 pub struct LanguageRuleSet {
     /// The ISO 639-1 two-letter language code.
     pub(crate) language: &'static str,
+    /// The BCP 47 tag of a regional or script-specific rule set, or `None` for the rule set of
+    /// the whole language.
+    pub(crate) tag: Option<&'static str>,
     /// Function to singularize a word.
     pub(crate) singularize_fn: SingularizeFn,
     /// Function to pluralize a word.
     pub(crate) pluralize_fn: PluralizeFn,
+    /// Function selecting the form required by a number, for languages where it is not simply
+    /// the singular or the plural.
+    pub(crate) count_form_fn: Option<CountFormFn>,
+    /// Function returning ranked singular forms, for languages whose plural endings are
+    /// ambiguous. Without it, the result of `singularize_fn` is the only candidate.
+    pub(crate) singularize_candidates_fn: Option<SingularizeCandidatesFn>,
+    /// Function detecting the number of nouns the suffix rules cannot tell apart, such as
+    /// genitive plurals without an ending. Irregulars and uncountables are detected without it.
+    pub(crate) number_fn: Option<NumberFn>,
+    /// Irregular nouns consulted by the language's functions, reported when explaining an
+    /// inflection. Languages that keep their irregulars in another form leave this `None`.
+    pub(crate) irregulars: Option<&'static Irregulars>,
+    /// Function explaining `singularize_fn`, for languages whose suffix rules are tables that
+    /// can report the entry that fired. Without it, a change made by the suffix rules is
+    /// reported as [`Rule::Unreported`](crate::Rule::Unreported).
+    pub(crate) explain_singularize_fn: Option<ExplainSingularizeFn>,
+    /// Function explaining `pluralize_fn`, like `explain_singularize_fn`.
+    pub(crate) explain_pluralize_fn: Option<ExplainPluralizeFn>,
+    /// Uncountable and invariant nouns consulted before the suffix rules.
+    pub(crate) lexicon: &'static Lexicon,
+}
+
+impl LanguageRuleSet {
+    /// Creates the rule set of a whole language from its singularize and pluralize functions,
+    /// without count forms, singular candidates, number detection, irregulars or lexicon.
+    ///
+    /// Language modules that need more set the other fields with struct update syntax.
+    #[must_use]
+    pub(crate) const fn new(
+        language: &'static str,
+        singularize_fn: SingularizeFn,
+        pluralize_fn: PluralizeFn,
+    ) -> Self {
+        LanguageRuleSet {
+            language,
+            tag: None,
+            singularize_fn,
+            pluralize_fn,
+            count_form_fn: None,
+            singularize_candidates_fn: None,
+            number_fn: None,
+            irregulars: None,
+            explain_singularize_fn: None,
+            explain_pluralize_fn: None,
+            lexicon: &Lexicon::EMPTY,
+        }
+    }
+
+    /// Returns the form of a lowercase noun required by a number in `category`.
+    pub(crate) fn count_form<'a>(&self, word: &'a str, category: PluralCategory) -> Cow<'a, str> {
+        if self.lexicon.is_uncountable(word) {
+            return Cow::Borrowed(word);
+        }
+        let singular = (self.singularize_fn)(word);
+        inflect_derived(singular, |singular| {
+            if let Some(form) = self
+                .count_form_fn
+                .and_then(|count_form| count_form(singular, category))
+            {
+                return form;
+            }
+            if category == PluralCategory::One || self.lexicon.is_invariant(singular) {
+                return Cow::Borrowed(singular);
+            }
+            (self.pluralize_fn)(singular)
+                .into_iter()
+                .next()
+                .unwrap_or(Cow::Borrowed(singular))
+        })
+    }
 }
 
 impl LanguageRules for LanguageRuleSet {
@@ -43,20 +288,98 @@ impl LanguageRules for LanguageRuleSet {
         self.language
     }
 
+    fn locale(&self) -> &'static str {
+        self.tag.unwrap_or(self.language)
+    }
+
     fn singularize<'a>(&self, name: &'a str) -> Cow<'a, str> {
-        (self.singularize_fn)(name)
+        case::inflect_cased(self.language, name, |word| {
+            if self.lexicon.is_uncountable(word) {
+                return Cow::Borrowed(word);
+            }
+            (self.singularize_fn)(word)
+        })
+    }
+
+    fn singularize_candidates<'a>(&self, name: &'a str) -> Vec<Candidate<'a>> {
+        case::candidates_cased(self.language, name, |word| {
+            if self.lexicon.is_uncountable(word) {
+                return vec![Candidate::new(word, Confidence::Certain)];
+            }
+            match self.singularize_candidates_fn {
+                Some(singularize_candidates) => candidate::rank(singularize_candidates(word)),
+                None => candidate::rank(vec![(self.singularize_fn)(word)]),
+            }
+        })
     }
 
     fn pluralize<'a>(&self, name: &'a str) -> Vec<Cow<'a, str>> {
-        (self.pluralize_fn)(name)
+        case::pluralize_cased(self.language, name, |word| {
+            if self.lexicon.is_invariant(word) {
+                return vec![Cow::Borrowed(word)];
+            }
+            (self.pluralize_fn)(word)
+        })
+    }
+
+    fn pluralize_candidates<'a>(&self, name: &'a str) -> Vec<Candidate<'a>> {
+        case::candidates_cased(self.language, name, |word| {
+            if self.lexicon.is_invariant(word) {
+                return vec![Candidate::new(word, Confidence::Certain)];
+            }
+            if let Some(plural) = self
+                .irregulars
+                .and_then(|irregulars| irregulars.plural(word))
+            {
+                return vec![Candidate::new(plural, Confidence::Certain)];
+            }
+            candidate::rank((self.pluralize_fn)(word))
+        })
+    }
+
+    fn explain_singularize<'a>(&self, name: &'a str) -> Explanation<Cow<'a, str>> {
+        let mut steps = Vec::new();
+        let output = case::inflect_cased(self.language, name, |word| {
+            explain::trace_singularize(self, word, &mut steps)
+        });
+        Explanation::new(output, steps)
+    }
+
+    fn explain_pluralize<'a>(&self, name: &'a str) -> Explanation<Vec<Cow<'a, str>>> {
+        let mut steps = Vec::new();
+        let output = case::pluralize_cased(self.language, name, |word| {
+            explain::trace_pluralize(self, word, &mut steps)
+        });
+        Explanation::new(output, steps)
+    }
+
+    fn number_of(&self, word: &str) -> Number {
+        let lower = case::to_lower(self.language, word);
+        if self.lexicon.is_uncountable(&lower) {
+            return Number::Invariant;
+        }
+        self.irregulars
+            .and_then(|irregulars| irregulars.number(&lower))
+            .or_else(|| self.number_fn.and_then(|number_fn| number_fn(&lower)))
+            .unwrap_or_else(|| number::number_of(self, word))
+    }
+
+    fn inflect_for_count<'a, N: Into<PluralOperands>>(&self, word: &'a str, n: N) -> Cow<'a, str> {
+        let category = self.plural_category(n);
+        case::inflect_cased(self.language, word, |word| self.count_form(word, category))
+    }
+
+    fn head_position(&self) -> HeadPosition {
+        identifier::head_position(self.language)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lexicon::irregulars;
     use alloc::format;
-    use alloc::vec;
+    use phf::phf_set;
 
     fn test_singularize(name: &str) -> Cow<'_, str> {
         Cow::Borrowed(name)
@@ -86,16 +409,24 @@ mod tests {
         vec![Cow::Borrowed(name)]
     }
 
-    static TEST_RULES: LanguageRuleSet = LanguageRuleSet {
-        language: "xx",
-        singularize_fn: test_singularize,
-        pluralize_fn: test_pluralize,
-    };
+    static TEST_RULES: LanguageRuleSet =
+        LanguageRuleSet::new("xx", test_singularize, test_pluralize);
 
     static TEST_RULES_WITH_EXCEPTIONS: LanguageRuleSet = LanguageRuleSet {
-        language: "xy",
-        singularize_fn: test_singularize_with_exc,
-        pluralize_fn: test_pluralize_with_exc,
+        irregulars: Some(&TEST_IRREGULARS),
+        lexicon: &TEST_LEXICON,
+        ..LanguageRuleSet::new("xy", test_singularize_with_exc, test_pluralize_with_exc)
+    };
+
+    static TEST_IRREGULARS: Irregulars = irregulars! {
+        "child" => "children",
+        "ox" => "oxen",
+    };
+
+    static TEST_LEXICON: Lexicon = Lexicon {
+        uncountables: phf_set! { "sheep" },
+        uncountable_suffixes: &["ware"],
+        invariant_suffixes: &["ix"],
     };
 
     #[test]
@@ -131,6 +462,186 @@ mod tests {
         assert_eq!(TEST_RULES_WITH_EXCEPTIONS.pluralize("other"), vec!["other"]);
     }
 
+    #[test]
+    fn test_lexicon() {
+        let rules = &TEST_RULES_WITH_EXCEPTIONS;
+        assert_eq!(rules.singularize("sheep"), "sheep");
+        assert_eq!(rules.pluralize("sheep"), vec!["sheep"]);
+        assert_eq!(rules.singularize("Software"), "Software");
+        assert_eq!(rules.pluralize("software"), vec!["software"]);
+        assert_eq!(rules.pluralize("prix"), vec!["prix"]);
+        assert_eq!(rules.pluralize("PRIX"), vec!["PRIX"]);
+    }
+
+    #[test]
+    fn test_pluralize_candidates() {
+        let rules = &TEST_RULES_WITH_EXCEPTIONS;
+        assert_eq!(
+            rules.pluralize_candidates("Sheep"),
+            vec![Candidate::new("Sheep", Confidence::Certain)]
+        );
+        assert_eq!(
+            rules.pluralize_candidates("child"),
+            vec![Candidate::new("children", Confidence::Certain)]
+        );
+        assert_eq!(
+            rules.pluralize_candidates("Other"),
+            vec![Candidate::new("Other", Confidence::Likely)]
+        );
+        assert_eq!(rules.pluralize_best("OX"), "OXEN");
+        assert_eq!(rules.pluralize_best("prix"), "prix");
+    }
+
+    #[test]
+    fn test_pluralize_best_empty_candidates() {
+        fn no_plural(_name: &str) -> Vec<Cow<'_, str>> {
+            Vec::new()
+        }
+        let rules = LanguageRuleSet::new("xz", test_singularize, no_plural);
+        assert!(rules.pluralize_candidates("test").is_empty());
+        assert_eq!(rules.pluralize_best("test"), "test");
+    }
+
+    #[test]
+    fn test_singularize_candidates() {
+        fn candidates(name: &str) -> Vec<Cow<'_, str>> {
+            let stem = name.strip_suffix('n').unwrap_or(name);
+            vec![
+                Cow::Borrowed(stem),
+                format!("{stem}e").into(),
+                Cow::Borrowed(stem),
+            ]
+        }
+        let rules = LanguageRuleSet {
+            singularize_candidates_fn: Some(candidates),
+            lexicon: &TEST_LEXICON,
+            ..LanguageRuleSet::new("xz", test_singularize, test_pluralize)
+        };
+        assert_eq!(
+            rules.singularize_candidates("Kunden"),
+            vec![
+                Candidate::new("Kunde", Confidence::Likely),
+                Candidate::new("Kundee", Confidence::Possible),
+            ]
+        );
+        assert_eq!(
+            rules.singularize_candidates("sheep"),
+            vec![Candidate::new("sheep", Confidence::Certain)]
+        );
+        assert_eq!(
+            TEST_RULES_WITH_EXCEPTIONS.singularize_candidates("Children"),
+            vec![Candidate::new("Child", Confidence::Likely)]
+        );
+    }
+
+    #[test]
+    fn test_exceptions_case_insensitive() {
+        assert_eq!(TEST_RULES_WITH_EXCEPTIONS.singularize("Children"), "Child");
+        assert_eq!(TEST_RULES_WITH_EXCEPTIONS.singularize("OXEN"), "OX");
+        assert_eq!(
+            TEST_RULES_WITH_EXCEPTIONS.pluralize("Child"),
+            vec!["Children"]
+        );
+        assert_eq!(TEST_RULES_WITH_EXCEPTIONS.pluralize("OX"), vec!["OXEN"]);
+    }
+
+    #[test]
+    fn test_inflect_for_count() {
+        let rules = LanguageRuleSet {
+            language: "en",
+            ..TEST_RULES_WITH_EXCEPTIONS
+        };
+        assert_eq!(rules.inflect_for_count("children", 1), "child");
+        assert_eq!(rules.inflect_for_count("child", 2), "children");
+        assert_eq!(rules.inflect_for_count("Child", 0), "Children");
+        let one_point_zero: PluralOperands = "1.0".parse().unwrap();
+        assert_eq!(rules.inflect_for_count("ox", one_point_zero), "oxen");
+        assert_eq!(rules.inflect_for_count("sheep", 2), "sheep");
+        assert_eq!(rules.inflect_for_count("prix", 2), "prix");
+        assert!(matches!(
+            rules.inflect_for_count("child", 1),
+            Cow::Borrowed("child")
+        ));
+    }
+
+    #[test]
+    fn test_inflect_for_count_count_form() {
+        fn paucal(name: &str, category: PluralCategory) -> Option<Cow<'_, str>> {
+            (category == PluralCategory::Few).then(|| Cow::Owned(format!("{name}a")))
+        }
+        let rules = LanguageRuleSet {
+            language: "pl",
+            count_form_fn: Some(paucal),
+            singularize_candidates_fn: None,
+            number_fn: None,
+            irregulars: None,
+            ..TEST_RULES_WITH_EXCEPTIONS
+        };
+        assert_eq!(rules.inflect_for_count("oxen", 1), "ox");
+        assert_eq!(rules.inflect_for_count("oxen", 3), "oxa");
+        assert_eq!(rules.inflect_for_count("ox", 5), "oxen");
+    }
+
+    #[test]
+    fn test_explain() {
+        use crate::explain::{Rule, RuleOrigin};
+        use alloc::string::String;
+
+        let rules = &TEST_RULES_WITH_EXCEPTIONS;
+        let explanation = rules.explain_singularize("Oxen");
+        assert_eq!(explanation.output(), "Ox");
+        assert_eq!(explanation.rule(), Some(&Rule::Irregular));
+        assert_eq!(explanation.matched().unwrap().origin(), RuleOrigin::BuiltIn);
+        assert_eq!(explanation.rejected().count(), 2);
+
+        // Without an irregular table the change is reported as an unreported rule
+        let untraced = LanguageRuleSet {
+            irregulars: None,
+            ..TEST_RULES_WITH_EXCEPTIONS
+        };
+        let explanation = untraced.explain_singularize("Oxen");
+        assert_eq!(explanation.output(), "Ox");
+        assert_eq!(explanation.rule(), Some(&Rule::Unreported));
+
+        let explanation = rules.explain_singularize("Software");
+        assert_eq!(
+            explanation.rule(),
+            Some(&Rule::UncountableSuffix {
+                suffix: "ware".into()
+            })
+        );
+        let explanation = rules.explain_pluralize("prix");
+        assert_eq!(explanation.output(), &vec!["prix"]);
+        assert_eq!(
+            explanation.rule(),
+            Some(&Rule::InvariantSuffix {
+                suffix: "ix".into()
+            })
+        );
+
+        let explanation = TEST_RULES.explain_pluralize("test");
+        assert_eq!(explanation.into_output(), vec!["test"]);
+
+        let explanation = crate::languages::en::RULES.explain_singularize("Children");
+        assert_eq!(explanation.output(), "Child");
+        assert_eq!(explanation.rule(), Some(&Rule::Irregular));
+        let explanation = crate::languages::en::RULES.explain_pluralize("mouse");
+        assert_eq!(explanation.output(), &vec!["mice"]);
+        assert_eq!(explanation.rule(), Some(&Rule::Irregular));
+        // Irregular nouns at the end of a compound
+        let explanation = crate::languages::en::RULES.explain_singularize("firemen");
+        assert_eq!(explanation.output(), "fireman");
+        assert_eq!(explanation.rule(), Some(&Rule::Irregular));
+        let explanation = crate::languages::en::RULES.explain_pluralize("human");
+        assert_eq!(
+            explanation.rule(),
+            Some(&Rule::Suffix {
+                suffix: String::new(),
+                replacement: "s".into(),
+            })
+        );
+    }
+
     #[test]
     fn test_debug() {
         let _ = format!("{TEST_RULES:?}");
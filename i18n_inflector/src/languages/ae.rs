@@ -4,8 +4,4 @@ use crate::language_rules::LanguageRuleSet;
 
 pub(crate) use super::ja::{pluralize, singularize};
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "ae",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("ae", singularize, pluralize);
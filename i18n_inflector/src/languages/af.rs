@@ -6,11 +6,7 @@ use alloc::format;
 use alloc::vec;
 use alloc::vec::Vec;
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "af",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("af", singularize, pluralize);
 
 /// Converts a plural Afrikaans noun to its singular form.
 ///
@@ -8,11 +8,7 @@ use alloc::format;
 use alloc::vec;
 use alloc::vec::Vec;
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "am",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("am", singularize, pluralize);
 
 /// Converts a plural Amharic noun (Latin transliteration) to its singular form.
 ///
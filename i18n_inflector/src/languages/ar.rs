@@ -3,15 +3,14 @@
 //! Also used for Hebrew (he) and Yiddish (yi).
 
 use crate::language_rules::LanguageRuleSet;
+use crate::plural_rules::PluralCategory;
 use alloc::borrow::Cow;
 use alloc::format;
-use alloc::vec;
 use alloc::vec::Vec;
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "ar",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    count_form_fn: Some(count_form),
+    ..LanguageRuleSet::new("ar", singularize, pluralize)
 };
 
 /// Converts a plural Semitic noun to its singular form.
@@ -51,24 +50,59 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a Semitic noun.
+/// Returns `true` if the word is written in the Arabic script.
+fn is_arabic(name: &str) -> bool {
+    name.chars()
+        .any(|ch| ('\u{0600}'..='\u{06FF}').contains(&ch))
+}
+
+/// Returns a list of possible plural forms for a Semitic noun, most likely first.
 ///
-/// Generates both Latin transliteration and native Arabic plural forms.
+/// Generates both Latin transliteration and native Arabic plural forms; forms in the script of
+/// the input are ranked first.
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    vec![
+    let latin = [
         format!("{name}im").into(),
         format!("{name}ot").into(),
         format!("{name}at").into(),
         format!("{name}in").into(),
+    ];
+    let native = [
         format!("{name}ون").into(),
         format!("{name}ين").into(),
         format!("{name}ات").into(),
-    ]
+    ];
+    if is_arabic(name) {
+        native.into_iter().chain(latin).collect()
+    } else {
+        latin.into_iter().chain(native).collect()
+    }
+}
+
+/// Returns the dual of an Arabic noun (`ملف` -> `ملفان`, `صفحة` -> `صفحتان`).
+fn dual(name: &str) -> Cow<'_, str> {
+    match name.strip_suffix('ة') {
+        Some(stem) => format!("{stem}تان").into(),
+        None => format!("{name}ان").into(),
+    }
+}
+
+/// Returns the form of a singular Arabic noun required by a number.
+///
+/// Two takes the dual (Arabic script only), `3`-`10` take the plural, and `11` and above take
+/// the singular (`١١ ملفًا`, `١٠٠ ملف`).
+pub(crate) fn count_form(name: &str, category: PluralCategory) -> Option<Cow<'_, str>> {
+    match category {
+        PluralCategory::Two if is_arabic(name) => Some(dual(name)),
+        PluralCategory::Many | PluralCategory::Other => Some(Cow::Borrowed(name)),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::language_rules::LanguageRules;
 
     #[test]
     fn test_singularize_latin() {
@@ -119,4 +153,29 @@ mod tests {
     fn test_empty() {
         assert_eq!(singularize(""), "");
     }
+
+    #[test]
+    fn test_pluralize_ranking() {
+        assert_eq!(pluralize("meshtatef")[0], "meshtatefim");
+        assert_eq!(pluralize("معلم")[0], "معلمون");
+        assert_eq!(pluralize("معلم").len(), 7);
+    }
+
+    #[test]
+    fn test_count_form() {
+        assert_eq!(count_form("ملف", PluralCategory::Two).unwrap(), "ملفان");
+        assert_eq!(count_form("صفحة", PluralCategory::Two).unwrap(), "صفحتان");
+        assert_eq!(count_form("ملف", PluralCategory::Many).unwrap(), "ملف");
+        assert!(count_form("ملف", PluralCategory::Few).is_none());
+        assert!(count_form("kitab", PluralCategory::Two).is_none());
+    }
+
+    #[test]
+    fn test_inflect_for_count() {
+        assert_eq!(RULES.inflect_for_count("معلم", 1), "معلم");
+        assert_eq!(RULES.inflect_for_count("معلم", 2), "معلمان");
+        assert_eq!(RULES.inflect_for_count("معلم", 3), "معلمون");
+        assert_eq!(RULES.inflect_for_count("معلمون", 11), "معلم");
+        assert_eq!(RULES.inflect_for_count("معلم", 100), "معلم");
+    }
 }
@@ -1,11 +1,174 @@
 //! Belarusian (be) inflection rules.
+//!
+//! Words in Cyrillic script are declined natively; words in Latin script use the Ukrainian
+//! transliteration rules. The script is detected per word.
 
 use crate::language_rules::LanguageRuleSet;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 
-pub(crate) use super::uk::{pluralize, singularize};
+use super::uk::{self, is_cyrillic};
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "be",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+use super::strip;
+
+/// Irregular nouns as (singular, nominative plural, genitive plural).
+static IRREGULARS: &[(&str, &str, &str)] = &[
+    ("чалавек", "людзі", "людзей"),
+    ("дзіця", "дзеці", "дзяцей"),
+    ("сябар", "сябры", "сяброў"),
+    ("вока", "вочы", "вачэй"),
+    ("вуха", "вушы", "вушэй"),
+    ("імя", "імёны", "імёнаў"),
+    ("дзень", "дні", "дзён"),
+];
+
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("be", singularize, pluralize);
+
+/// Converts a plural Belarusian noun to its singular form.
+///
+/// Handles irregular nouns (`людзі` -> `чалавек`), the nominative plural endings `-ы` and `-і`
+/// (`файлы` -> `файл`, `кнігі` -> `кніга`, `музеі` -> `музей`, `коні` -> `конь`) and the
+/// genitive plural endings `-аў`, `-оў` and `-яў` (`файлаў` -> `файл`). Latin transliterations
+/// use the Ukrainian rules.
+pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if !is_cyrillic(name) {
+        return uk::singularize(name);
+    }
+    if let Some(&(singular, _, _)) = IRREGULARS
+        .iter()
+        .find(|&&(_, plural, genitive)| name == plural || name == genitive)
+    {
+        return Cow::Borrowed(singular);
+    }
+    if let Some(stem) = strip(name, "аў").or_else(|| strip(name, "оў")) {
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = strip(name, "яў").or_else(|| strip(name, "ёў")) {
+        return if stem.ends_with(is_vowel) {
+            format!("{stem}й").into()
+        } else {
+            format!("{stem}ь").into()
+        };
+    }
+    if let Some(stem) = strip(name, "ы") {
+        if stem.ends_with("іц") {
+            return format!("{stem}а").into();
+        }
+        if let Some(root) = strip(stem, "ц") {
+            return format!("{root}ец").into();
+        }
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = strip(name, "і") {
+        // Feminines in `-ка` (`памылка`) and `-іга`/`-уга` (`кніга`, `паслуга`) against
+        // masculines with a vowel before the velar (`урок`, `дыялог`).
+        if let Some(root) = stem.strip_suffix(['к', 'г', 'х']) {
+            let feminine = if stem.ends_with('г') {
+                root.ends_with(['і', 'у'])
+            } else {
+                !root.is_empty() && !root.ends_with(is_vowel)
+            };
+            return if feminine {
+                format!("{stem}а").into()
+            } else {
+                Cow::Borrowed(stem)
+            };
+        }
+        if stem.ends_with(is_vowel) {
+            return format!("{stem}й").into();
+        }
+        return format!("{stem}ь").into();
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns a list of possible plural forms for a Belarusian noun, most likely first.
+///
+/// Hard stems take `-ы` and soft and velar stems `-і` (`файл` -> `файлы`, `задача` -> `задачы`,
+/// `кніга` -> `кнігі`, `песня` -> `песні`, `музей` -> `музеі`). Latin transliterations use the
+/// Ukrainian rules.
+pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if !is_cyrillic(name) {
+        return uk::pluralize(name);
+    }
+    if let Some(&(_, plural, _)) = IRREGULARS
+        .iter()
+        .find(|&&(singular, _, _)| name == singular)
+    {
+        return vec![Cow::Borrowed(plural)];
+    }
+    if let Some(stem) = strip(name, "ец") {
+        let fleeting = if stem.ends_with(is_vowel) {
+            format!("{stem}йцы")
+        } else {
+            format!("{stem}цы")
+        };
+        return vec![fleeting.into()];
+    }
+    if let Some(stem) = strip(name, "а").or_else(|| strip(name, "о")) {
+        let ending = if is_velar(stem) { "і" } else { "ы" };
+        return vec![format!("{stem}{ending}").into()];
+    }
+    if let Some(stem) = strip(name, "я")
+        .or_else(|| strip(name, "е"))
+        .or_else(|| strip(name, "ь"))
+        .or_else(|| strip(name, "й"))
+    {
+        return vec![format!("{stem}і").into()];
+    }
+    let ending = if is_velar(name) { "і" } else { "ы" };
+    vec![format!("{name}{ending}").into()]
+}
+
+/// Returns `true` if `ch` is a Belarusian vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "аеёіоуыэюя".contains(ch)
+}
+
+/// Returns `true` if the stem ends in a velar consonant, after which `ы` is spelled `і`.
+fn is_velar(stem: &str) -> bool {
+    stem.ends_with(['к', 'г', 'х'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("файлы"), "файл");
+        assert_eq!(singularize("кнігі"), "кніга");
+        assert_eq!(singularize("памылкі"), "памылка");
+        assert_eq!(singularize("урокі"), "урок");
+        assert_eq!(singularize("музеі"), "музей");
+        assert_eq!(singularize("коні"), "конь");
+        assert_eq!(singularize("вуліцы"), "вуліца");
+        assert_eq!(singularize("файлаў"), "файл");
+        assert_eq!(singularize("людзі"), "чалавек");
+        assert_eq!(singularize("дзяцей"), "дзіця");
+    }
+
+    #[test]
+    fn test_singularize_transliterated() {
+        assert_eq!(singularize("pradukty"), "pradukta");
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("файл"), vec!["файлы"]);
+        assert_eq!(pluralize("задача"), vec!["задачы"]);
+        assert_eq!(pluralize("кніга"), vec!["кнігі"]);
+        assert_eq!(pluralize("урок"), vec!["урокі"]);
+        assert_eq!(pluralize("песня"), vec!["песні"]);
+        assert_eq!(pluralize("музей"), vec!["музеі"]);
+        assert_eq!(pluralize("чалавек"), vec!["людзі"]);
+        assert!(pluralize("produkt").iter().any(|v| v == "produkty"));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(singularize(""), "");
+    }
+}
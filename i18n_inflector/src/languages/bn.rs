@@ -8,11 +8,7 @@ use alloc::format;
 use alloc::vec;
 use alloc::vec::Vec;
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "bn",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("bn", singularize, pluralize);
 
 /// Converts a plural Bengali noun to its singular form.
 ///
@@ -2,10 +2,10 @@
 
 use crate::language_rules::LanguageRuleSet;
 
-pub(crate) use super::it::{pluralize, singularize};
+pub(crate) use super::it::{pluralize, singularize, singularize_candidates};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "co",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    irregulars: Some(&super::it::IRREGULARS),
+    ..LanguageRuleSet::new("co", singularize, pluralize)
 };
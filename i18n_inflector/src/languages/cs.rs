@@ -1,74 +1,420 @@
-//! Czech and Slovak (cs, sk) inflection rules.
+//! Czech (cs) inflection rules.
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, irregulars};
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
+
+use super::strip;
+
+/// Irregular Czech nouns with their nominative plurals: suppletive plurals, old duals
+/// (`oko` -> `oči`), the mobile `-e-` (`pes` -> `psi`), the `ů` -> `o` alternation
+/// (`stůl` -> `stoly`) and animate masculines without a telling ending (`kluk` -> `kluci`).
+static IRREGULARS: Irregulars = irregulars! {
+    "bratr" => "bratři",
+    "člověk" => "lidé",
+    "dítě" => "děti",
+    "den" => "dny",
+    "host" => "hosté",
+    "kůň" => "koně",
+    "noha" => "nohy",
+    "oko" => "oči",
+    "pes" => "psi",
+    "přítel" => "přátelé",
+    "rok" => "roky",
+    "ruka" => "ruce",
+    "týden" => "týdny",
+    "ucho" => "uši",
+    // Vowel alternation
+    "dvůr" => "dvory",
+    "důl" => "doly",
+    "dům" => "domy",
+    "nůž" => "nože",
+    "stůl" => "stoly",
+    "vůl" => "voli",
+    "vůz" => "vozy",
+    // Animate masculines
+    "kluk" => "kluci",
+    "muž" => "muži",
+    "pán" => "páni",
+    "soused" => "sousedé",
+    "vnuk" => "vnuci",
+};
+
+/// Feminine Czech nouns in `-a` with a hard stem, whose plural in `-y` cannot be told apart from
+/// that of a masculine noun (`ženy` -> `žena`, but `soubory` -> `soubor`).
+static FEMININES: phf::Set<&'static str> = phf_set! {
+    "adresa", "budova", "cena", "cesta", "doba", "firma", "forma", "hodina", "hora", "hra",
+    "chyba", "kapitola", "karta", "kniha", "mapa", "metoda", "minuta", "osoba", "platba",
+    "položka", "řada", "sada", "sekunda", "sestra", "skupina", "škola", "služba", "smlouva",
+    "strana", "struktura", "voda", "změna", "zpráva", "žena",
+};
+
+/// Neuter Czech nouns in `-o`, whose plural in `-a` cannot be told apart from a feminine singular
+/// (`města` -> `město`, but `žena` is singular).
+static NEUTERS: phf::Set<&'static str> = phf_set! {
+    "auto", "číslo", "divadlo", "heslo", "jablko", "jídlo", "jméno", "kolo", "křeslo", "letadlo",
+    "logo", "místo", "město", "okno", "pivo", "pravidlo", "právo", "slovo", "tělo",
+    "tlačítko", "video", "víno",
+};
+
+/// Masculine Czech nouns for people without an ending in [`PERSONAL_ENDINGS`], which take the
+/// animate plural (`Čech` -> `Češi`, `mnich` -> `mniši`, `pilot` -> `piloti`).
+static PERSONS: phf::Set<&'static str> = phf_set! {
+    "advokát", "architekt", "astronaut", "čech", "demokrat", "diplomat", "expert", "francouz",
+    "hoch", "idiot", "inženýr", "kamarád", "kandidát", "manažer", "mnich", "partner", "pilot",
+    "vrah",
+};
+
+/// Feminine and neuter Czech nouns in `-e` after a soft consonant, whose plural is the same word
+/// and which would otherwise be read as masculine plurals (`růže`, but `klíče` -> `klíč`).
+static SOFT_E_NOUNS: phf::Set<&'static str> = phf_set! {
+    "bouře", "duše", "dveře", "kaše", "kůže", "lože", "moře", "paže", "růže", "večeře", "záře",
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "cs",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    irregulars: Some(&IRREGULARS),
+    ..LanguageRuleSet::new("cs", singularize, pluralize)
 };
 
-/// Converts a plural Czech / Slovak noun to its singular form.
+/// Endings of masculine nouns that usually denote people and take the animate plural
+/// (`student` -> `studenti`, `doktor` -> `doktoři`, `učitel` -> `učitelé`).
+const PERSONAL_ENDINGS: &[&str] = &[
+    "ent", "ant", "log", "tel", "tor", "ař", "ář", "íř", "ák", "ník",
+];
+
+/// Endings in [`PERSONAL_ENDINGS`] of nouns that denote things (`dokument` -> `dokumenty`).
+const IMPERSONAL_ENDINGS: &[&str] = &["ment"];
+
+/// Stem-final consonants of masculine animate nouns with the plural endings that replace them
+/// (`voják` -> `vojáci`, `doktor` -> `doktoři`, `Čech` -> `Češi`, `vrah` -> `vrazi`).
+const PERSONAL_ALTERNATIONS: &[(&str, &str)] = &[
+    ("tel", "telé"),
+    ("log", "logové"),
+    ("ch", "ši"),
+    ("k", "ci"),
+    ("r", "ři"),
+    ("h", "zi"),
+];
+
+/// Soft consonants, after which nouns take `-e` in the plural (`klíč` -> `klíče`).
+const SOFT_CONSONANTS: &[char] = &['č', 'ř', 'š', 'ž', 'c', 'j', 'ť', 'ď', 'ň'];
+
+/// Returns `true` if `ch` is a Czech vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "aáeéěiíoóuúůyý".contains(ch)
+}
+
+/// Returns the animate plural of a masculine noun, which alternates its final consonant
+/// (`voják` -> `vojáci`, `doktor` -> `doktoři`) or adds `-i` (`student` -> `studenti`).
+fn personal_plural(name: &str) -> String {
+    PERSONAL_ALTERNATIONS
+        .iter()
+        .find_map(|&(consonant, ending)| {
+            let stem = name.strip_suffix(consonant)?;
+            Some(format!("{stem}{ending}"))
+        })
+        .unwrap_or_else(|| format!("{name}i"))
+}
+
+/// Converts a plural Czech noun to its singular form.
 ///
-/// Handles `-y`, `-e`, and `-i` plural suffixes.
+/// Handles irregular nouns (`lidé` -> `člověk`), the animate endings `-ové`, `-é` and `-isté`,
+/// and reverses the consonant alternations of masculine animate nouns (`vojáci` -> `voják`,
+/// `doktoři` -> `doktor`, `Češi` -> `Čech`), except `-zi`, which is more often a plain `z` or
+/// `ž` than an alternated `h`. Feminines in `-ky` take `-ka` after a consonant
+/// (`banky` -> `banka`), Latin neuters in `-ea` and `-ia` take `-eum` and `-ium`, and soft stems
+/// drop `-e` (`klíče` -> `klíč`) unless they are known feminines or neuters in `-e` (`růže`).
+/// Known neuters and those in `-dla` and `-sla` replace `-a` with `-o` (`města` -> `město`),
+/// known feminines replace `-y` with `-a` (`ženy` -> `žena`), and other nouns drop the `-y` and
+/// `-i` plural suffixes.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
-    if let Some(stem) = name.strip_suffix('y')
-        && !stem.is_empty()
-    {
+    if let Some(singular) = IRREGULARS.singular(name) {
+        return Cow::Borrowed(singular);
+    }
+    if let Some(stem) = strip(name, "ové") {
         return Cow::Borrowed(stem);
     }
-    if let Some(stem) = name.strip_suffix('e')
-        && !stem.is_empty()
+    for (plural, singular) in [
+        ("isté", "ista"),
+        ("telé", "tel"),
+        ("tele", "tel"),
+        ("osti", "ost"),
+        ("ea", "eum"),
+        ("ia", "ium"),
+        ("ši", "ch"),
+    ] {
+        if let Some(stem) = strip(name, plural) {
+            return format!("{stem}{singular}").into();
+        }
+    }
+    if let Some(stem) = strip(name, "ci") {
+        // A vowel before `-ci` marks an alternated `-k` (`vojáci` -> `voják`), a consonant
+        // marks a noun in `-ec` with its mobile `-e-` (`Němci` -> `Němec`).
+        let ending = if stem.ends_with(is_vowel) { "k" } else { "ec" };
+        return format!("{stem}{ending}").into();
+    }
+    if let Some(stem) = strip(name, "ři") {
+        // Nouns in `-ař`, `-ář` and `-íř` keep their `ř` (`lékaři` -> `lékař`), others
+        // alternate `r` (`doktoři` -> `doktor`).
+        let ending = if stem.ends_with(['a', 'á', 'í']) {
+            "ř"
+        } else {
+            "r"
+        };
+        return format!("{stem}{ending}").into();
+    }
+    if let Some(stem) = strip(name, "ky")
+        && !stem.ends_with(is_vowel)
     {
-        return Cow::Borrowed(stem);
+        return format!("{stem}ka").into();
     }
-    if let Some(stem) = name.strip_suffix('i')
-        && !stem.is_empty()
+    if let Some(stem) = strip(name, "e")
+        && stem.ends_with(SOFT_CONSONANTS)
+        && !stem.ends_with('c')
+        && !SOFT_E_NOUNS.contains(name)
     {
         return Cow::Borrowed(stem);
     }
+    if let Some(stem) = strip(name, "a") {
+        let neuter = format!("{stem}o");
+        if NEUTERS.contains(&*neuter) || stem.ends_with("dl") || stem.ends_with("sl") {
+            return neuter.into();
+        }
+    }
+    if let Some(stem) = strip(name, "y") {
+        let feminine = format!("{stem}a");
+        if FEMININES.contains(&*feminine) {
+            return feminine.into();
+        }
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = strip(name, "i") {
+        return Cow::Borrowed(stem);
+    }
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a Czech/Slovak noun.
+/// Returns the possible singular forms of a Czech noun, most likely first.
+///
+/// Starts with the result of [`singularize`], followed by the other readings of a plural: `-y`
+/// may belong to a feminine in `-a` or a masculine (`ženy` -> `žena`, `žen`), `-a` after a
+/// consonant to a neuter in `-o` or a feminine singular (`okna` -> `okno`, `okna`), and `-e`
+/// after a soft consonant to a masculine or a feminine in `-e` (`klíče` -> `klíč`, `klíče`).
+/// Irregular nouns only have their irregular singular.
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    let mut candidates = vec![singularize(name)];
+    if IRREGULARS.singular(name).is_some() {
+        return candidates;
+    }
+    if let Some(stem) = strip(name, "y") {
+        candidates.push(format!("{stem}a").into());
+        candidates.push(Cow::Borrowed(stem));
+    } else if let Some(stem) = strip(name, "a")
+        && !stem.ends_with(is_vowel)
+        && !FEMININES.contains(name)
+    {
+        candidates.push(format!("{stem}o").into());
+        candidates.push(Cow::Borrowed(name));
+    } else if let Some(stem) = strip(name, "e")
+        && stem.ends_with(SOFT_CONSONANTS)
+    {
+        candidates.push(Cow::Borrowed(stem));
+        candidates.push(Cow::Borrowed(name));
+    }
+    candidates
+}
+
+/// Returns a list of possible plural forms for a Czech noun, most likely first.
+///
+/// Irregular nouns are looked up first (`člověk` -> `lidé`, `dítě` -> `děti`). Neuters in `-o`
+/// and `-um` take `-a`, feminines in `-a` take `-y` or `-e` after a soft consonant, feminines in
+/// `-ost` take `-i`, and nouns in `-e`, `-ě` and `-í` keep their form (`ulice`, `nádraží`).
+/// Masculines take `-y`, or `-e` after a soft consonant (`soubor` -> `soubory`, `klíč` ->
+/// `klíče`), shortening a final `ů` to `o` (`kůl` -> `koly`). Known nouns for people take the
+/// animate plural (`Čech` -> `Češi`, `mnich` -> `mniši`), and those ending like them prefer it
+/// (`doktor` -> `doktoři`, `specialista` -> `specialisté`).
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    vec![
-        format!("{name}y").into(),
-        format!("{name}e").into(),
-        format!("{name}i").into(),
-    ]
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    if let Some(stem) = strip(name, "um").or_else(|| strip(name, "o")) {
+        return vec![format!("{stem}a").into()];
+    }
+    if let Some(stem) = strip(name, "ista") {
+        return vec![format!("{stem}isté").into()];
+    }
+    if let Some(stem) = strip(name, "a") {
+        let ending = if stem.ends_with(SOFT_CONSONANTS) {
+            "e"
+        } else {
+            "y"
+        };
+        return vec![format!("{stem}{ending}").into()];
+    }
+    if name.ends_with("ost") {
+        return vec![format!("{name}i").into()];
+    }
+    if !name.ends_with(|ch: char| ch.is_alphabetic() && !is_vowel(ch)) {
+        return vec![Cow::Borrowed(name)];
+    }
+    let ending = if name.ends_with(SOFT_CONSONANTS) {
+        "e"
+    } else {
+        "y"
+    };
+    let inanimate = match name.char_indices().rev().nth(1) {
+        Some((index, 'ů')) => format!(
+            "{}o{}{ending}",
+            &name[..index],
+            &name[index + 'ů'.len_utf8()..]
+        ),
+        _ => format!("{name}{ending}"),
+    }
+    .into();
+    let personal = PERSONAL_ENDINGS.iter().any(|suffix| name.ends_with(suffix))
+        && !IMPERSONAL_ENDINGS
+            .iter()
+            .any(|suffix| name.ends_with(suffix));
+    if PERSONS.contains(name) {
+        vec![personal_plural(name).into()]
+    } else if personal {
+        vec![personal_plural(name).into(), inanimate]
+    } else {
+        vec![inanimate]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::language_rules::LanguageRules;
 
     #[test]
     fn test_singularize() {
         assert_eq!(singularize("produkty"), "produkt");
         assert_eq!(singularize("uzivatele"), "uzivatel");
         assert_eq!(singularize("muzi"), "muz");
+        assert_eq!(singularize("soubory"), "soubor");
+        assert_eq!(singularize("klíče"), "klíč");
+        assert_eq!(singularize("banky"), "banka");
+        assert_eq!(singularize("místnosti"), "místnost");
+        assert_eq!(singularize("muzea"), "muzeum");
+        assert_eq!(singularize("ulice"), "ulice");
+        assert_eq!(singularize("ženy"), "žena");
+        assert_eq!(singularize("skupiny"), "skupina");
+        assert_eq!(singularize("města"), "město");
+        assert_eq!(singularize("pravidla"), "pravidlo");
+        assert_eq!(singularize("stoly"), "stůl");
     }
 
     #[test]
     fn test_singularize_already_singular() {
         assert_eq!(singularize("produkt"), "produkt");
+        assert_eq!(singularize("žena"), "žena");
+        assert_eq!(singularize("město"), "město");
+    }
+
+    #[test]
+    fn test_singularize_alternations() {
+        assert_eq!(singularize("vojáci"), "voják");
+        assert_eq!(singularize("kluci"), "kluk");
+        assert_eq!(singularize("Němci"), "Němec");
+        assert_eq!(singularize("doktoři"), "doktor");
+        assert_eq!(singularize("lékaři"), "lékař");
+        assert_eq!(singularize("Češi"), "Čech");
+        assert_eq!(singularize("biologové"), "biolog");
+        assert_eq!(singularize("učitelé"), "učitel");
+        assert_eq!(singularize("specialisté"), "specialista");
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("lidé"), "člověk");
+        assert_eq!(singularize("děti"), "dítě");
+        assert_eq!(singularize("psi"), "pes");
+        assert_eq!(pluralize("člověk"), vec!["lidé"]);
+        assert_eq!(pluralize("oko"), vec!["oči"]);
+        assert_eq!(pluralize("týden"), vec!["týdny"]);
+        assert_eq!(pluralize("stůl"), vec!["stoly"]);
+        assert_eq!(pluralize("kluk"), vec!["kluci"]);
     }
 
     #[test]
     fn test_pluralize() {
-        let result = pluralize("produkt");
-        assert!(result.iter().any(|v| v == "produkty"));
-        assert!(result.iter().any(|v| v == "produkte"));
-        assert!(result.iter().any(|v| v == "produkti"));
+        assert_eq!(pluralize("produkt"), vec!["produkty"]);
+        assert_eq!(pluralize("soubor"), vec!["soubory"]);
+        assert_eq!(pluralize("klíč"), vec!["klíče"]);
+        assert_eq!(pluralize("stroj"), vec!["stroje"]);
+        assert_eq!(pluralize("žena"), vec!["ženy"]);
+        assert_eq!(pluralize("banka"), vec!["banky"]);
+        assert_eq!(pluralize("město"), vec!["města"]);
+        assert_eq!(pluralize("muzeum"), vec!["muzea"]);
+        assert_eq!(pluralize("místnost"), vec!["místnosti"]);
+        assert_eq!(pluralize("ulice"), vec!["ulice"]);
+        assert_eq!(pluralize("nádraží"), vec!["nádraží"]);
+        assert_eq!(pluralize("kůl"), vec!["koly"]);
+    }
+
+    #[test]
+    fn test_pluralize_personal() {
+        assert_eq!(pluralize("student"), vec!["studenti", "studenty"]);
+        assert_eq!(pluralize("doktor"), vec!["doktoři", "doktory"]);
+        assert_eq!(pluralize("lékař"), vec!["lékaři", "lékaře"]);
+        assert_eq!(pluralize("učitel")[0], "učitelé");
+        assert_eq!(pluralize("biolog")[0], "biologové");
+        assert_eq!(pluralize("specialista"), vec!["specialisté"]);
+        assert_eq!(pluralize("dokument"), vec!["dokumenty"]);
+        assert_eq!(pluralize("voják"), vec!["vojáci", "vojáky"]);
+        assert_eq!(pluralize("pracovník")[0], "pracovníci");
+        assert_eq!(personal_plural("voják"), "vojáci");
+        assert_eq!(personal_plural("Čech"), "Češi");
+        assert_eq!(personal_plural("vrah"), "vrazi");
+    }
+
+    #[test]
+    fn test_pluralize_persons() {
+        assert_eq!(pluralize("čech"), vec!["češi"]);
+        assert_eq!(pluralize("mnich"), vec!["mniši"]);
+        assert_eq!(pluralize("pilot"), vec!["piloti"]);
+        assert_eq!(pluralize("inženýr"), vec!["inženýři"]);
+        assert_eq!(RULES.pluralize("Čech"), vec!["Češi"]);
+        assert_eq!(singularize("mniši"), "mnich");
+        assert_eq!(singularize("inženýři"), "inženýr");
+    }
+
+    #[test]
+    fn test_singularize_soft_e_nouns() {
+        assert_eq!(singularize("růže"), "růže");
+        assert_eq!(singularize("moře"), "moře");
+        assert_eq!(singularize("klíče"), "klíč");
+        assert_eq!(pluralize("růže"), vec!["růže"]);
     }
 
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
     }
+
+    #[test]
+    fn test_singularize_candidates() {
+        let candidates = singularize_candidates("ženy");
+        assert_eq!(candidates[0], "žena");
+        assert!(candidates.iter().any(|v| v == "žen"));
+        let candidates = singularize_candidates("soubory");
+        assert_eq!(candidates[0], "soubor");
+        assert!(candidates.iter().any(|v| v == "soubora"));
+        let candidates = singularize_candidates("okna");
+        assert_eq!(candidates[0], "okno");
+        assert!(candidates.iter().any(|v| v == "okna"));
+        let candidates = singularize_candidates("ulice");
+        assert!(candidates.iter().any(|v| v == "ulice"));
+        assert_eq!(singularize_candidates("žena"), vec!["žena"]);
+        assert_eq!(singularize_candidates("lidé"), vec!["člověk"]);
+    }
 }
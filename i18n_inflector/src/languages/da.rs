@@ -1,6 +1,4 @@
 //! Danish (da) inflection rules.
-//!
-//! Also used for Norwegian (no) and Swedish (sv).
 
 use crate::language_rules::LanguageRuleSet;
 use alloc::borrow::Cow;
@@ -8,16 +6,11 @@ use alloc::format;
 use alloc::vec;
 use alloc::vec::Vec;
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "da",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("da", singularize, pluralize);
 
-/// Converts a plural Scandinavian noun to its singular form.
+/// Converts a plural Danish noun to its singular form.
 ///
-/// Handles `-ere`, `-er`, `-ar`, `-or`, and `-r` plural suffixes common
-/// across Danish, Norwegian, and Swedish.
+/// Handles `-ere`, `-er`, `-ar`, `-or`, and `-r` plural suffixes.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     if let Some(stem) = name.strip_suffix("ere")
         && !stem.is_empty()
@@ -47,15 +40,18 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a Scandinavian noun.
+/// Returns a list of possible plural forms for a Danish noun, most likely first.
+///
+/// Nouns ending in `-e` prefer `-r`; all other nouns prefer `-er`.
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    vec![
-        format!("{name}ere").into(),
-        format!("{name}er").into(),
-        format!("{name}ar").into(),
-        format!("{name}or").into(),
-        format!("{name}r").into(),
-    ]
+    let preferred = if name.ends_with('e') { "r" } else { "er" };
+    let mut candidates = vec![format!("{name}{preferred}").into()];
+    for suffix in ["ere", "er", "ar", "or", "r"] {
+        if suffix != preferred {
+            candidates.push(format!("{name}{suffix}").into());
+        }
+    }
+    candidates
 }
 
 #[cfg(test)]
@@ -118,6 +114,12 @@ mod tests {
         assert!(result.iter().any(|v| v == "produktr"));
     }
 
+    #[test]
+    fn test_pluralize_ranking() {
+        assert_eq!(pluralize("produkt")[0], "produkter");
+        assert_eq!(pluralize("kone")[0], "koner");
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
@@ -1,94 +1,834 @@
 //! German (de) inflection rules.
+//!
+//! Plurals follow the main German noun classes: `-e`, often with umlaut (`Tag` -> `Tage`,
+//! `Baum` -> `Bäume`), `-er` with umlaut (`Haus` -> `Häuser`), `-(e)n` for feminines and weak
+//! masculines (`Rechnung` -> `Rechnungen`, `Kunde` -> `Kunden`), `-s` for loanwords (`Auto` ->
+//! `Autos`) and zero plurals for nouns in `-el`, `-er` and `-en` (`Lehrer` -> `Lehrer`). Nouns
+//! whose class cannot be told from their ending are listed in an irregular table, which also
+//! applies to the last element of compounds (`Rathaus` -> `Rathäuser`).
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
+
+use super::{strip, vowel_groups};
+
+/// Irregular German nouns, also applied to the last element of compounds (`Rathaus` ->
+/// `Rathäuser`): umlauted plurals, nouns in a consonant that take `-(e)n`, compounds in `-mann`
+/// with plurals in `-leute`, English loanwords in `-s` and classical loanwords. Nouns whose plural
+/// follows from the suffix rules (`Christ`, `Spur`) are not listed.
+pub(crate) static IRREGULARS: Irregulars = irregulars! {
+    // -er, mostly with umlaut
+    "altertum" => "altertümer",
+    "amt" => "ämter",
+    "bad" => "bäder",
+    "biest" => "biester",
+    "bild" => "bilder",
+    "bistum" => "bistümer",
+    "blatt" => "blätter",
+    "brett" => "bretter",
+    "buch" => "bücher",
+    "dach" => "dächer",
+    "denkmal" => "denkmäler",
+    "dorf" => "dörfer",
+    "fach" => "fächer",
+    "fass" => "fässer",
+    "feld" => "felder",
+    "fürstentum" => "fürstentümer",
+    "gehalt" => "gehälter",
+    "geist" => "geister",
+    "geld" => "gelder",
+    "gemüt" => "gemüter",
+    "geschlecht" => "geschlechter",
+    "gesicht" => "gesichter",
+    "gespenst" => "gespenster",
+    "gewand" => "gewänder",
+    "glas" => "gläser",
+    "glied" => "glieder",
+    "gott" => "götter",
+    "grab" => "gräber",
+    "gras" => "gräser",
+    "gut" => "güter",
+    "haupt" => "häupter",
+    "haus" => "häuser",
+    "heiligtum" => "heiligtümer",
+    "herzogtum" => "herzogtümer",
+    "holz" => "hölzer",
+    "horn" => "hörner",
+    "hospital" => "hospitäler",
+    "huhn" => "hühner",
+    "irrtum" => "irrtümer",
+    "kalb" => "kälber",
+    "kind" => "kinder",
+    "kleid" => "kleider",
+    "korn" => "körner",
+    "kraut" => "kräuter",
+    "lamm" => "lämmer",
+    "land" => "länder",
+    "leib" => "leiber",
+    "licht" => "lichter",
+    "lid" => "lider",
+    "lied" => "lieder",
+    "loch" => "löcher",
+    "mann" => "männer",
+    "maul" => "mäuler",
+    "mund" => "münder",
+    "nest" => "nester",
+    "rand" => "ränder",
+    "regiment" => "regimenter",
+    "reichtum" => "reichtümer",
+    "rind" => "rinder",
+    "schild" => "schilder",
+    "schloss" => "schlösser",
+    "schwert" => "schwerter",
+    "spital" => "spitäler",
+    "strauch" => "sträucher",
+    "tuch" => "tücher",
+    "volk" => "völker",
+    "wald" => "wälder",
+    "weib" => "weiber",
+    "wort" => "wörter",
+    "wurm" => "würmer",
+    // -e with umlaut
+    "angst" => "ängste",
+    "arzt" => "ärzte",
+    "axt" => "äxte",
+    "bach" => "bäche",
+    "ball" => "bälle",
+    "bart" => "bärte",
+    "bauch" => "bäuche",
+    "baum" => "bäume",
+    "block" => "blöcke",
+    "bock" => "böcke",
+    "brand" => "brände",
+    "brauch" => "bräuche",
+    "braut" => "bräute",
+    "bruch" => "brüche",
+    "brust" => "brüste",
+    "bund" => "bünde",
+    "busch" => "büsche",
+    "damm" => "dämme",
+    "dampf" => "dämpfe",
+    "darm" => "därme",
+    "draht" => "drähte",
+    "duft" => "düfte",
+    "fall" => "fälle",
+    "fang" => "fänge",
+    "faust" => "fäuste",
+    "floh" => "flöhe",
+    "floß" => "flöße",
+    "fluch" => "flüche",
+    "flug" => "flüge",
+    "fluss" => "flüsse",
+    "frosch" => "frösche",
+    "frost" => "fröste",
+    "frucht" => "früchte",
+    "fuchs" => "füchse",
+    "fuß" => "füße",
+    "gang" => "gänge",
+    "gans" => "gänse",
+    "gast" => "gäste",
+    "gaul" => "gäule",
+    "geruch" => "gerüche",
+    "gruft" => "grüfte",
+    "grund" => "gründe",
+    "gruß" => "grüße",
+    "guss" => "güsse",
+    "hahn" => "hähne",
+    "hals" => "hälse",
+    "hand" => "hände",
+    "hang" => "hänge",
+    "haut" => "häute",
+    "hof" => "höfe",
+    "hut" => "hüte",
+    "kamm" => "kämme",
+    "kampf" => "kämpfe",
+    "kanal" => "kanäle",
+    "kauf" => "käufe",
+    "kauz" => "käuze",
+    "klang" => "klänge",
+    "klotz" => "klötze",
+    "kloß" => "klöße",
+    "kluft" => "klüfte",
+    "knopf" => "knöpfe",
+    "koch" => "köche",
+    "kopf" => "köpfe",
+    "korb" => "körbe",
+    "kraft" => "kräfte",
+    "kran" => "kräne",
+    "kranz" => "kränze",
+    "kropf" => "kröpfe",
+    "krug" => "krüge",
+    "kuh" => "kühe",
+    "kunst" => "künste",
+    "kuss" => "küsse",
+    "lauf" => "läufe",
+    "laus" => "läuse",
+    "lohn" => "löhne",
+    "luft" => "lüfte",
+    "lust" => "lüste",
+    "macht" => "mächte",
+    "magd" => "mägde",
+    "markt" => "märkte",
+    "marsch" => "märsche",
+    "maus" => "mäuse",
+    "nacht" => "nächte",
+    "naht" => "nähte",
+    "napf" => "näpfe",
+    "not" => "nöte",
+    "nuss" => "nüsse",
+    "palast" => "paläste",
+    "pass" => "pässe",
+    "pfahl" => "pfähle",
+    "pflock" => "pflöcke",
+    "plan" => "pläne",
+    "platz" => "plätze",
+    "rang" => "ränge",
+    "raum" => "räume",
+    "rausch" => "räusche",
+    "rock" => "röcke",
+    "saal" => "säle",
+    "sack" => "säcke",
+    "saft" => "säfte",
+    "sarg" => "särge",
+    "satz" => "sätze",
+    "schlag" => "schläge",
+    "schlauch" => "schläuche",
+    "schluss" => "schlüsse",
+    "schnur" => "schnüre",
+    "schrank" => "schränke",
+    "schuss" => "schüsse",
+    "schwamm" => "schwämme",
+    "schwan" => "schwäne",
+    "schwarm" => "schwärme",
+    "sohn" => "söhne",
+    "spaß" => "späße",
+    "spruch" => "sprüche",
+    "stab" => "stäbe",
+    "stadt" => "städte",
+    "stall" => "ställe",
+    "stamm" => "stämme",
+    "stand" => "stände",
+    "stock" => "stöcke",
+    "stoß" => "stöße",
+    "strang" => "stränge",
+    "strauß" => "sträuße",
+    "strom" => "ströme",
+    "stuhl" => "stühle",
+    "sturm" => "stürme",
+    "sumpf" => "sümpfe",
+    "tanz" => "tänze",
+    "topf" => "töpfe",
+    "trag" => "träge",
+    "traum" => "träume",
+    "trog" => "tröge",
+    "turm" => "türme",
+    "wall" => "wälle",
+    "wand" => "wände",
+    "wolf" => "wölfe",
+    "wunsch" => "wünsche",
+    "wurst" => "würste",
+    "zahn" => "zähne",
+    "zaun" => "zäune",
+    "zoll" => "zölle",
+    "zopf" => "zöpfe",
+    "zug" => "züge",
+    "zunft" => "zünfte",
+    "zwang" => "zwänge",
+    // Zero plural with umlaut
+    "acker" => "äcker",
+    "apfel" => "äpfel",
+    "boden" => "böden",
+    "bruder" => "brüder",
+    "faden" => "fäden",
+    "garten" => "gärten",
+    "graben" => "gräben",
+    "hafen" => "häfen",
+    "hammer" => "hämmer",
+    "kasten" => "kästen",
+    "kloster" => "klöster",
+    "laden" => "läden",
+    "magen" => "mägen",
+    "mangel" => "mängel",
+    "mantel" => "mäntel",
+    "mutter" => "mütter",
+    "nagel" => "nägel",
+    "ofen" => "öfen",
+    "sattel" => "sättel",
+    "schaden" => "schäden",
+    "schnabel" => "schnäbel",
+    "schwager" => "schwäger",
+    "tochter" => "töchter",
+    "vater" => "väter",
+    "vogel" => "vögel",
+    // Feminines in -el and -er, and in a consonant, which take -(e)n
+    "achsel" => "achseln",
+    "ampel" => "ampeln",
+    "amsel" => "amseln",
+    "antwort" => "antworten",
+    "arbeit" => "arbeiten",
+    "bahn" => "bahnen",
+    "bibel" => "bibeln",
+    "burg" => "burgen",
+    "fabel" => "fabeln",
+    "fahrt" => "fahrten",
+    "faser" => "fasern",
+    "feder" => "federn",
+    "fessel" => "fesseln",
+    "flut" => "fluten",
+    "form" => "formen",
+    "formel" => "formeln",
+    "gabel" => "gabeln",
+    "geburt" => "geburten",
+    "gestalt" => "gestalten",
+    "insel" => "inseln",
+    "jagd" => "jagden",
+    "kachel" => "kacheln",
+    "kammer" => "kammern",
+    "kartoffel" => "kartoffeln",
+    "klammer" => "klammern",
+    "kugel" => "kugeln",
+    "kurbel" => "kurbeln",
+    "last" => "lasten",
+    "leber" => "lebern",
+    "mauer" => "mauern",
+    "muschel" => "muscheln",
+    "nachricht" => "nachrichten",
+    "nadel" => "nadeln",
+    "nudel" => "nudeln",
+    "nummer" => "nummern",
+    "orgel" => "orgeln",
+    "pflicht" => "pflichten",
+    "qual" => "qualen",
+    "regel" => "regeln",
+    "saat" => "saaten",
+    "schachtel" => "schachteln",
+    "schar" => "scharen",
+    "schaufel" => "schaufeln",
+    "schicht" => "schichten",
+    "schlacht" => "schlachten",
+    "schrift" => "schriften",
+    "schuld" => "schulden",
+    "schüssel" => "schüsseln",
+    "schwester" => "schwestern",
+    "sicht" => "sichten",
+    "steuer" => "steuern",
+    "tafel" => "tafeln",
+    "tracht" => "trachten",
+    "tür" => "türen",
+    "uhr" => "uhren",
+    "wahl" => "wahlen",
+    "welt" => "welten",
+    "wimper" => "wimpern",
+    "windel" => "windeln",
+    "wurzel" => "wurzeln",
+    "zahl" => "zahlen",
+    "zeit" => "zeiten",
+    "zwiebel" => "zwiebeln",
+    // Weak masculines and other nouns in a consonant, which take -(e)n
+    "advokat" => "advokaten",
+    "architekt" => "architekten",
+    "aristokrat" => "aristokraten",
+    "astronaut" => "astronauten",
+    "athlet" => "athleten",
+    "automat" => "automaten",
+    "bandit" => "banditen",
+    "bär" => "bären",
+    "barbar" => "barbaren",
+    "bett" => "betten",
+    "bürokrat" => "bürokraten",
+    "demokrat" => "demokraten",
+    "despot" => "despoten",
+    "diplomat" => "diplomaten",
+    "eremit" => "eremiten",
+    "favorit" => "favoriten",
+    "fotograf" => "fotografen",
+    "fürst" => "fürsten",
+    "graf" => "grafen",
+    "held" => "helden",
+    "hemd" => "hemden",
+    "herr" => "herren",
+    "herz" => "herzen",
+    "hirt" => "hirten",
+    "husar" => "husaren",
+    "idiot" => "idioten",
+    "insekt" => "insekten",
+    "juwel" => "juwelen",
+    "kamerad" => "kameraden",
+    "kandidat" => "kandidaten",
+    "komet" => "kometen",
+    "kosmonaut" => "kosmonauten",
+    "magnat" => "magnaten",
+    "mensch" => "menschen",
+    "monarch" => "monarchen",
+    "narr" => "narren",
+    "nerv" => "nerven",
+    "ohr" => "ohren",
+    "patriot" => "patrioten",
+    "philosoph" => "philosophen",
+    "pilot" => "piloten",
+    "pirat" => "piraten",
+    "planet" => "planeten",
+    "poet" => "poeten",
+    "prälat" => "prälaten",
+    "prinz" => "prinzen",
+    "prophet" => "propheten",
+    "rebell" => "rebellen",
+    "satellit" => "satelliten",
+    "schmerz" => "schmerzen",
+    "soldat" => "soldaten",
+    "spatz" => "spatzen",
+    "staat" => "staaten",
+    "strahl" => "strahlen",
+    "typ" => "typen",
+    "tyrann" => "tyrannen",
+    "untertan" => "untertanen",
+    "vasall" => "vasallen",
+    "vorfahr" => "vorfahren",
+    "zar" => "zaren",
+    // Masculines in -el and -er, and nouns in -ar, which take -n
+    "bauer" => "bauern",
+    "konsul" => "konsuln",
+    "muskel" => "muskeln",
+    "nachbar" => "nachbarn",
+    "pantoffel" => "pantoffeln",
+    "stachel" => "stacheln",
+    "vetter" => "vettern",
+    // Nouns in -au that do not take -en
+    "bau" => "bauten",
+    "sau" => "säue",
+    // Loanwords
+    "adverb" => "adverbien",
+    "album" => "alben",
+    "aroma" => "aromen",
+    "atlas" => "atlanten",
+    "basis" => "basen",
+    "bus" => "busse",
+    "cello" => "celli",
+    "dogma" => "dogmen",
+    "drama" => "dramen",
+    "epos" => "epen",
+    "firma" => "firmen",
+    "fossil" => "fossilien",
+    "genus" => "genera",
+    "globus" => "globen",
+    "index" => "indizes",
+    "kaktus" => "kakteen",
+    "kaufmann" => "kaufleute",
+    "konto" => "konten",
+    "korpus" => "korpora",
+    "lexikon" => "lexika",
+    "material" => "materialien",
+    "mineral" => "mineralien",
+    "modus" => "modi",
+    "museum" => "museen",
+    "mythos" => "mythen",
+    "paradigma" => "paradigmen",
+    "praxis" => "praxen",
+    "prinzip" => "prinzipien",
+    "prisma" => "prismen",
+    "privileg" => "privilegien",
+    "reptil" => "reptilien",
+    "rhythmus" => "rhythmen",
+    "risiko" => "risiken",
+    "saldo" => "salden",
+    "schema" => "schemata",
+    "stigma" => "stigmata",
+    "studium" => "studien",
+    "tempus" => "tempora",
+    "thema" => "themen",
+    "utensil" => "utensilien",
+    "villa" => "villen",
+    "virus" => "viren",
+    "visum" => "visa",
+    "zyklus" => "zyklen",
+    // Compounds in -mann with plurals in -leute
+    "bergmann" => "bergleute",
+    "edelmann" => "edelleute",
+    "fachmann" => "fachleute",
+    "feuerwehrmann" => "feuerwehrleute",
+    "geschäftsmann" => "geschäftsleute",
+    "hauptmann" => "hauptleute",
+    "kameramann" => "kameraleute",
+    "landsmann" => "landsleute",
+    "seemann" => "seeleute",
+    "zimmermann" => "zimmerleute",
+    // English loanwords, which take -s
+    "account" => "accounts",
+    "blog" => "blogs",
+    "chef" => "chefs",
+    "chip" => "chips",
+    "club" => "clubs",
+    "download" => "downloads",
+    "event" => "events",
+    "fan" => "fans",
+    "job" => "jobs",
+    "link" => "links",
+    "login" => "logins",
+    "park" => "parks",
+    "scheck" => "schecks",
+    "shop" => "shops",
+    "song" => "songs",
+    "star" => "stars",
+    "streik" => "streiks",
+    "team" => "teams",
+    "trend" => "trends",
+    "update" => "updates",
+    "upload" => "uploads",
+    "website" => "websites",
+};
+
+/// Irregular German nouns that only apply to whole words, since they end many unrelated nouns
+/// (`Datei`, `Apparat`, `Portal`).
+static IRREGULAR_WORDS: Irregulars = irregulars! {
+    "art" => "arten",
+    "datum" => "daten",
+    "ei" => "eier",
+    "fahrrad" => "fahrräder",
+    "rad" => "räder",
+    "rat" => "räte",
+    "tal" => "täler",
+    "ton" => "töne",
+};
+
+/// Suffixes of feminine nouns and weak masculines that take `-en`.
+const EN_SUFFIXES: &[&str] = &[
+    "anz", "ei", "enz", "heit", "ik", "ion", "ist", "keit", "or", "schaft", "tät", "ung", "ur",
+];
+
+/// Suffixes of feminine nouns derived from masculines, which take `-nen`.
+const FEMININE_IN_SUFFIXES: &[&str] = &["antin", "ärin", "entin", "erin", "istin", "orin"];
+
+/// Endings of native German nouns whose final `-s` is part of the singular.
+const NATIVE_S_ENDINGS: &[&str] = &["as", "chs", "es", "is", "ls", "ns", "os", "rs", "ss", "us"];
+
+/// Loanwords in `-el`, `-er` and `-en` that take `-s` rather than the zero plural of native nouns
+/// (`Hotel` -> `Hotels`, but `Löffel` -> `Löffel`).
+static S_LOANWORDS: phf::Set<&'static str> = phf_set! {
+    "cover", "hotel", "label", "level", "motel", "poster", "token", "tunnel",
+};
+
+/// Uncountable and invariant German nouns.
+pub(crate) static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "eltern", "ferien", "gemüse", "geschwister", "kosten", "leute", "milch", "obst", "vieh",
+    },
+    uncountable_suffixes: &["chen", "lein"],
+    invariant_suffixes: &[],
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "de",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    irregulars: Some(&IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("de", singularize, pluralize)
 };
 
 /// Converts a plural German noun to its singular form.
 ///
-/// Handles common German plural suffixes: `-en`, `-er`, `-e`, `-n`, `-s`.
+/// Irregular plurals and compounds ending in one are looked up first (`Häuser` -> `Haus`,
+/// `Datensätze` -> `Datensatz`). Otherwise `-innen` becomes `-in`, `-en` is removed after the
+/// feminine and weak masculine suffixes (`Rechnungen` -> `Rechnung`, `Studenten` -> `Student`),
+/// `-n` is removed after `-e`, `-el` and `-er` (`Kunden` -> `Kunde`), `-e` is removed with umlaut
+/// reversal (`Bäume` -> `Baum`, `Kanäle` -> `Kanal`) and loanword `-s` is removed (`Autos` ->
+/// `Auto`, `Hotels` -> `Hotel`). Nouns in `-el`, `-er` and `-en` are returned unchanged, since
+/// their plural is usually identical.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
-    if let Some(stem) = name.strip_suffix("en")
-        && !stem.is_empty()
-    {
-        return Cow::Borrowed(stem);
+    if let Some(singular) = IRREGULAR_WORDS.singular(name) {
+        return Cow::Borrowed(singular);
     }
-    if let Some(stem) = name.strip_suffix("er")
-        && !stem.is_empty()
+    if let Some(singular) = compound(name, |head| IRREGULARS.singular(head)) {
+        return singular;
+    }
+    if let Some(stem) = strip(name, "innen") {
+        return format!("{stem}in").into();
+    }
+    if let Some(stem) = strip(name, "en")
+        && (EN_SUFFIXES.iter().any(|suffix| stem.ends_with(suffix))
+            || stem.ends_with("ent")
+            || stem.ends_with("ant")
+            || stem.ends_with(['a', 'o', 'u']))
     {
         return Cow::Borrowed(stem);
     }
-    if let Some(stem) = name.strip_suffix('e')
-        && !stem.is_empty()
+    if let Some(stem) = strip(name, "n")
+        && (stem.ends_with('e') || stem.ends_with("el") || stem.ends_with("er"))
     {
         return Cow::Borrowed(stem);
     }
-    if let Some(stem) = name.strip_suffix('n')
-        && !stem.is_empty()
+    if let Some(stem) = strip(name, "e")
+        && !stem.ends_with(is_vowel)
     {
+        if !keeps_umlaut(stem)
+            && let Some(stem) = remove_umlaut(stem)
+        {
+            return stem.into();
+        }
         return Cow::Borrowed(stem);
     }
-    if let Some(stem) = name.strip_suffix('s')
-        && !stem.is_empty()
+    if let Some(stem) = strip(name, "s")
+        && (stem.ends_with(['a', 'o', 'y'])
+            || S_LOANWORDS.contains(stem)
+            || !NATIVE_S_ENDINGS.iter().any(|ending| name.ends_with(ending)))
     {
         return Cow::Borrowed(stem);
     }
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a German noun.
+/// Returns the possible singular forms of a German noun, most likely first.
+///
+/// Starts with the result of [`singularize`], followed by the stems left by removing each plural
+/// ending, with and without umlaut reversal (`Kunden` -> `Kunde`, `Kund`), and finally the word
+/// itself, since many nouns in `-el`, `-er` and `-en` have no plural ending. Irregular nouns
+/// only have their irregular singular.
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    let mut candidates = vec![singularize(name)];
+    if IRREGULAR_WORDS.singular(name).is_some()
+        || compound(name, |head| IRREGULARS.singular(head)).is_some()
+    {
+        return candidates;
+    }
+    for suffix in ["n", "en", "e", "er", "s"] {
+        if let Some(stem) = strip(name, suffix) {
+            candidates.push(Cow::Borrowed(stem));
+            if let Some(stem) = remove_umlaut(stem) {
+                candidates.push(stem.into());
+            }
+        }
+    }
+    candidates.push(Cow::Borrowed(name));
+    candidates
+}
+
+/// Returns a list of possible plural forms for a German noun, most likely first.
+///
+/// Irregular nouns and compounds ending in one are looked up first. Otherwise nouns ending in
+/// `-e` take `-n`, feminine and weak masculine suffixes (`-ung`, `-heit`, `-keit`, `-schaft`,
+/// `-ion`, `-tät`, `-ei`, `-or`, `-ist`, `-ent`, ...) take `-en`, `-in` takes `-nen`, `-nis`
+/// takes `-se`, `-um` becomes `-en`, and `-au` takes `-en`. Native nouns in `-el`, `-er` and `-en`
+/// are unchanged, loanwords in a vowel take `-s` (`Auto` -> `Autos`, `Hotel` -> `Hotels`), and
+/// all other nouns take `-e`. Umlauted plurals only come from the irregular table.
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    vec![
-        format!("{name}e").into(),
-        format!("{name}en").into(),
-        format!("{name}er").into(),
-        format!("{name}n").into(),
-        format!("{name}s").into(),
-    ]
+    if name.is_empty() {
+        return vec![Cow::Borrowed(name)];
+    }
+    if let Some(plural) = IRREGULAR_WORDS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    if let Some(plural) = compound(name, |head| IRREGULARS.plural(head)) {
+        return vec![plural];
+    }
+    if name.ends_with('e') {
+        return vec![format!("{name}n").into()];
+    }
+    if FEMININE_IN_SUFFIXES
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+    {
+        return vec![format!("{name}nen").into()];
+    }
+    if name.ends_with("ment") {
+        return vec![format!("{name}e").into()];
+    }
+    if EN_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+        || name.ends_with("ent")
+        || name.ends_with("ant")
+    {
+        return vec![format!("{name}en").into()];
+    }
+    if name.ends_with("is") {
+        return vec![format!("{name}se").into()];
+    }
+    if let Some(stem) = strip(name, "ismus") {
+        return vec![format!("{stem}ismen").into()];
+    }
+    if name.ends_with("us") {
+        return vec![Cow::Borrowed(name), format!("{name}se").into()];
+    }
+    if let Some(stem) = strip(name, "um") {
+        return vec![format!("{stem}en").into()];
+    }
+    if name.ends_with("au") {
+        return vec![format!("{name}en").into()];
+    }
+    if S_LOANWORDS.contains(name)
+        || name.ends_with(['a', 'i', 'o', 'u', 'y'])
+        || name.ends_with("ing")
+    {
+        return vec![format!("{name}s").into()];
+    }
+    if ["el", "er", "en"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+        && !name.ends_with("ier")
+    {
+        return vec![Cow::Borrowed(name)];
+    }
+    vec![format!("{name}e").into()]
+}
+
+/// Applies `lookup` to `name` and then to ever shorter endings of it, so that an irregular noun
+/// also matches as the last element of a compound (`Datensätze` -> `Datensatz`). The rest of the
+/// compound is kept in front of the form found.
+fn compound<F>(name: &str, lookup: F) -> Option<Cow<'_, str>>
+where
+    F: Fn(&str) -> Option<&'static str>,
+{
+    name.char_indices().find_map(|(index, _)| {
+        let form = lookup(&name[index..])?;
+        Some(if index == 0 {
+            Cow::Borrowed(form)
+        } else {
+            format!("{}{form}", &name[..index]).into()
+        })
+    })
+}
+
+/// Returns `true` if `ch` is a German vowel letter.
+fn is_vowel(ch: char) -> bool {
+    matches!(ch, 'a' | 'e' | 'i' | 'o' | 'u' | 'ä' | 'ö' | 'ü' | 'y')
+}
+
+/// Returns the byte range of the last vowel group in `word`.
+fn last_vowel_group(word: &str) -> Option<(usize, usize)> {
+    let mut end = None;
+    for (index, ch) in word.char_indices().rev() {
+        if is_vowel(ch) {
+            if end.is_none() {
+                end = Some(index + ch.len_utf8());
+            }
+        } else if let Some(end) = end {
+            return Some((index + ch.len_utf8(), end));
+        }
+    }
+    end.map(|end| (0, end))
+}
+
+/// Returns `true` if a stem keeps its umlaut in the singular: nouns with an unstressed prefix
+/// (`Geräte` -> `Gerät`) and loanwords in `-är` and `-ör` (`Sekretäre` -> `Sekretär`).
+fn keeps_umlaut(stem: &str) -> bool {
+    vowel_groups(stem, is_vowel) > 1
+        && (["be", "ge", "ver", "zer"]
+            .iter()
+            .any(|prefix| stem.starts_with(prefix))
+            || stem.ends_with("är")
+            || stem.ends_with("ör"))
+}
+
+/// Reverses the umlaut of the last vowel of a stem (`Bäum` -> `Baum`), or returns `None` if it
+/// has none.
+fn remove_umlaut(stem: &str) -> Option<String> {
+    let (start, end) = last_vowel_group(stem)?;
+    let vowel = match &stem[start..end] {
+        "ä" => "a",
+        "ö" => "o",
+        "ü" => "u",
+        "äu" => "au",
+        _ => return None,
+    };
+    Some(format!("{}{vowel}{}", &stem[..start], &stem[end..]))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_singularize_candidates() {
+        let candidates = singularize_candidates("kunden");
+        assert_eq!(candidates[0], "kunde");
+        assert!(candidates.iter().any(|v| v == "kund"));
+        let candidates = singularize_candidates("tische");
+        assert_eq!(candidates[0], "tisch");
+        assert_eq!(candidates.last().unwrap(), "tische");
+        assert_eq!(singularize_candidates("stühle"), vec!["stuhl"]);
+        assert_eq!(singularize_candidates("häuser"), vec!["haus"]);
+        let candidates = singularize_candidates("lehrer");
+        assert!(candidates.iter().any(|v| v == "lehrer"));
+    }
+
     #[test]
     fn test_singularize_en_suffix() {
-        assert_eq!(singularize("kunden"), "kund");
+        assert_eq!(singularize("kunden"), "kunde");
         assert_eq!(singularize("frauen"), "frau");
+        assert_eq!(singularize("rechnungen"), "rechnung");
+        assert_eq!(singularize("studenten"), "student");
+        assert_eq!(singularize("motoren"), "motor");
+        assert_eq!(singularize("dateien"), "datei");
+        assert_eq!(singularize("lehrerinnen"), "lehrerin");
+        assert_eq!(singularize("nummern"), "nummer");
+        assert_eq!(singularize("kategorien"), "kategorie");
     }
 
     #[test]
     fn test_singularize_er_suffix() {
         assert_eq!(singularize("kinder"), "kind");
+        assert_eq!(singularize("häuser"), "haus");
+        assert_eq!(singularize("bücher"), "buch");
+        assert_eq!(singularize("lehrer"), "lehrer");
+        assert_eq!(singularize("benutzer"), "benutzer");
     }
 
     #[test]
     fn test_singularize_e_suffix() {
         assert_eq!(singularize("produkte"), "produkt");
+        assert_eq!(singularize("städte"), "stadt");
+        assert_eq!(singularize("bäume"), "baum");
+        assert_eq!(singularize("züge"), "zug");
+        assert_eq!(singularize("stühle"), "stuhl");
+        assert_eq!(singularize("flüsse"), "fluss");
     }
 
     #[test]
     fn test_singularize_n_suffix() {
-        // Words ending in 'n' but not 'en' hit the '-n' branch
-        assert_eq!(singularize("lin"), "li");
+        // Words ending in 'n' are only stripped after an '-e', '-el' or '-er' stem
+        assert_eq!(singularize("lin"), "lin");
+        assert_eq!(singularize("regeln"), "regel");
     }
 
     #[test]
     fn test_singularize_s_suffix() {
         assert_eq!(singularize("autos"), "auto");
+        assert_eq!(singularize("teams"), "team");
+        assert_eq!(singularize("kurs"), "kurs");
+        assert_eq!(singularize("preis"), "preis");
+        assert_eq!(singularize("haus"), "haus");
+    }
+
+    #[test]
+    fn test_singularize_compound() {
+        assert_eq!(singularize("rathäuser"), "rathaus");
+        assert_eq!(singularize("datensätze"), "datensatz");
+        assert_eq!(singularize("verträge"), "vertrag");
+        assert_eq!(singularize("passwörter"), "passwort");
+        assert_eq!(singularize("antworten"), "antwort");
+        // Whole-word entries do not match inside other nouns
+        assert_ne!(singularize("soldaten"), "soldatum");
+    }
+
+    #[test]
+    fn test_singularize_zero_plural() {
+        assert_eq!(singularize("löffel"), "löffel");
+        assert_eq!(singularize("väter"), "vater");
+        assert_eq!(singularize("gärten"), "garten");
+        assert_eq!(singularize("äpfel"), "apfel");
+        assert_eq!(singularize("kanäle"), "kanal");
+        assert_eq!(singularize("museen"), "museum");
+        assert_eq!(singularize("geräte"), "gerät");
+        assert_eq!(singularize("hotels"), "hotel");
     }
 
     #[test]
     fn test_singularize_already_singular() {
         assert_eq!(singularize("produkt"), "produkt");
+        assert_eq!(singularize("rechnung"), "rechnung");
+        assert_eq!(singularize("kind"), "kind");
     }
 
     #[test]
@@ -99,25 +839,138 @@ mod tests {
         assert_eq!(singularize("s"), "s");
         // "en" -> empty stem, skip -> 'n' branch: stem "e" non-empty -> "e"
         assert_eq!(singularize("en"), "e");
-        // "er" -> empty stem, skip -> no further suffix match -> "er"
+        // "er" -> no suffix rule applies -> "er"
         assert_eq!(singularize("er"), "er");
     }
 
     #[test]
     fn test_pluralize() {
-        let result = pluralize("produkt");
-        assert_eq!(result.len(), 5);
-        assert!(result.iter().any(|v| v == "produkte"));
-        assert!(result.iter().any(|v| v == "produkten"));
-        assert!(result.iter().any(|v| v == "produkter"));
-        assert!(result.iter().any(|v| v == "produktn"));
-        assert!(result.iter().any(|v| v == "produkts"));
+        assert_eq!(pluralize("produkt"), vec!["produkte"]);
+        assert_eq!(pluralize("baum"), vec!["bäume"]);
+        assert_eq!(pluralize("tag"), vec!["tage"]);
+        assert_eq!(pluralize("grad"), vec!["grade"]);
+        assert_eq!(pluralize("haus"), vec!["häuser"]);
+        assert_eq!(pluralize("stadt"), vec!["städte"]);
+        assert_eq!(pluralize("lehrerin"), vec!["lehrerinnen"]);
+        assert_eq!(pluralize("ergebnis"), vec!["ergebnisse"]);
+        assert_eq!(pluralize("museum"), vec!["museen"]);
+        assert_eq!(pluralize("student"), vec!["studenten"]);
+        assert_eq!(pluralize("dokument"), vec!["dokumente"]);
+        assert_eq!(pluralize("papier"), vec!["papiere"]);
+        assert_eq!(pluralize("frau"), vec!["frauen"]);
+        assert_eq!(pluralize("neubau"), vec!["neubauten"]);
+        assert_eq!(pluralize("auto"), vec!["autos"]);
+        assert_eq!(pluralize("hotel"), vec!["hotels"]);
+    }
+
+    #[test]
+    fn test_pluralize_no_genitive() {
+        assert_eq!(pluralize("bein"), vec!["beine"]);
+        assert_eq!(pluralize("freund"), vec!["freunde"]);
+        assert_eq!(pluralize("monat"), vec!["monate"]);
+        assert_eq!(pluralize("apparat"), vec!["apparate"]);
+        assert_eq!(pluralize("format"), vec!["formate"]);
+    }
+
+    #[test]
+    fn test_pluralize_ranking() {
+        assert_eq!(pluralize("produkt")[0], "produkte");
+        assert_eq!(pluralize("kunde")[0], "kunden");
+        assert_eq!(pluralize("rechnung")[0], "rechnungen");
+        assert_eq!(pluralize("auto")[0], "autos");
+        assert_eq!(pluralize("kunde").len(), 1);
+    }
+
+    #[test]
+    fn test_pluralize_zero_plural() {
+        assert_eq!(pluralize("lehrer"), vec!["lehrer"]);
+        assert_eq!(pluralize("löffel"), vec!["löffel"]);
+        assert_eq!(pluralize("wagen"), vec!["wagen"]);
+        assert_eq!(pluralize("vater"), vec!["väter"]);
+        assert_eq!(pluralize("garten"), vec!["gärten"]);
+        assert_eq!(pluralize("nummer"), vec!["nummern"]);
+    }
+
+    #[test]
+    fn test_pluralize_compound() {
+        assert_eq!(pluralize("rathaus"), vec!["rathäuser"]);
+        assert_eq!(pluralize("datensatz"), vec!["datensätze"]);
+        assert_eq!(pluralize("kaufvertrag"), vec!["kaufverträge"]);
+        assert_eq!(pluralize("antwort"), vec!["antworten"]);
+        assert_eq!(pluralize("datei"), vec!["dateien"]);
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("menschen"), "mensch");
+        assert_eq!(singularize("nachbarn"), "nachbar");
+        assert_eq!(singularize("würmer"), "wurm");
+        assert_eq!(singularize("räte"), "rat");
+        assert_eq!(singularize("lexika"), "lexikon");
+        assert_eq!(pluralize("wolf"), vec!["wölfe"]);
+        assert_eq!(pluralize("herz"), vec!["herzen"]);
+        assert_eq!(pluralize("bundesstaat"), vec!["bundesstaaten"]);
+        assert_eq!(singularize("irrtümer"), "irrtum");
+        assert_eq!(singularize("vorschläge"), "vorschlag");
+        assert_eq!(singularize("fachleute"), "fachmann");
+        assert_eq!(singularize("jobs"), "job");
+        assert_eq!(pluralize("schaden"), vec!["schäden"]);
+        assert_eq!(pluralize("wurzel"), vec!["wurzeln"]);
+        assert_eq!(pluralize("pirat"), vec!["piraten"]);
+        assert_eq!(pluralize("team"), vec!["teams"]);
+        assert_eq!(pluralize("prinzip"), vec!["prinzipien"]);
+        // Whole-word entries only match the whole word
+        assert_eq!(pluralize("tal"), vec!["täler"]);
+        assert_ne!(pluralize("portal"), vec!["portäler"]);
+    }
+
+    #[test]
+    fn test_umlaut() {
+        assert_eq!(remove_umlaut("bäum").unwrap(), "baum");
+        assert_eq!(remove_umlaut("städt").unwrap(), "stadt");
+        assert!(remove_umlaut("tisch").is_none());
+        assert!(keeps_umlaut("gerät"));
+        assert!(keeps_umlaut("sekretär"));
+        assert!(!keeps_umlaut("kanäl"));
+        assert!(!keeps_umlaut("bäum"));
     }
 
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
-        let result = pluralize("");
-        assert_eq!(result.len(), 5);
+        assert_eq!(pluralize(""), vec![""]);
+    }
+
+    #[test]
+    fn test_rules_invariant() {
+        use crate::language_rules::LanguageRules;
+        assert_eq!(RULES.pluralize("Lehrer"), vec!["Lehrer"]);
+        assert_eq!(RULES.pluralize("Löffel"), vec!["Löffel"]);
+        assert_eq!(RULES.pluralize("Kuchen"), vec!["Kuchen"]);
+        assert_eq!(RULES.pluralize("Mädchen"), vec!["Mädchen"]);
+        assert_eq!(RULES.singularize("Mädchen"), "Mädchen");
+        assert_eq!(RULES.singularize("Leute"), "Leute");
+        assert_eq!(RULES.singularize("Kosten"), "Kosten");
+    }
+
+    #[test]
+    fn test_rules_umlaut() {
+        use crate::language_rules::LanguageRules;
+        assert_eq!(RULES.singularize("Häuser"), "Haus");
+        assert_eq!(RULES.pluralize("Haus"), vec!["Häuser"]);
+        assert_eq!(RULES.pluralize_best("Stadt"), "Städte");
+        assert_eq!(RULES.singularize("HÄUSER"), "HAUS");
+    }
+
+    #[test]
+    fn test_number_of() {
+        use crate::language_rules::LanguageRules;
+        use crate::number::Number;
+        assert_eq!(RULES.number_of("Frau"), Number::Singular);
+        assert_eq!(RULES.number_of("Frauen"), Number::Plural);
+        assert_ne!(RULES.number_of("Fraus"), Number::Plural);
+        assert_eq!(RULES.number_of("Museum"), Number::Singular);
+        assert_eq!(RULES.number_of("Museen"), Number::Plural);
+        assert_eq!(RULES.number_of("Lehrer"), Number::Invariant);
     }
 }
@@ -0,0 +1,76 @@
+//! Swiss Standard German (de-CH) inflection rules.
+//!
+//! Swiss and Liechtenstein German do not use the letter `ß`, which is written `ss` instead.
+//! Otherwise the German rules apply.
+
+use crate::language_rules::LanguageRuleSet;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use super::de;
+
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
+    tag: Some("de-CH"),
+    singularize_candidates_fn: Some(singularize_candidates),
+    irregulars: Some(&de::IRREGULARS),
+    lexicon: &de::LEXICON,
+    ..LanguageRuleSet::new("de", singularize, pluralize)
+};
+
+/// Liechtenstein German (de-LI) shares the Swiss spelling.
+pub(crate) static LI_RULES: LanguageRuleSet = LanguageRuleSet {
+    tag: Some("de-LI"),
+    singularize_candidates_fn: Some(singularize_candidates),
+    irregulars: Some(&de::IRREGULARS),
+    lexicon: &de::LEXICON,
+    ..LanguageRuleSet::new("de", singularize, pluralize)
+};
+
+/// Converts a plural German noun to its singular form, spelling `ß` as `ss`.
+pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    without_eszett(de::singularize(name))
+}
+
+/// Returns the possible singular forms of a German noun, spelling `ß` as `ss`.
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    de::singularize_candidates(name)
+        .into_iter()
+        .map(without_eszett)
+        .collect()
+}
+
+/// Returns a list of possible plural forms for a German noun, spelling `ß` as `ss`.
+pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    de::pluralize(name)
+        .into_iter()
+        .map(without_eszett)
+        .collect()
+}
+
+/// Replaces every `ß` with `ss`.
+fn without_eszett(word: Cow<'_, str>) -> Cow<'_, str> {
+    if word.contains('ß') {
+        Cow::Owned(word.replace('ß', "ss"))
+    } else {
+        word
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("kinder"), "kind");
+        assert_eq!(singularize("straßen"), "strasse");
+        assert_eq!(singularize("strassen"), "strasse");
+    }
+
+    #[test]
+    fn test_pluralize() {
+        let result = pluralize("fuß");
+        assert!(result.iter().all(|v| !v.contains('ß')));
+        assert!(result.iter().any(|v| v == "füsse"));
+    }
+}
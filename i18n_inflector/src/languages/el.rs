@@ -1,20 +1,86 @@
-//! Greek (el) inflection rules (Latin transliteration).
+//! Greek (el) inflection rules.
+//!
+//! Words in Greek script are declined natively; words in Latin script are treated as
+//! transliterations. The script is detected per word.
 
 use crate::language_rules::LanguageRuleSet;
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
+
+use super::strip;
+
+/// Irregular singular -> plural mappings, mostly neuters in `-ος` whose plural is `-η`.
+static IRREGULARS: &[(&str, &str)] = &[
+    ("βάθος", "βάθη"),
+    ("βάρος", "βάρη"),
+    ("γάλα", "γάλατα"),
+    ("γεγονός", "γεγονότα"),
+    ("γένος", "γένη"),
+    ("δάσος", "δάση"),
+    ("έδαφος", "εδάφη"),
+    ("έθνος", "έθνη"),
+    ("είδος", "είδη"),
+    ("κράτος", "κράτη"),
+    ("κρέας", "κρέατα"),
+    ("λάθος", "λάθη"),
+    ("μέγεθος", "μεγέθη"),
+    ("μέλος", "μέλη"),
+    ("μέρος", "μέρη"),
+    ("πέλαγος", "πελάγη"),
+    ("πόλη", "πόλεις"),
+    ("τέλος", "τέλη"),
+    ("ύψος", "ύψη"),
+    ("φως", "φώτα"),
+];
+
+/// Neuter nouns whose plural in `-α` reads the same as a feminine singular (`βιβλία`, `βουνά`)
+/// and nouns in `-ιο` whose plural in `-ια` reads the same as that of a noun in `-ι` (`δωμάτια`).
+/// Neuters accented on the antepenult (`αυτοκίνητο`) and nouns in `-είο` or with one of the
+/// [`IO_ENDINGS`] need not be listed.
+static NEUTERS: phf::Set<&'static str> = phf_set! {
+    "άρθρο", "αβγό", "βιβλίο", "βουνό", "γυμνάσιο", "δέντρο", "δώρο", "ζώο", "έργο", "κέντρο",
+    "κτίριο", "λεπτό", "λεξικό", "μέσο", "μέτρο", "μήλο", "μοντέλο", "μυαλό", "μωρό", "νερό",
+    "ξύλο", "όριο", "πεδίο", "πλοίο", "ποσοστό", "ποτό", "ρούχο", "σχέδιο", "στάδιο", "συνέδριο",
+    "φαγητό", "φορτηγό", "φυτό", "φύλλο", "χωριό",
+};
+
+/// Endings of neuters in `-ιο` (`κριτήριο`, `δωμάτιο`, `ημερολόγιο`).
+const IO_ENDINGS: &[&str] = &["τήριο", "όγιο", "άτιο", "ήμιο", "όμιο", "τόριο", "ίδιο"];
+
+/// Feminine singulars that end like the plural of a neuter (`καρδιά`, `εταιρεία`, `τράπεζα`).
+static FEMININES: phf::Set<&'static str> = phf_set! {
+    "άμαξα", "αρρώστια", "βασιλεία", "βραδιά", "γειτονιά", "γιαγιά", "γωνιά", "δουλεία",
+    "εταιρεία", "ζήλια", "θεραπεία", "κάμαρα", "καρδιά", "μαγεία", "ματιά", "ομορφιά", "πάπια",
+    "πέρδικα", "πλατεία", "πολιτεία", "πορεία", "σάλπιγγα", "τράπεζα", "φωτιά",
+};
+
+/// Endings of feminines in `-α` accented on the antepenult (`θάλασσα`, `αλήθεια`).
+const FEMININE_ENDINGS: &[&str] = &["σσα", "εια", "τρια", "αινα"];
+
+/// Masculine nouns in `-ας` whose plural in `-ες` reads the same as that of a feminine in `-α`
+/// (`μήνες` -> `μήνας`, but `ώρες` -> `ώρα`). Stems in `-ήρ`, `-ακ` and `-ντρ` need not be listed.
+static MASCULINES: phf::Set<&'static str> = phf_set! {
+    "αγώνας", "αέρας", "αιώνας", "γείτονας", "δαίμονας", "έλληνας", "ήρωας", "κανόνας", "μάρτυρας",
+    "μήνας", "πατέρας", "σωλήνας", "χειμώνας",
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "el",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    ..LanguageRuleSet::new("el", singularize, pluralize)
 };
 
-/// Converts a plural Greek noun (Latin transliteration) to its singular form.
+/// Converts a plural Greek noun to its singular form.
 ///
-/// Handles `-es` -> `-is` transformation and `-a` suffix stripping.
+/// Greek-script nouns are handled by [`singularize_greek`]. For Latin transliterations, handles
+/// `-es` -> `-is` transformation and `-a` suffix stripping.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if is_greek(name) {
+        return singularize_greek(name);
+    }
     if let Some(stem) = name.strip_suffix("es")
         && !stem.is_empty()
     {
@@ -28,9 +94,49 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a Greek noun (Latin
-/// transliteration).
+/// Returns the possible singular forms of a Greek noun, most likely first.
+///
+/// Starts with the result of [`singularize`], followed by the other readings of a Greek-script
+/// plural: `-ες` may belong to a feminine in `-α` or `-η` or a masculine in `-ας` (`ώρες` ->
+/// `ώρα`, `μήνες` -> `μήνας`, `τέχνες` -> `τέχνη`), and a neuter plural in `-α` may be a feminine
+/// singular (`βιβλία` -> `βιβλίο`, `βιβλία`).
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    let mut candidates = vec![singularize(name)];
+    if !is_greek(name) || IRREGULARS.iter().any(|&(_, plural)| name == plural) {
+        return candidates;
+    }
+    if let Some(stem) = strip(name, "ες")
+        && !stem.ends_with('τ')
+    {
+        candidates.extend(
+            ["α", "ας", "η"]
+                .iter()
+                .map(|ending| Cow::Owned(format!("{stem}{ending}"))),
+        );
+    } else if let Some(stem) = strip(name, "ιά") {
+        candidates.extend([format!("{stem}ί"), format!("{stem}ιό")].map(Cow::Owned));
+        candidates.push(Cow::Borrowed(name));
+    } else if let Some(stem) = strip(name, "ια").filter(|stem| !stem.ends_with('ε')) {
+        candidates.extend([format!("{stem}ι"), format!("{stem}ιο")].map(Cow::Owned));
+        candidates.push(Cow::Borrowed(name));
+    } else if let Some(stem) = strip(name, "ά") {
+        candidates.push(Cow::Owned(format!("{stem}ό")));
+        candidates.push(Cow::Borrowed(name));
+    } else if let Some(stem) = strip(name, "α") {
+        candidates.push(Cow::Owned(format!("{stem}ο")));
+        candidates.push(Cow::Borrowed(name));
+    }
+    candidates
+}
+
+/// Returns a list of possible plural forms for a Greek noun, most likely first.
+///
+/// Greek-script nouns are handled by [`pluralize_greek`]; Latin transliterations take `-es` or
+/// `-a`.
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if is_greek(name) {
+        return pluralize_greek(name);
+    }
     let mut candidates = Vec::new();
     if let Some(stem) = name.strip_suffix("is") {
         candidates.push(format!("{stem}es").into());
@@ -40,9 +146,316 @@ pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
     candidates
 }
 
+/// Returns `true` if the word is written in the Greek script.
+fn is_greek(name: &str) -> bool {
+    name.chars().any(|ch| {
+        ('\u{0370}'..='\u{03FF}').contains(&ch) || ('\u{1F00}'..='\u{1FFF}').contains(&ch)
+    })
+}
+
+/// Returns the unaccented form of a lowercase Greek vowel, or `None` if `ch` is not a vowel.
+fn base_vowel(ch: char) -> Option<char> {
+    match ch {
+        'α' | 'ά' => Some('α'),
+        'ε' | 'έ' => Some('ε'),
+        'η' | 'ή' => Some('η'),
+        'ι' | 'ί' => Some('ι'),
+        'ϊ' | 'ΐ' => Some('ϊ'),
+        'ο' | 'ό' => Some('ο'),
+        'υ' | 'ύ' => Some('υ'),
+        'ϋ' | 'ΰ' => Some('ϋ'),
+        'ω' | 'ώ' => Some('ω'),
+        _ => None,
+    }
+}
+
+/// Returns the accented form of an unaccented lowercase Greek vowel.
+fn accented(ch: char) -> char {
+    match ch {
+        'α' => 'ά',
+        'ε' => 'έ',
+        'η' => 'ή',
+        'ι' => 'ί',
+        'ϊ' => 'ΐ',
+        'ο' => 'ό',
+        'υ' => 'ύ',
+        'ϋ' => 'ΰ',
+        'ω' => 'ώ',
+        _ => ch,
+    }
+}
+
+/// Returns the character indices of the vowel that carries each syllable's accent, from the
+/// first syllable to the last.
+///
+/// Diphthongs (`αι`, `ει`, `οι`, `ου`, `αυ`, `ευ`, `ηυ`, `υι`) form a single syllable and carry
+/// the accent on their second letter; a diaeresis or an accent on the first letter splits them.
+fn syllables(chars: &[char]) -> Vec<usize> {
+    let mut nuclei: Vec<usize> = Vec::new();
+    let mut previous: Option<char> = None;
+    for (index, &ch) in chars.iter().enumerate() {
+        let Some(vowel) = base_vowel(ch) else {
+            previous = None;
+            continue;
+        };
+        if let Some(previous_ch) = previous
+            && base_vowel(previous_ch) == Some(previous_ch)
+            && matches!(
+                (previous_ch, vowel),
+                ('α' | 'ε' | 'ο' | 'υ', 'ι') | ('α' | 'ε' | 'η' | 'ο', 'υ')
+            )
+        {
+            if let Some(last) = nuclei.last_mut() {
+                *last = index;
+            }
+            previous = None;
+            continue;
+        }
+        nuclei.push(index);
+        previous = Some(ch);
+    }
+    nuclei
+}
+
+/// Returns the syllable carrying the accent, counted from the end (`0` for the last syllable).
+fn accent_position(word: &str) -> Option<usize> {
+    let chars: Vec<char> = word.chars().collect();
+    let nuclei = syllables(&chars);
+    nuclei
+        .iter()
+        .rev()
+        .position(|&index| base_vowel(chars[index]) != Some(chars[index]))
+}
+
+/// Moves the accent of `word` to the syllable `position` counted from the end, or to the first
+/// syllable of shorter words. Monosyllables are left unaccented.
+fn accent_at(word: &str, position: usize) -> String {
+    let mut chars: Vec<char> = word
+        .chars()
+        .map(|ch| base_vowel(ch).unwrap_or(ch))
+        .collect();
+    let nuclei = syllables(&chars);
+    if nuclei.len() > 1 {
+        let index = nuclei[nuclei.len() - 1 - position.min(nuclei.len() - 1)];
+        chars[index] = accented(chars[index]);
+    }
+    chars.into_iter().collect()
+}
+
+/// Moves an accent on the antepenult to the penult, as required when a word takes an ending with
+/// a long vowel (`σύνδεση` -> `συνδέσεις`, `μέγεθος` -> `μεγέθη`).
+fn shift_for_long_ending(word: String) -> String {
+    if accent_position(&word).is_some_and(|position| position >= 2) {
+        accent_at(&word, 1)
+    } else {
+        word
+    }
+}
+
+/// Converts a plural Greek noun in Greek script to its singular form.
+///
+/// Handles `-οι` -> `-ος`, `-ες` -> `-ας`/`-ης`/`-α`/`-ή`, `-εις` -> `-η`, `-ματα` -> `-μα` and
+/// `-όντα` -> `-όν`, restoring the accent of the singular (`συνδέσεις` -> `σύνδεση`,
+/// `προβλήματα` -> `πρόβλημα`). Plurals in `-ες` are read as feminines in `-α` unless the stem is
+/// masculine (`πατέρες` -> `πατέρας`). Neuter plurals in `-α` and `-ια` are recognized with
+/// [`neuter_singular`].
+fn singularize_greek(name: &str) -> Cow<'_, str> {
+    if let Some(&(singular, _)) = IRREGULARS.iter().find(|&&(_, plural)| name == plural) {
+        return Cow::Borrowed(singular);
+    }
+    if IRREGULARS.iter().any(|&(singular, _)| name == singular) {
+        return Cow::Borrowed(name);
+    }
+    if let Some(stem) = strip(name, "οι").or_else(|| strip(name, "οί")) {
+        let ending = if name.ends_with("οί") {
+            "ός"
+        } else {
+            "ος"
+        };
+        return format!("{stem}{ending}").into();
+    }
+    if let Some(stem) = strip(name, "ματα") {
+        return proparoxytone(&format!("{stem}μα")).into();
+    }
+    if let Some(stem) = strip(name, "όντα").or_else(|| strip(name, "οντα")) {
+        let ending = if name.ends_with("όντα") {
+            "όν"
+        } else {
+            "ον"
+        };
+        return format!("{stem}{ending}").into();
+    }
+    if let Some(stem) = strip(name, "εις") {
+        return proparoxytone(&format!("{stem}η")).into();
+    }
+    if let Some(stem) = strip(name, "ες").or_else(|| strip(name, "ές")) {
+        let oxytone = name.ends_with("ές");
+        if stem.ends_with("ήδ") || stem.ends_with("ούδ") {
+            let stem = stem.strip_suffix('δ').unwrap_or(stem);
+            return format!("{stem}ς").into();
+        }
+        if stem.ends_with('τ') {
+            let ending = if oxytone { "ής" } else { "ης" };
+            return format!("{stem}{ending}").into();
+        }
+        if oxytone {
+            if stem.ends_with('ι') {
+                return format!("{stem}ά").into();
+            }
+            return format!("{stem}ή").into();
+        }
+        let masculine = format!("{stem}ας");
+        if MASCULINES.contains(masculine.as_str())
+            || ["ήρ", "ακ", "ντρ"]
+                .iter()
+                .any(|ending| stem.ends_with(ending))
+        {
+            return masculine.into();
+        }
+        return format!("{stem}α").into();
+    }
+    if let Some(singular) = neuter_singular(name) {
+        return singular.into();
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns the singular of a neuter plural, or `None` if the word reads as a feminine singular.
+///
+/// Plurals in `-ιά` and unaccented `-ια` belong to neuters in `-ί` and `-ι` (`παιδιά` -> `παιδί`,
+/// `σπίτια` -> `σπίτι`), or in `-ιό` and `-ιο` for the [`NEUTERS`] and [`IO_ENDINGS`] (`χωριά`
+/// -> `χωριό`, `δωμάτια` -> `δωμάτιο`). Plurals in `-α` belong to neuters in `-ο` if they are
+/// accented on the antepenult (`αυτοκίνητα` -> `αυτοκίνητο`), end in `-εία` (`γραφεία` ->
+/// `γραφείο`) or are listed in [`NEUTERS`] (`βιβλία` -> `βιβλίο`, `βουνά` -> `βουνό`).
+fn neuter_singular(name: &str) -> Option<String> {
+    if FEMININES.contains(name) {
+        return None;
+    }
+    let io_neuter = |singular: &String| {
+        NEUTERS.contains(singular.as_str())
+            || IO_ENDINGS.iter().any(|ending| singular.ends_with(ending))
+    };
+    if let Some(stem) = strip(name, "ιά") {
+        let singular = format!("{stem}ιό");
+        return Some(if io_neuter(&singular) {
+            singular
+        } else {
+            format!("{stem}ί")
+        });
+    }
+    if let Some(stem) = strip(name, "ια")
+        && !stem.ends_with('ε')
+    {
+        let singular = format!("{stem}ιο");
+        return Some(if io_neuter(&singular) {
+            singular
+        } else {
+            format!("{stem}ι")
+        });
+    }
+    if let Some(stem) = strip(name, "ά") {
+        let singular = format!("{stem}ό");
+        return NEUTERS.contains(singular.as_str()).then_some(singular);
+    }
+    let stem = strip(name, "α")?;
+    let singular = format!("{stem}ο");
+    let antepenult = accent_position(name).is_some_and(|position| position >= 2)
+        && !FEMININE_ENDINGS.iter().any(|ending| name.ends_with(ending));
+    (antepenult || name.ends_with("εία") || NEUTERS.contains(singular.as_str())).then_some(singular)
+}
+
+/// Places the accent of a singular neuter in `-μα` or feminine in `-η` on the antepenult, or on
+/// the penult of two-syllable words (`πρόβλημα`, `σύνδεση`, `θέμα`, `πόλη`).
+fn proparoxytone(word: &str) -> String {
+    accent_at(word, 2)
+}
+
+/// Returns a list of possible plural forms for a Greek noun in Greek script, most likely first.
+///
+/// Covers the major declension classes: `-ος` -> `-οι`, `-ας` -> `-ες`, `-ης` -> `-ες`, `-η` ->
+/// `-ες`, `-α` -> `-ες`, `-ά` -> `-άδες`, `-ο` -> `-α`, `-ι` -> `-ια`, `-μα` -> `-ματα`, `-ση` ->
+/// `-σεις` and `-όν` -> `-όντα`, moving the accent where the ending requires it (`πρόβλημα` ->
+/// `προβλήματα`, `σύνδεση` -> `συνδέσεις`, `παιδί` -> `παιδιά`). Loanwords ending in other
+/// consonants are indeclinable.
+fn pluralize_greek(name: &str) -> Vec<Cow<'_, str>> {
+    if let Some(&(_, plural)) = IRREGULARS.iter().find(|&&(singular, _)| name == singular) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    if let Some(stem) = strip(name, "ος").or_else(|| strip(name, "ός")) {
+        let ending = if name.ends_with("ός") {
+            "οί"
+        } else {
+            "οι"
+        };
+        return vec![format!("{stem}{ending}").into()];
+    }
+    if let Some(stem) =
+        strip(name, "άς").or_else(|| strip(name, "ής").or_else(|| strip(name, "ούς")))
+    {
+        let vowel = if name.ends_with("άς") {
+            "ά"
+        } else if name.ends_with("ής") {
+            "ή"
+        } else {
+            "ού"
+        };
+        let mut candidates = vec![format!("{stem}{vowel}δες").into()];
+        if vowel == "ή" {
+            candidates.insert(0, format!("{stem}ές").into());
+        }
+        return candidates;
+    }
+    if let Some(stem) = strip(name, "ας").or_else(|| strip(name, "ης")) {
+        return vec![format!("{stem}ες").into()];
+    }
+    if let Some(stem) = strip(name, "μα") {
+        return vec![accent_at(&format!("{stem}ματα"), 2).into()];
+    }
+    if let Some(stem) = strip(name, "όν").or_else(|| strip(name, "ον")) {
+        let ending = if name.ends_with("όν") {
+            "όντα"
+        } else {
+            "οντα"
+        };
+        return vec![format!("{stem}{ending}").into()];
+    }
+    if ["ση", "ξη", "ψη"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+        && let Some(stem) = strip(name, "η")
+    {
+        let plural = shift_for_long_ending(format!("{stem}εις"));
+        return vec![plural.into(), format!("{stem}ες").into()];
+    }
+    if let Some(stem) = strip(name, "ή").or_else(|| strip(name, "ιά")) {
+        let vowel = if name.ends_with("ιά") { "ι" } else { "" };
+        return vec![format!("{stem}{vowel}ές").into()];
+    }
+    if let Some(stem) = strip(name, "ά") {
+        return vec![format!("{stem}άδες").into()];
+    }
+    if let Some(stem) = strip(name, "η").or_else(|| strip(name, "α")) {
+        return vec![format!("{stem}ες").into()];
+    }
+    if let Some(stem) = strip(name, "ό") {
+        return vec![format!("{stem}ά").into()];
+    }
+    if let Some(stem) = strip(name, "ο") {
+        return vec![format!("{stem}α").into()];
+    }
+    if let Some(stem) = strip(name, "ί") {
+        return vec![format!("{stem}ιά").into()];
+    }
+    if name.ends_with('ι') {
+        return vec![format!("{name}α").into()];
+    }
+    vec![Cow::Borrowed(name)]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::language_rules::LanguageRules;
 
     #[test]
     fn test_singularize() {
@@ -64,6 +477,148 @@ mod tests {
         assert!(result.iter().any(|v| v == "themata"));
     }
 
+    #[test]
+    fn test_accent_position() {
+        assert_eq!(accent_position("πρόβλημα"), Some(2));
+        assert_eq!(accent_position("χρήστης"), Some(1));
+        assert_eq!(accent_position("παιδί"), Some(0));
+        assert_eq!(accent_position("διεύθυνση"), Some(2));
+        assert_eq!(accent_position("προϊόν"), Some(0));
+        assert_eq!(accent_position("φως"), None);
+    }
+
+    #[test]
+    fn test_singularize_greek() {
+        assert_eq!(singularize("χρήστες"), "χρήστης");
+        assert_eq!(singularize("μαθητές"), "μαθητής");
+        assert_eq!(singularize("δρόμοι"), "δρόμος");
+        assert_eq!(singularize("άνθρωποι"), "άνθρωπος");
+        assert_eq!(singularize("ουρανοί"), "ουρανός");
+        assert_eq!(singularize("προϊόντα"), "προϊόν");
+        assert_eq!(singularize("πόλεις"), "πόλη");
+        assert_eq!(singularize("συνδέσεις"), "σύνδεση");
+        assert_eq!(singularize("διευθύνσεις"), "διεύθυνση");
+        assert_eq!(singularize("λέξεις"), "λέξη");
+        assert_eq!(singularize("θέματα"), "θέμα");
+        assert_eq!(singularize("προβλήματα"), "πρόβλημα");
+        assert_eq!(singularize("ονόματα"), "όνομα");
+        assert_eq!(singularize("κατηγορίες"), "κατηγορία");
+        assert_eq!(singularize("ώρες"), "ώρα");
+        assert_eq!(singularize("τιμές"), "τιμή");
+        assert_eq!(singularize("καφετζήδες"), "καφετζής");
+        assert_eq!(singularize("λάθη"), "λάθος");
+        assert_eq!(singularize("μεγέθη"), "μέγεθος");
+        assert_eq!(singularize("άντρες"), "άντρας");
+        assert_eq!(singularize("μήνες"), "μήνας");
+        assert_eq!(singularize("πατέρες"), "πατέρας");
+        assert_eq!(singularize("πίνακες"), "πίνακας");
+        assert_eq!(singularize("καρδιές"), "καρδιά");
+    }
+
+    #[test]
+    fn test_singularize_greek_neuter() {
+        assert_eq!(singularize("βιβλία"), "βιβλίο");
+        assert_eq!(singularize("αυτοκίνητα"), "αυτοκίνητο");
+        assert_eq!(singularize("γραφεία"), "γραφείο");
+        assert_eq!(singularize("βουνά"), "βουνό");
+        assert_eq!(singularize("παιδιά"), "παιδί");
+        assert_eq!(singularize("χωριά"), "χωριό");
+        assert_eq!(singularize("σπίτια"), "σπίτι");
+        assert_eq!(singularize("τραπέζια"), "τραπέζι");
+        assert_eq!(singularize("δωμάτια"), "δωμάτιο");
+        assert_eq!(singularize("κριτήρια"), "κριτήριο");
+        for word in [
+            "ώρα",
+            "κατηγορία",
+            "θάλασσα",
+            "οικογένεια",
+            "εταιρεία",
+            "καρδιά",
+            "χαρά",
+        ] {
+            assert_eq!(singularize(word), word);
+        }
+    }
+
+    #[test]
+    fn test_singularize_candidates() {
+        assert_eq!(
+            singularize_candidates("ώρες"),
+            vec!["ώρα", "ώρα", "ώρας", "ώρη"]
+        );
+        assert_eq!(
+            singularize_candidates("μήνες"),
+            vec!["μήνας", "μήνα", "μήνας", "μήνη"]
+        );
+        assert_eq!(
+            singularize_candidates("βιβλία"),
+            vec!["βιβλίο", "βιβλίο", "βιβλία"]
+        );
+        assert_eq!(singularize_candidates("χρήστες"), vec!["χρήστης"]);
+        assert_eq!(singularize_candidates("λάθη"), vec!["λάθος"]);
+        let candidates = RULES.singularize_candidates("Σπίτια");
+        assert_eq!(candidates[0].value(), "Σπίτι");
+        assert_eq!(candidates[1].value(), "Σπίτιο");
+    }
+
+    #[test]
+    fn test_number_of_neuter() {
+        use crate::Number;
+        assert_eq!(RULES.number_of("βιβλία"), Number::Plural);
+        assert_eq!(RULES.number_of("παιδιά"), Number::Plural);
+        assert_eq!(RULES.number_of("αυτοκίνητα"), Number::Plural);
+        assert_eq!(RULES.number_of("ώρα"), Number::Singular);
+    }
+
+    #[test]
+    fn test_singularize_greek_already_singular() {
+        for word in ["χρήστης", "δρόμος", "πόλη", "θέμα", "βιβλίο", "λάθος"]
+        {
+            assert_eq!(singularize(word), word);
+        }
+    }
+
+    #[test]
+    fn test_pluralize_greek() {
+        assert_eq!(pluralize("δρόμος")[0], "δρόμοι");
+        assert_eq!(pluralize("άνθρωπος"), vec!["άνθρωποι"]);
+        assert_eq!(pluralize("ουρανός")[0], "ουρανοί");
+        assert_eq!(pluralize("πατέρας"), vec!["πατέρες"]);
+        assert_eq!(pluralize("χρήστης"), vec!["χρήστες"]);
+        assert_eq!(pluralize("μαθητής")[0], "μαθητές");
+        assert_eq!(pluralize("μπαμπάς"), vec!["μπαμπάδες"]);
+        assert_eq!(pluralize("τέχνη"), vec!["τέχνες"]);
+        assert_eq!(pluralize("φωνή"), vec!["φωνές"]);
+        assert_eq!(pluralize("καρδιά"), vec!["καρδιές"]);
+        assert_eq!(pluralize("μαμά"), vec!["μαμάδες"]);
+        assert_eq!(pluralize("ώρα"), vec!["ώρες"]);
+        assert_eq!(pluralize("βιβλίο"), vec!["βιβλία"]);
+        assert_eq!(pluralize("παιδί"), vec!["παιδιά"]);
+        assert_eq!(pluralize("σπίτι"), vec!["σπίτια"]);
+        assert_eq!(pluralize("προϊόν"), vec!["προϊόντα"]);
+        assert_eq!(pluralize("λάθος"), vec!["λάθη"]);
+        assert_eq!(pluralize("πόλη"), vec!["πόλεις"]);
+    }
+
+    #[test]
+    fn test_pluralize_greek_accent_shift() {
+        assert_eq!(pluralize("θέμα"), vec!["θέματα"]);
+        assert_eq!(pluralize("πρόβλημα"), vec!["προβλήματα"]);
+        assert_eq!(pluralize("όνομα"), vec!["ονόματα"]);
+        assert_eq!(pluralize("σύνδεση")[0], "συνδέσεις");
+        assert_eq!(pluralize("διεύθυνση")[0], "διευθύνσεις");
+        assert_eq!(pluralize("λέξη")[0], "λέξεις");
+        assert_eq!(pluralize("έδαφος"), vec!["εδάφη"]);
+    }
+
+    #[test]
+    fn test_rules_greek() {
+        assert_eq!(RULES.singularize("Χρήστες"), "Χρήστης");
+        assert_eq!(RULES.singularize("ΔΡΟΜΟΙ"), "ΔΡΟΜΟΣ");
+        assert_eq!(RULES.pluralize_best("πρόβλημα"), "προβλήματα");
+        assert_eq!(RULES.pluralize("μπαρ"), vec!["μπαρ"]);
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
@@ -2,103 +2,692 @@
 
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 
+use crate::explain::{self, Rule, RuleOrigin, Step};
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
+use phf::phf_set;
+
+/// Irregular English nouns: native plurals, `-f(e)` -> `-ves`, `-o` -> `-oes`, classical plurals,
+/// compounds whose first element is pluralized (`mothers-in-law`), and regular nouns whose endings
+/// mislead the suffix rules (`movies`, `buses`, `lenses`).
+/// The [`COMPOUND_HEADS`] among them also apply to compounds that end in them.
+static IRREGULARS: Irregulars = irregulars! {
+    // Native plurals
+    "child" => "children",
+    "die" => "dice",
+    "foot" => "feet",
+    "goose" => "geese",
+    "louse" => "lice",
+    "man" => "men",
+    "mouse" => "mice",
+    "ox" => "oxen",
+    "person" => "people",
+    "tooth" => "teeth",
+    "woman" => "women",
+    // -f and -fe -> -ves
+    "calf" => "calves",
+    "elf" => "elves",
+    "half" => "halves",
+    "hoof" => "hooves",
+    "knife" => "knives",
+    "leaf" => "leaves",
+    "life" => "lives",
+    "loaf" => "loaves",
+    "scarf" => "scarves",
+    "self" => "selves",
+    "sheaf" => "sheaves",
+    "shelf" => "shelves",
+    "thief" => "thieves",
+    "wharf" => "wharves",
+    "wife" => "wives",
+    "wolf" => "wolves",
+    // Regular plurals of nouns in -lve and -oe that the suffix rules would misread
+    "aloe" => "aloes",
+    "canoe" => "canoes",
+    "doe" => "does",
+    "floe" => "floes",
+    "foe" => "foes",
+    "hoe" => "hoes",
+    "mistletoe" => "mistletoes",
+    "oboe" => "oboes",
+    "roe" => "roes",
+    "shoe" => "shoes",
+    "sloe" => "sloes",
+    "throe" => "throes",
+    "tiptoe" => "tiptoes",
+    "toe" => "toes",
+    "valve" => "valves",
+    "woe" => "woes",
+    // -o -> -oes
+    "buffalo" => "buffaloes",
+    "calico" => "calicoes",
+    "cargo" => "cargoes",
+    "desperado" => "desperadoes",
+    "dingo" => "dingoes",
+    "domino" => "dominoes",
+    "echo" => "echoes",
+    "embargo" => "embargoes",
+    "fresco" => "frescoes",
+    "grotto" => "grottoes",
+    "hero" => "heroes",
+    "hobo" => "hoboes",
+    "innuendo" => "innuendoes",
+    "jingo" => "jingoes",
+    "mango" => "mangoes",
+    "mosquito" => "mosquitoes",
+    "motto" => "mottoes",
+    "peccadillo" => "peccadilloes",
+    "potato" => "potatoes",
+    "stucco" => "stuccoes",
+    "tomato" => "tomatoes",
+    "tornado" => "tornadoes",
+    "torpedo" => "torpedoes",
+    "veto" => "vetoes",
+    "volcano" => "volcanoes",
+    // Greek -is -> -es
+    "apotheosis" => "apotheoses",
+    "axis" => "axes",
+    "catharsis" => "catharses",
+    "crisis" => "crises",
+    "diagnosis" => "diagnoses",
+    "ellipsis" => "ellipses",
+    "emphasis" => "emphases",
+    "exegesis" => "exegeses",
+    "genesis" => "geneses",
+    "metamorphosis" => "metamorphoses",
+    "nemesis" => "nemeses",
+    "neurosis" => "neuroses",
+    "oasis" => "oases",
+    "prognosis" => "prognoses",
+    "psychosis" => "psychoses",
+    "synopsis" => "synopses",
+    "thesis" => "theses",
+    // Greek -on -> -a
+    "automaton" => "automata",
+    "criterion" => "criteria",
+    "ganglion" => "ganglia",
+    "noumenon" => "noumena",
+    "phenomenon" => "phenomena",
+    "polyhedron" => "polyhedra",
+    "prolegomenon" => "prolegomena",
+    // Latin -um -> -a
+    "addendum" => "addenda",
+    "atrium" => "atria",
+    "bacterium" => "bacteria",
+    "candelabrum" => "candelabra",
+    "cilium" => "cilia",
+    "compendium" => "compendia",
+    "consortium" => "consortia",
+    "continuum" => "continua",
+    "corrigendum" => "corrigenda",
+    "cranium" => "crania",
+    "curriculum" => "curricula",
+    "datum" => "data",
+    "desideratum" => "desiderata",
+    "epithelium" => "epithelia",
+    "equilibrium" => "equilibria",
+    "erratum" => "errata",
+    "flagellum" => "flagella",
+    "honorarium" => "honoraria",
+    "labium" => "labia",
+    "maximum" => "maxima",
+    "medium" => "media",
+    "memorandum" => "memoranda",
+    "millennium" => "millennia",
+    "minimum" => "minima",
+    "momentum" => "momenta",
+    "optimum" => "optima",
+    "ovum" => "ova",
+    "phylum" => "phyla",
+    "quantum" => "quanta",
+    "referendum" => "referenda",
+    "rostrum" => "rostra",
+    "sanatorium" => "sanatoria",
+    "septum" => "septa",
+    "spectrum" => "spectra",
+    "stratum" => "strata",
+    "substratum" => "substrata",
+    "symposium" => "symposia",
+    // Latin -us -> -i and -a
+    "alumnus" => "alumni",
+    "bacillus" => "bacilli",
+    "bronchus" => "bronchi",
+    "calculus" => "calculi",
+    "colossus" => "colossi",
+    "corpus" => "corpora",
+    "cumulus" => "cumuli",
+    "embolus" => "emboli",
+    "fungus" => "fungi",
+    "genus" => "genera",
+    "gladiolus" => "gladioli",
+    "homunculus" => "homunculi",
+    "locus" => "loci",
+    "magus" => "magi",
+    "meniscus" => "menisci",
+    "modulus" => "moduli",
+    "narcissus" => "narcissi",
+    "nimbus" => "nimbi",
+    "nucleus" => "nuclei",
+    "papyrus" => "papyri",
+    "radius" => "radii",
+    "sarcophagus" => "sarcophagi",
+    "stimulus" => "stimuli",
+    "syllabus" => "syllabi",
+    "terminus" => "termini",
+    "thrombus" => "thrombi",
+    "torus" => "tori",
+    "uterus" => "uteri",
+    "villus" => "villi",
+    "viscus" => "viscera",
+    // Latin -a -> -ae
+    "alga" => "algae",
+    "alumna" => "alumnae",
+    "amoeba" => "amoebae",
+    "hypha" => "hyphae",
+    "lacuna" => "lacunae",
+    "larva" => "larvae",
+    "minutia" => "minutiae",
+    "nebula" => "nebulae",
+    "nova" => "novae",
+    "papilla" => "papillae",
+    "persona" => "personae",
+    "pupa" => "pupae",
+    "supernova" => "supernovae",
+    "vertebra" => "vertebrae",
+    // Latin -ex and -ix -> -ices
+    "appendix" => "appendices",
+    "cervix" => "cervices",
+    "codex" => "codices",
+    "cortex" => "cortices",
+    "helix" => "helices",
+    "matrix" => "matrices",
+    "radix" => "radices",
+    "varix" => "varices",
+    "vertex" => "vertices",
+    "vortex" => "vortices",
+    // French -eau -> -eaux
+    "bandeau" => "bandeaux",
+    "beau" => "beaux",
+    "chateau" => "chateaux",
+    "flambeau" => "flambeaux",
+    "gateau" => "gateaux",
+    "rondeau" => "rondeaux",
+    "tableau" => "tableaux",
+    "trousseau" => "trousseaux",
+    // Regular plurals in -ses and -zes of nouns ending in -s and -z
+    "abacus" => "abacuses",
+    "alias" => "aliases",
+    "apparatus" => "apparatuses",
+    "atlas" => "atlases",
+    "bias" => "biases",
+    "bonus" => "bonuses",
+    "bus" => "buses",
+    "callus" => "calluses",
+    "campus" => "campuses",
+    "canvas" => "canvases",
+    "caucus" => "caucuses",
+    "census" => "censuses",
+    "chorus" => "choruses",
+    "chrysalis" => "chrysalises",
+    "circus" => "circuses",
+    "consensus" => "consensuses",
+    "crocus" => "crocuses",
+    "discus" => "discuses",
+    "exodus" => "exoduses",
+    "fetus" => "fetuses",
+    "fez" => "fezzes",
+    "gas" => "gases",
+    "genius" => "geniuses",
+    "hiatus" => "hiatuses",
+    "hippopotamus" => "hippopotamuses",
+    "ignoramus" => "ignoramuses",
+    "impetus" => "impetuses",
+    "iris" => "irises",
+    "isthmus" => "isthmuses",
+    "lens" => "lenses",
+    "lotus" => "lotuses",
+    "mantis" => "mantises",
+    "metropolis" => "metropolises",
+    "minus" => "minuses",
+    "nexus" => "nexuses",
+    "octopus" => "octopuses",
+    "pancreas" => "pancreases",
+    "pelvis" => "pelvises",
+    "platypus" => "platypuses",
+    "plus" => "pluses",
+    "prospectus" => "prospectuses",
+    "quiz" => "quizzes",
+    "rebus" => "rebuses",
+    "rhinoceros" => "rhinoceroses",
+    "ruckus" => "ruckuses",
+    "sinus" => "sinuses",
+    "status" => "statuses",
+    "surplus" => "surpluses",
+    "topaz" => "topazes",
+    "trellis" => "trellises",
+    "virus" => "viruses",
+    "walrus" => "walruses",
+    "whiz" => "whizzes",
+    // Regular plurals in -ies of nouns ending in -ie
+    "auntie" => "aunties",
+    "aussie" => "aussies",
+    "beanie" => "beanies",
+    "bestie" => "besties",
+    "birdie" => "birdies",
+    "bogie" => "bogies",
+    "bookie" => "bookies",
+    "bootie" => "booties",
+    "bowtie" => "bowties",
+    "brasserie" => "brasseries",
+    "brownie" => "brownies",
+    "budgie" => "budgies",
+    "cabbie" => "cabbies",
+    "calorie" => "calories",
+    "collie" => "collies",
+    "cookie" => "cookies",
+    "coolie" => "coolies",
+    "coterie" => "coteries",
+    "cowrie" => "cowries",
+    "eyrie" => "eyries",
+    "foodie" => "foodies",
+    "freebie" => "freebies",
+    "genie" => "genies",
+    "goalie" => "goalies",
+    "goodie" => "goodies",
+    "groupie" => "groupies",
+    "hankie" => "hankies",
+    "hippie" => "hippies",
+    "hoodie" => "hoodies",
+    "hottie" => "hotties",
+    "indie" => "indies",
+    "junkie" => "junkies",
+    "kelpie" => "kelpies",
+    "kiddie" => "kiddies",
+    "lassie" => "lassies",
+    "lie" => "lies",
+    "magpie" => "magpies",
+    "meanie" => "meanies",
+    "menagerie" => "menageries",
+    "movie" => "movies",
+    "necktie" => "neckties",
+    "newbie" => "newbies",
+    "nightie" => "nighties",
+    "oldie" => "oldies",
+    "onesie" => "onesies",
+    "patisserie" => "patisseries",
+    "pie" => "pies",
+    "pinkie" => "pinkies",
+    "pixie" => "pixies",
+    "prairie" => "prairies",
+    "quickie" => "quickies",
+    "reverie" => "reveries",
+    "roadie" => "roadies",
+    "rookie" => "rookies",
+    "roomie" => "roomies",
+    "rotisserie" => "rotisseries",
+    "selfie" => "selfies",
+    "smoothie" => "smoothies",
+    "sortie" => "sorties",
+    "sweetie" => "sweeties",
+    "talkie" => "talkies",
+    "techie" => "techies",
+    "tie" => "ties",
+    "toughie" => "toughies",
+    "veggie" => "veggies",
+    "walkie-talkie" => "walkie-talkies",
+    "wheelie" => "wheelies",
+    "yuppie" => "yuppies",
+    "zombie" => "zombies",
+    // Regular plurals in -ches of nouns ending in -che
+    "avalanche" => "avalanches",
+    "backache" => "backaches",
+    "brioche" => "brioches",
+    "cache" => "caches",
+    "cliche" => "cliches",
+    "cloche" => "cloches",
+    "creche" => "creches",
+    "douche" => "douches",
+    "earache" => "earaches",
+    "headache" => "headaches",
+    "heartache" => "heartaches",
+    "microfiche" => "microfiches",
+    "moustache" => "moustaches",
+    "niche" => "niches",
+    "pastiche" => "pastiches",
+    "psyche" => "psyches",
+    "quiche" => "quiches",
+    "stomachache" => "stomachaches",
+    "toothache" => "toothaches",
+    // Regular plurals in -chs where -ch is pronounced /k/
+    "diptych" => "diptychs",
+    "epoch" => "epochs",
+    "eunuch" => "eunuchs",
+    "exarch" => "exarchs",
+    "hierarch" => "hierarchs",
+    "loch" => "lochs",
+    "matriarch" => "matriarchs",
+    "monarch" => "monarchs",
+    "oligarch" => "oligarchs",
+    "patriarch" => "patriarchs",
+    "stomach" => "stomachs",
+    "tetrarch" => "tetrarchs",
+    "triptych" => "triptychs",
+    // Other classical and foreign plurals
+    "castrato" => "castrati",
+    "cherub" => "cherubim",
+    "dilettante" => "dilettanti",
+    "graffito" => "graffiti",
+    "kibbutz" => "kibbutzim",
+    "lied" => "lieder",
+    "madame" => "mesdames",
+    "mademoiselle" => "mesdemoiselles",
+    "monsieur" => "messieurs",
+    "paparazzo" => "paparazzi",
+    "putto" => "putti",
+    "seraph" => "seraphim",
+    "timpano" => "timpani",
+    "virtuoso" => "virtuosi",
+    // Compounds whose first element is pluralized
+    "attorney general" => "attorneys general",
+    "brother-in-law" => "brothers-in-law",
+    "chief of staff" => "chiefs of staff",
+    "coat of arms" => "coats of arms",
+    "commander-in-chief" => "commanders-in-chief",
+    "court martial" => "courts martial",
+    "daughter-in-law" => "daughters-in-law",
+    "editor-in-chief" => "editors-in-chief",
+    "father-in-law" => "fathers-in-law",
+    "governor-general" => "governors-general",
+    "grant-in-aid" => "grants-in-aid",
+    "hanger-on" => "hangers-on",
+    "heir apparent" => "heirs apparent",
+    "jack-in-the-box" => "jacks-in-the-box",
+    "jack-of-all-trades" => "jacks-of-all-trades",
+    "lady-in-waiting" => "ladies-in-waiting",
+    "looker-on" => "lookers-on",
+    "man-at-arms" => "men-at-arms",
+    "man-of-war" => "men-of-war",
+    "mother-in-law" => "mothers-in-law",
+    "notary public" => "notaries public",
+    "passer-by" => "passers-by",
+    "poet laureate" => "poets laureate",
+    "point of view" => "points of view",
+    "postmaster general" => "postmasters general",
+    "right of way" => "rights of way",
+    "runner-up" => "runners-up",
+    "secretary-general" => "secretaries-general",
+    "sergeant-at-arms" => "sergeants-at-arms",
+    "sister-in-law" => "sisters-in-law",
+    "son-in-law" => "sons-in-law",
+    "surgeon general" => "surgeons general";
+    plural_to_singular:
+    // Alternative plurals
+    "antennae" => "antenna",
+    "bureaux" => "bureau",
+    "cacti" => "cactus",
+    "dwarves" => "dwarf",
+    "foci" => "focus",
+    "formulae" => "formula",
+    "indices" => "index",
+    "persons" => "person",
+    "plateaux" => "plateau",
+    "schemata" => "schema",
+    "thesauri" => "thesaurus",
+};
 
-/// Irregular plural -> singular mappings.
-static EXCEPTIONS_PLURAL_TO_SINGULAR: &[(&str, &str)] = &[
-    ("children", "child"),
-    ("geese", "goose"),
-    ("men", "man"),
-    ("mice", "mouse"),
-    ("oxen", "ox"),
-    ("people", "person"),
-    ("teeth", "tooth"),
-    ("women", "woman"),
-];
+/// Uncountable and invariant English nouns.
+static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "advice", "athletics", "baggage", "barracks", "bison", "butter", "cattle", "chaos",
+        "chassis", "clothing", "cod", "corps", "cosmos", "crossroads", "data", "debris", "deer",
+        "diabetes", "economics", "education", "electricity", "electronics", "equipment", "ethics",
+        "ethos", "evidence", "feedback", "fish", "flounder", "furniture", "gallows", "gold",
+        "gymnastics", "hardware", "headquarters", "health", "homework", "information", "jeans",
+        "knowledge", "kudos", "linguistics", "logistics", "luggage", "mackerel", "management",
+        "mathematics", "means", "metadata", "money", "moose", "music", "news", "offspring",
+        "pathos", "physics", "pike", "police", "politics", "progress", "research", "rice", "salmon",
+        "scissors", "series", "sheep", "shrimp", "silver", "software", "species", "swine", "tennis",
+        "traffic", "trout", "tuna", "weather", "wheat", "wildlife",
+    },
+    uncountable_suffixes: &["craft", "deer", "fish", "sheep", "ware"],
+    invariant_suffixes: &[],
+};
 
-/// Irregular singular -> plural mappings.
-static EXCEPTIONS_SINGULAR_TO_PLURAL: &[(&str, &str)] = &[
-    ("child", "children"),
-    ("goose", "geese"),
-    ("man", "men"),
-    ("mouse", "mice"),
-    ("ox", "oxen"),
-    ("person", "people"),
-    ("tooth", "teeth"),
-    ("woman", "women"),
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
+    irregulars: Some(&IRREGULARS),
+    explain_singularize_fn: Some(explain_singularize),
+    explain_pluralize_fn: Some(explain_pluralize),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("en", singularize, pluralize)
+};
+
+/// Irregular nouns that keep their irregular plural as the last part of a compound (`fireman`
+/// -> `firemen`, `grandchild` -> `grandchildren`). Longer heads come first.
+const COMPOUND_HEADS: &[&str] = &[
+    "person", "child", "knife", "mouse", "goose", "shelf", "tooth", "woman", "foot", "half",
+    "leaf", "life", "self", "wife", "wolf", "man",
 ];
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "en",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+/// Words that end like a compound of one of the [`COMPOUND_HEADS`] or their plurals but are not
+/// compounds (`human`, `specimen`).
+static FALSE_COMPOUNDS: phf::Set<&'static str> = phf_set! {
+    "abdomen", "acumen", "albumen", "amen", "behalf", "bitumen", "bluetooth", "caiman", "cayman",
+    "cyclamen", "desman", "doberman", "dolman", "dolmen", "german", "human", "hymen", "lumen",
+    "olives", "omen", "ottoman", "pumice", "ramen", "regimen", "roman", "rumen", "semen", "shaman",
+    "specimen", "stamen", "talisman", "walkman", "yemen",
 };
 
+/// Ordered plural -> singular suffix rules; the first rule whose suffix ends the word wins.
+/// Identity rules keep singulars that end like plurals (`class`, `status`, `analysis`).
+/// Whole words that would otherwise match a shorter suffix belong in [`IRREGULARS`].
+const SINGULAR_RULES: &[(&str, &str)] = &[
+    // Classical loan words
+    ("eaux", "eau"),
+    ("lyses", "lysis"),
+    ("theses", "thesis"),
+    ("cti", "ctus"),
+    ("clei", "cleus"),
+    ("uli", "ulus"),
+    ("ci", "cus"),
+    ("ae", "a"),
+    // -y -> -ies
+    ("quies", "quy"),
+    ("ies", "y"),
+    // Sibilants -> -es
+    ("sses", "ss"),
+    ("shes", "sh"),
+    ("ches", "ch"),
+    ("xes", "x"),
+    ("zzes", "zz"),
+    ("zes", "ze"),
+    // -o -> -oes, except nouns in -oe and their compounds (`horseshoes`)
+    ("shoes", "shoe"),
+    ("oes", "o"),
+    // -lf -> -lves
+    ("lves", "lf"),
+    // Singulars that end in -s
+    ("ss", "ss"),
+    ("us", "us"),
+    ("sis", "sis"),
+    ("itis", "itis"),
+    ("his", "his"),
+    // Everything else
+    ("s", ""),
+];
+
+/// Ordered singular -> plural suffix rules; the first rule whose suffix ends the word wins.
+const PLURAL_RULES: &[(&str, &str)] = &[
+    // Greek -is -> -es
+    ("sis", "ses"),
+    // -y -> -ies, except after a vowel
+    ("quy", "quies"),
+    ("ay", "ays"),
+    ("ey", "eys"),
+    ("oy", "oys"),
+    ("uy", "uys"),
+    ("y", "ies"),
+    // Sibilants -> -es
+    ("s", "ses"),
+    ("x", "xes"),
+    ("z", "zes"),
+    ("ch", "ches"),
+    ("sh", "shes"),
+    // Everything else
+    ("", "s"),
+];
+
+/// Returns the index of the first rule in `rules` whose suffix ends `name`, provided a non-empty
+/// stem remains or the suffix is empty, together with the word the rule produces.
+fn match_rule<'a>(name: &'a str, rules: &[(&str, &str)]) -> Option<(usize, Cow<'a, str>)> {
+    rules
+        .iter()
+        .enumerate()
+        .find_map(|(index, &(suffix, replacement))| {
+            let stem = name
+                .strip_suffix(suffix)
+                .filter(|stem| !stem.is_empty() || suffix.is_empty())?;
+            let form = if suffix == replacement {
+                Cow::Borrowed(name)
+            } else if replacement.is_empty() {
+                Cow::Borrowed(stem)
+            } else {
+                format!("{stem}{replacement}").into()
+            };
+            Some((index, form))
+        })
+}
+
+/// Applies the first rule in `rules` whose suffix ends `name`.
+fn apply_rules<'a>(name: &'a str, rules: &[(&str, &str)]) -> Cow<'a, str> {
+    match_rule(name, rules).map_or(Cow::Borrowed(name), |(_, form)| form)
+}
+
+/// Applies `rules` like [`apply_rules`], recording the rule that fired and the rules tried before
+/// it.
+fn trace_rules<'a>(name: &'a str, rules: &[(&str, &str)], steps: &mut Vec<Step>) -> Cow<'a, str> {
+    let matched = match_rule(name, rules);
+    explain::trace_table(rules, matched.as_ref().map(|&(index, _)| index), steps);
+    matched.map_or(Cow::Borrowed(name), |(_, form)| form)
+}
+
+/// Returns the singular of a compound that ends in the plural of one of the [`COMPOUND_HEADS`]
+/// (`firemen` -> `fireman`).
+fn compound_singular(name: &str) -> Option<String> {
+    if FALSE_COMPOUNDS.contains(name) {
+        return None;
+    }
+    COMPOUND_HEADS.iter().find_map(|&head| {
+        let plural = IRREGULARS.plural(head)?;
+        let prefix = name.strip_suffix(plural).filter(|stem| !stem.is_empty())?;
+        Some(format!("{prefix}{head}"))
+    })
+}
+
+/// Returns the plural of a compound that ends in one of the [`COMPOUND_HEADS`] (`fireman` ->
+/// `firemen`).
+fn compound_plural(name: &str) -> Option<String> {
+    if FALSE_COMPOUNDS.contains(name) {
+        return None;
+    }
+    COMPOUND_HEADS.iter().find_map(|&head| {
+        let prefix = name.strip_suffix(head).filter(|stem| !stem.is_empty())?;
+        Some(format!("{prefix}{}", IRREGULARS.plural(head)?))
+    })
+}
+
+/// Returns the singular of an irregular plural or of a compound ending in one.
+fn irregular_singular(name: &str) -> Option<Cow<'_, str>> {
+    IRREGULARS
+        .singular(name)
+        .map(Cow::Borrowed)
+        .or_else(|| compound_singular(name).map(Cow::Owned))
+}
+
+/// Returns the plural of an irregular singular or of a compound ending in one.
+fn irregular_plural(name: &str) -> Option<Cow<'_, str>> {
+    IRREGULARS
+        .plural(name)
+        .map(Cow::Borrowed)
+        .or_else(|| compound_plural(name).map(Cow::Owned))
+}
+
 /// Converts a plural English noun to its singular form.
 ///
-/// Handles irregular exceptions (e.g., children -> child, oxen -> ox) before falling back to
-/// regular suffix rules: `-s`, `-es`, `-ies`, `-sses`.
+/// Handles irregular nouns (e.g., children -> child, oxen -> ox, analyses -> analysis) and
+/// compounds ending in them (firemen -> fireman) before falling back to the ordered
+/// [`SINGULAR_RULES`]. Words of two letters or fewer are not plurals (`is`, `us`).
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
-    // Check exceptions first
-    for &(plural, singular) in EXCEPTIONS_PLURAL_TO_SINGULAR {
-        if name == plural {
-            return Cow::Borrowed(singular);
-        }
+    if let Some(singular) = irregular_singular(name) {
+        return singular;
+    }
+    if name.chars().nth(2).is_none() {
+        return Cow::Borrowed(name);
     }
+    apply_rules(name, SINGULAR_RULES)
+}
 
-    // Algorithmic suffix rules
-    if let Some(stem) = name.strip_suffix("ies")
-        && !stem.is_empty()
-    {
-        return format!("{stem}y").into();
-    }
-    if let Some(stem) = name.strip_suffix("sses")
-        && !stem.is_empty()
-    {
-        return format!("{stem}ss").into();
-    }
-    if let Some(stem) = name.strip_suffix("es") {
-        if stem.ends_with('x')
-            || stem.ends_with("ch")
-            || stem.ends_with("sh")
-            || stem.ends_with('z')
-        {
-            return Cow::Borrowed(stem);
-        }
-        return Cow::Borrowed(name.strip_suffix('s').unwrap_or(name));
+/// Singularizes `name` like [`singularize`], recording the irregular lookup and the
+/// [`SINGULAR_RULES`] that were tried.
+fn explain_singularize<'a>(name: &'a str, steps: &mut Vec<Step>) -> Cow<'a, str> {
+    let irregular = irregular_singular(name);
+    steps.push(Step::new(
+        Rule::Irregular,
+        RuleOrigin::BuiltIn,
+        irregular.is_some(),
+    ));
+    if let Some(singular) = irregular {
+        return singular;
     }
-    if let Some(stem) = name.strip_suffix('s')
-        && !stem.is_empty()
-        && !stem.ends_with('s')
-        && !name.ends_with("us")
-    {
-        return Cow::Borrowed(stem);
+    if name.chars().nth(2).is_none() {
+        return Cow::Borrowed(name);
     }
-    Cow::Borrowed(name)
+    trace_rules(name, SINGULAR_RULES, steps)
 }
 
-/// Returns a list of possible plural forms for an English noun.
+/// Returns a list of possible plural forms for an English noun, most likely first.
 ///
-/// Checks irregular exceptions first (e.g., child -> children), then generates candidates with
-/// `-s`, `-es`, and `-ies` suffixes.
+/// Nouns that are already plural are returned unchanged (users, children, criteria). Otherwise
+/// checks irregular nouns and compounds ending in them first (e.g., child -> children,
+/// grandchild -> grandchildren), then returns the form given by the ordered [`PLURAL_RULES`].
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    // Check exceptions first
-    for &(singular, plural) in EXCEPTIONS_SINGULAR_TO_PLURAL {
-        if name == singular {
-            return vec![Cow::Borrowed(plural)];
-        }
+    if is_plural(name) {
+        return vec![Cow::Borrowed(name)];
     }
+    vec![plural(name)]
+}
 
-    let mut candidates = vec![format!("{name}s").into(), format!("{name}es").into()];
-    if let Some(stem) = name.strip_suffix('y')
-        && !stem.is_empty()
-    {
-        candidates.push(format!("{stem}ies").into());
+/// Pluralizes `name` like [`pluralize`], recording the plural check, the irregular lookup and the
+/// [`PLURAL_RULES`] that were tried.
+fn explain_pluralize<'a>(name: &'a str, steps: &mut Vec<Step>) -> Vec<Cow<'a, str>> {
+    let plural = is_plural(name);
+    steps.push(Step::new(Rule::Unchanged, RuleOrigin::BuiltIn, plural));
+    if plural {
+        return vec![Cow::Borrowed(name)];
     }
-    candidates
+    let irregular = irregular_plural(name);
+    steps.push(Step::new(
+        Rule::Irregular,
+        RuleOrigin::BuiltIn,
+        irregular.is_some(),
+    ));
+    vec![irregular.unwrap_or_else(|| trace_rules(name, PLURAL_RULES, steps))]
+}
+
+/// Returns the plural of a singular English noun.
+fn plural(name: &str) -> Cow<'_, str> {
+    irregular_plural(name).unwrap_or_else(|| apply_rules(name, PLURAL_RULES))
+}
+
+/// Returns `true` if `name` is the plural of its own singular: an irregular plural (`people`), a
+/// compound ending in one (`firemen`) or a regular `-s` or `-es` plural (`users`, `boxes`).
+/// Singulars that merely end in `-s` (`bus`, `status`) do not lead back to themselves.
+fn is_plural(name: &str) -> bool {
+    let singular = singularize(name);
+    singular != name && plural(&singular) == name
 }
 
 #[cfg(test)]
@@ -162,6 +751,7 @@ mod tests {
         assert_eq!(singularize("status"), "status");
         assert_eq!(singularize("bus"), "bus");
         assert_eq!(singularize("class"), "class");
+        assert_eq!(singularize("this"), "this");
     }
 
     #[test]
@@ -183,18 +773,148 @@ mod tests {
         assert_eq!(singularize("people"), "person");
     }
 
+    #[test]
+    fn test_singularize_sibilant_stems() {
+        assert_eq!(singularize("horses"), "horse");
+        assert_eq!(singularize("databases"), "database");
+        assert_eq!(singularize("responses"), "response");
+        assert_eq!(singularize("houses"), "house");
+        assert_eq!(singularize("sizes"), "size");
+        assert_eq!(singularize("caches"), "cache");
+    }
+
+    #[test]
+    fn test_singularize_classical() {
+        assert_eq!(singularize("leaves"), "leaf");
+        assert_eq!(singularize("knives"), "knife");
+        assert_eq!(singularize("wolves"), "wolf");
+        assert_eq!(singularize("heroes"), "hero");
+        assert_eq!(singularize("analyses"), "analysis");
+        assert_eq!(singularize("hypotheses"), "hypothesis");
+        assert_eq!(singularize("criteria"), "criterion");
+        assert_eq!(singularize("matrices"), "matrix");
+        assert_eq!(singularize("cacti"), "cactus");
+        assert_eq!(singularize("stimuli"), "stimulus");
+        assert_eq!(singularize("formulae"), "formula");
+        assert_eq!(singularize("plateaux"), "plateau");
+        assert_eq!(singularize("shoes"), "shoe");
+        assert_eq!(singularize("archives"), "archive");
+        assert_eq!(singularize("soliloquies"), "soliloquy");
+        assert_eq!(singularize("consortia"), "consortium");
+        assert_eq!(singularize("termini"), "terminus");
+        assert_eq!(singularize("cherubim"), "cherub");
+        assert_eq!(pluralize("supernova"), vec!["supernovae"]);
+        assert_eq!(pluralize("cookie"), vec!["cookies"]);
+    }
+
+    #[test]
+    fn test_first_element_compounds() {
+        assert_eq!(singularize("mothers-in-law"), "mother-in-law");
+        assert_eq!(singularize("passers-by"), "passer-by");
+        assert_eq!(pluralize("attorney general"), vec!["attorneys general"]);
+        assert_eq!(pluralize("runner-up"), vec!["runners-up"]);
+    }
+
+    #[test]
+    fn test_compounds() {
+        assert_eq!(singularize("firemen"), "fireman");
+        assert_eq!(singularize("grandchildren"), "grandchild");
+        assert_eq!(singularize("businesswomen"), "businesswoman");
+        assert_eq!(singularize("townspeople"), "townsperson");
+        assert_eq!(singularize("bookshelves"), "bookshelf");
+        assert_eq!(pluralize("fireman"), vec!["firemen"]);
+        assert_eq!(pluralize("grandchild"), vec!["grandchildren"]);
+        assert_eq!(pluralize("businesswoman"), vec!["businesswomen"]);
+        assert_eq!(pluralize("midwife"), vec!["midwives"]);
+        assert_eq!(pluralize("human")[0], "humans");
+        assert_eq!(pluralize("german")[0], "germans");
+        assert_eq!(pluralize("specimen")[0], "specimens");
+        assert_eq!(singularize("specimen"), "specimen");
+        assert_eq!(singularize("humans"), "human");
+        assert_eq!(singularize("olives"), "olive");
+        assert_eq!(singularize("pumice"), "pumice");
+        assert_eq!(singularize("titmice"), "titmouse");
+        assert_eq!(pluralize("bluetooth")[0], "bluetooths");
+        assert_eq!(pluralize("behalf")[0], "behalfs");
+    }
+
+    #[test]
+    fn test_pluralize_already_plural() {
+        assert_eq!(pluralize("users"), vec!["users"]);
+        assert_eq!(pluralize("boxes"), vec!["boxes"]);
+        assert_eq!(pluralize("categories"), vec!["categories"]);
+        assert_eq!(pluralize("children"), vec!["children"]);
+        assert_eq!(pluralize("people"), vec!["people"]);
+        assert_eq!(pluralize("criteria"), vec!["criteria"]);
+        assert_eq!(pluralize("firemen"), vec!["firemen"]);
+        assert_eq!(pluralize("bus"), vec!["buses"]);
+        assert_eq!(pluralize("status"), vec!["statuses"]);
+        assert_eq!(pluralize("analysis"), vec!["analyses"]);
+        assert_eq!(
+            RULES.pluralize_identifier("user_accounts"),
+            vec!["user_accounts"]
+        );
+        assert_eq!(RULES.pluralize_identifier("BlogPosts"), vec!["BlogPosts"]);
+    }
+
+    #[test]
+    fn test_singularize_false_matches() {
+        assert_eq!(singularize("canoes"), "canoe");
+        assert_eq!(singularize("oboes"), "oboe");
+        assert_eq!(singularize("apis"), "api");
+        assert_eq!(singularize("taxis"), "taxi");
+        assert_eq!(singularize("is"), "is");
+        assert_eq!(singularize("basis"), "basis");
+        assert_eq!(singularize("arthritis"), "arthritis");
+        assert_eq!(singularize("hawaii"), "hawaii");
+        assert_eq!(singularize("magpies"), "magpie");
+        assert_eq!(singularize("radii"), "radius");
+    }
+
+    #[test]
+    fn test_pluralize_rules() {
+        assert_eq!(pluralize("analysis")[0], "analyses");
+        assert_eq!(pluralize("basis")[0], "bases");
+        assert_eq!(pluralize("soliloquy")[0], "soliloquies");
+        assert_eq!(pluralize("key")[0], "keys");
+        assert_eq!(pluralize("waltz")[0], "waltzes");
+        assert_eq!(pluralize("photo")[0], "photos");
+        assert_eq!(pluralize("leaf"), vec!["leaves"]);
+        assert_eq!(pluralize("hero"), vec!["heroes"]);
+        assert_eq!(pluralize("cactus")[0], "cactuses");
+        assert_eq!(singularize("horseshoes"), "horseshoe");
+    }
+
     #[test]
     fn test_pluralize() {
         let result = pluralize("user");
         assert!(result.iter().any(|v| v == "users"));
-        assert!(result.iter().any(|v| v == "useres"));
+        assert!(!result.iter().any(|v| v == "useres"));
 
         let result = pluralize("category");
         assert!(result.iter().any(|v| v == "categories"));
 
         let result = pluralize("box");
-        assert!(result.iter().any(|v| v == "boxs"));
+        assert!(!result.iter().any(|v| v == "boxs"));
         assert!(result.iter().any(|v| v == "boxes"));
+
+        assert_eq!(pluralize("horse"), vec!["horses"]);
+        assert_eq!(pluralize("volcano"), vec!["volcanoes"]);
+        assert_eq!(pluralize("photo"), vec!["photos"]);
+        assert_eq!(pluralize("album"), vec!["albums"]);
+        assert_eq!(pluralize("suffix"), vec!["suffixes"]);
+    }
+
+    #[test]
+    fn test_pluralize_ranking() {
+        assert_eq!(pluralize("user")[0], "users");
+        assert_eq!(pluralize("box")[0], "boxes");
+        assert_eq!(pluralize("match")[0], "matches");
+        assert_eq!(pluralize("address")[0], "addresses");
+        assert_eq!(pluralize("category")[0], "categories");
+        assert_eq!(pluralize("day")[0], "days");
+        assert_eq!(RULES.pluralize_best("user"), "users");
+        assert_eq!(RULES.pluralize_best("Company"), "Companies");
     }
 
     #[test]
@@ -238,4 +958,57 @@ mod tests {
         let result = pluralize("person");
         assert_eq!(result, vec!["people"]);
     }
+
+    #[test]
+    fn test_explain() {
+        let words = [
+            "users", "boxes", "children", "firemen", "is", "status", "analyses", "user", "box",
+            "child", "fireman", "human", "day", "city",
+        ];
+        for word in words {
+            let mut steps = Vec::new();
+            assert_eq!(explain_singularize(word, &mut steps), singularize(word));
+            steps.clear();
+            assert_eq!(explain_pluralize(word, &mut steps), pluralize(word));
+        }
+
+        let mut steps = Vec::new();
+        explain_singularize("classes", &mut steps);
+        let rule = |suffix: &str, replacement: &str| Rule::Suffix {
+            suffix: suffix.into(),
+            replacement: replacement.into(),
+        };
+        assert_eq!(steps.last().unwrap().rule(), &rule("sses", "ss"));
+        let index = SINGULAR_RULES
+            .iter()
+            .position(|&rule| rule == ("sses", "ss"));
+        // The irregular lookup and every rule before `-sses`
+        assert_eq!(Some(steps.len() - 2), index);
+        assert!(steps[..steps.len() - 1].iter().all(|step| !step.matched()));
+
+        steps.clear();
+        assert_eq!(explain_pluralize("users", &mut steps), ["users"]);
+        assert_eq!(
+            steps,
+            [Step::new(Rule::Unchanged, RuleOrigin::BuiltIn, true)]
+        );
+    }
+
+    #[test]
+    fn test_rules_uncountable() {
+        assert_eq!(RULES.singularize("news"), "news");
+        assert_eq!(RULES.singularize("series"), "series");
+        assert_eq!(RULES.singularize("species"), "species");
+        assert_eq!(RULES.singularize("data"), "data");
+        assert_eq!(RULES.singularize("chaos"), "chaos");
+        assert_eq!(RULES.singularize("tennis"), "tennis");
+        assert_eq!(RULES.singularize("This"), "This");
+        assert_eq!(RULES.pluralize("chaos"), vec!["chaos"]);
+        assert_eq!(RULES.number_of("tennis"), crate::Number::Invariant);
+        assert_eq!(RULES.pluralize("information"), vec!["information"]);
+        assert_eq!(RULES.pluralize("sheep"), vec!["sheep"]);
+        assert_eq!(RULES.pluralize("Equipment"), vec!["Equipment"]);
+        assert_eq!(RULES.pluralize("middleware"), vec!["middleware"]);
+        assert_eq!(RULES.singularize("aircraft"), "aircraft");
+    }
 }
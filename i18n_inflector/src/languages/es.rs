@@ -1,22 +1,192 @@
 //! Spanish (es) inflection rules.
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
+
+/// Irregular Spanish nouns: stress shifts, stressed `-í` and `-ú` that take `-es`, `-y` after a
+/// vowel, loanwords, nouns in `-ce` whose plural would be read as that of a noun in `-z`
+/// (`índices`), and compounds written as one word whose first element is pluralized
+/// (`hijosdalgo`). Nouns whose plural follows from the suffix and accent rules (`canción`,
+/// `lápiz`, `examen`) are not listed, nor are phrases, whose words are inflected one at a time.
+pub(crate) static IRREGULARS: Irregulars = irregulars! {
+    // Stress shifts
+    "carácter" => "caracteres",
+    "espécimen" => "especímenes",
+    "hipérbaton" => "hipérbatos",
+    "régimen" => "regímenes",
+    // Stressed -í and -ú take -es
+    "ají" => "ajíes",
+    "alauí" => "alauíes",
+    "alfaquí" => "alfaquíes",
+    "alhelí" => "alhelíes",
+    "ambigú" => "ambigúes",
+    "andalusí" => "andalusíes",
+    "baladí" => "baladíes",
+    "bambú" => "bambúes",
+    "bantú" => "bantúes",
+    "bengalí" => "bengalíes",
+    "benjuí" => "benjuíes",
+    "berbiquí" => "berbiquíes",
+    "bigudí" => "bigudíes",
+    "bisturí" => "bisturíes",
+    "borceguí" => "borceguíes",
+    "cadí" => "cadíes",
+    "canesú" => "canesúes",
+    "caribú" => "caribúes",
+    "carmesí" => "carmesíes",
+    "catarí" => "cataríes",
+    "cebú" => "cebúes",
+    "cequí" => "cequíes",
+    "ceutí" => "ceutíes",
+    "chacolí" => "chacolíes",
+    "colibrí" => "colibríes",
+    "frenesí" => "frenesíes",
+    "guaraní" => "guaraníes",
+    "gurú" => "gurúes",
+    "hindú" => "hindúes",
+    "hurí" => "huríes",
+    "iraní" => "iraníes",
+    "iraquí" => "iraquíes",
+    "israelí" => "israelíes",
+    "jabalí" => "jabalíes",
+    "kuwaití" => "kuwaitíes",
+    "magrebí" => "magrebíes",
+    "mambí" => "mambíes",
+    "manatí" => "manatíes",
+    "maniquí" => "maniquíes",
+    "maorí" => "maoríes",
+    "maravedí" => "maravedíes",
+    "marroquí" => "marroquíes",
+    "muftí" => "muftíes",
+    "ñandú" => "ñandúes",
+    "nepalí" => "nepalíes",
+    "omaní" => "omaníes",
+    "ombú" => "ombúes",
+    "pakistaní" => "pakistaníes",
+    "paquistaní" => "paquistaníes",
+    "pirulí" => "pirulíes",
+    "rubí" => "rubíes",
+    "saharauí" => "saharauíes",
+    "saudí" => "saudíes",
+    "sefardí" => "sefardíes",
+    "somalí" => "somalíes",
+    "tabú" => "tabúes",
+    "tisú" => "tisúes",
+    "tití" => "titíes",
+    "tunecí" => "tunecíes",
+    "urubú" => "urubúes",
+    "yemení" => "yemeníes",
+    "zahorí" => "zahoríes",
+    "zulú" => "zulúes",
+    // -y after a vowel -> -yes or -is
+    "ay" => "ayes",
+    "bey" => "beyes",
+    "bocoy" => "bocoyes",
+    "buey" => "bueyes",
+    "carey" => "careyes",
+    "convoy" => "convoyes",
+    "coy" => "coyes",
+    "cuy" => "cuyes",
+    "dey" => "deyes",
+    "espray" => "espráis",
+    "estay" => "estayes",
+    "gay" => "gais",
+    "grey" => "greyes",
+    "guirigay" => "guirigáis",
+    "jagüey" => "jagüeyes",
+    "jersey" => "jerséis",
+    "ley" => "leyes",
+    "maguey" => "magueyes",
+    "mamey" => "mameyes",
+    "noray" => "noráis",
+    "paipay" => "paipáis",
+    "rey" => "reyes",
+    "samuray" => "samuráis",
+    "tepuy" => "tepuyes",
+    "verdegay" => "verdegayes",
+    "virrey" => "virreyes",
+    "yóquey" => "yoqueis",
+    // Stressed monosyllables
+    "no" => "noes",
+    "sí" => "síes",
+    // Loanwords
+    "álbum" => "álbumes",
+    "box" => "boxes",
+    "canon" => "cánones",
+    "club" => "clubes",
+    "fan" => "fans",
+    "fax" => "faxes",
+    "flash" => "flashes",
+    "gin" => "gins",
+    "lord" => "lores",
+    "memorándum" => "memorandos",
+    "récord" => "récords",
+    "referéndum" => "referendos",
+    "sándwich" => "sándwiches",
+    "sketch" => "sketches",
+    // The first element is pluralized
+    "gentilhombre" => "gentileshombres",
+    "hijodalgo" => "hijosdalgo",
+    "ricohombre" => "ricoshombres",
+    // Nouns in a vowel and -ce, whose plural looks like that of a noun in -z
+    "apéndice" => "apéndices",
+    "artífice" => "artífices",
+    "códice" => "códices",
+    "cómplice" => "cómplices",
+    "desenlace" => "desenlaces",
+    "enlace" => "enlaces",
+    "goce" => "goces",
+    "índice" => "índices",
+    "pontífice" => "pontífices",
+    "rebece" => "rebeces",
+    "roce" => "roces",
+    "várice" => "várices",
+    "vértice" => "vértices",
+};
+
+/// Uncountable and invariant Spanish nouns.
+static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "abrelatas", "análisis", "caries", "crisis", "cumpleaños", "dosis", "génesis", "hipótesis",
+        "jueves", "lunes", "martes", "miércoles", "paraguas", "parálisis", "sacacorchos",
+        "síntesis", "tesis", "tórax", "viernes", "virus",
+    },
+    uncountable_suffixes: &[],
+    invariant_suffixes: &[],
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "es",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    irregulars: Some(&IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("es", singularize, pluralize)
 };
 
 /// Converts a plural Spanish noun to its singular form.
 ///
-/// Handles `-es` plurals for words ending in consonants (`d`, `r`, `n`, `l`, `z`, `j`, `s`) and
-/// regular `-s` plurals.
+/// Looks up irregular nouns first (`regímenes` -> `régimen`, `leyes` -> `ley`). Otherwise
+/// reverses `-ces` to `-z` (`luces` -> `luz`), handles `-es` plurals for words ending in
+/// consonants (`d`, `r`, `n`, `l`, `z`, `j`, `s`), restoring the written accent of nouns in `-n`
+/// and `-s` (`canciones` -> `canción`, `exámenes` -> `examen`), and regular `-s` plurals.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(singular) = IRREGULARS.singular(name) {
+        return Cow::Borrowed(singular);
+    }
+    singularize_regular(name)
+}
+
+/// Applies the suffix rules of [`singularize`] without looking up irregular nouns.
+fn singularize_regular(name: &str) -> Cow<'_, str> {
+    if let Some(stem) = name.strip_suffix("ces")
+        && stem.ends_with(is_vowel)
+    {
+        return format!("{stem}z").into();
+    }
     if let Some(stem) = name.strip_suffix("es")
         && (stem.ends_with('d')
             || stem.ends_with('r')
@@ -26,7 +196,7 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
             || stem.ends_with('j')
             || stem.ends_with('s'))
     {
-        return Cow::Borrowed(stem);
+        return singular_stem(stem);
     }
     if let Some(stem) = name.strip_suffix('s')
         && !stem.is_empty()
@@ -36,20 +206,155 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a Spanish noun.
+/// Returns a list of possible plural forms for a Spanish noun, most likely first.
+///
+/// Irregular nouns are looked up first (`joven` -> `jóvenes`). Otherwise `-z` becomes `-ces`
+/// (`lápiz` -> `lápices`), and nouns ending in `-d`, `-r`, `-n`, `-l`, `-j` or `-s` take `-es`,
+/// adjusting the written accent of nouns in `-n` and `-s` (`canción` -> `canciones`, `examen`
+/// -> `exámenes`); all other nouns take `-s`.
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    let mut candidates = vec![format!("{name}s").into()];
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    pluralize_regular(name)
+}
+
+/// Applies the suffix rules of [`pluralize`] without looking up irregular nouns.
+fn pluralize_regular(name: &str) -> Vec<Cow<'_, str>> {
+    if let Some(stem) = name.strip_suffix('z') {
+        return vec![format!("{stem}ces").into()];
+    }
     if name.ends_with('d')
         || name.ends_with('r')
         || name.ends_with('n')
         || name.ends_with('l')
-        || name.ends_with('z')
         || name.ends_with('j')
         || name.ends_with('s')
     {
-        candidates.push(format!("{name}es").into());
+        return vec![format!("{}es", plural_stem(name)).into()];
+    }
+    vec![format!("{name}s").into()]
+}
+
+/// Spanish vowels paired with their forms with an acute accent.
+const ACCENTS: [(char, char); 5] = [('a', 'á'), ('e', 'é'), ('i', 'í'), ('o', 'ó'), ('u', 'ú')];
+
+/// Returns `true` if `ch` is a Spanish vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "aeiouáéíóúü".contains(ch)
+}
+
+/// Returns `true` if `ch` carries a written accent.
+fn is_accented(ch: char) -> bool {
+    "áéíóú".contains(ch)
+}
+
+/// Returns `true` if `ch` is a strong vowel (`a`, `e`, `o`), or a weak vowel whose written
+/// accent keeps it out of a diphthong (`país`).
+fn is_strong(ch: char) -> bool {
+    "aeoáéóíú".contains(ch)
+}
+
+/// Returns the byte index of the vowel that can carry the stress in each syllable of `word`.
+///
+/// A weak vowel forms a diphthong with its neighbour (`can-ción`), two strong vowels belong to
+/// separate syllables (`po-e-ta`), and the `u` of `que`, `qui`, `gue` and `gui` is silent.
+fn syllable_vowels(word: &str) -> Vec<usize> {
+    let chars: Vec<(usize, char)> = word.char_indices().collect();
+    let mut vowels: Vec<usize> = Vec::new();
+    let mut previous: Option<char> = None;
+    for (position, &(index, ch)) in chars.iter().enumerate() {
+        let silent = ch == 'u'
+            && position > 0
+            && matches!(chars[position - 1].1, 'q' | 'g')
+            && chars
+                .get(position + 1)
+                .is_some_and(|&(_, next)| "eiéí".contains(next));
+        if !is_vowel(ch) || silent {
+            previous = None;
+            continue;
+        }
+        match (previous, vowels.last_mut()) {
+            (Some(vowel), Some(last)) if !(is_strong(vowel) && is_strong(ch)) => {
+                // The stress of a diphthong falls on its strong vowel, or on the second of two
+                // weak vowels.
+                if !is_strong(vowel) {
+                    *last = index;
+                }
+            }
+            _ => vowels.push(index),
+        }
+        previous = Some(ch);
+    }
+    vowels
+}
+
+/// Adds or removes the written accent of the vowel at byte `index` of `word`.
+fn set_accent(word: &str, index: usize, accented: bool) -> String {
+    let Some(ch) = word[index..].chars().next() else {
+        return word.into();
+    };
+    let replacement = ACCENTS
+        .iter()
+        .find_map(|&(plain, acute)| {
+            let (from, to) = if accented {
+                (plain, acute)
+            } else {
+                (acute, plain)
+            };
+            (ch == from).then_some(to)
+        })
+        .unwrap_or(ch);
+    format!(
+        "{}{replacement}{}",
+        &word[..index],
+        &word[index + ch.len_utf8()..]
+    )
+}
+
+/// Returns `true` if the vowel at byte `index` of `word` carries a written accent that only
+/// marks a hiatus with a neighbouring vowel (`país`, `baúl`), which the plural keeps.
+fn marks_hiatus(word: &str, index: usize) -> bool {
+    word[index..].starts_with(['í', 'ú'])
+        && (word[..index].ends_with(is_vowel) || word[index..].chars().nth(1).is_some_and(is_vowel))
+}
+
+/// Adjusts the written accent of a singular in `-n` or `-s` before `-es` is added.
+///
+/// A stressed final syllable loses its accent (`canción` -> `canciones`, `inglés` ->
+/// `ingleses`), and a noun in `-en` stressed on the second-to-last syllable gains one, since its
+/// plural is stressed on the third-to-last (`examen` -> `exámenes`).
+fn plural_stem(name: &str) -> Cow<'_, str> {
+    if !name.ends_with(['n', 's']) {
+        return Cow::Borrowed(name);
+    }
+    match *syllable_vowels(name).as_slice() {
+        [.., last] if name[last..].starts_with(is_accented) && !marks_hiatus(name, last) => {
+            set_accent(name, last, false).into()
+        }
+        [.., second_to_last, _] if name.ends_with("en") && !name.contains(is_accented) => {
+            set_accent(name, second_to_last, true).into()
+        }
+        _ => Cow::Borrowed(name),
+    }
+}
+
+/// Restores the written accent of a singular in `-n` or `-s` once `-es` has been removed.
+///
+/// The reverse of [`plural_stem`]: an unaccented plural regains the accent on its final
+/// syllable (`canciones` -> `canción`), and a plural accented on the third-to-last syllable loses
+/// it (`exámenes` -> `examen`).
+fn singular_stem(stem: &str) -> Cow<'_, str> {
+    if !stem.ends_with(['n', 's']) {
+        return Cow::Borrowed(stem);
+    }
+    match *syllable_vowels(stem).as_slice() {
+        [.., second_to_last, _] if stem[second_to_last..].starts_with(is_accented) => {
+            set_accent(stem, second_to_last, false).into()
+        }
+        [_, .., last] if !stem.contains(is_accented) => set_accent(stem, last, true).into(),
+        _ => Cow::Borrowed(stem),
     }
-    candidates
 }
 
 #[cfg(test)]
@@ -82,8 +387,87 @@ mod tests {
         assert!(result.iter().any(|v| v == "animales"));
     }
 
+    #[test]
+    fn test_pluralize_single_form() {
+        assert_eq!(pluralize("usuario"), vec!["usuarios"]);
+        assert_eq!(pluralize("ciudad"), vec!["ciudades"]);
+        assert_eq!(pluralize("canción"), vec!["canciones"]);
+        assert_eq!(pluralize("país"), vec!["países"]);
+        assert_eq!(pluralize("robot"), vec!["robots"]);
+    }
+
+    #[test]
+    fn test_irregulars_not_regular() {
+        let regular = IRREGULARS.regular_entries(singularize_regular, pluralize_regular);
+        assert!(regular.is_empty(), "{regular:?}");
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("regímenes"), "régimen");
+        assert_eq!(singularize("leyes"), "ley");
+        assert_eq!(singularize("rubíes"), "rubí");
+        assert_eq!(singularize("jerséis"), "jersey");
+        assert_eq!(singularize("hijosdalgo"), "hijodalgo");
+        assert_eq!(singularize("joven"), "joven");
+        assert_eq!(pluralize("carácter"), vec!["caracteres"]);
+        assert_eq!(pluralize("club"), vec!["clubes"]);
+        assert_eq!(pluralize("buey"), vec!["bueyes"]);
+        assert_eq!(pluralize("lord"), vec!["lores"]);
+        assert_eq!(singularize("índices"), "índice");
+    }
+
+    #[test]
+    fn test_accents() {
+        assert_eq!(singularize("canciones"), "canción");
+        assert_eq!(singularize("direcciones"), "dirección");
+        assert_eq!(singularize("alemanes"), "alemán");
+        assert_eq!(singularize("intereses"), "interés");
+        assert_eq!(singularize("exámenes"), "examen");
+        assert_eq!(singularize("imágenes"), "imagen");
+        assert_eq!(singularize("países"), "país");
+        assert_eq!(singularize("árboles"), "árbol");
+        assert_eq!(singularize("trenes"), "tren");
+        assert_eq!(singularize("meses"), "mes");
+        assert_eq!(pluralize("dirección")[0], "direcciones");
+        assert_eq!(pluralize("autobús")[0], "autobuses");
+        assert_eq!(pluralize("imagen")[0], "imágenes");
+        assert_eq!(pluralize("origen")[0], "orígenes");
+        assert_eq!(pluralize("país")[0], "países");
+        assert_eq!(pluralize("tren")[0], "trenes");
+        assert_eq!(pluralize("árbol")[0], "árboles");
+        assert_eq!(pluralize("jardín")[0], "jardines");
+        assert_eq!(singularize("jardines"), "jardín");
+    }
+
+    #[test]
+    fn test_z_to_c() {
+        assert_eq!(singularize("luces"), "luz");
+        assert_eq!(singularize("raíces"), "raíz");
+        assert_eq!(singularize("dulces"), "dulce");
+        assert_eq!(pluralize("luz"), vec!["luces"]);
+        assert_eq!(pluralize("actriz"), vec!["actrices"]);
+    }
+
+    #[test]
+    fn test_syllable_vowels() {
+        assert_eq!(syllable_vowels("canción"), vec![1, 5]);
+        assert_eq!(syllable_vowels("poeta"), vec![1, 2, 4]);
+        assert_eq!(syllable_vowels("queso"), vec![2, 4]);
+        assert_eq!(syllable_vowels("país").len(), 2);
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
     }
+
+    #[test]
+    fn test_rules_uncountable() {
+        use crate::language_rules::LanguageRules;
+        assert_eq!(RULES.singularize("crisis"), "crisis");
+        assert_eq!(RULES.singularize("lunes"), "lunes");
+        assert_eq!(RULES.pluralize("virus"), vec!["virus"]);
+        assert_eq!(RULES.pluralize("análisis"), vec!["análisis"]);
+    }
 }
@@ -1,24 +1,167 @@
 //! French (fr) inflection rules.
 //!
-//! Also used for Interlingua (ia), Interlingue (ie), Occitan (oc), Romansh (rm), and
-//! Walloon (wa).
+//! Nouns in `-eau`, `-au` and `-eu` take `-x`, nouns in `-al` take `-aux`, nouns in `-s`, `-x` and
+//! `-z` are invariant and all others take `-s`. The exceptions to each class (`bal` -> `bals`,
+//! `bijou` -> `bijoux`, `travail` -> `travaux`, `pneu` -> `pneus`) are listed as irregulars.
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
 use alloc::borrow::Cow;
 use alloc::format;
 use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
+
+/// Irregular French nouns: irregular plurals, the `-ou` -> `-oux` and `-ail` -> `-aux`
+/// exceptions, the `-al` -> `-als`, `-au` -> `-aus` and `-eu` -> `-eus` exceptions, nouns in `-au`
+/// whose plural would be read as that of a noun in `-al`, English and classical loanwords, and
+/// compounds written as one word with more than their last element pluralized (`bonshommes`).
+/// Nouns whose plural follows from the suffix rules (`cheval`, `bateau`, `jeu`) are not listed,
+/// nor are phrases and hyphenated compounds, whose words are inflected one at a time.
+pub(crate) static IRREGULARS: Irregulars = irregulars! {
+    // Irregular plurals
+    "aïeul" => "aïeux",
+    "bonhomme" => "bonshommes",
+    "ciel" => "cieux",
+    "gentilhomme" => "gentilshommes",
+    "œil" => "yeux",
+    "madame" => "mesdames",
+    "mademoiselle" => "mesdemoiselles",
+    "monseigneur" => "messeigneurs",
+    "monsieur" => "messieurs",
+    // -ou -> -oux
+    "bijou" => "bijoux",
+    "caillou" => "cailloux",
+    "chou" => "choux",
+    "genou" => "genoux",
+    "hibou" => "hiboux",
+    "joujou" => "joujoux",
+    "pou" => "poux",
+    // -ail -> -aux
+    "bail" => "baux",
+    "corail" => "coraux",
+    "émail" => "émaux",
+    "soupirail" => "soupiraux",
+    "travail" => "travaux",
+    "vantail" => "vantaux",
+    "vitrail" => "vitraux",
+    // -al -> -als
+    "aval" => "avals",
+    "bal" => "bals",
+    "bancal" => "bancals",
+    "cal" => "cals",
+    "caracal" => "caracals",
+    "carnaval" => "carnavals",
+    "cérémonial" => "cérémonials",
+    "chacal" => "chacals",
+    "choral" => "chorals",
+    "copal" => "copals",
+    "corral" => "corrals",
+    "étal" => "étals",
+    "festival" => "festivals",
+    "gavial" => "gavials",
+    "mistral" => "mistrals",
+    "narval" => "narvals",
+    "nopal" => "nopals",
+    "pal" => "pals",
+    "récital" => "récitals",
+    "régal" => "régals",
+    "rorqual" => "rorquals",
+    "santal" => "santals",
+    "serval" => "servals",
+    "val" => "vals",
+    // -au -> -aux and -aus
+    "aloyau" => "aloyaux",
+    "boyau" => "boyaux",
+    "esquimau" => "esquimaux",
+    "étau" => "étaux",
+    "fléau" => "fléaux",
+    "gruau" => "gruaux",
+    "hoyau" => "hoyaux",
+    "joyau" => "joyaux",
+    "landau" => "landaus",
+    "matériau" => "matériaux",
+    "noyau" => "noyaux",
+    "préau" => "préaux",
+    "sarrau" => "sarraus",
+    "tuyau" => "tuyaux",
+    "unau" => "unaus",
+    // -eu -> -eux and -eus
+    "bleu" => "bleus",
+    "émeu" => "émeus",
+    "pneu" => "pneus",
+    "vœu" => "vœux",
+    // English nouns in -man and -y
+    "baby" => "babies",
+    "barman" => "barmen",
+    "brandy" => "brandies",
+    "businessman" => "businessmen",
+    "cameraman" => "cameramen",
+    "clergyman" => "clergymen",
+    "dandy" => "dandies",
+    "derby" => "derbies",
+    "garden-party" => "garden-parties",
+    "gentleman" => "gentlemen",
+    "hobby" => "hobbies",
+    "jazzman" => "jazzmen",
+    "lady" => "ladies",
+    "lobby" => "lobbies",
+    "penalty" => "penalties",
+    "perchman" => "perchmen",
+    "policeman" => "policemen",
+    "recordman" => "recordmen",
+    "rugbyman" => "rugbymen",
+    "sportsman" => "sportsmen",
+    "tennisman" => "tennismen",
+    "whisky" => "whiskies",
+    "yachtman" => "yachtmen",
+    // Latin, German and Italian plurals
+    "carbonaro" => "carbonari",
+    "condottiere" => "condottieri",
+    "desideratum" => "desiderata",
+    "erratum" => "errata",
+    "graffito" => "graffiti",
+    "land" => "länder",
+    "leitmotiv" => "leitmotive",
+    "lied" => "lieder",
+    "maximum" => "maxima",
+    "minimum" => "minima",
+    "paparazzo" => "paparazzi",
+};
+
+/// Uncountable and invariant French nouns.
+static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {},
+    uncountable_suffixes: &[],
+    invariant_suffixes: &["s", "x", "z"],
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "fr",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    irregulars: Some(&IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("fr", singularize, pluralize)
 };
 
 /// Converts a plural French noun to its singular form.
 ///
-/// Handles `-aux` -> `-al` transformation and regular `-s` plurals.
+/// Handles irregular nouns (`yeux` -> `œil`, `travaux` -> `travail`, `bijoux` -> `bijou`), the
+/// `-eaux` and `-eux` plurals (`bateaux` -> `bateau`, `jeux` -> `jeu`), the `-aux` -> `-al`
+/// transformation and regular `-s` plurals. Invariant nouns in `-x` and `-z` are returned
+/// unchanged.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(singular) = IRREGULARS.singular(name) {
+        return Cow::Borrowed(singular);
+    }
+    singularize_regular(name)
+}
+
+/// Applies the suffix rules of [`singularize`] without looking up irregular nouns.
+fn singularize_regular(name: &str) -> Cow<'_, str> {
+    if (name.ends_with("eaux") || name.ends_with("eux"))
+        && let Some(stem) = name.strip_suffix('x')
+    {
+        return Cow::Borrowed(stem);
+    }
     if let Some(stem) = name.strip_suffix("aux")
         && !stem.is_empty()
     {
@@ -32,13 +175,30 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a French noun.
+/// Returns a list of possible plural forms for a French noun, most likely first.
+///
+/// Irregular nouns are looked up first (`œil` -> `yeux`, `bal` -> `bals`). Otherwise nouns in
+/// `-s`, `-x` and `-z` are invariant (`prix`), nouns in `-au` and `-eu` take `-x` (`bateau` ->
+/// `bateaux`), nouns in `-al` prefer `-aux` over `-als`, and all other nouns take `-s`.
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    let mut candidates = vec![format!("{name}s").into()];
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    pluralize_regular(name)
+}
+
+/// Applies the suffix rules of [`pluralize`] without looking up irregular nouns.
+fn pluralize_regular(name: &str) -> Vec<Cow<'_, str>> {
+    if name.ends_with(['s', 'x', 'z']) {
+        return vec![Cow::Borrowed(name)];
+    }
+    if name.ends_with("au") || name.ends_with("eu") {
+        return vec![format!("{name}x").into()];
+    }
     if let Some(stem) = name.strip_suffix("al") {
-        candidates.push(format!("{stem}aux").into());
+        return vec![format!("{stem}aux").into(), format!("{name}s").into()];
     }
-    candidates
+    vec![format!("{name}s").into()]
 }
 
 #[cfg(test)]
@@ -67,8 +227,88 @@ mod tests {
         assert!(result.iter().any(|v| v == "journaux"));
     }
 
+    #[test]
+    fn test_irregulars_not_regular() {
+        let regular = IRREGULARS.regular_entries(singularize_regular, pluralize_regular);
+        assert!(regular.is_empty(), "{regular:?}");
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("yeux"), "œil");
+        assert_eq!(singularize("cieux"), "ciel");
+        assert_eq!(singularize("bijoux"), "bijou");
+        assert_eq!(singularize("travaux"), "travail");
+        assert_eq!(singularize("bateaux"), "bateau");
+        assert_eq!(singularize("jeux"), "jeu");
+        assert_eq!(pluralize("œil"), vec!["yeux"]);
+        assert_eq!(pluralize("bal"), vec!["bals"]);
+        assert_eq!(pluralize("monsieur"), vec!["messieurs"]);
+    }
+
+    #[test]
+    fn test_x_plurals() {
+        assert_eq!(singularize("rouleaux"), "rouleau");
+        assert_eq!(singularize("pinceaux"), "pinceau");
+        assert_eq!(singularize("cheveux"), "cheveu");
+        assert_eq!(singularize("hiboux"), "hibou");
+        assert_eq!(singularize("clous"), "clou");
+        assert_eq!(singularize("vitraux"), "vitrail");
+        assert_eq!(singularize("détails"), "détail");
+        assert_eq!(pluralize("tableau"), vec!["tableaux"]);
+        assert_eq!(pluralize("tuyau"), vec!["tuyaux"]);
+        assert_eq!(pluralize("jeu"), vec!["jeux"]);
+        assert_eq!(pluralize("pneu"), vec!["pneus"]);
+        assert_eq!(pluralize("genou"), vec!["genoux"]);
+        assert_eq!(pluralize("trou"), vec!["trous"]);
+        assert_eq!(pluralize("travail"), vec!["travaux"]);
+        assert_eq!(pluralize("détail"), vec!["détails"]);
+    }
+
+    #[test]
+    fn test_al_plurals() {
+        assert_eq!(pluralize("cheval")[0], "chevaux");
+        assert_eq!(singularize("chevaux"), "cheval");
+        assert_eq!(pluralize("bal"), vec!["bals"]);
+        assert_eq!(pluralize("festival"), vec!["festivals"]);
+        assert_eq!(pluralize("portail")[0], "portails");
+        assert_eq!(pluralize("tribunal")[0], "tribunaux");
+        assert_eq!(singularize("festivals"), "festival");
+    }
+
+    #[test]
+    fn test_compounds() {
+        assert_eq!(pluralize("bonhomme"), vec!["bonshommes"]);
+        assert_eq!(singularize("gentilshommes"), "gentilhomme");
+        assert_eq!(pluralize("garden-party"), vec!["garden-parties"]);
+    }
+
+    #[test]
+    fn test_invariant() {
+        assert_eq!(pluralize("prix"), vec!["prix"]);
+        assert_eq!(pluralize("nez"), vec!["nez"]);
+        assert_eq!(pluralize("bras"), vec!["bras"]);
+        assert_eq!(singularize("prix"), "prix");
+        assert_eq!(singularize("nez"), "nez");
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
     }
+
+    #[test]
+    fn test_rules_invariant() {
+        use crate::language_rules::LanguageRules;
+        assert_eq!(RULES.pluralize("prix"), vec!["prix"]);
+        assert_eq!(RULES.pluralize("nez"), vec!["nez"]);
+        assert_eq!(RULES.pluralize("bras"), vec!["bras"]);
+        assert!(
+            RULES
+                .pluralize("utilisateur")
+                .iter()
+                .any(|v| v == "utilisateurs")
+        );
+        assert_eq!(RULES.singularize("utilisateurs"), "utilisateur");
+    }
 }
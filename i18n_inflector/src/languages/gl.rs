@@ -5,7 +5,6 @@ use crate::language_rules::LanguageRuleSet;
 pub(crate) use super::pt::{pluralize, singularize};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "gl",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    irregulars: Some(&super::pt::IRREGULARS),
+    ..LanguageRuleSet::new("gl", singularize, pluralize)
 };
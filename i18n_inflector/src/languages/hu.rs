@@ -6,11 +6,7 @@ use alloc::format;
 use alloc::vec;
 use alloc::vec::Vec;
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "hu",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("hu", singularize, pluralize);
 
 /// Converts a plural Hungarian noun to its singular form.
 ///
@@ -25,13 +21,32 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a Hungarian noun.
+/// Back vowels in Hungarian vowel harmony.
+const BACK_VOWELS: &[char] = &['a', 'á', 'o', 'ó', 'u', 'ú'];
+/// Front vowels in Hungarian vowel harmony.
+const FRONT_VOWELS: &[char] = &['e', 'é', 'i', 'í', 'ö', 'ő', 'ü', 'ű'];
+
+/// Returns a list of possible plural forms for a Hungarian noun, most likely first.
+///
+/// Nouns ending in a vowel prefer `-k`; otherwise the last vowel selects `-ok` (back) or `-ek`
+/// (front).
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    vec![
-        format!("{name}k").into(),
-        format!("{name}ok").into(),
-        format!("{name}ek").into(),
-    ]
+    let k = format!("{name}k").into();
+    let ok = format!("{name}ok").into();
+    let ek = format!("{name}ek").into();
+    if name.ends_with(BACK_VOWELS) || name.ends_with(FRONT_VOWELS) {
+        return vec![k, ok, ek];
+    }
+    let back = name
+        .chars()
+        .rev()
+        .find(|ch| BACK_VOWELS.contains(ch) || FRONT_VOWELS.contains(ch))
+        .is_none_or(|ch| BACK_VOWELS.contains(&ch));
+    if back {
+        vec![ok, ek, k]
+    } else {
+        vec![ek, ok, k]
+    }
 }
 
 #[cfg(test)]
@@ -62,4 +77,12 @@ mod tests {
     fn test_empty() {
         assert_eq!(singularize(""), "");
     }
+
+    #[test]
+    fn test_pluralize_ranking() {
+        assert_eq!(pluralize("felhasznalo")[0], "felhasznalok");
+        assert_eq!(pluralize("asztal")[0], "asztalok");
+        assert_eq!(pluralize("rendeles")[0], "rendelesek");
+        assert_eq!(pluralize("könyv")[0], "könyvek");
+    }
 }
@@ -1,11 +1,78 @@
 //! Interlingua (ia) inflection rules.
+//!
+//! Also used for Interlingue (ie), which forms its plurals the same way.
 
 use crate::language_rules::LanguageRuleSet;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 
-pub(crate) use super::fr::{pluralize, singularize};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("ia", singularize, pluralize);
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "ia",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+/// Returns `true` if `ch` is a vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "aeiouy".contains(ch)
+}
+
+/// Converts a plural Interlingua noun to its singular form.
+///
+/// Handles `-es` after `-c`, `-l`, `-n`, `-r`, `-s`, `-x` and `-z` (`nationes` -> `nation`,
+/// `colores` -> `color`) and `-s` after vowels (`libros` -> `libro`).
+pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(stem) = name.strip_suffix("es")
+        && stem.ends_with(['c', 'l', 'n', 'r', 's', 'x', 'z'])
+    {
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = name.strip_suffix('s')
+        && stem.ends_with(is_vowel)
+    {
+        return Cow::Borrowed(stem);
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns the plural of an Interlingua noun: `-s` after a vowel and `-es` after a consonant.
+pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if name.is_empty() || name.ends_with(is_vowel) {
+        vec![format!("{name}s").into()]
+    } else {
+        vec![format!("{name}es").into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("libros"), "libro");
+        assert_eq!(singularize("linguas"), "lingua");
+        assert_eq!(singularize("nationes"), "nation");
+        assert_eq!(singularize("colores"), "color");
+        assert_eq!(singularize("animales"), "animal");
+        assert_eq!(singularize("citates"), "citate");
+    }
+
+    #[test]
+    fn test_singularize_already_singular() {
+        assert_eq!(singularize("libro"), "libro");
+        assert_eq!(singularize("nation"), "nation");
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("libro"), vec!["libros"]);
+        assert_eq!(pluralize("citate"), vec!["citates"]);
+        assert_eq!(pluralize("nation"), vec!["nationes"]);
+        assert_eq!(pluralize("animal"), vec!["animales"]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(singularize(""), "");
+        assert_eq!(pluralize(""), vec!["s"]);
+    }
+}
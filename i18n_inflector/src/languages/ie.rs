@@ -2,10 +2,6 @@
 
 use crate::language_rules::LanguageRuleSet;
 
-pub(crate) use super::fr::{pluralize, singularize};
+pub(crate) use super::ia::{pluralize, singularize};
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "ie",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("ie", singularize, pluralize);
@@ -1,50 +1,447 @@
 //! Italian (it) inflection rules.
+//!
+//! Masculine nouns in `-o` and nouns of either gender in `-e` take `-i`, and feminine nouns in
+//! `-a` take `-e`. The velar stems `-co`, `-go`, `-ca` and `-ga` insert an `h` to keep their
+//! sound (`lago` -> `laghi`, `amica` -> `amiche`), and stressed final vowels, monosyllables and
+//! loanwords ending in a consonant are invariant (`città`, `re`, `sport`).
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
+
+use super::{strip, vowel_groups};
+
+/// Irregular Italian nouns: irregular plurals, neuter plurals in `-a`, `-co` and `-go` nouns
+/// whose plural goes against the `-ico` rule, masculines in `-a`, and compounds with more than
+/// their last element pluralized (`capistazione`, `casseforti`). Nouns whose plural follows from
+/// the suffix rules (`parco`, `amica`, `valigia`) are not listed, nor are phrases, whose words
+/// are inflected one at a time.
+pub(crate) static IRREGULARS: Irregulars = irregulars! {
+    // Irregular plurals
+    "ala" => "ali",
+    "arma" => "armi",
+    "belga" => "belgi",
+    "bue" => "buoi",
+    "dio" => "dei",
+    "galantuomo" => "galantuomini",
+    "gentiluomo" => "gentiluomini",
+    "moglie" => "mogli",
+    "orecchio" => "orecchie",
+    "semidio" => "semidei",
+    "superficie" => "superfici",
+    "superuomo" => "superuomini",
+    "tempio" => "templi",
+    "uomo" => "uomini",
+    // Neuter plurals in -a
+    "braccio" => "braccia",
+    "budello" => "budella",
+    "calcagno" => "calcagna",
+    "centinaio" => "centinaia",
+    "ciglio" => "ciglia",
+    "corno" => "corna",
+    "cuoio" => "cuoia",
+    "dito" => "dita",
+    "fondamento" => "fondamenta",
+    "ginocchio" => "ginocchia",
+    "grido" => "grida",
+    "labbro" => "labbra",
+    "lenzuolo" => "lenzuola",
+    "migliaio" => "migliaia",
+    "miglio" => "miglia",
+    "moggio" => "moggia",
+    "osso" => "ossa",
+    "paio" => "paia",
+    "sopracciglio" => "sopracciglia",
+    "staio" => "staia",
+    "uovo" => "uova",
+    "urlo" => "urla",
+    "vestigio" => "vestigia",
+    // -co -> -ci
+    "austriaco" => "austriaci",
+    "cardiaco" => "cardiaci",
+    "demoniaco" => "demoniaci",
+    "equivoco" => "equivoci",
+    "farmaco" => "farmaci",
+    "greco" => "greci",
+    "maniaco" => "maniaci",
+    "monaco" => "monaci",
+    "parroco" => "parroci",
+    "porco" => "porci",
+    "sindaco" => "sindaci",
+    "stomaco" => "stomaci",
+    // -co -> -chi
+    "carico" => "carichi",
+    "dimentico" => "dimentichi",
+    "fico" => "fichi",
+    "incarico" => "incarichi",
+    "pizzico" => "pizzichi",
+    "strascico" => "strascichi",
+    "valico" => "valichi",
+    // -go -> -ghi and -gi
+    "antropofago" => "antropofagi",
+    "antropologo" => "antropologi",
+    "archeologo" => "archeologi",
+    "asparago" => "asparagi",
+    "astrologo" => "astrologi",
+    "biologo" => "biologi",
+    "cardiologo" => "cardiologi",
+    "dermatologo" => "dermatologi",
+    "ecologo" => "ecologi",
+    "egittologo" => "egittologi",
+    "esofago" => "esofagi",
+    "filologo" => "filologi",
+    "geologo" => "geologi",
+    "ginecologo" => "ginecologi",
+    "mago" => "magi",
+    "musicologo" => "musicologi",
+    "neurologo" => "neurologi",
+    "oncologo" => "oncologi",
+    "psicologo" => "psicologi",
+    "radiologo" => "radiologi",
+    "sarcofago" => "sarcofagi",
+    "sociologo" => "sociologi",
+    "teologo" => "teologi",
+    "zoologo" => "zoologi",
+    // Stressed -io -> -ii
+    "addio" => "addii",
+    "pendio" => "pendii",
+    "zio" => "zii",
+    // Masculines in -a
+    "acrobata" => "acrobati",
+    "anagramma" => "anagrammi",
+    "aroma" => "aromi",
+    "artista" => "artisti",
+    "assioma" => "assiomi",
+    "astronauta" => "astronauti",
+    "atleta" => "atleti",
+    "carcinoma" => "carcinomi",
+    "carisma" => "carismi",
+    "clima" => "climi",
+    "collega" => "colleghi",
+    "cosmonauta" => "cosmonauti",
+    "cromosoma" => "cromosomi",
+    "despota" => "despoti",
+    "diadema" => "diademi",
+    "diagramma" => "diagrammi",
+    "dilemma" => "dilemmi",
+    "diploma" => "diplomi",
+    "dogma" => "dogmi",
+    "dramma" => "drammi",
+    "duca" => "duchi",
+    "emblema" => "emblemi",
+    "enigma" => "enigmi",
+    "epigramma" => "epigrammi",
+    "eremita" => "eremiti",
+    "fantasma" => "fantasmi",
+    "geometra" => "geometri",
+    "glaucoma" => "glaucomi",
+    "idioma" => "idiomi",
+    "idiota" => "idioti",
+    "monarca" => "monarchi",
+    "omicida" => "omicidi",
+    "panorama" => "panorami",
+    "papa" => "papi",
+    "patriarca" => "patriarchi",
+    "patriota" => "patrioti",
+    "pediatra" => "pediatri",
+    "pianeta" => "pianeti",
+    "pigiama" => "pigiami",
+    "pilota" => "piloti",
+    "pirata" => "pirati",
+    "poema" => "poemi",
+    "poeta" => "poeti",
+    "prisma" => "prismi",
+    "problema" => "problemi",
+    "profeta" => "profeti",
+    "programma" => "programmi",
+    "psichiatra" => "psichiatri",
+    "schema" => "schemi",
+    "sintoma" => "sintomi",
+    "sisma" => "sismi",
+    "sistema" => "sistemi",
+    "stemma" => "stemmi",
+    "stigma" => "stigmi",
+    "stratega" => "strateghi",
+    "suicida" => "suicidi",
+    "telegramma" => "telegrammi",
+    "tema" => "temi",
+    "teorema" => "teoremi",
+    "trauma" => "traumi",
+    // -ie -> -i
+    "effigie" => "effigi",
+    // Compounds in capo-, whose first element is pluralized
+    "capostazione" => "capistazione",
+    "capofamiglia" => "capifamiglia",
+    "caposquadra" => "capisquadra",
+    "capoufficio" => "capiufficio",
+    "capogruppo" => "capigruppo",
+    "capotreno" => "capitreno",
+    "capoclasse" => "capiclasse",
+    "capobanda" => "capibanda",
+    "caporeparto" => "capireparto",
+    "capocantiere" => "capicantiere",
+    "capobranco" => "capibranco",
+    "capocordata" => "capicordata",
+    "capoturno" => "capiturno",
+    "capolista" => "capilista",
+    "caposervizio" => "capiservizio",
+    "capotavola" => "capitavola",
+    "caposcuola" => "capiscuola",
+    // Compounds whose elements are both pluralized
+    "cassaforte" => "casseforti",
+    "pellerossa" => "pellirosse",
+    "terracotta" => "terrecotte",
+    "mezzaluna" => "mezzelune",
+    "roccaforte" => "roccheforti",
+    "mezzanotte" => "mezzenotti",
+    "altoforno" => "altiforni",
+    "acquaforte" => "acqueforti",
+    "acquavite" => "acqueviti",
+    "pescespada" => "pescispada",
+    "pescecane" => "pescicani",
+    "camposanto" => "campisanti",
+};
+
+/// Uncountable and invariant Italian nouns.
+static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "analisi", "auto", "bar", "bici", "cinema", "computer", "crisi", "film", "foto", "gru",
+        "ipotesi", "moto", "radio", "re", "serie", "sintesi", "specie", "sport", "tesi",
+    },
+    uncountable_suffixes: &["à", "è", "é", "ì", "ò", "ù"],
+    invariant_suffixes: &[],
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "it",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    irregulars: Some(&IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("it", singularize, pluralize)
 };
 
+/// Stems of `-i` plurals whose singular ends in `-e` rather than the masculine `-o`: feminines in
+/// `-ione` and `-trice`, masculines in `-tore`, `-sore` and `-ale`, and nouns in `-ente` and
+/// `-ante` of either gender.
+const E_STEM_ENDINGS: &[&str] = &["ion", "tric", "tor", "sor", "al", "ent", "ant"];
+
+/// Nouns in `-e` outside the classes of [`E_STEM_ENDINGS`], whose `-i` plural would otherwise be
+/// read as that of a masculine in `-o` (`cani` -> `cane`, `fiori` -> `fiore`).
+static E_NOUNS: phf::Set<&'static str> = phf_set! {
+    "abete", "amore", "ape", "arte", "base", "bicchiere", "bottone", "calce", "cameriere", "cane",
+    "cannone", "carattere", "carcere", "carne", "cartone", "cavaliere", "cenere", "chiave",
+    "classe", "codice", "colle", "colore", "confine", "consigliere", "corte", "croce", "cuore",
+    "dolore", "dote", "errore", "esame", "fase", "febbre", "fine", "fiore", "fiume", "fonte",
+    "frase", "fronte", "fune", "furgone", "genere", "gente", "giardiniere", "giovane", "giudice",
+    "immagine", "indice", "infermiere", "ingegnere", "latte", "legge", "leone", "limite", "limone",
+    "luce", "madre", "mare", "margine", "mattone", "melone", "mente", "mese", "mestiere", "monte",
+    "morte", "nave", "neve", "nipote", "noce", "nome", "notte", "odore", "onore", "ordine",
+    "origine", "ospite", "pace", "padre", "padrone", "paese", "pallone", "parete", "parte",
+    "peperone", "pesce", "piacere", "piede", "pollice", "ponte", "portone", "prete", "radice",
+    "rete", "rumore", "sale", "sapone", "sapore", "seme", "signore", "sole", "sorte", "termine",
+    "tigre", "torre", "vagone", "valore", "verme", "vertice", "voce", "volpe", "volume",
+};
+
+/// Stems in `-ent` of masculine nouns in `-o` (`momenti` -> `momento`, `eventi` -> `evento`).
+const O_STEM_ENDINGS: &[&str] = &["ment", "vent", "lent", "ccent"];
+
+/// Stems of `-i` plurals whose singular ends in an unstressed `-io` (`figli` -> `figlio`,
+/// `viaggi` -> `viaggio`).
+const IO_STEM_ENDINGS: &[&str] = &["gl", "gg", "cc", "fic"];
+
+/// Endings of masculine nouns in `-a`, which take `-i` (`problema` -> `problemi`, `programma`
+/// -> `programmi`). Nouns in `-ista` are of either gender and prefer `-i`.
+const MASCULINE_A_ENDINGS: &[&str] = &["ema", "gramma", "ista"];
+
+/// Returns `true` if `ch` is an Italian vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "aeiouàèéìíòóù".contains(ch)
+}
+
+/// Returns `true` if a singular Italian noun is invariant whichever ending it has: stressed final
+/// vowels (`città`, `virtù`), monosyllables (`re`, `gru`) and loanwords ending in a consonant
+/// (`sport`, `computer`).
+fn is_invariant(name: &str) -> bool {
+    name.ends_with(['à', 'è', 'é', 'ì', 'ò', 'ù'])
+        || !name.ends_with(is_vowel)
+        || vowel_groups(name, is_vowel) <= 1
+}
+
+/// Returns `true` if an `-i` plural with `stem` comes from a singular in `-io`: stems with one of
+/// the [`IO_STEM_ENDINGS`] or a `z` after a vowel (`servizi` -> `servizio`).
+fn is_io_stem(stem: &str) -> bool {
+    let vowel_z = stem
+        .strip_suffix('z')
+        .is_some_and(|rest| rest.ends_with(is_vowel));
+    vowel_z || IO_STEM_ENDINGS.iter().any(|ending| stem.ends_with(ending))
+}
+
+/// Returns `true` if an `-i` plural with `stem` may come from a singular in `-io`: the
+/// [`is_io_stem`] stems and stems in a single consonant after a vowel (`studi` -> `studio`), but
+/// not stems in other consonant clusters (`gatti`, `libri`).
+fn may_be_io_stem(stem: &str) -> bool {
+    let mut chars = stem.chars().rev();
+    let single_consonant = matches!(
+        (chars.next(), chars.next()),
+        (Some(last), Some(previous)) if !is_vowel(last) && is_vowel(previous)
+    );
+    single_consonant || stem.ends_with("mp") || is_io_stem(stem)
+}
+
+/// Returns `true` if `stem` followed by `-e` is one of the [`E_NOUNS`].
+fn is_e_noun(stem: &str) -> bool {
+    E_NOUNS.contains(format!("{stem}e").as_str())
+}
+
 /// Converts a plural Italian noun to its singular form.
 ///
-/// Handles masculine `-i` -> `-o` and feminine `-e` -> `-a` transformations.
+/// Looks up irregular nouns first (`uomini` -> `uomo`, `uova` -> `uovo`) and leaves invariant
+/// nouns unchanged. Otherwise restores the velar stems (`laghi` -> `lago`, `amiche` -> `amica`)
+/// and `-io` (`zii` -> `zio`, `figli` -> `figlio`), maps `-i` back to `-e` for the noun classes
+/// that end in it and the known nouns in `-e` (`nazioni` -> `nazione`, `cani` -> `cane`) and to
+/// the masculine `-o` otherwise, and maps the feminine `-e` back to `-a` (`aziende` -> `azienda`, `arance` ->
+/// `arancia`).
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
-    if let Some(stem) = name.strip_suffix('i')
-        && !stem.is_empty()
-    {
+    if let Some(singular) = IRREGULARS.singular(name) {
+        return Cow::Borrowed(singular);
+    }
+    singularize_regular(name)
+}
+
+/// Applies the suffix rules of [`singularize`] without looking up irregular nouns.
+fn singularize_regular(name: &str) -> Cow<'_, str> {
+    if is_invariant(name) {
+        return Cow::Borrowed(name);
+    }
+    for (plural, singular) in [
+        ("chi", "co"),
+        ("ghi", "go"),
+        ("che", "ca"),
+        ("ghe", "ga"),
+        ("ii", "io"),
+    ] {
+        if let Some(stem) = strip(name, plural) {
+            return format!("{stem}{singular}").into();
+        }
+    }
+    if let Some(stem) = strip(name, "i") {
+        if is_e_noun(stem)
+            || E_STEM_ENDINGS.iter().any(|ending| stem.ends_with(ending))
+                && !O_STEM_ENDINGS.iter().any(|ending| stem.ends_with(ending))
+        {
+            return format!("{stem}e").into();
+        }
+        if is_io_stem(stem) {
+            return format!("{stem}io").into();
+        }
         return format!("{stem}o").into();
     }
-    if let Some(stem) = name.strip_suffix('e')
-        && !stem.is_empty()
-    {
-        return format!("{stem}a").into();
+    if let Some(stem) = strip(name, "e") {
+        // `-cia` and `-gia` drop their `i` after a consonant (`arancia` -> `arance`).
+        let velar_after_consonant = stem
+            .strip_suffix(['c', 'g'])
+            .is_some_and(|rest| !rest.is_empty() && !rest.ends_with(is_vowel));
+        let ending = if velar_after_consonant { "ia" } else { "a" };
+        return format!("{stem}{ending}").into();
     }
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for an Italian noun.
+/// Returns the possible singular forms of an Italian noun, most likely first.
+///
+/// Starts with the result of [`singularize`], followed by the other genders and classes that
+/// share the plural ending: `-i` may come from `-o`, `-e` or masculine `-a` (`cani` -> `cane`,
+/// `problemi` -> `problema`) and from `-io` where the stem allows it (`studi` -> `studio`), `-chi`
+/// and `-ghi` from `-co`, `-go`, `-ca` and `-ga`, and `-e` from `-a` or an invariant noun in `-e`.
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    let mut candidates = vec![singularize(name)];
+    if IRREGULARS.singular(name).is_some()
+        || is_invariant(name)
+        || strip(name, "i").is_some_and(is_e_noun)
+    {
+        return candidates;
+    }
+    let endings: &[(&str, &[&str])] = &[
+        ("chi", &["co", "ca"]),
+        ("ghi", &["go", "ga"]),
+        ("i", &["o", "e", "a", "io"]),
+        ("e", &["a", "e"]),
+    ];
+    if let Some((stem, singulars)) = endings
+        .iter()
+        .find_map(|&(plural, singulars)| Some((strip(name, plural)?, singulars)))
+    {
+        candidates.extend(
+            singulars
+                .iter()
+                .filter(|&&singular| singular != "io" || may_be_io_stem(stem))
+                .map(|singular| Cow::Owned(format!("{stem}{singular}"))),
+        );
+    }
+    candidates
+}
+
+/// Returns a list of possible plural forms for an Italian noun, most likely first.
+///
+/// Irregular nouns are looked up first (`uomo` -> `uomini`, `psicologo` -> `psicologi`), and
+/// invariant nouns are returned unchanged. Otherwise `-co` and `-go` prefer `-chi` and `-ghi`
+/// (`parco` -> `parchi`, `lago` -> `laghi`) except `-ico`, which prefers `-ici` (`medico` ->
+/// `medici`); `-ca` and `-ga` take `-che` and `-ghe`; `-cia` and `-gia` keep their `i` only after
+/// a vowel (`camicia` -> `camicie`, `arancia` -> `arance`); `-o` and `-e` take `-i`; and `-a`
+/// takes the feminine `-e`, or `-i` for masculine endings (`sistema` -> `sistemi`).
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    let mut candidates = Vec::new();
-    if let Some(stem) = name.strip_suffix('o') {
-        candidates.push(format!("{stem}i").into());
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
     }
-    if let Some(stem) = name.strip_suffix('a') {
-        candidates.push(format!("{stem}e").into());
-        candidates.push(format!("{stem}i").into());
+    pluralize_regular(name)
+}
+
+/// Applies the suffix rules of [`pluralize`] without looking up irregular nouns.
+fn pluralize_regular(name: &str) -> Vec<Cow<'_, str>> {
+    if is_invariant(name) {
+        return vec![Cow::Borrowed(name)];
     }
-    if let Some(stem) = name.strip_suffix('e') {
-        candidates.push(format!("{stem}i").into());
+    if let Some(stem) = strip(name, "ico") {
+        return vec![format!("{stem}ici").into(), format!("{stem}ichi").into()];
     }
-    if candidates.is_empty() {
-        candidates.push(format!("{name}i").into());
+    if let Some(stem) = strip(name, "co") {
+        return vec![format!("{stem}chi").into(), format!("{stem}ci").into()];
     }
-    candidates
+    if let Some(stem) = strip(name, "go") {
+        return vec![format!("{stem}ghi").into(), format!("{stem}gi").into()];
+    }
+    if let Some(stem) = strip(name, "io") {
+        return vec![format!("{stem}i").into(), format!("{stem}ii").into()];
+    }
+    if let Some(stem) = strip(name, "o").or_else(|| strip(name, "e")) {
+        return vec![format!("{stem}i").into()];
+    }
+    if let Some(stem) = strip(name, "ca") {
+        return vec![format!("{stem}che").into()];
+    }
+    if let Some(stem) = strip(name, "ga") {
+        return vec![format!("{stem}ghe").into()];
+    }
+    for (suffix, velar) in [("cia", "c"), ("gia", "g")] {
+        if let Some(stem) = strip(name, suffix) {
+            let ending = if stem.ends_with(is_vowel) { "ie" } else { "e" };
+            return vec![format!("{stem}{velar}{ending}").into()];
+        }
+    }
+    if let Some(stem) = strip(name, "a") {
+        let (feminine, masculine) = (format!("{stem}e").into(), format!("{stem}i").into());
+        if MASCULINE_A_ENDINGS
+            .iter()
+            .any(|ending| name.ends_with(ending))
+        {
+            return vec![masculine, feminine];
+        }
+        return vec![feminine, masculine];
+    }
+    vec![Cow::Borrowed(name)]
 }
 
 #[cfg(test)]
@@ -55,7 +452,7 @@ mod tests {
     fn test_singularize() {
         assert_eq!(singularize("prodotti"), "prodotto");
         assert_eq!(singularize("aziende"), "azienda");
-        assert_eq!(singularize("clienti"), "cliento");
+        assert_eq!(singularize("clienti"), "cliente");
         assert_eq!(singularize("libri"), "libro");
     }
 
@@ -78,13 +475,141 @@ mod tests {
     }
 
     #[test]
-    fn test_pluralize_no_known_suffix() {
-        let result = pluralize("bar");
-        assert!(result.iter().any(|v| v == "bari"));
+    fn test_pluralize_velar() {
+        assert_eq!(pluralize("parco")[0], "parchi");
+        assert_eq!(pluralize("medico")[0], "medici");
+        assert_eq!(pluralize("dialogo")[0], "dialoghi");
+        assert_eq!(pluralize("banca"), vec!["banche"]);
+        assert_eq!(pluralize("riga"), vec!["righe"]);
+        assert_eq!(pluralize("camicia"), vec!["camicie"]);
+        assert_eq!(pluralize("arancia"), vec!["arance"]);
+        assert_eq!(pluralize("spiaggia"), vec!["spiagge"]);
+    }
+
+    #[test]
+    fn test_singularize_candidates() {
+        let candidates = singularize_candidates("pani");
+        assert_eq!(candidates[0], "pano");
+        assert!(candidates.iter().any(|v| v == "pane"));
+        assert!(candidates.iter().any(|v| v == "pana"));
+        assert_eq!(singularize_candidates("cani"), vec!["cane"]);
+        let candidates = singularize_candidates("cuochi");
+        assert_eq!(candidates[0], "cuoco");
+        assert!(candidates.iter().any(|v| v == "cuoca"));
+        assert_eq!(singularize_candidates("duchi"), vec!["duca"]);
+        assert_eq!(singularize_candidates("città"), vec!["città"]);
+        assert_eq!(singularize_candidates("uomini"), vec!["uomo"]);
+        let candidates = singularize_candidates("gatti");
+        assert_eq!(candidates[0], "gatto");
+        assert!(!candidates.iter().any(|v| v == "gattio"));
+        assert!(
+            !singularize_candidates("libri")
+                .iter()
+                .any(|v| v == "librio")
+        );
+        assert!(
+            singularize_candidates("studi")
+                .iter()
+                .any(|v| v == "studio")
+        );
+        assert!(
+            singularize_candidates("esempi")
+                .iter()
+                .any(|v| v == "esempio")
+        );
+    }
+
+    #[test]
+    fn test_singularize_velar() {
+        assert_eq!(singularize("parchi"), "parco");
+        assert_eq!(singularize("dialoghi"), "dialogo");
+        assert_eq!(singularize("banche"), "banca");
+        assert_eq!(singularize("righe"), "riga");
+        assert_eq!(singularize("camicie"), "camicia");
+        assert_eq!(singularize("arance"), "arancia");
+        assert_eq!(singularize("spiagge"), "spiaggia");
+    }
+
+    #[test]
+    fn test_gender() {
+        assert_eq!(singularize("nazioni"), "nazione");
+        assert_eq!(singularize("attrici"), "attrice");
+        assert_eq!(singularize("utenti"), "utente");
+        assert_eq!(singularize("motori"), "motore");
+        assert_eq!(singularize("animali"), "animale");
+        assert_eq!(singularize("documenti"), "documento");
+        assert_eq!(singularize("eventi"), "evento");
+        assert_eq!(singularize("servizi"), "servizio");
+        assert_eq!(singularize("viaggi"), "viaggio");
+        assert_eq!(singularize("prezzi"), "prezzo");
+        assert_eq!(pluralize("sistema")[0], "sistemi");
+        assert_eq!(pluralize("programma")[0], "programmi");
+        assert_eq!(pluralize("giornalista")[0], "giornalisti");
+        assert_eq!(pluralize("pagina")[0], "pagine");
+        assert_eq!(pluralize("stazione"), vec!["stazioni"]);
+    }
+
+    #[test]
+    fn test_invariant() {
+        assert_eq!(pluralize("città"), vec!["città"]);
+        assert_eq!(pluralize("virtù"), vec!["virtù"]);
+        assert_eq!(pluralize("re"), vec!["re"]);
+        assert_eq!(pluralize("bar"), vec!["bar"]);
+        assert_eq!(pluralize("computer"), vec!["computer"]);
+        assert_eq!(singularize("città"), "città");
+        assert_eq!(singularize("re"), "re");
+        assert_eq!(singularize("film"), "film");
+    }
+
+    #[test]
+    fn test_irregulars_not_regular() {
+        let regular = IRREGULARS.regular_entries(singularize_regular, pluralize_regular);
+        assert!(regular.is_empty(), "{regular:?}");
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("uomini"), "uomo");
+        assert_eq!(singularize("dei"), "dio");
+        assert_eq!(singularize("uova"), "uovo");
+        assert_eq!(singularize("amiche"), "amica");
+        assert_eq!(singularize("problemi"), "problema");
+        assert_eq!(pluralize("uomo"), vec!["uomini"]);
+        assert_eq!(pluralize("amico")[0], "amici");
+        assert_eq!(pluralize("lago")[0], "laghi");
+        assert_eq!(pluralize("valigia"), vec!["valigie"]);
+        assert_eq!(pluralize("farmaco"), vec!["farmaci"]);
+        assert_eq!(singularize("colleghi"), "collega");
+        assert_eq!(singularize("geologi"), "geologo");
+    }
+
+    #[test]
+    fn test_e_nouns() {
+        assert_eq!(singularize("cani"), "cane");
+        assert_eq!(singularize("fiori"), "fiore");
+        assert_eq!(singularize("menti"), "mente");
+        assert_eq!(pluralize("cane"), vec!["cani"]);
+    }
+
+    #[test]
+    fn test_compounds() {
+        assert_eq!(pluralize("capostazione"), vec!["capistazione"]);
+        assert_eq!(singularize("casseforti"), "cassaforte");
     }
 
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
     }
+
+    #[test]
+    fn test_rules_uncountable() {
+        use crate::language_rules::LanguageRules;
+        use alloc::vec;
+        assert_eq!(RULES.pluralize("città"), vec!["città"]);
+        assert_eq!(RULES.pluralize("virtù"), vec!["virtù"]);
+        assert_eq!(RULES.singularize("città"), "città");
+        assert_eq!(RULES.pluralize("sport"), vec!["sport"]);
+        assert_eq!(RULES.singularize("crisi"), "crisi");
+    }
 }
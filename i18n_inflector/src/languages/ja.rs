@@ -14,11 +14,7 @@ use alloc::borrow::Cow;
 use alloc::vec;
 use alloc::vec::Vec;
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "ja",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("ja", singularize, pluralize);
 
 /// Returns the word unchanged since the language has no morphological plural.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
@@ -2,10 +2,10 @@
 
 use crate::language_rules::LanguageRuleSet;
 
-pub(crate) use super::de::{pluralize, singularize};
+pub(crate) use super::de::{pluralize, singularize, singularize_candidates};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "lb",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    irregulars: Some(&super::de::IRREGULARS),
+    ..LanguageRuleSet::new("lb", singularize, pluralize)
 };
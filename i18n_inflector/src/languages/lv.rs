@@ -3,17 +3,17 @@
 use crate::language_rules::LanguageRuleSet;
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::vec;
 use alloc::vec::Vec;
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "lv",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    ..LanguageRuleSet::new("lv", singularize, pluralize)
 };
 
 /// Converts a plural Latvian noun to its singular form.
 ///
-/// Handles `-i` -> `-s` and `-as` -> `-a` transformations.
+/// Handles `-i` -> `-s`, `-as` -> `-a` and `-es` -> `-e` transformations.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     if let Some(stem) = name.strip_suffix('i')
         && !stem.is_empty()
@@ -25,9 +25,35 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     {
         return format!("{stem}a").into();
     }
+    if let Some(stem) = name.strip_suffix("es")
+        && !stem.is_empty()
+    {
+        return format!("{stem}e").into();
+    }
     Cow::Borrowed(name)
 }
 
+/// Returns the possible singular forms of a Latvian noun, most likely first.
+///
+/// Masculine plurals in `-i` belong to nouns in `-s`, `-is` or `-us` (`darbi` -> `darbs`,
+/// `kaķi` -> `kaķis`, `tirgi` -> `tirgus`), and feminine plurals in `-is` to nouns in `-s`
+/// (`sirdis` -> `sirds`).
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    let mut candidates = vec![singularize(name)];
+    if let Some(stem) = name.strip_suffix('i')
+        && !stem.is_empty()
+    {
+        candidates.push(format!("{stem}is").into());
+        candidates.push(format!("{stem}us").into());
+    }
+    if let Some(stem) = name.strip_suffix("is")
+        && !stem.is_empty()
+    {
+        candidates.push(format!("{stem}s").into());
+    }
+    candidates
+}
+
 /// Returns a list of possible plural forms for a Latvian noun.
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
     let mut candidates = Vec::new();
@@ -54,6 +80,16 @@ mod tests {
         assert_eq!(singularize("graamatas"), "graamata");
     }
 
+    #[test]
+    fn test_singularize_candidates() {
+        assert_eq!(
+            singularize_candidates("kaki"),
+            vec!["kaks", "kakis", "kakus"]
+        );
+        assert_eq!(singularize_candidates("sirdis"), vec!["sirdis", "sirds"]);
+        assert_eq!(singularize_candidates("kartes"), vec!["karte"]);
+    }
+
     #[test]
     fn test_singularize_already_singular() {
         assert_eq!(singularize("lietotajs"), "lietotajs");
@@ -2,7 +2,8 @@
 //!
 //! Each module provides `singularize` and `pluralize` functions for a specific
 //! language. Modules that share an implementation re-export from a primary
-//! module.
+//! module. Modules named after a region (`de_ch`, `pt_pt`) hold the rules of a
+//! regional variant of a language.
 
 pub(crate) mod aa;
 pub(crate) mod ab;
@@ -35,6 +36,7 @@ pub(crate) mod cv;
 pub(crate) mod cy;
 pub(crate) mod da;
 pub(crate) mod de;
+pub(crate) mod de_ch;
 pub(crate) mod dv;
 pub(crate) mod dz;
 pub(crate) mod ee;
@@ -124,6 +126,7 @@ pub(crate) mod pi;
 pub(crate) mod pl;
 pub(crate) mod ps;
 pub(crate) mod pt;
+pub(crate) mod pt_pt;
 pub(crate) mod qu;
 pub(crate) mod rm;
 pub(crate) mod ro;
@@ -171,3 +174,23 @@ pub(crate) mod yi;
 pub(crate) mod yo;
 pub(crate) mod zh;
 pub(crate) mod zu;
+
+/// Strips `suffix` from `name`, provided a non-empty stem remains.
+pub(crate) fn strip<'a>(name: &'a str, suffix: &str) -> Option<&'a str> {
+    name.strip_suffix(suffix).filter(|stem| !stem.is_empty())
+}
+
+/// Returns the number of groups of consecutive letters in `word` that `is_vowel` accepts, which
+/// approximates its syllable count.
+pub(crate) fn vowel_groups(word: &str, is_vowel: fn(char) -> bool) -> usize {
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for ch in word.chars() {
+        let vowel = is_vowel(ch);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+    count
+}
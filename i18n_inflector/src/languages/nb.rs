@@ -1,11 +1,253 @@
 //! Norwegian Bokmål (nb) inflection rules.
+//!
+//! Also used for Norwegian (no).
+//!
+//! Most Bokmål nouns take `-er` in the indefinite plural (`bil` -> `biler`), nouns in `-e` add
+//! `-r` (`jente` -> `jenter`), agent nouns in `-er` take `-e` (`bruker` -> `brukere`), and nouns
+//! in an unstressed `-el` drop the `e` (`regel` -> `regler`). A group of neuters, mostly
+//! monosyllables, has no plural ending (`barn`, `år`).
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use phf::phf_set;
 
-pub(crate) use super::da::{pluralize, singularize};
+use super::strip;
+
+/// Irregular Bokmål nouns: umlaut plurals, old plurals and nouns in `-el` with a double
+/// consonant.
+pub(crate) static IRREGULARS: Irregulars = irregulars! {
+    // Umlaut plurals
+    "bok" => "bøker",
+    "bonde" => "bønder",
+    "bror" => "brødre",
+    "datter" => "døtre",
+    "far" => "fedre",
+    "fot" => "føtter",
+    "gås" => "gjess",
+    "hånd" => "hender",
+    "ku" => "kyr",
+    "mann" => "menn",
+    "mor" => "mødre",
+    "natt" => "netter",
+    "rot" => "røtter",
+    "stang" => "stenger",
+    "tann" => "tenner",
+    "tå" => "tær",
+    // Other irregular plurals
+    "faktum" => "fakta",
+    "kne" => "knær",
+    "museum" => "museer",
+    "studium" => "studier",
+    "søster" => "søstre",
+    "tre" => "trær",
+    "øye" => "øyne",
+    // Nouns in -el with a double consonant
+    "himmel" => "himler",
+    "nøkkel" => "nøkler",
+    "sykkel" => "sykler",
+};
+
+/// Bokmål nouns with the same form in the singular and plural, mostly monosyllabic neuters.
+pub(crate) static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "ark", "bad", "barn", "bein", "ben", "blad", "bord", "brev", "bryllup", "bryst", "dyr",
+        "egg", "fag", "fat", "feil", "fjell", "fly", "forbud", "forhold", "forslag", "forsøk",
+        "garn", "glass", "gress", "hav", "hjul", "hull", "hus", "kast", "kjøkken", "kort", "krav",
+        "kyss", "lag", "lam", "land", "lik", "lys", "lån", "mus", "mål", "navn", "nett", "ord",
+        "par", "rom", "sett", "skap", "skilt", "skip", "sko", "slag", "slott", "spill", "spor",
+        "svar", "sår", "søk", "tak", "tall", "tau", "ting", "tog", "valg", "vann", "verk", "vers",
+        "våpen", "år",
+    },
+    uncountable_suffixes: &[],
+    invariant_suffixes: &[],
+};
+
+/// Bokmål nouns in `-e`, whose plural in `-er` cannot be told apart from that of a noun in a
+/// consonant (`jenter` -> `jente`, but `biler` -> `bil`).
+static NOUNS_IN_E: phf::Set<&'static str> = phf_set! {
+    "bakke", "bilde", "bølge", "dame", "ende", "eple", "fare", "flaske", "gate", "grense",
+    "hage", "hylle", "hytte", "jente", "kake", "kasse", "kirke", "klokke", "kolonne", "lampe",
+    "linje", "liste", "mage", "mappe", "merke", "minne", "måte", "pike", "pære", "rekke",
+    "rente", "rike", "rolle", "rute", "serie", "side", "skole", "sone", "stue", "stykke",
+    "tanke", "time", "tjeneste", "tone", "trappe", "type", "uke", "vare", "ønske",
+};
+
+/// Endings of nouns in `-e` that a noun in a consonant does not share (`hendelser` ->
+/// `hendelse`, `databaser` -> `database`).
+const E_ENDINGS: &[&str] = &["else", "ase", "yse"];
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "nb",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    irregulars: Some(&IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("nb", singularize, pluralize)
 };
+
+/// Returns `true` if `ch` is a Norwegian vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "aeiouyæøåé".contains(ch)
+}
+
+/// Returns `true` if `singular` is a known noun in `-e` or ends like one.
+fn is_noun_in_e(singular: &str) -> bool {
+    NOUNS_IN_E.contains(singular) || E_ENDINGS.iter().any(|ending| singular.ends_with(ending))
+}
+
+/// Converts a plural Bokmål noun to its singular form.
+///
+/// Handles irregular nouns (`menn` -> `mann`, `bøker` -> `bok`), agent nouns in `-ere`
+/// (`brukere` -> `bruker`), `-ler` with the unstressed `e` restored (`regler` -> `regel`), `-jer`
+/// for nouns in `-je` (`linjer` -> `linje`), and the `-er` plural suffix. Nouns in `-e` that add
+/// `-r` cannot be told from nouns that add `-er`, so only known nouns in `-e` and those ending in
+/// [`E_ENDINGS`] keep their `e` (`jenter` -> `jente`, `epler` -> `eple`).
+pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(singular) = IRREGULARS.singular(name) {
+        return Cow::Borrowed(singular);
+    }
+    if let Some(singular) = strip(name, "r")
+        && singular.ends_with('e')
+        && is_noun_in_e(singular)
+    {
+        return Cow::Borrowed(singular);
+    }
+    if let Some(stem) = strip(name, "ere") {
+        return format!("{stem}er").into();
+    }
+    if let Some(stem) = strip(name, "ler")
+        && stem.ends_with(|ch: char| !is_vowel(ch) && !"jlmnr".contains(ch))
+    {
+        return format!("{stem}el").into();
+    }
+    if let Some(stem) = strip(name, "jer") {
+        return format!("{stem}je").into();
+    }
+    if let Some(stem) = strip(name, "er") {
+        return Cow::Borrowed(stem);
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns the possible singular forms of a Bokmål noun, most likely first.
+///
+/// Starts with the result of [`singularize`], followed by the two readings of a plural in `-er`:
+/// a noun in `-e` that adds `-r` and a noun in a consonant that adds `-er` (`biler` -> `bil`,
+/// `bile`, `jenter` -> `jente`, `jent`). Irregular nouns only have their irregular singular.
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    let mut candidates = vec![singularize(name)];
+    if IRREGULARS.singular(name).is_some() {
+        return candidates;
+    }
+    if let Some(singular) = strip(name, "r")
+        && let Some(stem) = strip(singular, "e")
+        && stem.contains(is_vowel)
+    {
+        candidates.push(Cow::Borrowed(singular));
+        candidates.push(Cow::Borrowed(stem));
+    }
+    candidates
+}
+
+/// Returns a list of possible plural forms for a Bokmål noun, most likely first.
+///
+/// Irregular nouns are looked up first (`mann` -> `menn`). Nouns in `-e` add `-r`, nouns in
+/// `-er` of more than one syllable are agent nouns and add `-e` (`lærer` -> `lærere`), nouns in
+/// an unstressed `-el` drop the `e` before `-er` (`regel` -> `regler`), Latin nouns in `-eum` and
+/// `-ium` replace `-um` with `-er`, and all other nouns add `-er`.
+pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    if name.ends_with('e') {
+        return vec![format!("{name}r").into()];
+    }
+    if let Some(stem) = strip(name, "er")
+        && stem.contains(is_vowel)
+    {
+        return vec![format!("{name}e").into()];
+    }
+    if let Some(stem) = strip(name, "el")
+        && stem.contains(is_vowel)
+        && stem.ends_with(|ch: char| !is_vowel(ch))
+    {
+        return vec![format!("{stem}ler").into(), format!("{name}er").into()];
+    }
+    if let Some(stem) = strip(name, "um")
+        && stem.ends_with(['e', 'i'])
+    {
+        return vec![format!("{stem}er").into()];
+    }
+    vec![format!("{name}er").into()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("biler"), "bil");
+        assert_eq!(singularize("produkter"), "produkt");
+        assert_eq!(singularize("brukere"), "bruker");
+        assert_eq!(singularize("lærere"), "lærer");
+        assert_eq!(singularize("regler"), "regel");
+        assert_eq!(singularize("tabeller"), "tabell");
+        assert_eq!(singularize("linjer"), "linje");
+        assert_eq!(singularize("studier"), "studium");
+        assert_eq!(singularize("jenter"), "jente");
+        assert_eq!(singularize("epler"), "eple");
+        assert_eq!(singularize("hendelser"), "hendelse");
+    }
+
+    #[test]
+    fn test_singularize_candidates() {
+        assert_eq!(singularize_candidates("biler"), vec!["bil", "bile", "bil"]);
+        assert_eq!(
+            singularize_candidates("jenter"),
+            vec!["jente", "jente", "jent"]
+        );
+        assert_eq!(singularize_candidates("menn"), vec!["mann"]);
+    }
+
+    #[test]
+    fn test_singularize_already_singular() {
+        assert_eq!(singularize("produkt"), "produkt");
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("menn"), "mann");
+        assert_eq!(singularize("bøker"), "bok");
+        assert_eq!(singularize("sykler"), "sykkel");
+        assert_eq!(pluralize("mann"), vec!["menn"]);
+        assert_eq!(pluralize("bok"), vec!["bøker"]);
+        assert_eq!(pluralize("nøkkel"), vec!["nøkler"]);
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("bil"), vec!["biler"]);
+        assert_eq!(pluralize("jente"), vec!["jenter"]);
+        assert_eq!(pluralize("bruker"), vec!["brukere"]);
+        assert_eq!(pluralize("regel"), vec!["regler", "regeler"]);
+        assert_eq!(pluralize("konto"), vec!["kontoer"]);
+        assert_eq!(pluralize("kriterium"), vec!["kriterier"]);
+    }
+
+    #[test]
+    fn test_rules_uncountable() {
+        use crate::language_rules::LanguageRules;
+        assert_eq!(RULES.pluralize("barn"), vec!["barn"]);
+        assert_eq!(RULES.singularize("mus"), "mus");
+        assert_eq!(RULES.pluralize("hus"), vec!["hus"]);
+        assert_eq!(RULES.pluralize("land"), vec!["land"]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(singularize(""), "");
+    }
+}
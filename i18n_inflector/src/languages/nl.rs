@@ -3,21 +3,403 @@
 //! Also used for Limburgish (li).
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, irregulars};
 use alloc::borrow::Cow;
 use alloc::format;
 use alloc::vec;
 use alloc::vec::Vec;
 
+/// Irregular Dutch nouns: plurals in `-eren`, vowel length changes, doubled consonants, `-s` ->
+/// `-zen` and `-f` -> `-ven`, and nouns whose ending does not tell `-en` from `-s`. Nouns whose
+/// plural only adds `-en` or `-s` to the singular (`dag`, `familie`) are not listed.
+pub(crate) static IRREGULARS: Irregulars = irregulars! {
+    // Plurals in -eren
+    "blad" => "bladeren",
+    "ei" => "eieren",
+    "gemoed" => "gemoederen",
+    "goed" => "goederen",
+    "hoen" => "hoenderen",
+    "kalf" => "kalveren",
+    "kind" => "kinderen",
+    "lam" => "lammeren",
+    "lied" => "liederen",
+    "rad" => "raderen",
+    "rund" => "runderen",
+    "spaan" => "spaanders",
+    "volk" => "volkeren",
+    // Short vowel lengthened in the plural
+    "bevel" => "bevelen",
+    "glas" => "glazen",
+    "hof" => "hoven",
+    "lid" => "leden",
+    "schip" => "schepen",
+    "smid" => "smeden",
+    "spel" => "spelen",
+    "stad" => "steden",
+    "staf" => "staven",
+    // Long vowel written single in the plural
+    "aap" => "apen",
+    "afspraak" => "afspraken",
+    "baal" => "balen",
+    "baan" => "banen",
+    "beek" => "beken",
+    "been" => "benen",
+    "beer" => "beren",
+    "boog" => "bogen",
+    "boom" => "bomen",
+    "boon" => "bonen",
+    "boor" => "boren",
+    "boot" => "boten",
+    "brood" => "broden",
+    "buur" => "buren",
+    "daad" => "daden",
+    "deel" => "delen",
+    "draad" => "draden",
+    "draak" => "draken",
+    "droom" => "dromen",
+    "fenomeen" => "fenomenen",
+    "goot" => "goten",
+    "graan" => "granen",
+    "haak" => "haken",
+    "haan" => "hanen",
+    "haar" => "haren",
+    "heer" => "heren",
+    "hoop" => "hopen",
+    "jaar" => "jaren",
+    "kaak" => "kaken",
+    "kaap" => "kapen",
+    "kleed" => "kleden",
+    "knaap" => "knapen",
+    "knoop" => "knopen",
+    "kool" => "kolen",
+    "koor" => "koren",
+    "kraag" => "kragen",
+    "kraan" => "kranen",
+    "kroon" => "kronen",
+    "kuur" => "kuren",
+    "kwaal" => "kwalen",
+    "laag" => "lagen",
+    "laan" => "lanen",
+    "loon" => "lonen",
+    "maag" => "magen",
+    "maal" => "malen",
+    "maan" => "manen",
+    "maat" => "maten",
+    "meer" => "meren",
+    "meneer" => "meneren",
+    "muur" => "muren",
+    "naam" => "namen",
+    "noot" => "noten",
+    "oog" => "ogen",
+    "oor" => "oren",
+    "paal" => "palen",
+    "paar" => "paren",
+    "peer" => "peren",
+    "plaag" => "plagen",
+    "plaat" => "platen",
+    "pool" => "polen",
+    "poot" => "poten",
+    "preek" => "preken",
+    "raad" => "raden",
+    "raam" => "ramen",
+    "raap" => "rapen",
+    "reep" => "repen",
+    "schaal" => "schalen",
+    "schaap" => "schapen",
+    "school" => "scholen",
+    "schoot" => "schoten",
+    "schuur" => "schuren",
+    "speer" => "speren",
+    "spoor" => "sporen",
+    "staat" => "staten",
+    "steeg" => "stegen",
+    "steen" => "stenen",
+    "straat" => "straten",
+    "streek" => "streken",
+    "strook" => "stroken",
+    "stroom" => "stromen",
+    "stuur" => "sturen",
+    "taak" => "taken",
+    "taal" => "talen",
+    "toon" => "tonen",
+    "traan" => "tranen",
+    "troon" => "tronen",
+    "uur" => "uren",
+    "veer" => "veren",
+    "vlaag" => "vlagen",
+    "vloot" => "vloten",
+    "vraag" => "vragen",
+    "vuur" => "vuren",
+    "week" => "weken",
+    "zaad" => "zaden",
+    "zaag" => "zagen",
+    "zaak" => "zaken",
+    "zaal" => "zalen",
+    "zool" => "zolen",
+    "zoon" => "zonen",
+    "zweer" => "zweren",
+    // Consonant doubled in the plural
+    "atlas" => "atlassen",
+    "bak" => "bakken",
+    "bal" => "ballen",
+    "bed" => "bedden",
+    "begrafenis" => "begrafenissen",
+    "bel" => "bellen",
+    "bok" => "bokken",
+    "bom" => "bommen",
+    "bon" => "bonnen",
+    "boodschap" => "boodschappen",
+    "bril" => "brillen",
+    "brug" => "bruggen",
+    "bus" => "bussen",
+    "campus" => "campussen",
+    "dok" => "dokken",
+    "dop" => "doppen",
+    "eigenschap" => "eigenschappen",
+    "ergernis" => "ergernissen",
+    "fles" => "flessen",
+    "gebeurtenis" => "gebeurtenissen",
+    "gemeenschap" => "gemeenschappen",
+    "genootschap" => "genootschappen",
+    "getuigenis" => "getuigenissen",
+    "gevangenis" => "gevangenissen",
+    "gezelschap" => "gezelschappen",
+    "hek" => "hekken",
+    "hen" => "hennen",
+    "hut" => "hutten",
+    "jas" => "jassen",
+    "kam" => "kammen",
+    "kan" => "kannen",
+    "kat" => "katten",
+    "kennis" => "kennissen",
+    "kip" => "kippen",
+    "klok" => "klokken",
+    "kom" => "kommen",
+    "kop" => "koppen",
+    "landschap" => "landschappen",
+    "les" => "lessen",
+    "lip" => "lippen",
+    "man" => "mannen",
+    "mat" => "matten",
+    "mes" => "messen",
+    "mop" => "moppen",
+    "mus" => "mussen",
+    "nek" => "nekken",
+    "net" => "netten",
+    "non" => "nonnen",
+    "pen" => "pennen",
+    "pet" => "petten",
+    "pil" => "pillen",
+    "pit" => "pitten",
+    "plan" => "plannen",
+    "pop" => "poppen",
+    "pot" => "potten",
+    "put" => "putten",
+    "rat" => "ratten",
+    "rok" => "rokken",
+    "rug" => "ruggen",
+    "sok" => "sokken",
+    "spin" => "spinnen",
+    "stem" => "stemmen",
+    "stok" => "stokken",
+    "stuk" => "stukken",
+    "tak" => "takken",
+    "tas" => "tassen",
+    "trap" => "trappen",
+    "vak" => "vakken",
+    "vel" => "vellen",
+    "vennootschap" => "vennootschappen",
+    "virus" => "virussen",
+    "vis" => "vissen",
+    "vlag" => "vlaggen",
+    "vlek" => "vlekken",
+    "vos" => "vossen",
+    "vriendschap" => "vriendschappen",
+    "wal" => "wallen",
+    "web" => "webben",
+    "wetenschap" => "wetenschappen",
+    "zak" => "zakken",
+    "zon" => "zonnen",
+    "zus" => "zussen",
+    "zwangerschap" => "zwangerschappen",
+    // -s -> -zen
+    "baas" => "bazen",
+    "blaas" => "blazen",
+    "buis" => "buizen",
+    "doos" => "dozen",
+    "dwaas" => "dwazen",
+    "gans" => "ganzen",
+    "grens" => "grenzen",
+    "haas" => "hazen",
+    "huis" => "huizen",
+    "kaas" => "kazen",
+    "keus" => "keuzen",
+    "kies" => "kiezen",
+    "laars" => "laarzen",
+    "lens" => "lenzen",
+    "luis" => "luizen",
+    "muis" => "muizen",
+    "neus" => "neuzen",
+    "pees" => "pezen",
+    "poes" => "poezen",
+    "prijs" => "prijzen",
+    "reis" => "reizen",
+    "reus" => "reuzen",
+    "roos" => "rozen",
+    "vaas" => "vazen",
+    "vers" => "verzen",
+    "vlies" => "vliezen",
+    // -f -> -ven
+    "boef" => "boeven",
+    "brief" => "brieven",
+    "dief" => "dieven",
+    "druif" => "druiven",
+    "duif" => "duiven",
+    "erf" => "erven",
+    "geloof" => "geloven",
+    "golf" => "golven",
+    "graaf" => "graven",
+    "hoef" => "hoeven",
+    "kerf" => "kerven",
+    "kloof" => "kloven",
+    "korf" => "korven",
+    "lijf" => "lijven",
+    "neef" => "neven",
+    "proef" => "proeven",
+    "raaf" => "raven",
+    "schaaf" => "schaven",
+    "schijf" => "schijven",
+    "schroef" => "schroeven",
+    "slaaf" => "slaven",
+    "werf" => "werven",
+    "wijf" => "wijven",
+    "wolf" => "wolven",
+    "zeef" => "zeven",
+    // -heid -> -heden
+    "bevoegdheid" => "bevoegdheden",
+    "bijzonderheid" => "bijzonderheden",
+    "eenheid" => "eenheden",
+    "gelegenheid" => "gelegenheden",
+    "hoeveelheid" => "hoeveelheden",
+    "moeilijkheid" => "moeilijkheden",
+    "mogelijkheid" => "mogelijkheden",
+    "nieuwigheid" => "nieuwigheden",
+    "overheid" => "overheden",
+    "schoonheid" => "schoonheden",
+    "verantwoordelijkheid" => "verantwoordelijkheden",
+    "vrijheid" => "vrijheden",
+    "waarheid" => "waarheden",
+    "zekerheid" => "zekerheden",
+    // -man -> -lieden
+    "ambachtsman" => "ambachtslieden",
+    "bootsman" => "bootslieden",
+    "brandweerman" => "brandweerlieden",
+    "edelman" => "edellieden",
+    "koopman" => "kooplieden",
+    "raadsman" => "raadslieden",
+    "stuurman" => "stuurlieden",
+    "timmerman" => "timmerlieden",
+    "vakman" => "vaklieden",
+    "werkman" => "werklieden",
+    "zakenman" => "zakenlieden",
+    "zeeman" => "zeelieden",
+    // Stressed vowels and -ie
+    "allee" => "alleeën",
+    "bacterie" => "bacteriën",
+    "categorie" => "categorieën",
+    "energie" => "energieën",
+    "fantasie" => "fantasieën",
+    "fee" => "feeën",
+    "harmonie" => "harmonieën",
+    "idee" => "ideeën",
+    "industrie" => "industrieën",
+    "knie" => "knieën",
+    "koe" => "koeien",
+    "melodie" => "melodieën",
+    "olie" => "oliën",
+    "orchidee" => "orchideeën",
+    "porie" => "poriën",
+    "ree" => "reeën",
+    "strategie" => "strategieën",
+    "technologie" => "technologieën",
+    "theorie" => "theorieën",
+    "trofee" => "trofeeën",
+    "vlo" => "vlooien",
+    "zee" => "zeeën",
+    // Nouns which take -s
+    "admiraal" => "admiraals",
+    "album" => "albums",
+    "app" => "apps",
+    "bureau" => "bureaus",
+    "cadeau" => "cadeaus",
+    "café" => "cafés",
+    "chauffeur" => "chauffeurs",
+    "chef" => "chefs",
+    "club" => "clubs",
+    "comité" => "comités",
+    "e-mail" => "e-mails",
+    "film" => "films",
+    "generaal" => "generaals",
+    "ingenieur" => "ingenieurs",
+    "kapitein" => "kapiteins",
+    "kok" => "koks",
+    "laptop" => "laptops",
+    "link" => "links",
+    "luitenant" => "luitenants",
+    "monteur" => "monteurs",
+    "niveau" => "niveaus",
+    "oom" => "ooms",
+    "plateau" => "plateaus",
+    "restaurant" => "restaurants",
+    "roman" => "romans",
+    "station" => "stations",
+    "team" => "teams",
+    "tram" => "trams",
+    // Latin and Greek loanwords
+    "academicus" => "academici",
+    "alumnus" => "alumni",
+    "auditorium" => "auditoria",
+    "catalogus" => "catalogi",
+    "centrum" => "centra",
+    "chemicus" => "chemici",
+    "corpus" => "corpora",
+    "criterium" => "criteria",
+    "criticus" => "critici",
+    "curriculum" => "curricula",
+    "decennium" => "decennia",
+    "forum" => "fora",
+    "fysicus" => "fysici",
+    "genus" => "genera",
+    "historicus" => "historici",
+    "index" => "indices",
+    "maximum" => "maxima",
+    "medium" => "media",
+    "minimum" => "minima",
+    "museum" => "musea",
+    "musicus" => "musici",
+    "optimum" => "optima",
+    "podium" => "podia",
+    "politicus" => "politici",
+    "spectrum" => "spectra",
+    "stadium" => "stadia",
+    "symposium" => "symposia",
+    "technicus" => "technici",
+    "tempus" => "tempora",
+};
+
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "nl",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    irregulars: Some(&IRREGULARS),
+    ..LanguageRuleSet::new("nl", singularize, pluralize)
 };
 
 /// Converts a plural Dutch noun to its singular form.
 ///
-/// Handles `-en`, `-'s`, and `-s` plural suffixes.
+/// Handles irregular nouns (`kinderen` -> `kind`, `steden` -> `stad`) and the `-en`, `-'s`, and
+/// `-s` plural suffixes.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(singular) = IRREGULARS.singular(name) {
+        return Cow::Borrowed(singular);
+    }
     if let Some(stem) = name.strip_suffix("en")
         && !stem.is_empty()
     {
@@ -36,13 +418,28 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a Dutch noun.
+/// Returns a list of possible plural forms for a Dutch noun, most likely first.
+///
+/// Nouns ending in `-a`, `-i`, `-o`, `-u` or `-y` prefer `-'s`, nouns ending in an unstressed
+/// `-e`, `-el`, `-em`, `-en` or `-er` prefer `-s`, and all other nouns prefer `-en`. Irregular
+/// nouns are looked up first (`kind` -> `kinderen`, `schip` -> `schepen`).
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    vec![
-        format!("{name}en").into(),
-        format!("{name}s").into(),
-        format!("{name}'s").into(),
-    ]
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    let en = format!("{name}en").into();
+    let s = format!("{name}s").into();
+    let apostrophe_s = format!("{name}'s").into();
+    if name.ends_with(['a', 'i', 'o', 'u', 'y']) {
+        vec![apostrophe_s, s, en]
+    } else if ["e", "el", "em", "en", "er"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+    {
+        vec![s, en, apostrophe_s]
+    } else {
+        vec![en, s, apostrophe_s]
+    }
 }
 
 #[cfg(test)]
@@ -90,6 +487,33 @@ mod tests {
         assert!(result.iter().any(|v| v == "klant's"));
     }
 
+    #[test]
+    fn test_pluralize_ranking() {
+        assert_eq!(pluralize("klant")[0], "klanten");
+        assert_eq!(pluralize("auto")[0], "auto's");
+        assert_eq!(pluralize("tafel")[0], "tafels");
+        assert_eq!(pluralize("kamer")[0], "kamers");
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("kinderen"), "kind");
+        assert_eq!(singularize("steden"), "stad");
+        assert_eq!(singularize("huizen"), "huis");
+        assert_eq!(singularize("brieven"), "brief");
+        assert_eq!(singularize("musea"), "museum");
+        assert_eq!(pluralize("schip"), vec!["schepen"]);
+        assert_eq!(pluralize("koe"), vec!["koeien"]);
+        assert_eq!(pluralize("jaar"), vec!["jaren"]);
+        assert_eq!(singularize("bruggen"), "brug");
+        assert_eq!(singularize("gebeurtenissen"), "gebeurtenis");
+        assert_eq!(singularize("strategieën"), "strategie");
+        assert_eq!(pluralize("droom"), vec!["dromen"]);
+        assert_eq!(pluralize("cadeau"), vec!["cadeaus"]);
+        assert_eq!(pluralize("zakenman"), vec!["zakenlieden"]);
+        assert_eq!(pluralize("dag")[0], "dagen");
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
@@ -1,11 +1,247 @@
 //! Norwegian Nynorsk (nn) inflection rules.
+//!
+//! Nynorsk keeps the plural endings of the three genders apart: masculines take `-ar` (`bil` ->
+//! `bilar`, `hage` -> `hagar`), feminines take `-er` (`seng` -> `senger`, `jente` -> `jenter`)
+//! and neuters have no ending (`hus`, `eple`). Nouns in `-ing`, agent nouns in `-ar` and most
+//! loan words are masculine.
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use phf::phf_set;
 
-pub(crate) use super::da::{pluralize, singularize};
+use super::strip;
+
+/// Irregular Nynorsk nouns: umlaut plurals, old plurals and nouns in `-el` with a double
+/// consonant.
+static IRREGULARS: Irregulars = irregulars! {
+    // Umlaut plurals
+    "bok" => "bøker",
+    "bonde" => "bønder",
+    "bror" => "brør",
+    "dotter" => "døtrer",
+    "far" => "fedrar",
+    "fot" => "føter",
+    "gås" => "gjæser",
+    "hand" => "hender",
+    "ku" => "kyr",
+    "mann" => "menn",
+    "mor" => "mødrer",
+    "mus" => "mys",
+    "natt" => "netter",
+    "rot" => "røter",
+    "tann" => "tenner",
+    "tå" => "tær",
+    // Other irregular plurals
+    "auga" => "augo",
+    "sommar" => "somrar",
+    "syster" => "systrer",
+    "øyra" => "øyro",
+    // Nouns in -el with a double consonant
+    "himmel" => "himlar",
+    "nykkel" => "nyklar",
+    "sykkel" => "syklar",
+};
+
+/// Nynorsk neuters, which have the same form in the singular and plural.
+static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "ark", "bad", "barn", "bein", "bilete", "blad", "bord", "brev", "bryllaup", "bryst",
+        "dokument", "dyr", "egg", "eple", "fag", "fat", "fjell", "fly", "forbod", "forhold",
+        "forslag", "forsøk", "garn", "glas", "gras", "hav", "hjarte", "hjul", "hol", "hus",
+        "kjøkken", "kne", "kort", "krav", "kyss", "lag", "lam", "land", "lys", "lån", "merke",
+        "minne", "mål", "namn", "nett", "objekt", "ord", "par", "problem", "program", "prosjekt",
+        "rike", "rom", "sett", "skap", "skilt", "skip", "slag", "slott", "spel", "spor", "stykke",
+        "svar", "system", "sår", "søk", "tak", "tal", "tau", "tog", "tre", "val", "vatn", "verk",
+        "vers", "våpen", "ønske", "år",
+    },
+    uncountable_suffixes: &[],
+    invariant_suffixes: &[],
+};
+
+/// Nynorsk nouns in `-e`, whose plurals in `-ar` and `-er` cannot be told apart from those of a
+/// noun in a consonant (`skular` -> `skule`, `jenter` -> `jente`, but `bilar` -> `bil`).
+static NOUNS_IN_E: phf::Set<&'static str> = phf_set! {
+    "bakke", "bølgje", "dame", "ende", "flaske", "gate", "grense", "hage", "hylle", "hytte",
+    "jente", "kake", "kasse", "kjole", "klokke", "kyrkje", "lampe", "linje", "liste", "mage",
+    "mappe", "måte", "rekkje", "rente", "rolle", "rute", "serie", "side", "skule", "sone",
+    "stige", "stove", "tanke", "teneste", "time", "tone", "type", "vare", "veke",
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "nn",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    irregulars: Some(&IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("nn", singularize, pluralize)
 };
+
+/// Endings of feminine nouns, which take `-er` (`sikkerheit` -> `sikkerheiter`).
+const FEMININE_ENDINGS: &[&str] = &["heit", "semd"];
+
+/// Returns `true` if `ch` is a Norwegian vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "aeiouyæøåé".contains(ch)
+}
+
+/// Converts a plural Nynorsk noun to its singular form.
+///
+/// Handles irregular nouns (`menn` -> `mann`, `bøker` -> `bok`), agent nouns in `-arar`
+/// (`lærarar` -> `lærar`), `-lar`, `-nar` and `-rar` with the unstressed `e` restored (`reglar`
+/// -> `regel`, `vintrar` -> `vinter`), `-jer` for nouns in `-je` (`linjer` -> `linje`), and the
+/// `-ar` and `-er` plural suffixes. Nouns in `-e` cannot be told from nouns in a consonant, so
+/// only known nouns in `-e` keep their `e` (`skular` -> `skule`, `jenter` -> `jente`).
+pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(singular) = IRREGULARS.singular(name) {
+        return Cow::Borrowed(singular);
+    }
+    if let Some(stem) = strip(name, "ar").or_else(|| strip(name, "er")) {
+        let singular = format!("{stem}e");
+        if NOUNS_IN_E.contains(&*singular) {
+            return singular.into();
+        }
+    }
+    if let Some(stem) = strip(name, "arar") {
+        return format!("{stem}ar").into();
+    }
+    if let Some(stem) = strip(name, "ar") {
+        let mut chars = stem.chars().rev();
+        if let (Some(last @ ('l' | 'n' | 'r')), Some(previous)) = (chars.next(), chars.next())
+            && !is_vowel(previous)
+            && !"jlmnr".contains(previous)
+        {
+            return format!("{}e{last}", &stem[..stem.len() - 1]).into();
+        }
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = strip(name, "jer") {
+        return format!("{stem}je").into();
+    }
+    if let Some(stem) = strip(name, "er") {
+        return Cow::Borrowed(stem);
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns the possible singular forms of a Nynorsk noun, most likely first.
+///
+/// Starts with the result of [`singularize`], followed by the two readings of a plural in `-ar`
+/// or `-er`: a noun in `-e` and a noun in a consonant (`bilar` -> `bil`, `bile`, `jenter` ->
+/// `jente`, `jent`). Irregular nouns only have their irregular singular.
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    let mut candidates = vec![singularize(name)];
+    if IRREGULARS.singular(name).is_some() {
+        return candidates;
+    }
+    if let Some(stem) = strip(name, "ar").or_else(|| strip(name, "er"))
+        && stem.contains(is_vowel)
+    {
+        candidates.push(format!("{stem}e").into());
+        candidates.push(Cow::Borrowed(stem));
+    }
+    candidates
+}
+
+/// Returns a list of possible plural forms for a Nynorsk noun, most likely first.
+///
+/// Irregular nouns are looked up first (`mann` -> `menn`). Agent nouns in `-ar` add `-ar`
+/// (`lærar` -> `lærarar`), nouns in `-ing` take `-ar` (`løysing` -> `løysingar`), and the
+/// feminine endings in [`FEMININE_ENDINGS`] take `-er`. Nouns in an unstressed `-el`, `-en` or
+/// `-er` drop the `e` before `-ar` (`regel` -> `reglar`). Nouns in `-e` prefer the feminine `-r`
+/// over the masculine `-ar`, and other nouns prefer `-ar` over `-er`.
+pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    if name.ends_with("ar") || name.ends_with("ing") {
+        return vec![format!("{name}ar").into()];
+    }
+    if FEMININE_ENDINGS.iter().any(|ending| name.ends_with(ending)) {
+        return vec![format!("{name}er").into()];
+    }
+    if let Some(stem) = strip(name, "e") {
+        return vec![format!("{name}r").into(), format!("{stem}ar").into()];
+    }
+    if let Some((stem, consonant)) = [("el", "l"), ("en", "n"), ("er", "r")]
+        .iter()
+        .find_map(|&(ending, consonant)| Some((strip(name, ending)?, consonant)))
+        && stem.contains(is_vowel)
+        && stem.ends_with(|ch: char| !is_vowel(ch))
+    {
+        return vec![format!("{stem}{consonant}ar").into()];
+    }
+    vec![format!("{name}ar").into(), format!("{name}er").into()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("bilar"), "bil");
+        assert_eq!(singularize("senger"), "seng");
+        assert_eq!(singularize("lærarar"), "lærar");
+        assert_eq!(singularize("reglar"), "regel");
+        assert_eq!(singularize("vintrar"), "vinter");
+        assert_eq!(singularize("linjer"), "linje");
+        assert_eq!(singularize("løysingar"), "løysing");
+        assert_eq!(singularize("jenter"), "jente");
+        assert_eq!(singularize("skular"), "skule");
+        assert_eq!(singularize("hagar"), "hage");
+    }
+
+    #[test]
+    fn test_singularize_candidates() {
+        assert_eq!(singularize_candidates("bilar"), vec!["bil", "bile", "bil"]);
+        assert_eq!(
+            singularize_candidates("skular"),
+            vec!["skule", "skule", "skul"]
+        );
+        assert_eq!(singularize_candidates("menn"), vec!["mann"]);
+    }
+
+    #[test]
+    fn test_singularize_already_singular() {
+        assert_eq!(singularize("produkt"), "produkt");
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("menn"), "mann");
+        assert_eq!(singularize("bøker"), "bok");
+        assert_eq!(singularize("syklar"), "sykkel");
+        assert_eq!(pluralize("mann"), vec!["menn"]);
+        assert_eq!(pluralize("mus"), vec!["mys"]);
+        assert_eq!(pluralize("auga"), vec!["augo"]);
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("bil"), vec!["bilar", "biler"]);
+        assert_eq!(pluralize("lærar"), vec!["lærarar"]);
+        assert_eq!(pluralize("løysing"), vec!["løysingar"]);
+        assert_eq!(pluralize("jente"), vec!["jenter", "jentar"]);
+        assert_eq!(pluralize("regel"), vec!["reglar"]);
+        assert_eq!(pluralize("vinter"), vec!["vintrar"]);
+        assert_eq!(pluralize("fridom")[0], "fridomar");
+        assert_eq!(pluralize("sikkerheit"), vec!["sikkerheiter"]);
+        assert_eq!(pluralize("einsemd"), vec!["einsemder"]);
+    }
+
+    #[test]
+    fn test_rules_uncountable() {
+        use crate::language_rules::LanguageRules;
+        assert_eq!(RULES.pluralize("hus"), vec!["hus"]);
+        assert_eq!(RULES.pluralize("eple"), vec!["eple"]);
+        assert_eq!(RULES.pluralize("land"), vec!["land"]);
+        assert_eq!(RULES.pluralize("problem"), vec!["problem"]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(singularize(""), "");
+    }
+}
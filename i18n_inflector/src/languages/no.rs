@@ -2,10 +2,11 @@
 
 use crate::language_rules::LanguageRuleSet;
 
-pub(crate) use super::da::{pluralize, singularize};
+pub(crate) use super::nb::{pluralize, singularize, singularize_candidates};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "no",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    irregulars: Some(&super::nb::IRREGULARS),
+    lexicon: &super::nb::LEXICON,
+    ..LanguageRuleSet::new("no", singularize, pluralize)
 };
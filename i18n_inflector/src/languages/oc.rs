@@ -1,11 +1,66 @@
 //! Occitan (oc) inflection rules.
 
 use crate::language_rules::LanguageRuleSet;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 
-pub(crate) use super::fr::{pluralize, singularize};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("oc", singularize, pluralize);
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "oc",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+/// Returns `true` if the noun ends in a sibilant, which takes `-es` in the plural.
+fn ends_in_sibilant(name: &str) -> bool {
+    name.ends_with(['s', 'x', 'z'])
+}
+
+/// Converts a plural Occitan noun to its singular form.
+///
+/// Handles `-es` after a sibilant (`paises` -> `pais`) and regular `-s` plurals (`pòrtas` ->
+/// `pòrta`, `ostals` -> `ostal`).
+pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(stem) = name.strip_suffix("es")
+        && ends_in_sibilant(stem)
+    {
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = name.strip_suffix('s')
+        && !stem.is_empty()
+    {
+        return Cow::Borrowed(stem);
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns the plural of an Occitan noun: `-es` after a sibilant and `-s` otherwise.
+pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if ends_in_sibilant(name) {
+        vec![format!("{name}es").into()]
+    } else {
+        vec![format!("{name}s").into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("pòrtas"), "pòrta");
+        assert_eq!(singularize("ostals"), "ostal");
+        assert_eq!(singularize("gats"), "gat");
+        assert_eq!(singularize("paises"), "pais");
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("pòrta"), vec!["pòrtas"]);
+        assert_eq!(pluralize("ostal"), vec!["ostals"]);
+        assert_eq!(pluralize("pais"), vec!["paises"]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(singularize(""), "");
+    }
+}
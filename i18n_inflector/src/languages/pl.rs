@@ -1,64 +1,1515 @@
 //! Polish (pl) inflection rules.
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
+use crate::number::{self, Number};
+use crate::plural_rules::PluralCategory;
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
+
+use super::strip;
+
+/// Irregular Polish nouns with their nominative plurals: suppletive plurals, masculine personal
+/// nouns in `-owie`, `-anie` or with a consonant alternation the rules do not derive, vowel
+/// alternations (`stół` -> `stoły`), the mobile `-e-` (`pies` -> `psy`) and feminines in a
+/// consonant. Nouns whose plural follows from the suffix rules and the noun sets below
+/// (`student`, `kobieta`, `miasto`) are not listed, nor are phrases, whose words are inflected
+/// one at a time.
+static IRREGULARS: Irregulars = irregulars! {
+    // Irregular plurals
+    "brat" => "bracia",
+    "człowiek" => "ludzie",
+    "dziecko" => "dzieci",
+    "dzień" => "dni",
+    "gość" => "goście",
+    "ksiądz" => "księża",
+    "oko" => "oczy",
+    "rok" => "lata",
+    "ręka" => "ręce",
+    "tydzień" => "tygodnie",
+    "ucho" => "uszy",
+    // Neuters in -ę
+    "cielę" => "cielęta",
+    "imię" => "imiona",
+    "książę" => "książęta",
+    "kurczę" => "kurczęta",
+    "niemowlę" => "niemowlęta",
+    "plemię" => "plemiona",
+    "ramię" => "ramiona",
+    "zwierzę" => "zwierzęta",
+    // Masculine personal nouns in -owie
+    "admirał" => "admirałowie",
+    "bóg" => "bogowie",
+    "car" => "carowie",
+    "członek" => "członkowie",
+    "dziadek" => "dziadkowie",
+    "faraon" => "faraonowie",
+    "generał" => "generałowie",
+    "hrabia" => "hrabiowie",
+    "inżynier" => "inżynierowie",
+    "kapitan" => "kapitanowie",
+    "król" => "królowie",
+    "kuzyn" => "kuzyni",
+    "lord" => "lordowie",
+    "major" => "majorowie",
+    "marszałek" => "marszałkowie",
+    "mąż" => "mężowie",
+    "minister" => "ministrowie",
+    "mistrz" => "mistrzowie",
+    "monarcha" => "monarchowie",
+    "oficer" => "oficerowie",
+    "ojciec" => "ojcowie",
+    "ojczym" => "ojczymowie",
+    "pan" => "panowie",
+    "pradziadek" => "pradziadkowie",
+    "premier" => "premierzy",
+    "profesor" => "profesorowie",
+    "prorok" => "prorocy",
+    "rektor" => "rektorzy",
+    "sędzia" => "sędziowie",
+    "stryj" => "stryjowie",
+    "syn" => "synowie",
+    "szef" => "szefowie",
+    "szwagier" => "szwagrowie",
+    "teść" => "teściowie",
+    "uczeń" => "uczniowie",
+    "wnuk" => "wnukowie",
+    "wódz" => "wodzowie",
+    "wróg" => "wrogowie",
+    "wuj" => "wujowie",
+    "wujek" => "wujkowie",
+    "zięć" => "zięciowie",
+    // Masculine personal nouns with consonant alternation
+    "afrykanin" => "afrykanie",
+    "amerykanin" => "amerykanie",
+    "arystokrata" => "arystokraci",
+    "astronauta" => "astronauci",
+    "atleta" => "atleci",
+    "biurokrata" => "biurokraci",
+    "chłopiec" => "chłopcy",
+    "chrześcijanin" => "chrześcijanie",
+    "demokrata" => "demokraci",
+    "dominikanin" => "dominikanie",
+    "dworzanin" => "dworzanie",
+    "dyplomata" => "dyplomaci",
+    "franciszkanin" => "franciszkanie",
+    "goniec" => "gońcy",
+    "idiota" => "idioci",
+    "kapral" => "kaprale",
+    "kat" => "kaci",
+    "kierowca" => "kierowcy",
+    "kolega" => "koledzy",
+    "krakowianin" => "krakowianie",
+    "kupiec" => "kupcy",
+    "logopeda" => "logopedzi",
+    "meksykanin" => "meksykanie",
+    "mężczyzna" => "mężczyźni",
+    "mieszczanin" => "mieszczanie",
+    "mieszkaniec" => "mieszkańcy",
+    "niemiec" => "niemcy",
+    "obywatel" => "obywatele",
+    "parafianin" => "parafianie",
+    "patriota" => "patrioci",
+    "pediatra" => "pediatrzy",
+    "poeta" => "poeci",
+    "psychiatra" => "psychiatrzy",
+    "robotnik" => "robotnicy",
+    "rosjanin" => "rosjanie",
+    "sąsiad" => "sąsiedzi",
+    "słowianin" => "słowianie",
+    "sprzedawca" => "sprzedawcy",
+    "starzec" => "starcy",
+    "szaleniec" => "szaleńcy",
+    "warszawianin" => "warszawianie",
+    "ziemianin" => "ziemianie",
+    // Masculines with vowel alternation and mobile -e-
+    "łabędź" => "łabędzie",
+    "błąd" => "błędy",
+    "bęben" => "bębny",
+    "budynek" => "budynki",
+    "cień" => "cienie",
+    "cukierek" => "cukierki",
+    "czwartek" => "czwartki",
+    "dąb" => "dęby",
+    "diabeł" => "diabły",
+    "dół" => "doły",
+    "dodatek" => "dodatki",
+    "domek" => "domki",
+    "dowód" => "dowody",
+    "dwór" => "dwory",
+    "dworzec" => "dworce",
+    "dziób" => "dzioby",
+    "dzwonek" => "dzwonki",
+    "łeb" => "łby",
+    "gałąź" => "gałęzie",
+    "gatunek" => "gatunki",
+    "gołąb" => "gołębie",
+    "grób" => "groby",
+    "kabel" => "kable",
+    "kawałek" => "kawałki",
+    "kieł" => "kły",
+    "kierunek" => "kierunki",
+    "kocioł" => "kotły",
+    "koniec" => "końce",
+    "kościół" => "kościoły",
+    "kotek" => "kotki",
+    "kozioł" => "kozły",
+    "krąg" => "kręgi",
+    "krój" => "kroje",
+    "kubek" => "kubki",
+    "kwiatek" => "kwiatki",
+    "lew" => "lwy",
+    "lód" => "lody",
+    "majątek" => "majątki",
+    "mebel" => "meble",
+    "mech" => "mchy",
+    "miesiąc" => "miesiące",
+    "miód" => "miody",
+    "mróz" => "mrozy",
+    "naród" => "narody",
+    "nastrój" => "nastroje",
+    "obowiązek" => "obowiązki",
+    "obóz" => "obozy",
+    "obrazek" => "obrazki",
+    "obrząd" => "obrzędy",
+    "ogórek" => "ogórki",
+    "ogród" => "ogrody",
+    "ołówek" => "ołówki",
+    "orzeł" => "orły",
+    "osioł" => "osły",
+    "otwór" => "otwory",
+    "owies" => "owsy",
+    "palec" => "palce",
+    "piątek" => "piątki",
+    "pień" => "pnie",
+    "pies" => "psy",
+    "piesek" => "pieski",
+    "początek" => "początki",
+    "podatek" => "podatki",
+    "poniedziałek" => "poniedziałki",
+    "popiół" => "popioły",
+    "powód" => "powody",
+    "próg" => "progi",
+    "przewód" => "przewody",
+    "przypadek" => "przypadki",
+    "przystanek" => "przystanki",
+    "rachunek" => "rachunki",
+    "róg" => "rogi",
+    "rynek" => "rynki",
+    "rząd" => "rzędy",
+    "samochód" => "samochody",
+    "sen" => "sny",
+    "spór" => "spory",
+    "sposób" => "sposoby",
+    "stół" => "stoły",
+    "stóg" => "stogi",
+    "stopień" => "stopnie",
+    "stosunek" => "stosunki",
+    "strój" => "stroje",
+    "szew" => "szwy",
+    "taniec" => "tańce",
+    "tysiąc" => "tysiące",
+    "ubiór" => "ubiory",
+    "urząd" => "urzędy",
+    "walec" => "walce",
+    "warunek" => "warunki",
+    "wąż" => "węże",
+    "węgiel" => "węgle",
+    "węzeł" => "węzły",
+    "wieczór" => "wieczory",
+    "wieniec" => "wieńce",
+    "wizerunek" => "wizerunki",
+    "wniosek" => "wnioski",
+    "wół" => "woły",
+    "worek" => "worki",
+    "wóz" => "wozy",
+    "wschód" => "wschody",
+    "wtorek" => "wtorki",
+    "wybór" => "wybory",
+    "wydatek" => "wydatki",
+    "wyjątek" => "wyjątki",
+    "wypadek" => "wypadki",
+    "wzór" => "wzory",
+    "ząb" => "zęby",
+    "zachód" => "zachody",
+    "żagiel" => "żagle",
+    "zając" => "zające",
+    "zamek" => "zamki",
+    "zawód" => "zawody",
+    "zbiór" => "zbiory",
+    "znaczek" => "znaczki",
+    "żołądź" => "żołędzie",
+    "żłób" => "żłoby",
+    "związek" => "związki",
+    // Masculines in a soft consonant
+    "gwóźdź" => "gwoździe",
+    "liść" => "liście",
+    "nóż" => "noże",
+    "ogień" => "ognie",
+    "paznokieć" => "paznokcie",
+    "pokój" => "pokoje",
+    "łokieć" => "łokcie",
+    // Feminines
+    "bogini" => "boginie",
+    "brew" => "brwi",
+    "cerkiew" => "cerkwie",
+    "chorągiew" => "chorągwie",
+    "dłoń" => "dłonie",
+    "gospodyni" => "gospodynie",
+    "jabłoń" => "jabłonie",
+    "marchew" => "marchwie",
+    "mistrzyni" => "mistrzynie",
+    "moc" => "moce",
+    "myśl" => "myśli",
+    "mysz" => "myszy",
+    "nić" => "nici",
+    "noc" => "noce",
+    "odpowiedź" => "odpowiedzi",
+    "łódź" => "łodzie",
+    "pamięć" => "pamięci",
+    "pani" => "panie",
+    "pieśń" => "pieśni",
+    "pomoc" => "pomoce",
+    "postać" => "postacie",
+    "przyjaźń" => "przyjaźnie",
+    "rzecz" => "rzeczy",
+    "sieć" => "sieci",
+    "śmierć" => "śmierci",
+    "sól" => "sole",
+    "wieś" => "wsie",
+    // Neuters
+    "centrum" => "centra",
+};
+
+/// Genitive plurals of Polish nouns, mostly those in [`IRREGULARS`], which the rules in
+/// [`genitive_plural`] cannot derive (`człowiek` -> `ludzi`, `droga` -> `dróg`).
+static GENITIVE_PLURALS: Irregulars = irregulars! {
+    // Irregular plurals
+    "pieniądz" => "pieniędzy",
+    "przyjaciel" => "przyjaciół",
+    "brat" => "braci",
+    "człowiek" => "ludzi",
+    "dziecko" => "dzieci",
+    "dzień" => "dni",
+    "gość" => "gości",
+    "ksiądz" => "księży",
+    "oko" => "oczu",
+    "rok" => "lat",
+    "ręka" => "rąk",
+    "tydzień" => "tygodni",
+    "ucho" => "uszu",
+    // Neuters in -ę
+    "cielę" => "cieląt",
+    "imię" => "imion",
+    "książę" => "książąt",
+    "kurczę" => "kurcząt",
+    "niemowlę" => "niemowląt",
+    "plemię" => "plemion",
+    "ramię" => "ramion",
+    "zwierzę" => "zwierząt",
+    // Masculine personal nouns in -owie
+    "bóg" => "bogów",
+    "członek" => "członków",
+    "dziadek" => "dziadków",
+    "hrabia" => "hrabiów",
+    "król" => "królów",
+    "marszałek" => "marszałków",
+    "mąż" => "mężów",
+    "minister" => "ministrów",
+    "mistrz" => "mistrzów",
+    "monarcha" => "monarchów",
+    "ojciec" => "ojców",
+    "pradziadek" => "pradziadków",
+    "sędzia" => "sędziów",
+    "szwagier" => "szwagrów",
+    "teść" => "teściów",
+    "uczeń" => "uczniów",
+    "wódz" => "wodzów",
+    "wróg" => "wrogów",
+    "wujek" => "wujków",
+    "zięć" => "zięciów",
+    // Masculine personal nouns with consonant alternation
+    "artysta" => "artystów",
+    "dentysta" => "dentystów",
+    "turysta" => "turystów",
+    "afrykanin" => "afrykanów",
+    "amerykanin" => "amerykanów",
+    "arystokrata" => "arystokratów",
+    "astronauta" => "astronautów",
+    "atleta" => "atletów",
+    "biurokrata" => "biurokratów",
+    "chłopiec" => "chłopców",
+    "chrześcijanin" => "chrześcijan",
+    "demokrata" => "demokratów",
+    "dominikanin" => "dominikanów",
+    "dworzanin" => "dworzan",
+    "dyplomata" => "dyplomatów",
+    "franciszkanin" => "franciszkanów",
+    "goniec" => "gońców",
+    "idiota" => "idiotów",
+    "kapral" => "kapralów",
+    "kierowca" => "kierowców",
+    "kolega" => "kolegów",
+    "krakowianin" => "krakowian",
+    "kupiec" => "kupców",
+    "logopeda" => "logopedów",
+    "meksykanin" => "meksykanów",
+    "mieszczanin" => "mieszczan",
+    "mieszkaniec" => "mieszkańców",
+    "niemiec" => "niemców",
+    "parafianin" => "parafian",
+    "patriota" => "patriotów",
+    "pediatra" => "pediatrów",
+    "poeta" => "poetów",
+    "psychiatra" => "psychiatrów",
+    "rosjanin" => "rosjan",
+    "słowianin" => "słowian",
+    "sprzedawca" => "sprzedawców",
+    "starzec" => "starców",
+    "szaleniec" => "szaleńców",
+    "warszawianin" => "warszawian",
+    "ziemianin" => "ziemian",
+    // Masculines with vowel alternation and mobile -e-
+    "łabędź" => "łabędzi",
+    "błąd" => "błędów",
+    "bęben" => "bębnów",
+    "budynek" => "budynków",
+    "cień" => "cieni",
+    "cukierek" => "cukierków",
+    "czwartek" => "czwartków",
+    "dąb" => "dębów",
+    "diabeł" => "diabłów",
+    "dół" => "dołów",
+    "dodatek" => "dodatków",
+    "domek" => "domków",
+    "dowód" => "dowodów",
+    "dwór" => "dworów",
+    "dworzec" => "dworców",
+    "dziób" => "dziobów",
+    "dzwonek" => "dzwonków",
+    "łeb" => "łbów",
+    "gałąź" => "gałęzi",
+    "gatunek" => "gatunków",
+    "gołąb" => "gołębi",
+    "grób" => "grobów",
+    "kabel" => "kabli",
+    "kawałek" => "kawałków",
+    "kieł" => "kłów",
+    "kierunek" => "kierunków",
+    "kocioł" => "kotłów",
+    "koniec" => "końców",
+    "kościół" => "kościołów",
+    "kotek" => "kotków",
+    "kozioł" => "kozłów",
+    "krąg" => "kręgów",
+    "krój" => "krojów",
+    "kubek" => "kubków",
+    "kwiatek" => "kwiatków",
+    "lew" => "lwów",
+    "lód" => "lodów",
+    "majątek" => "majątków",
+    "mebel" => "mebli",
+    "mech" => "mchów",
+    "miesiąc" => "miesięcy",
+    "miód" => "miodów",
+    "mróz" => "mrozów",
+    "naród" => "narodów",
+    "nastrój" => "nastrojów",
+    "obowiązek" => "obowiązków",
+    "obóz" => "obozów",
+    "obrazek" => "obrazków",
+    "obrząd" => "obrzędów",
+    "ogórek" => "ogórków",
+    "ogród" => "ogrodów",
+    "ołówek" => "ołówków",
+    "orzeł" => "orłów",
+    "osioł" => "osłów",
+    "otwór" => "otworów",
+    "owies" => "owsów",
+    "palec" => "palców",
+    "piątek" => "piątków",
+    "pień" => "pni",
+    "pies" => "psów",
+    "piesek" => "piesków",
+    "początek" => "początków",
+    "podatek" => "podatków",
+    "poniedziałek" => "poniedziałków",
+    "popiół" => "popiołów",
+    "powód" => "powodów",
+    "próg" => "progów",
+    "przewód" => "przewodów",
+    "przypadek" => "przypadków",
+    "przystanek" => "przystanków",
+    "rachunek" => "rachunków",
+    "róg" => "rogów",
+    "rynek" => "rynków",
+    "rząd" => "rzędów",
+    "samochód" => "samochodów",
+    "sen" => "snów",
+    "spór" => "sporów",
+    "sposób" => "sposobów",
+    "stół" => "stołów",
+    "stóg" => "stogów",
+    "stopień" => "stopni",
+    "stosunek" => "stosunków",
+    "strój" => "strojów",
+    "szew" => "szwów",
+    "taniec" => "tańców",
+    "tysiąc" => "tysięcy",
+    "ubiór" => "ubiorów",
+    "urząd" => "urzędów",
+    "walec" => "walców",
+    "warunek" => "warunków",
+    "wąż" => "węży",
+    "węgiel" => "węgli",
+    "węzeł" => "węzłów",
+    "wieczór" => "wieczorów",
+    "wieniec" => "wieńców",
+    "wizerunek" => "wizerunków",
+    "wniosek" => "wniosków",
+    "wół" => "wołów",
+    "worek" => "worków",
+    "wóz" => "wozów",
+    "wschód" => "wschodów",
+    "wtorek" => "wtorków",
+    "wybór" => "wyborów",
+    "wydatek" => "wydatków",
+    "wyjątek" => "wyjątków",
+    "wypadek" => "wypadków",
+    "wzór" => "wzorów",
+    "ząb" => "zębów",
+    "zachód" => "zachodów",
+    "żagiel" => "żagli",
+    "zając" => "zajęcy",
+    "zamek" => "zamków",
+    "zawód" => "zawodów",
+    "zbiór" => "zbiorów",
+    "znaczek" => "znaczków",
+    "żołądź" => "żołędzi",
+    "żłób" => "żłobów",
+    "związek" => "związków",
+    // Masculines in a soft consonant
+    "cel" => "celów",
+    "kamień" => "kamieni",
+    "koń" => "koni",
+    "gwóźdź" => "gwoździ",
+    "liść" => "liści",
+    "nóż" => "noży",
+    "ogień" => "ogni",
+    "paznokieć" => "paznokci",
+    "pokój" => "pokoi",
+    "łokieć" => "łokci",
+    // Feminines
+    "część" => "części",
+    "córka" => "córek",
+    "deska" => "desek",
+    "droga" => "dróg",
+    "głowa" => "głów",
+    "kość" => "kości",
+    "krowa" => "krów",
+    "książka" => "książek",
+    "matka" => "matek",
+    "noga" => "nóg",
+    "osoba" => "osób",
+    "panna" => "panien",
+    "pszczoła" => "pszczół",
+    "siostra" => "sióstr",
+    "sobota" => "sobót",
+    "sosna" => "sosen",
+    "szkoła" => "szkół",
+    "wiadomość" => "wiadomości",
+    "woda" => "wód",
+    "wojna" => "wojen",
+    "łza" => "łez",
+    "bogini" => "bogiń",
+    "brew" => "brwi",
+    "cerkiew" => "cerkwi",
+    "chorągiew" => "chorągwi",
+    "dłoń" => "dłoni",
+    "gospodyni" => "gospodyń",
+    "jabłoń" => "jabłoni",
+    "marchew" => "marchwi",
+    "mistrzyni" => "mistrzyń",
+    "moc" => "mocy",
+    "nić" => "nici",
+    "noc" => "nocy",
+    "odpowiedź" => "odpowiedzi",
+    "łódź" => "łodzi",
+    "pamięć" => "pamięci",
+    "pani" => "pań",
+    "pieśń" => "pieśni",
+    "pomoc" => "pomocy",
+    "postać" => "postaci",
+    "przyjaźń" => "przyjaźni",
+    "sieć" => "sieci",
+    "śmierć" => "śmierci",
+    "sól" => "soli",
+    "wieś" => "wsi",
+    // Neuters
+    "jajko" => "jajek",
+    "koło" => "kół",
+    "krzesło" => "krzeseł",
+    "lustro" => "luster",
+    "morze" => "mórz",
+    "okno" => "okien",
+    "pole" => "pól",
+    "radio" => "radiów",
+    "słowo" => "słów",
+    "łóżko" => "łóżek",
+    "święto" => "świąt",
+};
+
+/// Genitive singulars of Polish nouns, mostly those in [`IRREGULARS`], which the rules in
+/// [`genitive_singular`] cannot derive: mobile `-e-` and vowel alternations (`dzień` -> `dnia`,
+/// `pies` -> `psa`, `stół` -> `stołu`), inanimate masculines in `-a`, feminines in a consonant and
+/// phrases.
+static GENITIVE_SINGULARS: Irregulars = irregulars! {
+    // Irregular plurals
+    "pieniądz" => "pieniądza",
+    "dzień" => "dnia",
+    "gość" => "gościa",
+    "ksiądz" => "księdza",
+    "tydzień" => "tygodnia",
+    "cielę" => "cielęcia",
+    // Masculine personal nouns
+    "członek" => "członka",
+    "dziadek" => "dziadka",
+    "hrabia" => "hrabiego",
+    "marszałek" => "marszałka",
+    "mąż" => "męża",
+    "minister" => "ministra",
+    "ojciec" => "ojca",
+    "pradziadek" => "pradziadka",
+    "sędzia" => "sędziego",
+    "szwagier" => "szwagra",
+    "teść" => "teścia",
+    "uczeń" => "ucznia",
+    "wódz" => "wodza",
+    "wróg" => "wroga",
+    "wujek" => "wujka",
+    "zięć" => "zięcia",
+    "afrykanin" => "afrykanina",
+    "amerykanin" => "amerykanina",
+    "chłopiec" => "chłopca",
+    "chrześcijanin" => "chrześcijanina",
+    "dominikanin" => "dominikanina",
+    "dworzanin" => "dworzanina",
+    "franciszkanin" => "franciszkanina",
+    "goniec" => "gońca",
+    "kapral" => "kaprala",
+    "krakowianin" => "krakowianina",
+    "kupiec" => "kupca",
+    "meksykanin" => "meksykanina",
+    "mieszczanin" => "mieszczanina",
+    "mieszkaniec" => "mieszkańca",
+    "niemiec" => "niemca",
+    "obywatel" => "obywatela",
+    "parafianin" => "parafianina",
+    "rosjanin" => "rosjanina",
+    "słowianin" => "słowianina",
+    "starzec" => "starca",
+    "szaleniec" => "szaleńca",
+    "warszawianin" => "warszawianina",
+    "ziemianin" => "ziemianina",
+    // Masculines with vowel alternation and mobile -e-
+    "anioł" => "anioła",
+    "łabędź" => "łabędzia",
+    "błąd" => "błędu",
+    "bęben" => "bębna",
+    "cień" => "cienia",
+    "cukierek" => "cukierka",
+    "dąb" => "dębu",
+    "diabeł" => "diabła",
+    "dół" => "dołu",
+    "dowód" => "dowodu",
+    "dwór" => "dworu",
+    "dworzec" => "dworca",
+    "dziób" => "dzioba",
+    "dzwonek" => "dzwonka",
+    "łeb" => "łba",
+    "gałąź" => "gałęzi",
+    "gołąb" => "gołębia",
+    "grób" => "grobu",
+    "kabel" => "kabla",
+    "kawałek" => "kawałka",
+    "kieł" => "kła",
+    "kocioł" => "kotła",
+    "koniec" => "końca",
+    "kościół" => "kościoła",
+    "kotek" => "kotka",
+    "kozioł" => "kozła",
+    "krąg" => "kręgu",
+    "krój" => "kroju",
+    "kubek" => "kubka",
+    "kwiatek" => "kwiatka",
+    "lew" => "lwa",
+    "lód" => "lodu",
+    "mebel" => "mebla",
+    "mech" => "mchu",
+    "miesiąc" => "miesiąca",
+    "miód" => "miodu",
+    "mróz" => "mrozu",
+    "naród" => "narodu",
+    "nastrój" => "nastroju",
+    "obóz" => "obozu",
+    "obrazek" => "obrazka",
+    "obrząd" => "obrzędu",
+    "ogórek" => "ogórka",
+    "ogród" => "ogrodu",
+    "ołówek" => "ołówka",
+    "orzeł" => "orła",
+    "osioł" => "osła",
+    "otwór" => "otworu",
+    "owies" => "owsa",
+    "pień" => "pnia",
+    "pies" => "psa",
+    "piesek" => "pieska",
+    "popiół" => "popiołu",
+    "powód" => "powodu",
+    "próg" => "progu",
+    "przewód" => "przewodu",
+    "róg" => "rogu",
+    "rząd" => "rzędu",
+    "samochód" => "samochodu",
+    "sen" => "snu",
+    "spór" => "sporu",
+    "sposób" => "sposobu",
+    "stół" => "stołu",
+    "stóg" => "stogu",
+    "stopień" => "stopnia",
+    "strój" => "stroju",
+    "szew" => "szwu",
+    "taniec" => "tańca",
+    "tysiąc" => "tysiąca",
+    "ubiór" => "ubioru",
+    "urząd" => "urzędu",
+    "wąż" => "węża",
+    "węgiel" => "węgla",
+    "węzeł" => "węzła",
+    "wieczór" => "wieczoru",
+    "wieniec" => "wieńca",
+    "wół" => "woła",
+    "worek" => "worka",
+    "wóz" => "wozu",
+    "wschód" => "wschodu",
+    "wybór" => "wyboru",
+    "wzór" => "wzoru",
+    "ząb" => "zęba",
+    "zachód" => "zachodu",
+    "żagiel" => "żagla",
+    "zając" => "zająca",
+    "zawód" => "zawodu",
+    "zbiór" => "zbioru",
+    "znaczek" => "znaczka",
+    "żołądź" => "żołędzia",
+    "żłób" => "żłobu",
+    // Masculines in a soft consonant
+    "kamień" => "kamienia",
+    "koń" => "konia",
+    "parasol" => "parasola",
+    "szpital" => "szpitala",
+    "gwóźdź" => "gwoździa",
+    "liść" => "liścia",
+    "nóż" => "noża",
+    "ogień" => "ognia",
+    "paznokieć" => "paznokcia",
+    "łokieć" => "łokcia",
+    // Feminines in a consonant
+    "twarz" => "twarzy",
+    "bogini" => "bogini",
+    "brew" => "brwi",
+    "cerkiew" => "cerkwi",
+    "chorągiew" => "chorągwi",
+    "dłoń" => "dłoni",
+    "gospodyni" => "gospodyni",
+    "jabłoń" => "jabłoni",
+    "marchew" => "marchwi",
+    "mistrzyni" => "mistrzyni",
+    "moc" => "mocy",
+    "myśl" => "myśli",
+    "mysz" => "myszy",
+    "nić" => "nici",
+    "noc" => "nocy",
+    "odpowiedź" => "odpowiedzi",
+    "łódź" => "łodzi",
+    "pamięć" => "pamięci",
+    "pani" => "pani",
+    "pieśń" => "pieśni",
+    "pomoc" => "pomocy",
+    "postać" => "postaci",
+    "przyjaźń" => "przyjaźni",
+    "rzecz" => "rzeczy",
+    "sieć" => "sieci",
+    "śmierć" => "śmierci",
+    "sól" => "soli",
+    "wieś" => "wsi",
+};
+
+/// Feminine Polish nouns in `-a` with a hard or velar stem, whose plural in `-y` or `-i` cannot be
+/// told apart from that of a masculine noun (`strony` -> `strona`, `drogi` -> `droga`, but
+/// `produkty` -> `produkt`, `pliki` -> `plik`).
+static FEMININES: phf::Set<&'static str> = phf_set! {
+    "ankieta", "apteka", "biblioteka", "cena", "data", "domena", "droga", "fabryka", "firma",
+    "forma", "godzina", "gra", "grafika", "grupa", "gwiazda", "głowa", "góra", "ikona",
+    "karta", "klasa", "kobieta", "kolumna", "krowa", "księga", "kwota", "liczba", "lista",
+    "logika", "mapa", "maszyna", "metoda", "minuta", "muzyka", "nagroda", "nauka", "nazwa",
+    "noga", "norma", "ocena", "oferta", "opłata", "osoba", "panna", "para", "platforma",
+    "polityka", "powieka", "praktyka", "pszczoła", "płyta", "reguła", "rodzina", "rzeka",
+    "sekunda", "siostra", "sobota", "sosna", "sprawa", "statystyka", "strefa", "strona",
+    "suma", "szansa", "szkoła", "sztuka", "technika", "umowa", "uwaga", "waluta", "witryna",
+    "wizyta", "woda", "wojna", "zasada", "zmiana", "łza", "żona",
+};
+
+/// Neuter Polish nouns in `-o` and `-e`, whose plural in `-a` cannot be told apart from a
+/// feminine singular (`miasta` -> `miasto`, `pola` -> `pole`).
+static NEUTERS: phf::Set<&'static str> = phf_set! {
+    "auto", "biuro", "biurko", "boisko", "ciasto", "ciało", "drzewo", "dzieło", "gniazdo",
+    "hasło", "jabłko", "jajko", "jezioro", "kino", "konto", "koło", "krzesło", "lotnisko",
+    "lustro", "miasto", "miejsce", "morze", "narzędzie", "nazwisko", "niebo", "okno",
+    "piętro", "pismo", "piwo", "pióro", "pole", "prawo", "pudełko", "radio", "serce",
+    "stanowisko", "słowo", "słońce", "wiadro", "wojsko", "wybrzeże", "zjawisko", "łóżko",
+    "środowisko", "święto",
+};
+
+/// Masculine Polish nouns in `-l` and `-ń`, whose plural in `-le` and `-nie` would be read as
+/// that of a feminine in `-la` or `-nia` or a neuter in `-enie` (`cele` -> `cel`, `konie` ->
+/// `koń`, `kamienie` -> `kamień`).
+static SOFT_MASCULINES: phf::Set<&'static str> = phf_set! {
+    "cel", "fotel", "hotel", "jeleń", "kamień", "koń", "korzeń", "medal", "model", "motyl",
+    "panel", "parasol", "piksel", "portal", "profil", "promień", "płomień", "słoń",
+    "spektakl", "styl", "symbol", "szpital", "tunel",
+};
+
+/// Masculine Polish nouns for people without an ending in [`PERSONAL_ENDINGS`], which take the
+/// masculine personal plural (`czech` -> `czesi`, `szkot` -> `szkoci`, `pracownik` ->
+/// `pracownicy`).
+static PERSONS: phf::Set<&'static str> = phf_set! {
+    "adwokat", "aktor", "anglik", "architekt", "autor", "biskup", "chemik", "chirurg",
+    "chłop", "czech", "demokrat", "doktor", "dyrektor", "dziekan", "ekspert", "elektryk", "fizyk",
+    "francuz", "grek", "historyk", "informatyk", "inspektor", "instruktor", "kandydat", "kapelan",
+    "kierownik", "krytyk", "lektor", "matematyk", "mechanik", "mnich", "muzyk", "operator", "pilot",
+    "polak", "policjant", "polityk", "pracownik", "prezydent", "redaktor", "rolnik", "student",
+    "szkot", "szwed", "technik", "uczestnik", "urzędnik", "użytkownik", "weteran", "włoch",
+    "zawodnik",
+};
+
+/// Masculine Polish nouns for animals, and for people whose plural does not mark them as persons
+/// (`brat` -> `bracia`), which take `-a` in the genitive singular like other nouns for people
+/// (`kot` -> `kota`, `człowiek` -> `człowieka`).
+static ANIMATES: phf::Set<&'static str> = phf_set! {
+    "baran", "bażant", "borsuk", "brat", "byk", "chomik", "chłopak", "człowiek", "delfin",
+    "dorsz", "dzik", "gepard", "goryl", "homar", "indyk", "jeleń", "jeż", "karp", "kogut",
+    "komar", "kot", "kret", "krokodyl", "królik", "kruk", "kurczak", "lis", "łoś", "łosoś",
+    "motyl", "niedźwiedź", "nietoperz", "owad", "pająk", "pingwin", "pstrąg", "ptak", "rekin",
+    "robak", "ryś", "słoń", "smok", "struś", "syn", "szczupak", "szczur", "szympans",
+    "ślimak", "tygrys", "wielbłąd", "wieloryb", "wilk", "żubr", "żuk",
+};
+
+/// Polish nouns that only have a plural (`drzwi`, `spodnie`), whose endings would otherwise be
+/// stripped as plural endings.
+static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "chrzciny", "drożdże", "drzwi", "dzieje", "ferie", "finanse", "grabie", "imieniny",
+        "kajdanki", "majtki", "nosze", "nożyce", "nożyczki", "obcęgi", "okulary", "perfumy",
+        "plecy", "rajstopy", "sanie", "sanki", "skrzypce", "spodnie", "szachy", "urodziny", "usta",
+        "wakacje", "warcaby", "wczasy", "widły", "wrota", "zaręczyny", "zwłoki",
+    },
+    uncountable_suffixes: &[],
+    invariant_suffixes: &[],
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "pl",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    count_form_fn: Some(count_form),
+    singularize_candidates_fn: Some(singularize_candidates),
+    number_fn: Some(number),
+    irregulars: Some(&IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("pl", singularize, pluralize)
 };
 
+/// Endings of masculine nouns that usually denote people and take the masculine personal plural
+/// (`student` -> `studenci`, `biolog` -> `biolodzy`).
+const PERSONAL_ENDINGS: &[&str] = &["ent", "ant", "log"];
+
+/// Endings in [`PERSONAL_ENDINGS`] of nouns that denote things (`dokument` -> `dokumenty`).
+const IMPERSONAL_ENDINGS: &[&str] = &["ment"];
+
+/// Stem-final consonants of masculine personal nouns with the plural endings that replace them
+/// (`Polak` -> `Polacy`, `autor` -> `autorzy`, `student` -> `studenci`, `Czech` -> `Czesi`).
+///
+/// Read in reverse by [`singularize`], so endings that end another ending come first.
+const PERSONAL_ALTERNATIONS: &[(&str, &str)] = &[
+    ("ch", "si"),
+    ("st", "ści"),
+    ("k", "cy"),
+    ("g", "dzy"),
+    ("r", "rzy"),
+    ("d", "dzi"),
+    ("t", "ci"),
+    ("z", "zi"),
+    ("n", "ni"),
+    ("p", "pi"),
+    ("b", "bi"),
+    ("f", "fi"),
+    ("w", "wi"),
+    ("m", "mi"),
+];
+
+/// Endings of neuter verbal nouns in the singular (`zadanie`, `ustawienie`, `zdjęcie`, `życie`),
+/// which would otherwise be read as feminine plurals in `-ie`.
+const NEUTER_ENDINGS: &[&str] = &["anie", "enie", "ęcie", "ycie"];
+
+/// Consonants after which a stem is soft or functionally soft and takes `-e` in the plural
+/// (`klucz` -> `klucze`, `ulica` -> `ulice`).
+const SOFT_ENDINGS: &[&str] = &["c", "cz", "dz", "dż", "rz", "sz", "ż", "j", "l"];
+
+/// Soft consonants written with an acute accent at the end of a word, and with a following `i`
+/// before the plural ending (`koń` -> `konie`, `gałąź` -> `gałęzie`).
+const SOFTENED: &[(&str, &str)] = &[
+    ("dź", "dzi"),
+    ("ć", "ci"),
+    ("ń", "ni"),
+    ("ś", "si"),
+    ("ź", "zi"),
+];
+
+/// Returns `true` if `ch` is a Polish vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "aąeęioóuy".contains(ch)
+}
+
+/// Returns `true` if the stem ends in a soft or functionally soft consonant.
+fn is_soft(stem: &str) -> bool {
+    SOFT_ENDINGS.iter().any(|ending| stem.ends_with(ending))
+}
+
+/// Returns the nominative plural of a hard, soft or velar stem that does not denote people:
+/// `-e` after soft consonants, `-i` after `k` and `g`, and `-y` otherwise.
+fn impersonal_plural(stem: &str) -> String {
+    if is_soft(stem) {
+        format!("{stem}e")
+    } else if stem.ends_with(['k', 'g']) {
+        format!("{stem}i")
+    } else {
+        format!("{stem}y")
+    }
+}
+
+/// Returns the masculine personal plural of a noun, which alternates its final consonant
+/// (`student` -> `studenci`, `Polak` -> `Polacy`), or `None` for soft stems, which take `-e`.
+fn personal_plural(stem: &str) -> Option<String> {
+    if is_soft(stem) {
+        return None;
+    }
+    PERSONAL_ALTERNATIONS
+        .iter()
+        .find_map(|&(consonant, ending)| {
+            let base = stem.strip_suffix(consonant)?;
+            Some(format!("{base}{ending}"))
+        })
+}
+
+/// Returns the singular of a plural in [`SOFT_MASCULINES`] (`cele` -> `cel`, `konie` -> `koń`).
+fn soft_masculine(name: &str) -> Option<Cow<'_, str>> {
+    let stem = strip(name, "e")?;
+    let singular = match strip(stem, "ni") {
+        Some(stem) => format!("{stem}ń").into(),
+        None => Cow::Borrowed(stem),
+    };
+    SOFT_MASCULINES.contains(&*singular).then_some(singular)
+}
+
 /// Converts a plural Polish noun to its singular form.
 ///
-/// Handles `-y` and `-i` plural suffixes.
+/// Handles irregular nouns in the nominative and genitive plural (`ludzie`, `ludzi` ->
+/// `człowiek`), `-owie` and the genitive `-ów`, and reverses the consonant alternations of
+/// masculine personal nouns (`studenci` -> `student`, `Polacy` -> `Polak`, `autorzy` -> `autor`).
+/// Soft stems in `-je` and `-ie` after a consonant are read as feminines in `-ja` and `-ia`
+/// (`funkcje` -> `funkcja`), other soft stems as masculines (`klucze` -> `klucz`). Neuter verbal
+/// nouns in `-nia` and `-cia` become `-nie` and `-cie` (`ustawienia` -> `ustawienie`), and are
+/// returned unchanged in the singular. Known masculines in `-l` and `-ń` and known neuters are
+/// restored (`konie` -> `koń`, `miasta` -> `miasto`), known feminines replace `-y` and `-i` with
+/// `-a` (`strony` -> `strona`, `drogi` -> `droga`), and other nouns drop the `-y` and `-i` plural
+/// suffixes.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
-    if let Some(stem) = name.strip_suffix('y')
-        && !stem.is_empty()
+    if let Some(singular) = IRREGULARS
+        .singular(name)
+        .or_else(|| GENITIVE_PLURALS.singular(name))
     {
+        return Cow::Borrowed(singular);
+    }
+    singularize_regular(name)
+}
+
+/// Applies the suffix rules of [`singularize`] without looking up irregular nouns.
+fn singularize_regular(name: &str) -> Cow<'_, str> {
+    if let Some(stem) = strip(name, "owie").or_else(|| strip(name, "ów")) {
         return Cow::Borrowed(stem);
     }
-    if let Some(stem) = name.strip_suffix('i')
-        && !stem.is_empty()
+    if let Some(singular) = soft_masculine(name) {
+        return singular;
+    }
+    if let Some(stem) = strip(name, "a")
+        && let Some(singular) = ["o", "e"]
+            .iter()
+            .map(|ending| format!("{stem}{ending}"))
+            .find(|singular| NEUTERS.contains(singular.as_str()))
+    {
+        return singular.into();
+    }
+    if NEUTER_ENDINGS.iter().any(|ending| name.ends_with(ending)) {
+        return Cow::Borrowed(name);
+    }
+    for (plural, singular) in [
+        ("ości", "ość"),
+        ("iści", "ista"),
+        ("yści", "ysta"),
+        ("eci", "eć"),
+        ("icy", "ik"),
+        ("ycy", "yk"),
+        ("acy", "ak"),
+        ("cy", "ca"),
+        ("ania", "anie"),
+        ("enia", "enie"),
+        ("ęcia", "ęcie"),
+        ("ycia", "ycie"),
+        ("ea", "eum"),
+    ] {
+        if let Some(stem) = strip(name, plural) {
+            return format!("{stem}{singular}").into();
+        }
+    }
+    if let Some((stem, consonant)) = PERSONAL_ALTERNATIONS
+        .iter()
+        .find_map(|&(consonant, ending)| Some((strip(name, ending)?, consonant)))
+    {
+        return format!("{stem}{consonant}").into();
+    }
+    if let Some(stem) = strip(name, "e") {
+        // Feminines in `-ja`, `-ia`, `-ca` and `-la` are more common than masculines in a
+        // consonant followed by these letters, except after a vowel (`kraje` -> `kraj`) and
+        // for nouns in `-ciel` (`nauczyciele` -> `nauczyciel`).
+        let feminine = (stem.ends_with(['j', 'i']) && !stem[..stem.len() - 1].ends_with(is_vowel))
+            || stem.ends_with('c')
+            || (stem.ends_with('l') && !stem.ends_with("ciel"));
+        if feminine {
+            return format!("{stem}a").into();
+        }
+        if is_soft(stem) {
+            return Cow::Borrowed(stem);
+        }
+    }
+    if let Some(stem) = strip(name, "ki")
+        && !stem.ends_with(is_vowel)
+        && !stem.ends_with('n')
+    {
+        // Consonant clusters before `-ki` mostly belong to feminines in `-ka` (`wtyczki` ->
+        // `wtyczka`), while masculines end in a vowel and `-k` (`pliki` -> `plik`).
+        return format!("{stem}ka").into();
+    }
+    if let Some(stem) =
+        strip(name, "y").or_else(|| strip(name, "i").filter(|stem| stem.ends_with(['k', 'g'])))
     {
+        let feminine = format!("{stem}a");
+        if FEMININES.contains(&*feminine) {
+            return feminine.into();
+        }
+    }
+    if let Some(stem) = strip(name, "y") {
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = strip(name, "i") {
         return Cow::Borrowed(stem);
     }
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a Polish noun.
+/// Returns the possible singular forms of a Polish noun, most likely first.
+///
+/// Starts with the result of [`singularize`], followed by the other readings of a nominative
+/// plural: `-y`, `-i` after a velar and `-e` after a soft consonant may belong to a feminine in
+/// `-a` or a masculine (`strony` -> `strona`, `stron`, `klucze` -> `klucz`, `klucza`). Irregular
+/// nouns and neuter verbal nouns only have the result of [`singularize`].
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    let mut candidates = vec![singularize(name)];
+    if IRREGULARS.singular(name).is_some()
+        || GENITIVE_PLURALS.singular(name).is_some()
+        || soft_masculine(name).is_some()
+        || NEUTER_ENDINGS.iter().any(|ending| name.ends_with(ending))
+    {
+        return candidates;
+    }
+    let stem = strip(name, "y")
+        .or_else(|| strip(name, "i").filter(|stem| stem.ends_with(['k', 'g'])))
+        .or_else(|| strip(name, "e").filter(|stem| is_soft(stem) || stem.ends_with('i')));
+    if let Some(stem) = stem {
+        candidates.push(format!("{stem}a").into());
+        candidates.push(Cow::Borrowed(stem));
+    }
+    candidates
+}
+
+/// Returns a list of possible plural forms for a Polish noun, most likely first.
+///
+/// Irregular nouns are looked up first (`człowiek` -> `ludzie`, `pies` -> `psy`). Neuters in
+/// `-o`, `-e` and `-um` take `-a` and neuters in `-ę` take `-ęta`, feminines in `-a` take `-y`,
+/// `-i` after velars and `-e` after soft consonants (`ulica` -> `ulice`, `stacja` -> `stacje`),
+/// and feminines in `-ść` take `-ści`. Masculines take `-y`, `-i` or `-e` by the same rules
+/// (`plik` -> `pliki`, `klucz` -> `klucze`). Known nouns for people take the masculine personal
+/// plural (`czech` -> `czesi`, `szkot` -> `szkoci`), and those ending like them prefer it
+/// (`student` -> `studenci`, `turysta` -> `turyści`).
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    vec![format!("{name}y").into(), format!("{name}i").into()]
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    pluralize_regular(name)
+}
+
+/// Applies the suffix rules of [`pluralize`] without looking up irregular nouns.
+fn pluralize_regular(name: &str) -> Vec<Cow<'_, str>> {
+    if let Some(stem) = strip(name, "um").or_else(|| strip(name, "o")) {
+        return vec![format!("{stem}a").into()];
+    }
+    if let Some(stem) = strip(name, "e") {
+        return vec![format!("{stem}a").into()];
+    }
+    if let Some(stem) = strip(name, "ę") {
+        return vec![format!("{stem}ęta").into()];
+    }
+    if let Some(stem) = strip(name, "ść") {
+        return vec![format!("{stem}ści").into()];
+    }
+    if let Some(stem) = strip(name, "ista").or_else(|| strip(name, "ysta")) {
+        let vowel = &name[stem.len()..=stem.len()];
+        return vec![format!("{stem}{vowel}ści").into()];
+    }
+    if let Some(stem) = strip(name, "a") {
+        if let Some(stem) = stem.strip_suffix('j').or_else(|| stem.strip_suffix('i')) {
+            let glide = &name[stem.len()..=stem.len()];
+            return vec![format!("{stem}{glide}e").into()];
+        }
+        return vec![impersonal_plural(stem).into()];
+    }
+    if let Some((stem, soft)) = SOFTENED
+        .iter()
+        .find_map(|&(letter, soft)| Some((strip(name, letter)?, soft)))
+    {
+        return vec![format!("{stem}{soft}e").into()];
+    }
+    if !name.ends_with(|ch: char| ch.is_alphabetic() && !is_vowel(ch)) {
+        return vec![Cow::Borrowed(name)];
+    }
+    let impersonal = impersonal_plural(name).into();
+    let personal = PERSONAL_ENDINGS.iter().any(|ending| name.ends_with(ending))
+        && !IMPERSONAL_ENDINGS
+            .iter()
+            .any(|ending| name.ends_with(ending));
+    match personal_plural(name) {
+        Some(plural) if PERSONS.contains(name) => vec![plural.into()],
+        Some(plural) if personal => vec![plural.into(), impersonal],
+        _ => vec![impersonal],
+    }
+}
+
+/// Returns the genitive singular of a Polish noun (`plik` -> `pliku`, `strona` -> `strony`,
+/// `student` -> `studenta`).
+fn genitive_singular(name: &str) -> Cow<'_, str> {
+    if let Some(genitive) = GENITIVE_SINGULARS.plural(name) {
+        return Cow::Borrowed(genitive);
+    }
+    if let Some(stem) = strip(name, "ja").or_else(|| strip(name, "ia")) {
+        let ending = if name.ends_with("ja") { "ji" } else { "ii" };
+        return format!("{stem}{ending}").into();
+    }
+    if let Some(stem) = strip(name, "a") {
+        let ending = if stem.ends_with(['k', 'g']) { "i" } else { "y" };
+        return format!("{stem}{ending}").into();
+    }
+    if let Some(stem) = strip(name, "o").or_else(|| strip(name, "e")) {
+        return format!("{stem}a").into();
+    }
+    if name.ends_with("um") {
+        return Cow::Borrowed(name);
+    }
+    if let Some(stem) = strip(name, "ść") {
+        return format!("{stem}ści").into();
+    }
+    if let Some(stem) = strip(name, "ek")
+        && !stem.ends_with(is_vowel)
+    {
+        return format!("{stem}ku").into();
+    }
+    if let Some(stem) = strip(name, "ec")
+        && !stem.ends_with(is_vowel)
+    {
+        return format!("{stem}ca").into();
+    }
+    // Masculine nouns denoting people and animals take `-a`, most inanimate ones `-u`.
+    let ending = if is_personal(name) || ANIMATES.contains(name) {
+        "a"
+    } else {
+        "u"
+    };
+    if let Some((stem, soft)) = SOFTENED
+        .iter()
+        .find_map(|&(letter, soft)| Some((strip(name, letter)?, soft)))
+    {
+        return format!("{stem}{soft}{ending}").into();
+    }
+    format!("{name}{ending}").into()
+}
+
+/// Returns `true` if a masculine noun in a consonant denotes a person: its plural ends in `-owie`
+/// or is the masculine personal plural (`student` -> `studenci`), it is a known noun for people
+/// (`szkot`), or it ends like one (`-nik`, `-arz`, `-ciel`).
+fn is_personal(name: &str) -> bool {
+    if PERSONS.contains(name) {
+        return true;
+    }
+    let plural = IRREGULARS.plural(name);
+    plural.is_some_and(|plural| {
+        plural.ends_with("owie") || personal_plural(name).as_deref() == Some(plural)
+    }) || ["nik", "arz", "erz", "ciel"]
+        .iter()
+        .any(|ending| name.ends_with(ending))
+        || (PERSONAL_ENDINGS.iter().any(|ending| name.ends_with(ending))
+            && !IMPERSONAL_ENDINGS
+                .iter()
+                .any(|ending| name.ends_with(ending)))
+}
+
+/// Returns the genitive plural of a Polish noun (`plik` -> `plików`, `strona` -> `stron`).
+fn genitive_plural(name: &str) -> Cow<'_, str> {
+    if let Some(plural) = GENITIVE_PLURALS.plural(name) {
+        return Cow::Borrowed(plural);
+    }
+    if let Some(stem) = strip(name, "nie") {
+        return format!("{stem}ń").into();
+    }
+    if let Some(stem) = strip(name, "cie") {
+        return format!("{stem}ć").into();
+    }
+    if let Some(stem) = strip(name, "ja").or_else(|| strip(name, "ia")) {
+        let ending = if name.ends_with("ja") { "ji" } else { "ii" };
+        return format!("{stem}{ending}").into();
+    }
+    if let Some(stem) = strip(name, "um") {
+        return format!("{stem}ów").into();
+    }
+    if let Some(stem) = strip(name, "a")
+        .or_else(|| strip(name, "o"))
+        .or_else(|| strip(name, "e"))
+    {
+        return Cow::Borrowed(stem);
+    }
+    if ["cz", "sz", "rz", "ż"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+    {
+        return format!("{name}y").into();
+    }
+    if name.ends_with('l') {
+        return format!("{name}i").into();
+    }
+    format!("{name}ów").into()
+}
+
+/// Returns the form of a singular Polish noun required by a number.
+///
+/// Numbers ending in `2`-`4` (except the teens) take the nominative plural (`2 pliki`), other
+/// integers take the genitive plural (`5 plików`), and fractions take the genitive singular
+/// (`1,5 pliku`).
+pub(crate) fn count_form(name: &str, category: PluralCategory) -> Option<Cow<'_, str>> {
+    match category {
+        PluralCategory::Many => Some(genitive_plural(name)),
+        PluralCategory::Other => Some(genitive_singular(name)),
+        _ => None,
+    }
+}
+
+/// Detects the genitive plurals of Polish nouns (`plików`, `stron`, `zadań`), which the suffix
+/// rules alone would read as singulars.
+pub(crate) fn number(name: &str) -> Option<Number> {
+    number::genitive_plural_number(name, singularize, pluralize, genitive_plural)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::language_rules::LanguageRules;
 
     #[test]
     fn test_singularize() {
         assert_eq!(singularize("produkty"), "produkt");
-        assert_eq!(singularize("klienci"), "klienc");
+        assert_eq!(singularize("klienci"), "klient");
         assert_eq!(singularize("zamowieni"), "zamowien");
     }
 
     #[test]
     fn test_singularize_already_singular() {
         assert_eq!(singularize("produkt"), "produkt");
+        assert_eq!(singularize("zadanie"), "zadanie");
+        assert_eq!(singularize("ustawienie"), "ustawienie");
+        assert_eq!(singularize("zdjęcie"), "zdjęcie");
+        assert_eq!(singularize("kuchnia"), "kuchnia");
+    }
+
+    #[test]
+    fn test_singularize_feminines() {
+        assert_eq!(singularize("strony"), "strona");
+        assert_eq!(singularize("kobiety"), "kobieta");
+        assert_eq!(singularize("kolumny"), "kolumna");
+        assert_eq!(singularize("kuchnie"), "kuchnia");
     }
 
     #[test]
     fn test_pluralize() {
-        let result = pluralize("produkt");
-        assert!(result.iter().any(|v| v == "produkty"));
-        assert!(result.iter().any(|v| v == "produkti"));
+        assert_eq!(pluralize("produkt"), vec!["produkty"]);
+        assert_eq!(pluralize("plik"), vec!["pliki"]);
+        assert_eq!(pluralize("klucz"), vec!["klucze"]);
+        assert_eq!(pluralize("strona"), vec!["strony"]);
+        assert_eq!(pluralize("ręka"), vec!["ręce"]);
+        assert_eq!(pluralize("ulica"), vec!["ulice"]);
+        assert_eq!(pluralize("funkcja"), vec!["funkcje"]);
+        assert_eq!(pluralize("kategoria"), vec!["kategorie"]);
+        assert_eq!(pluralize("okno"), vec!["okna"]);
+        assert_eq!(pluralize("zadanie"), vec!["zadania"]);
+        assert_eq!(pluralize("wartość"), vec!["wartości"]);
+        assert_eq!(pluralize("koń"), vec!["konie"]);
+    }
+
+    #[test]
+    fn test_pluralize_personal() {
+        assert_eq!(pluralize("docent"), vec!["docenci", "docenty"]);
+        assert_eq!(pluralize("biolog")[0], "biolodzy");
+        assert_eq!(pluralize("specjalista"), vec!["specjaliści"]);
+        assert_eq!(pluralize("dokument"), vec!["dokumenty"]);
+        assert_eq!(personal_plural("polak").unwrap(), "polacy");
+        assert_eq!(personal_plural("autor").unwrap(), "autorzy");
+        assert_eq!(personal_plural("czech").unwrap(), "czesi");
+        assert_eq!(personal_plural("szwed").unwrap(), "szwedzi");
+        assert!(personal_plural("lekarz").is_none());
+    }
+
+    #[test]
+    fn test_pluralize_persons() {
+        assert_eq!(pluralize("czech"), vec!["czesi"]);
+        assert_eq!(pluralize("szkot"), vec!["szkoci"]);
+        assert_eq!(pluralize("francuz"), vec!["francuzi"]);
+        assert_eq!(pluralize("mnich"), vec!["mnisi"]);
+        assert_eq!(pluralize("pracownik"), vec!["pracownicy"]);
+        assert_eq!(RULES.pluralize("Czech"), vec!["Czesi"]);
+        for plural in ["czesi", "szkoci", "francuzi", "mnisi", "pracownicy"] {
+            assert_eq!(pluralize(&singularize(plural))[0], plural);
+        }
+        assert_eq!(genitive_singular("szkot"), "szkota");
+    }
+
+    #[test]
+    fn test_singularize_alternations() {
+        assert_eq!(singularize("docenci"), "docent");
+        assert_eq!(singularize("polacy"), "polak");
+        assert_eq!(singularize("autorzy"), "autor");
+        assert_eq!(singularize("biolodzy"), "biolog");
+        assert_eq!(singularize("czesi"), "czech");
+        assert_eq!(singularize("specjaliści"), "specjalista");
+        assert_eq!(singularize("kierowcy"), "kierowca");
+        assert_eq!(singularize("panowie"), "pan");
+        assert_eq!(singularize("plików"), "plik");
+        assert_eq!(singularize("wartości"), "wartość");
+        assert_eq!(singularize("sieci"), "sieć");
+    }
+
+    #[test]
+    fn test_singularize_soft_stems() {
+        assert_eq!(singularize("funkcje"), "funkcja");
+        assert_eq!(singularize("kategorie"), "kategoria");
+        assert_eq!(singularize("ulice"), "ulica");
+        assert_eq!(singularize("tabele"), "tabela");
+        assert_eq!(singularize("klucze"), "klucz");
+        assert_eq!(singularize("kraje"), "kraj");
+        assert_eq!(singularize("nauczyciele"), "nauczyciel");
+        assert_eq!(singularize("ustawienia"), "ustawienie");
+        assert_eq!(singularize("zdjęcia"), "zdjęcie");
+        assert_eq!(singularize("wtyczki"), "wtyczka");
+        assert_eq!(singularize("pliki"), "plik");
+        assert_eq!(singularize("banki"), "bank");
+    }
+
+    #[test]
+    fn test_pluralize_ranking() {
+        assert_eq!(pluralize("produkt")[0], "produkty");
+        assert_eq!(pluralize("bank")[0], "banki");
+    }
+
+    #[test]
+    fn test_irregulars_not_regular() {
+        let regular = IRREGULARS.regular_entries(singularize_regular, pluralize_regular);
+        assert!(regular.is_empty(), "{regular:?}");
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("ludzie"), "człowiek");
+        assert_eq!(singularize("ludzi"), "człowiek");
+        assert_eq!(singularize("psy"), "pies");
+        assert_eq!(singularize("stoły"), "stół");
+        assert_eq!(pluralize("dziecko"), vec!["dzieci"]);
+        assert_eq!(pluralize("brat"), vec!["bracia"]);
+        assert_eq!(
+            count_form("człowiek", PluralCategory::Many).unwrap(),
+            "ludzi"
+        );
+        assert_eq!(count_form("droga", PluralCategory::Many).unwrap(), "dróg");
+        assert_eq!(RULES.inflect_for_count("pies", 2), "psy");
+        assert_eq!(RULES.inflect_for_count("pies", 5), "psów");
+        assert_eq!(singularize("kwiatki"), "kwiatek");
+        assert_eq!(pluralize("rosjanin"), vec!["rosjanie"]);
+        assert_eq!(RULES.inflect_for_count("kwiatek", 5), "kwiatków");
+    }
+
+    #[test]
+    fn test_noun_sets() {
+        assert_eq!(singularize("drogi"), "droga");
+        assert_eq!(singularize("miasta"), "miasto");
+        assert_eq!(singularize("pola"), "pole");
+        assert_eq!(singularize("cele"), "cel");
+        assert_eq!(singularize("konie"), "koń");
+        assert_eq!(singularize("kamienie"), "kamień");
+        assert_eq!(pluralize("student"), vec!["studenci"]);
     }
 
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
     }
+
+    #[test]
+    fn test_count_form() {
+        assert_eq!(count_form("plik", PluralCategory::Many).unwrap(), "plików");
+        assert_eq!(count_form("plik", PluralCategory::Other).unwrap(), "pliku");
+        assert_eq!(count_form("strona", PluralCategory::Many).unwrap(), "stron");
+        assert_eq!(
+            count_form("strona", PluralCategory::Other).unwrap(),
+            "strony"
+        );
+        assert_eq!(
+            count_form("zadanie", PluralCategory::Many).unwrap(),
+            "zadań"
+        );
+        assert_eq!(
+            count_form("zdjęcie", PluralCategory::Many).unwrap(),
+            "zdjęć"
+        );
+        assert_eq!(
+            count_form("stacja", PluralCategory::Many).unwrap(),
+            "stacji"
+        );
+        assert_eq!(count_form("klucz", PluralCategory::Many).unwrap(), "kluczy");
+        assert_eq!(count_form("hotel", PluralCategory::Many).unwrap(), "hoteli");
+        assert_eq!(
+            count_form("muzeum", PluralCategory::Many).unwrap(),
+            "muzeów"
+        );
+        assert_eq!(
+            count_form("użytkownik", PluralCategory::Other).unwrap(),
+            "użytkownika"
+        );
+        assert!(count_form("plik", PluralCategory::Few).is_none());
+    }
+
+    #[test]
+    fn test_count_form_animate() {
+        assert_eq!(count_form("kot", PluralCategory::Other).unwrap(), "kota");
+        assert_eq!(count_form("brat", PluralCategory::Other).unwrap(), "brata");
+        assert_eq!(
+            count_form("człowiek", PluralCategory::Other).unwrap(),
+            "człowieka"
+        );
+        assert_eq!(count_form("słoń", PluralCategory::Other).unwrap(), "słonia");
+        assert_eq!(count_form("dom", PluralCategory::Other).unwrap(), "domu");
+        let half: crate::PluralOperands = "1.5".parse().unwrap();
+        assert_eq!(RULES.inflect_for_count("koty", half), "kota");
+    }
+
+    #[test]
+    fn test_genitive_singular() {
+        assert_eq!(genitive_singular("dzień"), "dnia");
+        assert_eq!(genitive_singular("pies"), "psa");
+        assert_eq!(genitive_singular("student"), "studenta");
+        assert_eq!(genitive_singular("pan"), "pana");
+        assert_eq!(genitive_singular("autor"), "autora");
+        assert_eq!(genitive_singular("lekarz"), "lekarza");
+        assert_eq!(genitive_singular("nauczyciel"), "nauczyciela");
+        assert_eq!(genitive_singular("dokument"), "dokumentu");
+        assert_eq!(genitive_singular("stół"), "stołu");
+        assert_eq!(genitive_singular("początek"), "początku");
+        assert_eq!(genitive_singular("widelec"), "widelca");
+        assert_eq!(genitive_singular("wartość"), "wartości");
+        let half: crate::PluralOperands = "1.5".parse().unwrap();
+        assert_eq!(RULES.inflect_for_count("dzień", half), "dnia");
+        assert_eq!(RULES.inflect_for_count("pies", half), "psa");
+        assert_eq!(RULES.inflect_for_count("student", half), "studenta");
+    }
+
+    #[test]
+    fn test_inflect_for_count() {
+        assert_eq!(RULES.inflect_for_count("plik", 1), "plik");
+        assert_eq!(RULES.inflect_for_count("plik", 2), "pliki");
+        assert_eq!(RULES.inflect_for_count("plik", 5), "plików");
+        assert_eq!(RULES.inflect_for_count("plik", 12), "plików");
+        assert_eq!(RULES.inflect_for_count("plik", 22), "pliki");
+        assert_eq!(RULES.inflect_for_count("Plik", 0), "Plików");
+        assert_eq!(RULES.inflect_for_count("produkty", 5), "produktów");
+        assert_eq!(RULES.inflect_for_count("zadanie", 1), "zadanie");
+        assert_eq!(RULES.inflect_for_count("zadanie", 2), "zadania");
+        assert_eq!(RULES.inflect_for_count("zadanie", 5), "zadań");
+        assert_eq!(RULES.inflect_for_count("ustawienia", 1), "ustawienie");
+        assert_eq!(RULES.inflect_for_count("strony", 1), "strona");
+        assert_eq!(RULES.inflect_for_count("strony", 5), "stron");
+    }
+
+    #[test]
+    fn test_number_of() {
+        assert_eq!(RULES.number_of("zadanie"), Number::Singular);
+        assert_eq!(RULES.number_of("zadania"), Number::Plural);
+        assert_eq!(RULES.number_of("zadań"), Number::Plural);
+        assert_eq!(RULES.number_of("strona"), Number::Singular);
+        assert_eq!(RULES.number_of("stron"), Number::Plural);
+        assert_eq!(RULES.number_of("Plików"), Number::Plural);
+        assert_eq!(RULES.number_of("dom"), Number::Singular);
+        assert_eq!(RULES.number_of("ludzi"), Number::Plural);
+    }
+
+    #[test]
+    fn test_plural_only() {
+        assert_eq!(RULES.singularize("drzwi"), "drzwi");
+        assert_eq!(RULES.singularize("Nożyczki"), "Nożyczki");
+        assert_eq!(RULES.pluralize("spodnie"), vec!["spodnie"]);
+        assert_eq!(RULES.number_of("drzwi"), Number::Invariant);
+        assert_eq!(RULES.number_of("okulary"), Number::Invariant);
+        assert_eq!(RULES.inflect_for_count("drzwi", 5), "drzwi");
+    }
+
+    #[test]
+    fn test_singularize_candidates() {
+        let candidates = singularize_candidates("strony");
+        assert_eq!(candidates[0], "strona");
+        assert!(candidates.iter().any(|v| v == "stron"));
+        let candidates = singularize_candidates("domy");
+        assert_eq!(candidates[0], "dom");
+        assert!(candidates.iter().any(|v| v == "doma"));
+        let candidates = singularize_candidates("pliki");
+        assert_eq!(candidates[0], "plik");
+        assert!(candidates.iter().any(|v| v == "plika"));
+        let candidates = singularize_candidates("klucze");
+        assert_eq!(candidates[0], "klucz");
+        assert!(candidates.iter().any(|v| v == "klucza"));
+        assert_eq!(singularize_candidates("ludzie"), vec!["człowiek"]);
+        assert_eq!(singularize_candidates("zadania"), vec!["zadanie"]);
+    }
 }
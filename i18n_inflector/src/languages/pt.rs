@@ -1,21 +1,235 @@
 //! Portuguese (pt) inflection rules.
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
+
+use super::strip;
+
+/// Irregular Portuguese nouns: `-ão` nouns that take `-ães` or `-ãos`, stress shifts, `-il`
+/// plurals the accent rule gets wrong, nouns the `-l`, `-ns` and `-ês` rules would misread
+/// (`pais` -> `pai`, `elétrons` -> `elétron`, `teses` -> `tese`), English loanwords and
+/// diminutives whose base is pluralized too (`pãezinhos`). Nouns whose plural follows from the
+/// suffix rules (`coração`, `viagem`, `hotel`) are not listed, nor are phrases and hyphenated
+/// compounds, whose words are inflected one at a time.
+pub(crate) static IRREGULARS: Irregulars = irregulars! {
+    // -ão -> -ães
+    "alemão" => "alemães",
+    "bastião" => "bastiães",
+    "cão" => "cães",
+    "capelão" => "capelães",
+    "capitão" => "capitães",
+    "catalão" => "catalães",
+    "charlatão" => "charlatães",
+    "deão" => "deães",
+    "escrivão" => "escrivães",
+    "guardião" => "guardiães",
+    "maçapão" => "maçapães",
+    "pão" => "pães",
+    "rufião" => "rufiães",
+    "sacristão" => "sacristães",
+    "tabelião" => "tabeliães",
+    "tecelão" => "tecelães",
+    // -ão -> -ãos
+    "acórdão" => "acórdãos",
+    "aldeão" => "aldeãos",
+    "ancião" => "anciãos",
+    "bênção" => "bênçãos",
+    "chão" => "chãos",
+    "cidadão" => "cidadãos",
+    "corrimão" => "corrimãos",
+    "cortesão" => "cortesãos",
+    "cristão" => "cristãos",
+    "desvão" => "desvãos",
+    "grão" => "grãos",
+    "irmão" => "irmãos",
+    "mão" => "mãos",
+    "órfão" => "órfãos",
+    "órgão" => "órgãos",
+    "pagão" => "pagãos",
+    "são" => "sãos",
+    "sótão" => "sótãos",
+    "vão" => "vãos",
+    "zângão" => "zângãos",
+    // Stress shifts and -s, -r plurals
+    "caráter" => "caracteres",
+    "cônsul" => "cônsules",
+    "gás" => "gases",
+    "gol" => "gols",
+    "júnior" => "juniores",
+    "mal" => "males",
+    "real" => "réis",
+    "revés" => "reveses",
+    "sênior" => "seniores",
+    // -el, -ol and -il with an accent change
+    "barril" => "barris",
+    "canil" => "canis",
+    "fóssil" => "fósseis",
+    "funil" => "funis",
+    "fuzil" => "fuzis",
+    "míssil" => "mísseis",
+    "projétil" => "projéteis",
+    "réptil" => "répteis",
+    // Nouns in -ai, -ei, -oi, -ãe and -ese, which only add -s
+    "anamnese" => "anamneses",
+    "ascese" => "asceses",
+    "bonsai" => "bonsais",
+    "catequese" => "catequeses",
+    "caubói" => "caubóis",
+    "diocese" => "dioceses",
+    "diurese" => "diureses",
+    "dodói" => "dodóis",
+    "exegese" => "exegeses",
+    "grei" => "greis",
+    "herói" => "heróis",
+    "hóquei" => "hóqueis",
+    "jóquei" => "jóqueis",
+    "lei" => "leis",
+    "mãe" => "mães",
+    "mimese" => "mimeses",
+    "pai" => "pais",
+    "papai" => "papais",
+    "pônei" => "pôneis",
+    "rei" => "reis",
+    "samurai" => "samurais",
+    "tese" => "teses",
+    // Nouns in -n, which only add -s
+    "pólen" => "polens",
+    "líquen" => "liquens",
+    "abdômen" => "abdomens",
+    "gérmen" => "germens",
+    "sêmen" => "semens",
+    "próton" => "prótons",
+    "nêutron" => "nêutrons",
+    "elétron" => "elétrons",
+    "íon" => "íons",
+    "fóton" => "fótons",
+    "bóson" => "bósons",
+    "cátion" => "cátions",
+    "ânion" => "ânions",
+    "éden" => "édens",
+    "login" => "logins",
+    "náilon" => "náilons",
+    // English loanwords
+    "e-mail" => "e-mails",
+    "hacker" => "hackers",
+    "designer" => "designers",
+    "freelancer" => "freelancers",
+    "scanner" => "scanners",
+    "trailer" => "trailers",
+    "hamster" => "hamsters",
+    "cluster" => "clusters",
+    "container" => "containers",
+    "driver" => "drivers",
+    "folder" => "folders",
+    "player" => "players",
+    "poster" => "posters",
+    "browser" => "browsers",
+    "server" => "servers",
+    "router" => "routers",
+    "mouse" => "mouses",
+    "hobby" => "hobbies",
+    "lobby" => "lobbies",
+    "pixel" => "pixels",
+    "outdoor" => "outdoors",
+    "blazer" => "blazers",
+    "best-seller" => "best-sellers",
+    "sprinter" => "sprinters",
+    "rapper" => "rappers",
+    // Diminutives in -zinho and -zinha, whose base is pluralized too
+    "pãozinho" => "pãezinhos",
+    "cãozinho" => "cãezinhos",
+    "coraçãozinho" => "coraçõezinhos",
+    "balãozinho" => "balõezinhos",
+    "leãozinho" => "leõezinhos",
+    "botãozinho" => "botõezinhos",
+    "limãozinho" => "limõezinhos",
+    "caminhãozinho" => "caminhõezinhos",
+    "cartãozinho" => "cartõezinhos",
+    "aviãozinho" => "aviõezinhos",
+    "animalzinho" => "animaizinhos",
+    "jornalzinho" => "jornaizinhos",
+    "papelzinho" => "papeizinhos",
+    "pastelzinho" => "pasteizinhos",
+    "hotelzinho" => "hoteizinhos",
+    "anelzinho" => "aneizinhos",
+    "farolzinho" => "faroizinhos",
+    "florzinha" => "florezinhas",
+    "colarzinho" => "colarezinhos",
+    "mulherzinha" => "mulherezinhas",
+    "colherzinha" => "colherezinhas",
+    "lençolzinho" => "lençoizinhos",
+    "funilzinho" => "funizinhos",
+    "canalzinho" => "canaizinhos",
+    "quintalzinho" => "quintaizinhos",
+    "sinalzinho" => "sinaizinhos",
+};
+
+/// Uncountable and invariant Portuguese nouns.
+static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "atlas", "lápis", "oásis", "pires", "tênis", "tórax", "vírus", "ônibus",
+    },
+    uncountable_suffixes: &[],
+    invariant_suffixes: &["x"],
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "pt",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    irregulars: Some(&IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("pt", singularize, pluralize)
 };
 
 /// Converts a plural Portuguese noun to its singular form.
 ///
-/// Handles `-es` plurals for consonant-ending words and regular `-s` plurals.
+/// Looks up irregular nouns first (`pães` -> `pão`, `papéis` -> `papel`). Otherwise reverses the
+/// `-ão` plurals (`corações` -> `coração`), `-ns` -> `-m` (`homens` -> `homem`), the `-l` plurals
+/// (`animais` -> `animal`, `anzóis` -> `anzol`) and `-eses` -> `-ês` (`meses` -> `mês`), and
+/// handles `-es` plurals for consonant-ending words and regular `-s` plurals.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(singular) = IRREGULARS.singular(name) {
+        return Cow::Borrowed(singular);
+    }
+    singularize_regular(name)
+}
+
+/// Applies the suffix rules of [`singularize`] without looking up irregular nouns.
+fn singularize_regular(name: &str) -> Cow<'_, str> {
+    if let Some(stem) = ["ões", "ães", "ãos"]
+        .iter()
+        .find_map(|suffix| strip(name, suffix))
+    {
+        return format!("{stem}ão").into();
+    }
+    if let Some(stem) = strip(name, "ns") {
+        return format!("{stem}m").into();
+    }
+    for (plural, singular) in [
+        ("ais", "al"),
+        ("éis", "el"),
+        ("eis", "el"),
+        ("óis", "ol"),
+        ("uis", "ul"),
+    ] {
+        if let Some(stem) = strip(name, plural) {
+            return format!("{stem}{singular}").into();
+        }
+    }
+    if let Some(stem) = strip(name, "eses") {
+        // Only nouns in `-ês` are stressed on their last syllable; an accent earlier in the word
+        // marks a noun in `-ese` (`hipóteses` -> `hipótese`).
+        let ending = if stem.contains(is_accented) {
+            "ese"
+        } else {
+            "ês"
+        };
+        return format!("{stem}{ending}").into();
+    }
     if let Some(stem) = name.strip_suffix("es")
         && (stem.ends_with('r')
             || stem.ends_with('z')
@@ -33,18 +247,78 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a Portuguese noun.
+/// Returns a list of possible plural forms for a Portuguese noun, most likely first.
+///
+/// Irregular nouns are looked up first (`mão` -> `mãos`). Otherwise `-ão` prefers `-ões` over
+/// `-ãos` and `-ães`, `-m` becomes `-ns` (`viagem` -> `viagens`), `-l` becomes `-is` (`animal`
+/// -> `animais`, `anel` -> `anéis`, `funil` -> `funis`, `fóssil` -> `fósseis`), and `-ês`,
+/// `-ás` and `-ós` lose their accent before `-es` (`mês` -> `meses`). Nouns ending in `-r`, `-z`
+/// or `-s` take `-es`, nouns in `-n` prefer `-es` over `-s` (`hífen` -> `hífenes`, `hífens`) and
+/// all other nouns take `-s`.
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
-    let mut candidates = vec![format!("{name}s").into()];
-    if name.ends_with('r')
-        || name.ends_with('z')
-        || name.ends_with('l')
-        || name.ends_with('n')
-        || name.ends_with('s')
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    pluralize_regular(name)
+}
+
+/// Applies the suffix rules of [`pluralize`] without looking up irregular nouns.
+fn pluralize_regular(name: &str) -> Vec<Cow<'_, str>> {
+    if let Some(stem) = strip(name, "ão") {
+        return vec![
+            format!("{stem}ões").into(),
+            format!("{stem}ãos").into(),
+            format!("{stem}ães").into(),
+        ];
+    }
+    if let Some(stem) = strip(name, "m") {
+        return vec![format!("{stem}ns").into()];
+    }
+    if let Some(plural) = plural_of_l(name) {
+        return vec![plural.into()];
+    }
+    if let Some((stem, vowel)) = [("ês", "e"), ("ás", "a"), ("ós", "o")]
+        .iter()
+        .find_map(|&(suffix, vowel)| Some((strip(name, suffix)?, vowel)))
     {
-        candidates.push(format!("{name}es").into());
+        return vec![format!("{stem}{vowel}ses").into()];
     }
-    candidates
+    if name.ends_with('r') || name.ends_with('z') || name.ends_with('s') {
+        return vec![format!("{name}es").into()];
+    }
+    if name.ends_with('n') {
+        return vec![format!("{name}es").into(), format!("{name}s").into()];
+    }
+    vec![format!("{name}s").into()]
+}
+
+/// Returns `true` if `ch` carries a written accent.
+fn is_accented(ch: char) -> bool {
+    "áâéêíóôú".contains(ch)
+}
+
+/// Returns the plural of a noun in `-al`, `-el`, `-ol`, `-ul` or `-il`, which replace the `-l`
+/// with `-is`.
+///
+/// An accent elsewhere in the word marks a noun stressed before its last syllable, which keeps
+/// `-eis` unaccented (`túnel` -> `túneis`) and turns `-il` into `-eis` (`fóssil` -> `fósseis`).
+/// Nouns stressed on `-el` and `-ol` take `-éis` and `-óis` (`anel` -> `anéis`, `anzol` ->
+/// `anzóis`), and those stressed on `-il` take `-is` (`funil` -> `funis`).
+fn plural_of_l(name: &str) -> Option<String> {
+    let stem = strip(name, "l")?;
+    let stressed_last = !stem.contains(is_accented);
+    let plural = if let Some(stem) = strip(stem, "e") {
+        format!("{stem}{}", if stressed_last { "éis" } else { "eis" })
+    } else if let Some(stem) = strip(stem, "o") {
+        format!("{stem}{}", if stressed_last { "óis" } else { "ois" })
+    } else if let Some(stem) = strip(stem, "i") {
+        format!("{stem}{}", if stressed_last { "is" } else { "eis" })
+    } else if stem.ends_with(['a', 'u']) {
+        format!("{stem}is")
+    } else {
+        return None;
+    };
+    Some(plural)
 }
 
 #[cfg(test)]
@@ -56,7 +330,7 @@ mod tests {
         assert_eq!(singularize("clientes"), "cliente");
         assert_eq!(singularize("produtos"), "produto");
         assert_eq!(singularize("flores"), "flor");
-        assert_eq!(singularize("animais"), "animai");
+        assert_eq!(singularize("animais"), "animal");
     }
 
     #[test]
@@ -73,8 +347,90 @@ mod tests {
         assert!(result.iter().any(|v| v == "flores"));
     }
 
+    #[test]
+    fn test_pluralize_ranking() {
+        assert_eq!(pluralize("produto")[0], "produtos");
+        assert_eq!(pluralize("flor")[0], "flores");
+        assert_eq!(pluralize("flor"), vec!["flores"]);
+        assert_eq!(pluralize("cruz"), vec!["cruzes"]);
+        assert_eq!(pluralize("hífen"), vec!["hífenes", "hífens"]);
+    }
+
+    #[test]
+    fn test_irregulars_not_regular() {
+        let regular = IRREGULARS.regular_entries(singularize_regular, pluralize_regular);
+        assert!(regular.is_empty(), "{regular:?}");
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("pães"), "pão");
+        assert_eq!(singularize("mãos"), "mão");
+        assert_eq!(singularize("corações"), "coração");
+        assert_eq!(singularize("homens"), "homem");
+        assert_eq!(singularize("papéis"), "papel");
+        assert_eq!(singularize("guardiões"), "guardião");
+        assert_eq!(pluralize("cão"), vec!["cães"]);
+        assert_eq!(pluralize("irmão"), vec!["irmãos"]);
+        assert_eq!(pluralize("viagem"), vec!["viagens"]);
+        assert_eq!(pluralize("caráter"), vec!["caracteres"]);
+        assert_eq!(singularize("elétrons"), "elétron");
+        assert_eq!(singularize("pãezinhos"), "pãozinho");
+        assert_eq!(pluralize("e-mail"), vec!["e-mails"]);
+        assert_eq!(singularize("best-sellers"), "best-seller");
+    }
+
+    #[test]
+    fn test_ao_plurals() {
+        assert_eq!(singularize("ligações"), "ligação");
+        assert_eq!(
+            pluralize("ligação"),
+            vec!["ligações", "ligaçãos", "ligaçães"]
+        );
+    }
+
+    #[test]
+    fn test_m_to_ns() {
+        assert_eq!(singularize("mensagens"), "mensagem");
+        assert_eq!(singularize("jardins"), "jardim");
+        assert_eq!(pluralize("mensagem"), vec!["mensagens"]);
+        assert_eq!(pluralize("atum"), vec!["atuns"]);
+    }
+
+    #[test]
+    fn test_l_to_is() {
+        assert_eq!(singularize("animais"), "animal");
+        assert_eq!(singularize("aluguéis"), "aluguel");
+        assert_eq!(singularize("girassóis"), "girassol");
+        assert_eq!(singularize("azuis"), "azul");
+        assert_eq!(singularize("responsáveis"), "responsável");
+        assert_eq!(pluralize("canal"), vec!["canais"]);
+        assert_eq!(pluralize("aluguel"), vec!["aluguéis"]);
+        assert_eq!(pluralize("responsável"), vec!["responsáveis"]);
+        assert_eq!(pluralize("girassol"), vec!["girassóis"]);
+        assert_eq!(pluralize("azul"), vec!["azuis"]);
+        assert_eq!(pluralize("cantil"), vec!["cantis"]);
+        assert_eq!(pluralize("útil"), vec!["úteis"]);
+    }
+
+    #[test]
+    fn test_accents() {
+        assert_eq!(singularize("camponeses"), "camponês");
+        assert_eq!(singularize("hipóteses"), "hipótese");
+        assert_eq!(pluralize("camponês"), vec!["camponeses"]);
+        assert_eq!(pluralize("retrós"), vec!["retroses"]);
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
     }
+
+    #[test]
+    fn test_rules_uncountable() {
+        use crate::language_rules::LanguageRules;
+        assert_eq!(RULES.singularize("lápis"), "lápis");
+        assert_eq!(RULES.pluralize("vírus"), vec!["vírus"]);
+        assert_eq!(RULES.pluralize("tórax"), vec!["tórax"]);
+    }
 }
@@ -0,0 +1,27 @@
+//! European Portuguese (pt-PT) inflection rules.
+//!
+//! Uses the Portuguese suffix rules with European spellings of the invariant nouns (`ténis`
+//! rather than Brazilian `tênis`). European Portuguese also has its own CLDR plural rules, in
+//! which `0` takes the plural.
+
+use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::Lexicon;
+use phf::phf_set;
+
+pub(crate) use super::pt::{pluralize, singularize};
+
+/// Uncountable and invariant European Portuguese nouns.
+static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "atlas", "lápis", "oásis", "pires", "ténis", "tórax", "vírus",
+    },
+    uncountable_suffixes: &[],
+    invariant_suffixes: &["x"],
+};
+
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
+    tag: Some("pt-PT"),
+    irregulars: Some(&super::pt::IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("pt", singularize, pluralize)
+};
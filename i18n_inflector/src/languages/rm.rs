@@ -1,11 +1,52 @@
 //! Romansh (rm) inflection rules.
 
 use crate::language_rules::LanguageRuleSet;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 
-pub(crate) use super::fr::{pluralize, singularize};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("rm", singularize, pluralize);
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "rm",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+/// Converts a plural Romansh noun to its singular form by removing `-s` (`chasas` -> `chasa`).
+pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(stem) = name.strip_suffix('s')
+        && !stem.is_empty()
+    {
+        return Cow::Borrowed(stem);
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns the plural of a Romansh noun, which adds `-s` (`animal` -> `animals`); nouns already
+/// ending in `-s` are invariant.
+pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if name.ends_with('s') {
+        vec![Cow::Borrowed(name)]
+    } else {
+        vec![format!("{name}s").into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("chasas"), "chasa");
+        assert_eq!(singularize("animals"), "animal");
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("chasa"), vec!["chasas"]);
+        assert_eq!(pluralize("animal"), vec!["animals"]);
+        assert_eq!(pluralize("pass"), vec!["pass"]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(singularize(""), "");
+    }
+}
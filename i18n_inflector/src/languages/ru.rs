@@ -1,21 +1,717 @@
 //! Russian (ru) inflection rules.
+//!
+//! Words in Cyrillic script are declined natively; words in Latin script are treated as
+//! transliterations. The script is detected per word.
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
+use crate::number::{self, Number};
+use crate::plural_rules::PluralCategory;
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
+
+use super::strip;
+
+/// Irregular Russian nouns with their nominative plurals: suppletive plurals, plurals in `-ья`,
+/// stressed `-а` (`дом` -> `дома`), young animals in `-онок`, neuters in `-мя`, fleeting vowels
+/// the rules miss (`день` -> `дни`), plurals with `ё` in the stem (`жена` -> `жёны`), nouns in
+/// `-ец` and `-ок` that keep their vowel (`кузнец` -> `кузнецы`) and diminutives in `-ко`. Nouns
+/// whose plural follows from the suffix rules (`гражданин`, `котёнок`, `отец`) are not listed.
+static IRREGULARS: Irregulars = irregulars! {
+    // Suppletive and irregular plurals
+    "болгарин" => "болгары",
+    "боярин" => "бояре",
+    "веко" => "веки",
+    "год" => "годы",
+    "господин" => "господа",
+    "грузин" => "грузины",
+    "дно" => "донья",
+    "дочь" => "дочери",
+    "колено" => "колени",
+    "кум" => "кумовья",
+    "курица" => "куры",
+    "мать" => "матери",
+    "небо" => "небеса",
+    "облако" => "облака",
+    "око" => "очи",
+    "плечо" => "плечи",
+    "раз" => "разы",
+    "ребёнок" => "дети",
+    "солдат" => "солдаты",
+    "сосед" => "соседи",
+    "судно" => "суда",
+    "татарин" => "татары",
+    "турок" => "турки",
+    "ухо" => "уши",
+    "ушко" => "ушки",
+    "хозяин" => "хозяева",
+    "цветок" => "цветы",
+    "церковь" => "церкви",
+    "цыган" => "цыгане",
+    "человек" => "люди",
+    "чёрт" => "черти",
+    "чудо" => "чудеса",
+    "яблоко" => "яблоки",
+    // Plurals in -ья
+    "брат" => "братья",
+    "брус" => "брусья",
+    "дерево" => "деревья",
+    "друг" => "друзья",
+    "звено" => "звенья",
+    "зять" => "зятья",
+    "клин" => "клинья",
+    "клок" => "клочья",
+    "князь" => "князья",
+    "кол" => "колья",
+    "колос" => "колосья",
+    "ком" => "комья",
+    "крыло" => "крылья",
+    "крюк" => "крючья",
+    "лист" => "листья",
+    "муж" => "мужья",
+    "перо" => "перья",
+    "повод" => "поводья",
+    "полено" => "поленья",
+    "полоз" => "полозья",
+    "прут" => "прутья",
+    "струп" => "струпья",
+    "стул" => "стулья",
+    "сук" => "сучья",
+    "сын" => "сыновья",
+    "шурин" => "шурья",
+    // Stressed plurals in -а and -я
+    "адрес" => "адреса",
+    "берег" => "берега",
+    "бок" => "бока",
+    "борт" => "борта",
+    "буфер" => "буфера",
+    "веер" => "веера",
+    "век" => "века",
+    "вексель" => "векселя",
+    "вензель" => "вензеля",
+    "вечер" => "вечера",
+    "ворох" => "вороха",
+    "глаз" => "глаза",
+    "голос" => "голоса",
+    "город" => "города",
+    "директор" => "директора",
+    "доктор" => "доктора",
+    "дом" => "дома",
+    "егерь" => "егеря",
+    "жёлоб" => "желоба",
+    "жёрнов" => "жернова",
+    "инспектор" => "инспектора",
+    "катер" => "катера",
+    "китель" => "кителя",
+    "колокол" => "колокола",
+    "кондуктор" => "кондуктора",
+    "корм" => "корма",
+    "корпус" => "корпуса",
+    "край" => "края",
+    "кузов" => "кузова",
+    "купол" => "купола",
+    "кучер" => "кучера",
+    "лагерь" => "лагеря",
+    "лес" => "леса",
+    "луг" => "луга",
+    "мастер" => "мастера",
+    "мех" => "меха",
+    "невод" => "невода",
+    "номер" => "номера",
+    "обод" => "обода",
+    "окорок" => "окорока",
+    "округ" => "округа",
+    "ордер" => "ордера",
+    "остров" => "острова",
+    "отпуск" => "отпуска",
+    "парус" => "паруса",
+    "паспорт" => "паспорта",
+    "перепел" => "перепела",
+    "повар" => "повара",
+    "погреб" => "погреба",
+    "поезд" => "поезда",
+    "пояс" => "пояса",
+    "провод" => "провода",
+    "пропуск" => "пропуска",
+    "профессор" => "профессора",
+    "рог" => "рога",
+    "рукав" => "рукава",
+    "снег" => "снега",
+    "соболь" => "соболя",
+    "сорт" => "сорта",
+    "стог" => "стога",
+    "сторож" => "сторожа",
+    "счёт" => "счета",
+    "тенор" => "тенора",
+    "терем" => "терема",
+    "тетерев" => "тетерева",
+    "том" => "тома",
+    "тополь" => "тополя",
+    "тормоз" => "тормоза",
+    "учитель" => "учителя",
+    "фельдшер" => "фельдшера",
+    "флигель" => "флигеля",
+    "флюгер" => "флюгера",
+    "хлеб" => "хлеба",
+    "холод" => "холода",
+    "хутор" => "хутора",
+    "цвет" => "цвета",
+    "цех" => "цеха",
+    "череп" => "черепа",
+    "шёлк" => "шелка",
+    "шомпол" => "шомпола",
+    "штабель" => "штабеля",
+    "штемпель" => "штемпеля",
+    "юнкер" => "юнкера",
+    "якорь" => "якоря",
+    "ястреб" => "ястреба",
+    // Young animals in -ёнок and -онок
+    "барсучонок" => "барсучата",
+    "бельчонок" => "бельчата",
+    "верблюжонок" => "верблюжата",
+    "внучонок" => "внучата",
+    "волчонок" => "волчата",
+    "галчонок" => "галчата",
+    "ежонок" => "ежата",
+    "зайчонок" => "зайчата",
+    "кукушонок" => "кукушата",
+    "лягушонок" => "лягушата",
+    "медвежонок" => "медвежата",
+    "мышонок" => "мышата",
+    "чертёнок" => "чертенята",
+    // Neuters in -мя
+    "время" => "времена",
+    "знамя" => "знамёна",
+    "имя" => "имена",
+    "племя" => "племена",
+    "семя" => "семена",
+    "стремя" => "стремена",
+    // Fleeting vowels
+    "ботинок" => "ботинки",
+    "бубен" => "бубны",
+    "ветер" => "ветры",
+    "владелец" => "владельцы",
+    "воробей" => "воробьи",
+    "глоток" => "глотки",
+    "горшок" => "горшки",
+    "делец" => "дельцы",
+    "день" => "дни",
+    "дятел" => "дятлы",
+    "жилец" => "жильцы",
+    "заём" => "займы",
+    "замок" => "замки",
+    "заяц" => "зайцы",
+    "значок" => "значки",
+    "камень" => "камни",
+    "ковёр" => "ковры",
+    "коготь" => "когти",
+    "козёл" => "козлы",
+    "корень" => "корни",
+    "костёр" => "костры",
+    "котёл" => "котлы",
+    "кремень" => "кремни",
+    "кусок" => "куски",
+    "лапоть" => "лапти",
+    "лев" => "львы",
+    "лёд" => "льды",
+    "ливень" => "ливни",
+    "лоб" => "лбы",
+    "локоть" => "локти",
+    "ломоть" => "ломти",
+    "мешок" => "мешки",
+    "молоток" => "молотки",
+    "мох" => "мхи",
+    "муравей" => "муравьи",
+    "ноготь" => "ногти",
+    "носок" => "носки",
+    "огонь" => "огни",
+    "орёл" => "орлы",
+    "осёл" => "ослы",
+    "палец" => "пальцы",
+    "парень" => "парни",
+    "пень" => "пни",
+    "перстень" => "перстни",
+    "пёс" => "псы",
+    "платок" => "платки",
+    "плетень" => "плетни",
+    "порошок" => "порошки",
+    "посол" => "послы",
+    "потолок" => "потолки",
+    "прыжок" => "прыжки",
+    "ремень" => "ремни",
+    "ров" => "рвы",
+    "рот" => "рты",
+    "ручей" => "ручьи",
+    "рынок" => "рынки",
+    "соловей" => "соловьи",
+    "сон" => "сны",
+    "стрелец" => "стрельцы",
+    "угол" => "углы",
+    "уголь" => "угли",
+    "угорь" => "угри",
+    "удалец" => "удальцы",
+    "узел" => "узлы",
+    "улей" => "ульи",
+    "уровень" => "уровни",
+    "хребет" => "хребты",
+    "чулок" => "чулки",
+    "шатёр" => "шатры",
+    "шов" => "швы",
+    "щелчок" => "щелчки",
+    // Genitive plurals with a fleeting vowel or stress shift
+    "бедро" => "бёдра",
+    "блюдце" => "блюдца",
+    "ведро" => "вёдра",
+    "веретено" => "веретёна",
+    "весло" => "вёсла",
+    "весна" => "вёсны",
+    "гнездо" => "гнёзда",
+    "дело" => "дела",
+    "деревня" => "деревни",
+    "дядя" => "дяди",
+    "жена" => "жёны",
+    "звезда" => "звёзды",
+    "земля" => "земли",
+    "зерно" => "зёрна",
+    "колесо" => "колёса",
+    "кольцо" => "кольца",
+    "кресло" => "кресла",
+    "кухня" => "кухни",
+    "лицо" => "лица",
+    "место" => "места",
+    "метла" => "мётлы",
+    "море" => "моря",
+    "овца" => "овцы",
+    "озеро" => "озёра",
+    "окно" => "окна",
+    "песня" => "песни",
+    "письмо" => "письма",
+    "поле" => "поля",
+    "полотенце" => "полотенца",
+    "пчела" => "пчёлы",
+    "ребро" => "рёбра",
+    "решето" => "решёта",
+    "сверло" => "свёрла",
+    "свеча" => "свечи",
+    "свинья" => "свиньи",
+    "седло" => "сёдла",
+    "село" => "сёла",
+    "семья" => "семьи",
+    "сердце" => "сердца",
+    "сестра" => "сёстры",
+    "слеза" => "слёзы",
+    "слово" => "слова",
+    "спальня" => "спальни",
+    "статья" => "статьи",
+    "стекло" => "стёкла",
+    "судья" => "судьи",
+    "тётя" => "тёти",
+    "число" => "числа",
+    "юноша" => "юноши",
+    "яйцо" => "яйца",
+    // Nouns in -ец that keep their vowel
+    "беглец" => "беглецы",
+    "близнец" => "близнецы",
+    "жрец" => "жрецы",
+    "кузнец" => "кузнецы",
+    "льстец" => "льстецы",
+    "лжец" => "лжецы",
+    "мертвец" => "мертвецы",
+    "мудрец" => "мудрецы",
+    "наглец" => "наглецы",
+    "храбрец" => "храбрецы",
+    "хитрец" => "хитрецы",
+    "чтец" => "чтецы",
+    // Nouns in -ок that keep their vowel
+    "знаток" => "знатоки",
+    "поток" => "потоки",
+    "приток" => "притоки",
+    "седок" => "седоки",
+    "стрелок" => "стрелки",
+    // Diminutives in -ко with plurals in -и
+    "ведёрко" => "ведёрки",
+    "гнёздышко" => "гнёздышки",
+    "горлышко" => "горлышки",
+    "городишко" => "городишки",
+    "домишко" => "домишки",
+    "зёрнышко" => "зёрнышки",
+    "колечко" => "колечки",
+    "колёсико" => "колёсики",
+    "крылышко" => "крылышки",
+    "личико" => "личики",
+    "местечко" => "местечки",
+    "окошко" => "окошки",
+    "очко" => "очки",
+    "пёрышко" => "пёрышки",
+    "плечико" => "плечики",
+    "сердечко" => "сердечки",
+    "словечко" => "словечки",
+    "солнышко" => "солнышки",
+    "яблочко" => "яблочки",
+    "яичко" => "яички",
+    ;
+    singular_to_plural:
+    "ребенок" => "дети",
+};
+
+/// Genitive plurals after numerals of the nouns in [`IRREGULARS`] (`5 человек`, `5 лет`), along
+/// with nouns whose genitive plural the rules cannot derive (`девушка` -> `девушек`).
+static GENITIVE_PLURALS: Irregulars = irregulars! {
+    // Suppletive and irregular plurals
+    "боярин" => "бояр",
+    "волос" => "волос",
+    "год" => "лет",
+    "господин" => "господ",
+    "грузин" => "грузин",
+    "дно" => "доньев",
+    "дочь" => "дочерей",
+    "колено" => "коленей",
+    "кум" => "кумовьёв",
+    "курица" => "кур",
+    "мать" => "матерей",
+    "небо" => "небес",
+    "облако" => "облаков",
+    "око" => "очей",
+    "плечо" => "плеч",
+    "путь" => "путей",
+    "раз" => "раз",
+    "ребёнок" => "детей",
+    "сапог" => "сапог",
+    "солдат" => "солдат",
+    "сосед" => "соседей",
+    "судно" => "судов",
+    "турок" => "турок",
+    "ухо" => "ушей",
+    "хозяин" => "хозяев",
+    "цветок" => "цветов",
+    "церковь" => "церквей",
+    "цыган" => "цыган",
+    "человек" => "человек",
+    "чёрт" => "чертей",
+    "чудо" => "чудес",
+    "яблоко" => "яблок",
+    // Plurals in -ья
+    "брат" => "братьев",
+    "брус" => "брусьев",
+    "дерево" => "деревьев",
+    "друг" => "друзей",
+    "звено" => "звеньев",
+    "зять" => "зятьёв",
+    "клин" => "клиньев",
+    "клок" => "клочьев",
+    "князь" => "князей",
+    "кол" => "кольев",
+    "колос" => "колосьев",
+    "ком" => "комьев",
+    "крыло" => "крыльев",
+    "крюк" => "крючьев",
+    "лист" => "листьев",
+    "муж" => "мужей",
+    "перо" => "перьев",
+    "повод" => "поводьев",
+    "полено" => "поленьев",
+    "полоз" => "полозьев",
+    "прут" => "прутьев",
+    "струп" => "струпьев",
+    "стул" => "стульев",
+    "сук" => "сучьев",
+    "сын" => "сыновей",
+    "шурин" => "шурьёв",
+    // Stressed plurals in -а and -я
+    "адрес" => "адресов",
+    "берег" => "берегов",
+    "бок" => "боков",
+    "век" => "веков",
+    "вечер" => "вечеров",
+    "глаз" => "глаз",
+    "голос" => "голосов",
+    "город" => "городов",
+    "директор" => "директоров",
+    "доктор" => "докторов",
+    "дом" => "домов",
+    "жёлоб" => "желобов",
+    "жёрнов" => "жерновов",
+    "инспектор" => "инспекторов",
+    "колокол" => "колоколов",
+    "корпус" => "корпусов",
+    "край" => "краёв",
+    "кузов" => "кузовов",
+    "купол" => "куполов",
+    "лес" => "лесов",
+    "луг" => "лугов",
+    "мастер" => "мастеров",
+    "номер" => "номеров",
+    "ордер" => "ордеров",
+    "остров" => "островов",
+    "отпуск" => "отпусков",
+    "парус" => "парусов",
+    "паспорт" => "паспортов",
+    "повар" => "поваров",
+    "поезд" => "поездов",
+    "провод" => "проводов",
+    "пропуск" => "пропусков",
+    "профессор" => "профессоров",
+    "рог" => "рогов",
+    "рукав" => "рукавов",
+    "снег" => "снегов",
+    "сорт" => "сортов",
+    "сторож" => "сторожей",
+    "счёт" => "счетов",
+    "том" => "томов",
+    "тормоз" => "тормозов",
+    "учитель" => "учителей",
+    "холод" => "холодов",
+    "хутор" => "хуторов",
+    "череп" => "черепов",
+    "шёлк" => "шелков",
+    "якорь" => "якорей",
+    // Nouns in -анин and -янин
+    "англичанин" => "англичан",
+    "армянин" => "армян",
+    "болгарин" => "болгар",
+    "горожанин" => "горожан",
+    "гражданин" => "граждан",
+    "датчанин" => "датчан",
+    "дворянин" => "дворян",
+    "крестьянин" => "крестьян",
+    "римлянин" => "римлян",
+    "славянин" => "славян",
+    "татарин" => "татар",
+    "христианин" => "христиан",
+    "южанин" => "южан",
+    // Young animals in -ёнок and -онок
+    "барсучонок" => "барсучат",
+    "бельчонок" => "бельчат",
+    "верблюжонок" => "верблюжат",
+    "внучонок" => "внучат",
+    "волчонок" => "волчат",
+    "галчонок" => "галчат",
+    "гусёнок" => "гусят",
+    "ежонок" => "ежат",
+    "жеребёнок" => "жеребят",
+    "зайчонок" => "зайчат",
+    "козлёнок" => "козлят",
+    "котёнок" => "котят",
+    "кукушонок" => "кукушат",
+    "лисёнок" => "лисят",
+    "лягушонок" => "лягушат",
+    "медвежонок" => "медвежат",
+    "мышонок" => "мышат",
+    "поросёнок" => "поросят",
+    "телёнок" => "телят",
+    "утёнок" => "утят",
+    "цыплёнок" => "цыплят",
+    "чертёнок" => "чертенят",
+    "ягнёнок" => "ягнят",
+    // Neuters in -мя
+    "время" => "времён",
+    "знамя" => "знамён",
+    "имя" => "имён",
+    "племя" => "племён",
+    "семя" => "семян",
+    "стремя" => "стремян",
+    // Fleeting vowels
+    "боец" => "бойцов",
+    "ботинок" => "ботинок",
+    "ветер" => "ветров",
+    "владелец" => "владельцев",
+    "воробей" => "воробьёв",
+    "делец" => "дельцов",
+    "день" => "дней",
+    "жилец" => "жильцов",
+    "заяц" => "зайцев",
+    "камень" => "камней",
+    "ковёр" => "ковров",
+    "козёл" => "козлов",
+    "конец" => "концов",
+    "котёл" => "котлов",
+    "купец" => "купцов",
+    "лев" => "львов",
+    "лёд" => "льдов",
+    "лоб" => "лбов",
+    "муравей" => "муравьёв",
+    "немец" => "немцев",
+    "носок" => "носков",
+    "огонь" => "огней",
+    "огурец" => "огурцов",
+    "орёл" => "орлов",
+    "отец" => "отцов",
+    "палец" => "пальцев",
+    "пёс" => "псов",
+    "рот" => "ртов",
+    "ручей" => "ручьёв",
+    "соловей" => "соловьёв",
+    "сон" => "снов",
+    "стрелец" => "стрельцов",
+    "угол" => "углов",
+    "удалец" => "удальцов",
+    "узел" => "узлов",
+    "улей" => "ульев",
+    // Genitive plurals with a fleeting vowel or stress shift
+    "бабушка" => "бабушек",
+    "бедро" => "бёдер",
+    "блюдце" => "блюдец",
+    "ведро" => "вёдер",
+    "веретено" => "веретён",
+    "весло" => "вёсел",
+    "весна" => "вёсен",
+    "гнездо" => "гнёзд",
+    "девушка" => "девушек",
+    "дело" => "дел",
+    "деревня" => "деревень",
+    "дядя" => "дядей",
+    "жена" => "жён",
+    "звезда" => "звёзд",
+    "земля" => "земель",
+    "зерно" => "зёрен",
+    "колесо" => "колёс",
+    "кольцо" => "колец",
+    "копейка" => "копеек",
+    "кошка" => "кошек",
+    "кресло" => "кресел",
+    "кухня" => "кухонь",
+    "лицо" => "лиц",
+    "лодка" => "лодок",
+    "ложка" => "ложек",
+    "марка" => "марок",
+    "место" => "мест",
+    "метла" => "мётел",
+    "море" => "морей",
+    "овца" => "овец",
+    "озеро" => "озёр",
+    "окно" => "окон",
+    "открытка" => "открыток",
+    "ошибка" => "ошибок",
+    "песня" => "песен",
+    "письмо" => "писем",
+    "поле" => "полей",
+    "полотенце" => "полотенец",
+    "пчела" => "пчёл",
+    "ребро" => "рёбер",
+    "решето" => "решёт",
+    "ручка" => "ручек",
+    "сверло" => "свёрл",
+    "свеча" => "свечей",
+    "свинья" => "свиней",
+    "седло" => "сёдел",
+    "село" => "сёл",
+    "семья" => "семей",
+    "сердце" => "сердец",
+    "сестра" => "сестёр",
+    "сказка" => "сказок",
+    "слеза" => "слёз",
+    "слово" => "слов",
+    "сосна" => "сосен",
+    "спальня" => "спален",
+    "статья" => "статей",
+    "стекло" => "стёкол",
+    "судья" => "судей",
+    "тётя" => "тёть",
+    "тюрьма" => "тюрем",
+    "чашка" => "чашек",
+    "число" => "чисел",
+    "юноша" => "юношей",
+    "яйцо" => "яиц",
+    // Nouns in -ец that keep their vowel
+    "беглец" => "беглецов",
+    "близнец" => "близнецов",
+    "жрец" => "жрецов",
+    "кузнец" => "кузнецов",
+    "лжец" => "лжецов",
+    "льстец" => "льстецов",
+    "мертвец" => "мертвецов",
+    "мудрец" => "мудрецов",
+    "наглец" => "наглецов",
+    "хитрец" => "хитрецов",
+    "храбрец" => "храбрецов",
+    "чтец" => "чтецов",
+    // Diminutives in -ко with plurals in -и
+    "колёсико" => "колёсиков",
+    "личико" => "личиков",
+    "очко" => "очков",
+    "плечико" => "плечиков",
+    // Neuters in -ье with -ьев (most take -ий: `ущелье` -> `ущелий`)
+    "платье" => "платьев",
+    "устье" => "устьев",
+    ;
+    singular_to_plural:
+    "ребенок" => "детей",
+    "цвет" => "цветов",
+    ;
+    plural_to_singular:
+    "людей" => "человек",
+};
+
+/// Masculine Russian nouns in a hard consonant, whose plural in `-ы` would otherwise be read as
+/// that of a feminine in `-а` (`форматы` -> `формат`, but `машины` -> `машина`). Nouns with one
+/// of the [`MASCULINE_ENDINGS`] need not be listed.
+static MASCULINES: phf::Set<&'static str> = phf_set! {
+    "автомат", "адресат", "альбом", "аппарат", "банкомат", "бит", "бот", "бюджет", "вход",
+    "выход", "газ", "домен", "доход", "завод", "заказ", "закон", "зал", "интерфейс", "кандидат",
+    "код", "лимит", "логин", "магазин", "маршрут", "метод", "мир", "народ", "образ", "объём",
+    "отдел", "ответ", "отчёт", "пакет", "период", "перевод", "план", "показ", "порт", "предмет",
+    "приказ", "протокол", "раздел", "расход", "регион", "результат", "род", "сайт", "сезон",
+    "сервис", "сертификат", "символ", "скрипт", "слот", "способ", "стол", "сустав", "тариф",
+    "телефон", "тип", "товар", "токен", "транспорт", "фильм", "формат", "чат", "шаблон", "экран",
+    "этап",
+};
+
+/// Stem endings of masculine nouns whose plural is in `-ы` (`серверы`, `документы`, `месяцы`).
+const MASCULINE_ENDINGS: &[&str] = &[
+    "ер", "ор", "ёр", "тр", "нт", "кт", "ст", "изм", "ум", "ус", "ос", "рс", "сс", "йл", "йт",
+    "ал", "яц",
+];
+
+/// Feminine Russian nouns in a hushing consonant followed by `-а` or `-ь`, whose plural in `-и`
+/// would otherwise be read as that of a masculine (`задачи` -> `задача`, `мыши` -> `мышь`, but
+/// `ключи` -> `ключ`).
+static HUSHING_FEMININES: phf::Set<&'static str> = phf_set! {
+    "брошь", "вещь", "встреча", "дача", "задача", "каша", "ложь", "мощь", "мышь", "ночь",
+    "передача", "печь", "помощь", "речь", "роща", "рожь", "туча", "удача",
+};
+
+/// Stem endings of neuter nouns in `-ие`, whose plural in `-ия` would otherwise be read as a
+/// feminine singular (`здания` -> `здание`, `занятия` -> `занятие`, but `категория`).
+const NEUTER_IE_ENDINGS: &[&str] = &["ан", "ен", "ят", "ыт", "ит", "ов", "уж"];
+
+/// Singular nouns that end like a plural of a neuter in `-ие` or `-ье`: feminines in `-ия` and
+/// `-ья` (`компания`, but `здания` -> `здание`) and masculines in `-ий` (`гений`, but `зданий` ->
+/// `здание`). Those listed in [`IRREGULARS`] (`статья`) need not be repeated.
+static NEUTER_LOOKALIKES: phf::Set<&'static str> = phf_set! {
+    "армения", "бадья", "британия", "гений", "германия", "евгений", "испания", "кампания",
+    "компания", "кутья", "ладья", "мания", "полынья", "попадья", "скамья",
+};
+
+/// Indeclinable Russian nouns, mostly loanwords.
+static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "интервью", "кафе", "кино", "кофе", "меню", "метро", "пальто", "радио", "такси", "шоссе",
+    },
+    uncountable_suffixes: &[],
+    invariant_suffixes: &[],
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "ru",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    count_form_fn: Some(count_form),
+    singularize_candidates_fn: Some(singularize_candidates),
+    number_fn: Some(number),
+    irregulars: Some(&IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("ru", singularize, pluralize)
 };
 
-/// Converts a plural Russian noun (Latin transliteration) to its singular form.
+/// Converts a plural Russian noun to its singular form.
 ///
-/// Handles common transliterated Russian plural patterns: `-y` -> `-a` and
-/// stripping `-i`.
+/// Cyrillic nouns are handled by [`singularize_cyrillic`]. For Latin transliterations, handles the
+/// common plural patterns `-y` -> `-a` and stripping `-i`.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if is_cyrillic(name) {
+        return singularize_cyrillic(name);
+    }
     if let Some(stem) = name.strip_suffix('y')
         && !stem.is_empty()
     {
@@ -29,21 +725,479 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for a Russian noun (Latin transliteration).
+/// Returns the possible singular forms of a Russian noun, most likely first.
+///
+/// Starts with the result of [`singularize`], followed by the other readings of a nominative
+/// plural: `-ы` may belong to a feminine in `-а` or a masculine (`машины` -> `машина`, `машин`),
+/// `-и` after a velar or hushing consonant also to a feminine in `-ь` (`мыши` -> `мышь`), `-и`
+/// after other consonants to a feminine in `-я` or a noun in `-ь` (`недели` -> `неделя`), and
+/// `-ия` and `-ья` to a neuter in `-ие` or `-ье` or a feminine singular (`знания` -> `знание`,
+/// `знания`). Irregular nouns and Latin transliterations only have the result of [`singularize`].
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    let mut candidates = vec![singularize(name)];
+    if !is_cyrillic(name)
+        || IRREGULARS.singular(name).is_some()
+        || GENITIVE_PLURALS.singular(name).is_some()
+    {
+        return candidates;
+    }
+    if let Some((stem, neuter)) = strip(name, "ия")
+        .map(|stem| (stem, "ие"))
+        .or_else(|| strip(name, "ья").map(|stem| (stem, "ье")))
+    {
+        candidates.push(Cow::Owned(format!("{stem}{neuter}")));
+        candidates.push(Cow::Borrowed(name));
+        return candidates;
+    }
+    let (stem, endings): (&str, &[&str]) = if let Some(stem) = strip(name, "ы") {
+        let endings: &[&str] = if stem.ends_with('ц') {
+            &["а"]
+        } else {
+            &["а", ""]
+        };
+        (stem, endings)
+    } else if let Some(stem) = strip(name, "и") {
+        let endings: &[&str] = if stem.ends_with(['ж', 'ш', 'ч', 'щ']) {
+            &["а", "ь", ""]
+        } else if takes_i(stem) {
+            &["а", ""]
+        } else if stem.ends_with(is_vowel) {
+            &["я", "й"]
+        } else {
+            &["я", "ь"]
+        };
+        (stem, endings)
+    } else {
+        return candidates;
+    };
+    candidates.extend(
+        endings
+            .iter()
+            .map(|ending| Cow::Owned(format!("{stem}{ending}"))),
+    );
+    candidates
+}
+
+/// Returns a list of possible plural forms for a Russian noun, most likely first.
+///
+/// Cyrillic nouns are handled by [`pluralize_cyrillic`]. For Latin transliterations, stems ending
+/// in a velar or hushing consonant (`k`, `g`, `h`, `zh`, `sh`, `ch`) prefer `-i` by the spelling
+/// rule; all other stems prefer `-y`.
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if is_cyrillic(name) {
+        return pluralize_cyrillic(name);
+    }
     let mut candidates = Vec::new();
-    if let Some(stem) = name.strip_suffix('a') {
-        candidates.push(format!("{stem}y").into());
-        candidates.push(format!("{stem}i").into());
+    let (stem, feminine) = match name.strip_suffix('a') {
+        Some(stem) => (stem, true),
+        None => (name, false),
+    };
+    let endings = if stem.ends_with(['k', 'g', 'h']) {
+        ["i", "y"]
+    } else {
+        ["y", "i"]
+    };
+    if feminine {
+        for ending in endings {
+            candidates.push(format!("{stem}{ending}").into());
+        }
+    }
+    for ending in endings {
+        candidates.push(format!("{name}{ending}").into());
     }
-    candidates.push(format!("{name}y").into());
-    candidates.push(format!("{name}i").into());
     candidates
 }
 
+/// Returns `true` if the word is written in the Cyrillic script.
+fn is_cyrillic(name: &str) -> bool {
+    name.chars()
+        .any(|ch| ('\u{0400}'..='\u{04FF}').contains(&ch))
+}
+
+/// Returns `true` if the stem ends in a velar or hushing consonant, after which `ы` is spelled
+/// `и`.
+fn takes_i(stem: &str) -> bool {
+    stem.ends_with(['к', 'г', 'х', 'ж', 'ш', 'ч', 'щ'])
+}
+
+/// Returns `true` if `ch` is a Cyrillic vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "аеёиоуыэюя".contains(ch)
+}
+
+/// Returns `true` if the stem ends in two consonants (`звон`, but not `ур`).
+fn ends_in_cluster(stem: &str) -> bool {
+    let mut chars = stem.chars().rev();
+    matches!(
+        (chars.next(), chars.next()),
+        (Some(last), Some(previous)) if !is_vowel(last) && !is_vowel(previous)
+    )
+}
+
+/// Returns `true` if the `-ок` of a noun is a fleeting vowel dropped in the other forms
+/// (`звонок` -> `звонки`), as opposed to part of the root (`урок` -> `уроки`).
+fn has_fleeting_o(stem: &str) -> bool {
+    stem.chars().count() >= 3 && !stem.ends_with('р') && !ends_in_cluster(stem)
+}
+
+/// Converts a plural Russian noun in Cyrillic script to its singular form.
+///
+/// Handles irregular nouns (`люди` -> `человек`), the nominative plural endings `-ы` and `-и` of
+/// hard and soft stems (`файлы` -> `файл`, `книги` -> `книга`, `пользователи` ->
+/// `пользователь`, `категории` -> `категория`) and the genitive plural endings `-ов`, `-ев` and
+/// `-ей` (`файлов` -> `файл`, `музеев` -> `музей`, `ключей` -> `ключ`). Plurals in `-ы` are read
+/// as feminines in `-а` (`машины` -> `машина`) unless the stem is a known masculine
+/// (`форматы` -> `формат`), and `-ц` after a consonant restores the fleeting `-е-` (`продавцы` ->
+/// `продавец`). Neuter plurals in `-а` and `-я` read the same as feminine singulars; they are
+/// recognized for nouns in `-ье` (`платья` -> `платье`) and for nouns in `-ие` with one of the
+/// [`NEUTER_IE_ENDINGS`] (`здания` -> `здание`), unless listed in [`NEUTER_LOOKALIKES`].
+fn singularize_cyrillic(name: &str) -> Cow<'_, str> {
+    if let Some(singular) = IRREGULARS
+        .singular(name)
+        .or_else(|| GENITIVE_PLURALS.singular(name))
+    {
+        return Cow::Borrowed(singular);
+    }
+    if let Some(stem) = strip(name, "ята") {
+        return format!("{stem}ёнок").into();
+    }
+    if let Some(singular) = neuter_ie_singular(name) {
+        return singular.into();
+    }
+    if let Some(stem) = strip(name, "ии") {
+        return format!("{stem}ия").into();
+    }
+    if let Some(stem) = strip(name, "ане").or_else(|| strip(name, "яне")) {
+        let vowel = if name.ends_with("ане") {
+            "ан"
+        } else {
+            "ян"
+        };
+        return format!("{stem}{vowel}ин").into();
+    }
+
+    // Genitive plural
+    if let Some(stem) = strip(name, "ов") {
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = strip(name, "ев") {
+        if stem.ends_with('ц') {
+            return Cow::Borrowed(stem);
+        }
+        if stem.ends_with(is_vowel) {
+            return format!("{stem}й").into();
+        }
+        return Cow::Borrowed(name);
+    }
+    if let Some(stem) = strip(name, "ей") {
+        if stem.ends_with(['ж', 'ш', 'ч', 'щ']) {
+            return Cow::Borrowed(stem);
+        }
+        if stem.ends_with("тел") {
+            return format!("{stem}ь").into();
+        }
+        return Cow::Borrowed(name);
+    }
+
+    // Nominative plural
+    if let Some(stem) = strip(name, "ы") {
+        if let Some(root) = strip(stem, "йц") {
+            return format!("{root}ец").into();
+        }
+        if let Some(root) = strip(stem, "ц")
+            && !root.ends_with(is_vowel)
+        {
+            return format!("{root}ец").into();
+        }
+        if is_masculine(stem) {
+            return Cow::Borrowed(stem);
+        }
+        return format!("{stem}а").into();
+    }
+    if let Some(stem) = strip(name, "и") {
+        // Feminines in `-ка` (`заметка`) and `-ига`/`-уга` (`книга`, `услуга`) against masculines
+        // with a vowel before the velar (`урок`, `диалог`).
+        if let Some(root) = stem.strip_suffix(['к', 'г', 'х']) {
+            let feminine = if stem.ends_with('г') {
+                root.ends_with(['и', 'у'])
+            } else {
+                !root.is_empty() && !root.ends_with(is_vowel)
+            };
+            return if feminine {
+                format!("{stem}а").into()
+            } else {
+                Cow::Borrowed(stem)
+            };
+        }
+        if stem.ends_with(['ж', 'ш', 'ч', 'щ']) {
+            return ["а", "ь"]
+                .iter()
+                .map(|ending| format!("{stem}{ending}"))
+                .find(|feminine| HUSHING_FEMININES.contains(feminine.as_str()))
+                .map_or(Cow::Borrowed(stem), Cow::Owned);
+        }
+        if stem.ends_with(is_vowel) {
+            return format!("{stem}й").into();
+        }
+        return format!("{stem}ь").into();
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns the singular of a neuter plural in `-ия` or `-ья` or a genitive plural in `-ий`
+/// (`здания`, `зданий` -> `здание`, `платья` -> `платье`), or `None` if the word reads as a
+/// singular (`категория`, `компания`, `гений`). The genitive plural of a feminine listed in
+/// [`NEUTER_LOOKALIKES`] returns that feminine (`компаний` -> `компания`).
+fn neuter_ie_singular(name: &str) -> Option<String> {
+    if NEUTER_LOOKALIKES.contains(name) {
+        return None;
+    }
+    if let Some(stem) = strip(name, "ья") {
+        return Some(format!("{stem}ье"));
+    }
+    if let Some(stem) = strip(name, "ий") {
+        let feminine = format!("{stem}ия");
+        if NEUTER_LOOKALIKES.contains(feminine.as_str()) {
+            return Some(feminine);
+        }
+    }
+    let stem = strip(name, "ия").or_else(|| strip(name, "ий"))?;
+    NEUTER_IE_ENDINGS
+        .iter()
+        .any(|ending| stem.ends_with(ending))
+        .then(|| format!("{stem}ие"))
+}
+
+/// Returns `true` if a hard stem belongs to a masculine noun rather than a feminine in `-а`.
+fn is_masculine(stem: &str) -> bool {
+    MASCULINES.contains(stem)
+        || MASCULINE_ENDINGS
+            .iter()
+            .any(|ending| stem.ends_with(ending))
+}
+
+/// Returns a list of possible nominative plural forms for a Russian noun in Cyrillic script, most
+/// likely first.
+///
+/// Hard stems take `-ы` and soft stems `-и` (`файл` -> `файлы`, `книга` -> `книги`, `неделя` ->
+/// `недели`, `пользователь` -> `пользователи`), with `-и` spelled after velar and hushing
+/// consonants. Neuters in `-о` and `-е` take `-а` and `-я` (`окно` -> `окна`, `сообщение` ->
+/// `сообщения`), and fleeting vowels are dropped (`звонок` -> `звонки`, `продавец` ->
+/// `продавцы`).
+fn pluralize_cyrillic(name: &str) -> Vec<Cow<'_, str>> {
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    if let Some(stem) = strip(name, "ёнок") {
+        return vec![format!("{stem}ята").into()];
+    }
+    if let Some(stem) = strip(name, "анин").or_else(|| strip(name, "янин")) {
+        let vowel = if name.ends_with("анин") {
+            "ане"
+        } else {
+            "яне"
+        };
+        return vec![format!("{stem}{vowel}").into()];
+    }
+    if let Some(stem) = strip(name, "ок") {
+        let fleeting = format!("{stem}ки").into();
+        let regular = format!("{name}и").into();
+        return if has_fleeting_o(stem) {
+            vec![fleeting, regular]
+        } else {
+            vec![regular, fleeting]
+        };
+    }
+    if let Some(stem) = strip(name, "ец") {
+        let fleeting = if stem.ends_with(is_vowel) {
+            format!("{stem}йцы")
+        } else {
+            format!("{stem}цы")
+        };
+        return vec![fleeting.into()];
+    }
+    if let Some(stem) = strip(name, "мя") {
+        return vec![format!("{stem}мена").into()];
+    }
+    if let Some(stem) = strip(name, "а") {
+        let ending = if takes_i(stem) { "и" } else { "ы" };
+        return vec![format!("{stem}{ending}").into()];
+    }
+    if let Some(stem) = strip(name, "я")
+        .or_else(|| strip(name, "ь"))
+        .or_else(|| strip(name, "й"))
+    {
+        return vec![format!("{stem}и").into()];
+    }
+    if let Some(stem) = strip(name, "о") {
+        return vec![format!("{stem}а").into()];
+    }
+    if let Some(stem) = strip(name, "е") {
+        let ending = if stem.ends_with(['ж', 'ш', 'ч', 'щ', 'ц']) {
+            "а"
+        } else {
+            "я"
+        };
+        return vec![format!("{stem}{ending}").into()];
+    }
+    let ending = if takes_i(name) { "и" } else { "ы" };
+    vec![format!("{name}{ending}").into()]
+}
+
+/// Returns `true` if a noun in `-ь` is feminine (`запись`, `новость`).
+fn is_feminine_soft(name: &str) -> bool {
+    ["сть", "шь", "чь", "щь", "жь", "пись", "знь"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+}
+
+/// Returns the stem of the oblique cases of a masculine noun whose fleeting vowel drops out
+/// (`день` -> `дн`, `звонок` -> `звонк`, `боец` -> `бойц`), read from its nominative plural, or
+/// `None` if the noun has no fleeting vowel. Nouns in `-ёнок` and `-онок` always drop it
+/// (`котёнок` -> `котёнк`, `медвежонок` -> `медвежонк`).
+fn oblique_stem(name: &str) -> Option<String> {
+    if name.ends_with(is_vowel) {
+        return None;
+    }
+    if name.ends_with("ёнок") || name.ends_with("онок") {
+        return strip(name, "ок").map(|stem| format!("{stem}к"));
+    }
+    let stem = name.strip_suffix(['ь', 'й']).unwrap_or(name);
+    let plural = pluralize_cyrillic(name).into_iter().next()?;
+    let oblique = plural.strip_suffix(['ы', 'и'])?;
+    // A suppletive plural (`человек` -> `люди`) is not a shortened stem.
+    let dropped = stem.chars().count().checked_sub(oblique.chars().count());
+    (oblique != stem && matches!(dropped, Some(0 | 1))).then(|| oblique.into())
+}
+
+/// Inserts the fleeting vowel that breaks up the final consonant cluster of a feminine or neuter
+/// stem in the genitive plural (`ссылк` -> `ссылок`, `ложк` -> `ложек`, `копейк` -> `копеек`,
+/// `пятн` -> `пятен`), or returns `None` if the stem needs none.
+fn fleeting_genitive(stem: &str) -> Option<String> {
+    let mut chars = stem.chars().rev();
+    let (last, previous) = (chars.next()?, chars.next()?);
+    let base = &stem[..stem.len() - last.len_utf8() - previous.len_utf8()];
+    let vowel = match (previous, last) {
+        ('й' | 'ь', 'к' | 'г') => return Some(format!("{base}е{last}")),
+        ('ж' | 'ш' | 'ч' | 'щ' | 'ц', 'к') | ('с' | 'т' | 'д' | 'в', 'н') => 'е',
+        (_, 'к') | ('к', 'н') if !is_vowel(previous) => 'о',
+        _ => return None,
+    };
+    Some(format!("{base}{previous}{vowel}{last}"))
+}
+
+/// Returns the genitive singular of a Cyrillic noun (`файл` -> `файла`, `книга` -> `книги`,
+/// `день` -> `дня`, `имя` -> `имени`).
+fn genitive_singular(name: &str) -> Cow<'_, str> {
+    if let Some(stem) = strip(name, "мя") {
+        return format!("{stem}мени").into();
+    }
+    if let Some(stem) = strip(name, "а") {
+        let ending = if takes_i(stem) { "и" } else { "ы" };
+        return format!("{stem}{ending}").into();
+    }
+    if let Some(stem) = strip(name, "я") {
+        return format!("{stem}и").into();
+    }
+    if let Some(stem) = strip(name, "о") {
+        return format!("{stem}а").into();
+    }
+    if let Some(stem) = strip(name, "е") {
+        return format!("{stem}я").into();
+    }
+    if is_feminine_soft(name) {
+        return format!("{}и", &name[..name.len() - 'ь'.len_utf8()]).into();
+    }
+    let soft = name.ends_with(['ь', 'й']);
+    let stem = oblique_stem(name).map_or_else(
+        || Cow::Borrowed(name.strip_suffix(['ь', 'й']).unwrap_or(name)),
+        Cow::Owned,
+    );
+    let ending = if soft { "я" } else { "а" };
+    format!("{stem}{ending}").into()
+}
+
+/// Returns the genitive plural of a Cyrillic noun (`файл` -> `файлов`, `книга` -> `книг`).
+fn genitive_plural(name: &str) -> Cow<'_, str> {
+    if let Some(genitive) = GENITIVE_PLURALS.plural(name) {
+        return Cow::Borrowed(genitive);
+    }
+    if let Some(stem) = strip(name, "ия").or_else(|| strip(name, "ие")) {
+        return format!("{stem}ий").into();
+    }
+    if let Some(stem) = strip(name, "ья") {
+        return format!("{stem}ей").into();
+    }
+    if let Some(stem) = strip(name, "ье") {
+        return format!("{stem}ий").into();
+    }
+    if let Some(stem) = strip(name, "а").or_else(|| strip(name, "о")) {
+        return fleeting_genitive(stem).map_or(Cow::Borrowed(stem), Cow::Owned);
+    }
+    if let Some(stem) = strip(name, "я") {
+        return format!("{stem}ь").into();
+    }
+    if let Some(stem) = strip(name, "ёнок") {
+        return format!("{stem}ят").into();
+    }
+    if let Some(stem) = oblique_stem(name)
+        && !name.ends_with('ц')
+    {
+        let ending = if name.ends_with('ь') { "ей" } else { "ов" };
+        return format!("{stem}{ending}").into();
+    }
+    if let Some(stem) = strip(name, "й") {
+        return format!("{stem}ев").into();
+    }
+    if let Some(stem) = strip(name, "е").or_else(|| strip(name, "ь")) {
+        return format!("{stem}ей").into();
+    }
+    if name.ends_with(['ж', 'ш', 'ч', 'щ']) {
+        return format!("{name}ей").into();
+    }
+    if name.ends_with('ц') {
+        return format!("{name}ев").into();
+    }
+    format!("{name}ов").into()
+}
+
+/// Returns the form of a singular Russian noun (Cyrillic) required by a number.
+///
+/// Numbers ending in `2`-`4` and fractions take the genitive singular (`2 файла`), numbers ending
+/// in `0` or `5`-`9` and the teens take the genitive plural (`5 файлов`). Latin transliterations
+/// fall back to the plural.
+pub(crate) fn count_form(name: &str, category: PluralCategory) -> Option<Cow<'_, str>> {
+    if !is_cyrillic(name) {
+        return None;
+    }
+    match category {
+        PluralCategory::Few | PluralCategory::Other => Some(genitive_singular(name)),
+        PluralCategory::Many => Some(genitive_plural(name)),
+        _ => None,
+    }
+}
+
+/// Detects the genitive plurals of Cyrillic nouns (`файлов`, `машин`, `людей`), which the suffix
+/// rules alone would read as singulars.
+pub(crate) fn number(name: &str) -> Option<Number> {
+    if !is_cyrillic(name) {
+        return None;
+    }
+    if GENITIVE_PLURALS.number(name) == Some(Number::Plural) {
+        return Some(Number::Plural);
+    }
+    number::genitive_plural_number(
+        name,
+        singularize_cyrillic,
+        pluralize_cyrillic,
+        genitive_plural,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::language_rules::LanguageRules;
 
     #[test]
     fn test_singularize() {
@@ -67,8 +1221,365 @@ mod tests {
         assert!(result.iter().any(|v| v == "tablici"));
     }
 
+    #[test]
+    fn test_pluralize_ranking() {
+        assert_eq!(pluralize("produkt")[0], "produkty");
+        assert_eq!(pluralize("tablica")[0], "tablicy");
+        assert_eq!(pluralize("kniga")[0], "knigi");
+        assert_eq!(pluralize("učebnik")[0], "učebniki");
+    }
+
+    #[test]
+    fn test_singularize_cyrillic() {
+        assert_eq!(singularize("пользователи"), "пользователь");
+        assert_eq!(singularize("файлы"), "файл");
+        assert_eq!(singularize("книги"), "книга");
+        assert_eq!(singularize("заметки"), "заметка");
+        assert_eq!(singularize("уроки"), "урок");
+        assert_eq!(singularize("диалоги"), "диалог");
+        assert_eq!(singularize("таблицы"), "таблица");
+        assert_eq!(singularize("программы"), "программа");
+        assert_eq!(singularize("группы"), "группа");
+        assert_eq!(singularize("продавцы"), "продавец");
+        assert_eq!(singularize("бойцы"), "боец");
+        assert_eq!(singularize("ключи"), "ключ");
+        assert_eq!(singularize("музеи"), "музей");
+        assert_eq!(singularize("записи"), "запись");
+        assert_eq!(singularize("категории"), "категория");
+        assert_eq!(singularize("сообщения"), "сообщение");
+        assert_eq!(singularize("котята"), "котёнок");
+        assert_eq!(singularize("граждане"), "гражданин");
+    }
+
+    #[test]
+    fn test_singularize_cyrillic_gender() {
+        assert_eq!(singularize("машины"), "машина");
+        assert_eq!(singularize("системы"), "система");
+        assert_eq!(singularize("страницы"), "страница");
+        assert_eq!(singularize("задачи"), "задача");
+        assert_eq!(singularize("мыши"), "мышь");
+        assert_eq!(singularize("месяцы"), "месяц");
+        assert_eq!(singularize("танцы"), "танец");
+        assert_eq!(singularize("форматы"), "формат");
+        assert_eq!(singularize("серверы"), "сервер");
+        assert_eq!(singularize("документы"), "документ");
+        assert_eq!(singularize("альбомы"), "альбом");
+        assert_eq!(singularize("врачи"), "врач");
+    }
+
+    #[test]
+    fn test_singularize_cyrillic_neuter_ie() {
+        assert_eq!(singularize("здания"), "здание");
+        assert_eq!(singularize("знания"), "знание");
+        assert_eq!(singularize("занятия"), "занятие");
+        assert_eq!(singularize("условия"), "условие");
+        assert_eq!(singularize("платья"), "платье");
+        assert_eq!(singularize("решения"), "решение");
+        assert_eq!(singularize("компания"), "компания");
+        assert_eq!(singularize("статья"), "статья");
+        assert_eq!(singularize("зданий"), "здание");
+        assert_eq!(singularize("платьев"), "платье");
+        assert_eq!(singularize("компаний"), "компания");
+        assert_eq!(singularize("гений"), "гений");
+    }
+
+    #[test]
+    fn test_singularize_cyrillic_genitive_plural() {
+        assert_eq!(singularize("файлов"), "файл");
+        assert_eq!(singularize("музеев"), "музей");
+        assert_eq!(singularize("месяцев"), "месяц");
+        assert_eq!(singularize("ключей"), "ключ");
+        assert_eq!(singularize("пользователей"), "пользователь");
+    }
+
+    #[test]
+    fn test_singularize_cyrillic_irregular() {
+        assert_eq!(singularize("люди"), "человек");
+        assert_eq!(singularize("людей"), "человек");
+        assert_eq!(singularize("дети"), "ребёнок");
+        assert_eq!(singularize("детей"), "ребёнок");
+        assert_eq!(singularize("друзья"), "друг");
+        assert_eq!(singularize("города"), "город");
+        assert_eq!(singularize("имена"), "имя");
+        assert_eq!(singularize("дни"), "день");
+        assert_eq!(singularize("глаз"), "глаз");
+        assert_eq!(singularize("граждане"), "гражданин");
+        assert_eq!(singularize("котята"), "котёнок");
+        assert_eq!(singularize("девушек"), "девушка");
+        assert_eq!(singularize("лет"), "год");
+        assert_eq!(singularize("остров"), "остров");
+        assert_eq!(singularize("жёны"), "жена");
+        assert_eq!(singularize("кузнецы"), "кузнец");
+        assert_eq!(singularize("клочья"), "клок");
+        assert_eq!(singularize("окошки"), "окошко");
+        assert_eq!(singularize("медвежата"), "медвежонок");
+    }
+
+    #[test]
+    fn test_singularize_cyrillic_already_singular() {
+        for word in [
+            "файл",
+            "книга",
+            "задача",
+            "музей",
+            "пользователь",
+            "категория",
+            "сообщение",
+            "окно",
+            "человек",
+        ] {
+            assert_eq!(singularize(word), word);
+        }
+    }
+
+    #[test]
+    fn test_pluralize_cyrillic() {
+        assert_eq!(pluralize("файл"), vec!["файлы"]);
+        assert_eq!(pluralize("книга"), vec!["книги"]);
+        assert_eq!(pluralize("задача"), vec!["задачи"]);
+        assert_eq!(pluralize("таблица"), vec!["таблицы"]);
+        assert_eq!(pluralize("ключ"), vec!["ключи"]);
+        assert_eq!(pluralize("неделя"), vec!["недели"]);
+        assert_eq!(pluralize("пользователь"), vec!["пользователи"]);
+        assert_eq!(pluralize("музей"), vec!["музеи"]);
+        assert_eq!(pluralize("категория"), vec!["категории"]);
+        assert_eq!(pluralize("окно"), vec!["окна"]);
+        assert_eq!(pluralize("сообщение"), vec!["сообщения"]);
+        assert_eq!(pluralize("солнце"), vec!["солнца"]);
+        assert_eq!(pluralize("племя"), vec!["племена"]);
+        assert_eq!(pluralize("котёнок"), vec!["котята"]);
+        assert_eq!(pluralize("гражданин"), vec!["граждане"]);
+    }
+
+    #[test]
+    fn test_pluralize_cyrillic_fleeting_vowel() {
+        assert_eq!(pluralize("звонок")[0], "звонки");
+        assert_eq!(pluralize("потолок")[0], "потолки");
+        assert_eq!(pluralize("урок")[0], "уроки");
+        assert_eq!(pluralize("игрок")[0], "игроки");
+        assert_eq!(pluralize("восток")[0], "востоки");
+        assert_eq!(pluralize("продавец")[0], "продавцы");
+        assert_eq!(pluralize("боец")[0], "бойцы");
+        assert_eq!(pluralize("отец"), vec!["отцы"]);
+        assert_eq!(pluralize("продавец"), vec!["продавцы"]);
+        assert_eq!(pluralize("кузнец"), vec!["кузнецы"]);
+    }
+
+    #[test]
+    fn test_pluralize_cyrillic_irregular() {
+        assert_eq!(pluralize("человек"), vec!["люди"]);
+        assert_eq!(pluralize("ребёнок"), vec!["дети"]);
+        assert_eq!(pluralize("ребенок"), vec!["дети"]);
+        assert_eq!(pluralize("брат"), vec!["братья"]);
+        assert_eq!(pluralize("дом"), vec!["дома"]);
+        assert_eq!(pluralize("учитель"), vec!["учителя"]);
+        assert_eq!(pluralize("уровень"), vec!["уровни"]);
+        assert_eq!(pluralize("колесо"), vec!["колёса"]);
+        assert_eq!(pluralize("поток"), vec!["потоки"]);
+        assert_eq!(pluralize("воробей"), vec!["воробьи"]);
+    }
+
+    #[test]
+    fn test_rules_cyrillic() {
+        assert_eq!(RULES.singularize("Пользователи"), "Пользователь");
+        assert_eq!(RULES.pluralize("ФАЙЛ"), vec!["ФАЙЛЫ"]);
+        assert_eq!(RULES.pluralize("такси"), vec!["такси"]);
+        assert_eq!(RULES.singularize("кофе"), "кофе");
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
     }
+
+    #[test]
+    fn test_count_form() {
+        assert_eq!(count_form("файл", PluralCategory::Few).unwrap(), "файла");
+        assert_eq!(count_form("файл", PluralCategory::Many).unwrap(), "файлов");
+        assert_eq!(count_form("книга", PluralCategory::Few).unwrap(), "книги");
+        assert_eq!(count_form("книга", PluralCategory::Many).unwrap(), "книг");
+        assert_eq!(
+            count_form("таблица", PluralCategory::Few).unwrap(),
+            "таблицы"
+        );
+        assert_eq!(
+            count_form("сообщение", PluralCategory::Few).unwrap(),
+            "сообщения"
+        );
+        assert_eq!(
+            count_form("сообщение", PluralCategory::Many).unwrap(),
+            "сообщений"
+        );
+        assert_eq!(
+            count_form("категория", PluralCategory::Many).unwrap(),
+            "категорий"
+        );
+        assert_eq!(
+            count_form("здание", PluralCategory::Many).unwrap(),
+            "зданий"
+        );
+        assert_eq!(count_form("платье", PluralCategory::Few).unwrap(), "платья");
+        assert_eq!(
+            count_form("платье", PluralCategory::Many).unwrap(),
+            "платьев"
+        );
+        assert_eq!(
+            count_form("ущелье", PluralCategory::Many).unwrap(),
+            "ущелий"
+        );
+        assert_eq!(
+            count_form("пользователь", PluralCategory::Few).unwrap(),
+            "пользователя"
+        );
+        assert_eq!(
+            count_form("пользователь", PluralCategory::Many).unwrap(),
+            "пользователей"
+        );
+        assert_eq!(count_form("запись", PluralCategory::Few).unwrap(), "записи");
+        assert_eq!(count_form("ключ", PluralCategory::Many).unwrap(), "ключей");
+        assert_eq!(count_form("музей", PluralCategory::Many).unwrap(), "музеев");
+        assert_eq!(
+            count_form("неделя", PluralCategory::Many).unwrap(),
+            "недель"
+        );
+        assert_eq!(
+            count_form("человек", PluralCategory::Many).unwrap(),
+            "человек"
+        );
+        assert_eq!(
+            count_form("ребёнок", PluralCategory::Many).unwrap(),
+            "детей"
+        );
+        assert_eq!(count_form("год", PluralCategory::Many).unwrap(), "лет");
+        assert_eq!(
+            count_form("девушка", PluralCategory::Many).unwrap(),
+            "девушек"
+        );
+        assert_eq!(
+            count_form("ссылка", PluralCategory::Many).unwrap(),
+            "ссылок"
+        );
+        assert_eq!(
+            count_form("заметка", PluralCategory::Many).unwrap(),
+            "заметок"
+        );
+        assert_eq!(
+            count_form("копейка", PluralCategory::Many).unwrap(),
+            "копеек"
+        );
+        assert_eq!(count_form("пятно", PluralCategory::Many).unwrap(), "пятен");
+        assert_eq!(count_form("день", PluralCategory::Few).unwrap(), "дня");
+        assert_eq!(count_form("день", PluralCategory::Many).unwrap(), "дней");
+        assert_eq!(count_form("звонок", PluralCategory::Few).unwrap(), "звонка");
+        assert_eq!(
+            count_form("звонок", PluralCategory::Many).unwrap(),
+            "звонков"
+        );
+        assert_eq!(count_form("урок", PluralCategory::Few).unwrap(), "урока");
+        assert_eq!(
+            count_form("человек", PluralCategory::Few).unwrap(),
+            "человека"
+        );
+        assert!(count_form("файл", PluralCategory::One).is_none());
+        assert!(count_form("fajl", PluralCategory::Many).is_none());
+    }
+
+    #[test]
+    fn test_count_form_irregular() {
+        assert_eq!(
+            count_form("котёнок", PluralCategory::Few).unwrap(),
+            "котёнка"
+        );
+        assert_eq!(
+            count_form("тигрёнок", PluralCategory::Many).unwrap(),
+            "тигрят"
+        );
+        assert_eq!(
+            count_form("кузнец", PluralCategory::Many).unwrap(),
+            "кузнецов"
+        );
+        assert_eq!(count_form("жена", PluralCategory::Many).unwrap(), "жён");
+        assert_eq!(count_form("имя", PluralCategory::Few).unwrap(), "имени");
+        assert_eq!(count_form("имя", PluralCategory::Many).unwrap(), "имён");
+        assert_eq!(count_form("время", PluralCategory::Few).unwrap(), "времени");
+        assert_eq!(
+            count_form("знамя", PluralCategory::Other).unwrap(),
+            "знамени"
+        );
+    }
+
+    #[test]
+    fn test_inflect_for_count() {
+        assert_eq!(RULES.inflect_for_count("файл", 1), "файл");
+        assert_eq!(RULES.inflect_for_count("файл", 2), "файла");
+        assert_eq!(RULES.inflect_for_count("файл", 5), "файлов");
+        assert_eq!(RULES.inflect_for_count("файл", 21), "файл");
+        assert_eq!(RULES.inflect_for_count("файл", 111), "файлов");
+        assert_eq!(RULES.inflect_for_count("Файл", 3), "Файла");
+        let half: crate::PluralOperands = "1.5".parse().unwrap();
+        assert_eq!(RULES.inflect_for_count("файл", half), "файла");
+        assert_eq!(RULES.inflect_for_count("klient", 5), "klienty");
+        assert_eq!(RULES.inflect_for_count("файлы", 5), "файлов");
+        assert_eq!(RULES.inflect_for_count("дети", 5), "детей");
+        assert_eq!(RULES.inflect_for_count("такси", 5), "такси");
+        assert_eq!(RULES.inflect_for_count("ссылка", 5), "ссылок");
+        assert_eq!(RULES.inflect_for_count("день", 2), "дня");
+        assert_eq!(RULES.inflect_for_count("день", 5), "дней");
+        assert_eq!(RULES.inflect_for_count("день", half), "дня");
+        assert_eq!(RULES.inflect_for_count("имя", 2), "имени");
+    }
+
+    #[test]
+    fn test_number_of() {
+        assert_eq!(RULES.number_of("машина"), Number::Singular);
+        assert_eq!(RULES.number_of("машины"), Number::Plural);
+        assert_eq!(RULES.number_of("машин"), Number::Plural);
+        assert_eq!(RULES.number_of("ссылок"), Number::Plural);
+        assert_eq!(RULES.number_of("файлов"), Number::Plural);
+        assert_eq!(RULES.number_of("Файл"), Number::Singular);
+        assert_eq!(RULES.number_of("магазин"), Number::Singular);
+        assert_eq!(RULES.number_of("урок"), Number::Singular);
+        assert_eq!(RULES.number_of("человек"), Number::Singular);
+        assert_eq!(RULES.number_of("людей"), Number::Plural);
+        assert_eq!(RULES.number_of("лет"), Number::Plural);
+        assert_eq!(RULES.number_of("кофе"), Number::Invariant);
+        assert_eq!(RULES.number_of("здания"), Number::Plural);
+        assert_eq!(RULES.number_of("платья"), Number::Plural);
+        assert_eq!(RULES.number_of("зданий"), Number::Plural);
+        assert_eq!(RULES.number_of("здание"), Number::Singular);
+        assert_eq!(RULES.number_of("категория"), Number::Singular);
+    }
+
+    #[test]
+    fn test_singularize_candidates() {
+        let candidates = singularize_candidates("машины");
+        assert_eq!(candidates[0], "машина");
+        assert!(candidates.iter().any(|v| v == "машин"));
+        assert_eq!(singularize_candidates("форматы")[0], "формат");
+        assert!(
+            singularize_candidates("форматы")
+                .iter()
+                .any(|v| v == "формата")
+        );
+        assert_eq!(singularize_candidates("мыши")[0], "мышь");
+        assert!(singularize_candidates("ключи").iter().any(|v| v == "ключа"));
+        assert!(
+            singularize_candidates("недели")
+                .iter()
+                .any(|v| v == "неделя")
+        );
+        assert_eq!(
+            singularize_candidates("знания"),
+            vec!["знание", "знание", "знания"]
+        );
+        assert_eq!(
+            singularize_candidates("категория"),
+            vec!["категория", "категорие", "категория"]
+        );
+        assert_eq!(singularize_candidates("люди"), vec!["человек"]);
+        assert_eq!(singularize_candidates("klienti"), vec!["klient"]);
+        let candidates = RULES.singularize_candidates("Машины");
+        assert_eq!(candidates[0].value(), "Машина");
+        assert_eq!(candidates[1].value(), "Машин");
+    }
 }
@@ -1,11 +1,184 @@
 //! Serbian (sr) inflection rules.
+//!
+//! Serbian is written in both Latin and Cyrillic script, and the script is detected per word.
+//! Latin words use the South Slavic rules in [`super::hr`]. Cyrillic words are transliterated
+//! to Latin, inflected by the same rules, and the changed ending is transliterated back, so both
+//! scripts share one set of rules.
 
 use crate::language_rules::LanguageRuleSet;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 
-pub(crate) use super::hr::{pluralize, singularize};
+use super::hr;
+use super::uk::is_cyrillic;
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "sr",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("sr", singularize, pluralize);
+
+/// Serbian Cyrillic letters and their Latin spelling. `lj`, `nj` and `dž` are single letters.
+static ALPHABET: &[(char, &str)] = &[
+    ('а', "a"),
+    ('б', "b"),
+    ('в', "v"),
+    ('г', "g"),
+    ('д', "d"),
+    ('ђ', "đ"),
+    ('е', "e"),
+    ('ж', "ž"),
+    ('з', "z"),
+    ('и', "i"),
+    ('ј', "j"),
+    ('к', "k"),
+    ('л', "l"),
+    ('љ', "lj"),
+    ('м', "m"),
+    ('н', "n"),
+    ('њ', "nj"),
+    ('о', "o"),
+    ('п', "p"),
+    ('р', "r"),
+    ('с', "s"),
+    ('т', "t"),
+    ('ћ', "ć"),
+    ('у', "u"),
+    ('ф', "f"),
+    ('х', "h"),
+    ('ц', "c"),
+    ('ч', "č"),
+    ('џ', "dž"),
+    ('ш', "š"),
+];
+
+/// Converts a plural Serbian noun to its singular form.
+pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if !is_cyrillic(name) {
+        return hr::singularize(name);
+    }
+    let latin = Latin::new(name);
+    let singular = hr::singularize(&latin.text);
+    latin.to_cyrillic(name, &singular)
+}
+
+/// Returns a list of possible plural forms for a Serbian noun.
+pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if !is_cyrillic(name) {
+        return hr::pluralize(name);
+    }
+    let latin = Latin::new(name);
+    hr::pluralize(&latin.text)
+        .iter()
+        .map(|plural| latin.to_cyrillic(name, plural))
+        .collect()
+}
+
+/// A Cyrillic word transliterated to Latin script.
+struct Latin {
+    /// The Latin spelling.
+    text: String,
+    /// The byte offsets at which each letter starts, in the Latin and the Cyrillic spelling,
+    /// ending with the lengths of both.
+    starts: Vec<(usize, usize)>,
+}
+
+impl Latin {
+    /// Transliterates the Cyrillic `word`, leaving characters outside the alphabet unchanged.
+    fn new(word: &str) -> Self {
+        let mut text = String::with_capacity(word.len());
+        let mut starts = vec![];
+        for (start, ch) in word.char_indices() {
+            starts.push((text.len(), start));
+            match ALPHABET.iter().find(|&&(cyrillic, _)| cyrillic == ch) {
+                Some(&(_, latin)) => text.push_str(latin),
+                None => text.push(ch),
+            }
+        }
+        starts.push((text.len(), word.len()));
+        Latin { text, starts }
+    }
+
+    /// Spells `inflected`, a Latin inflection of this word, in Cyrillic. The letters it shares
+    /// with this word keep their spelling in `word`, so only the changed ending is
+    /// transliterated.
+    fn to_cyrillic<'a>(&self, word: &'a str, inflected: &str) -> Cow<'a, str> {
+        if inflected == self.text {
+            return Cow::Borrowed(word);
+        }
+        let common = self
+            .text
+            .bytes()
+            .zip(inflected.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let (latin_start, cyrillic_start) = self
+            .starts
+            .iter()
+            .rev()
+            .find(|&&(latin_start, _)| latin_start <= common)
+            .copied()
+            .unwrap_or_default();
+        let mut cyrillic = String::from(&word[..cyrillic_start]);
+        let mut rest = &inflected[latin_start..];
+        while let Some(ch) = rest.chars().next() {
+            let letter = ALPHABET
+                .iter()
+                .filter(|(_, latin)| rest.starts_with(latin))
+                .max_by_key(|(_, latin)| latin.len());
+            if let Some(&(letter, latin)) = letter {
+                cyrillic.push(letter);
+                rest = &rest[latin.len()..];
+            } else {
+                cyrillic.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+        Cow::Owned(cyrillic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("korisnici"), "korisnik");
+        assert_eq!(singularize("gradovi"), "grad");
+        assert_eq!(singularize("корисници"), "корисник");
+        assert_eq!(singularize("продукти"), "продукт");
+        assert_eq!(singularize("градови"), "град");
+        assert_eq!(singularize("краљеви"), "краљ");
+    }
+
+    #[test]
+    fn test_singularize_already_singular() {
+        assert_eq!(singularize("корисник"), "корисник");
+        assert!(matches!(singularize("корисник"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_singularize_keeps_stem_spelling() {
+        // `нј` across a morpheme boundary is not the letter `њ`
+        assert_eq!(singularize("конјунктури"), "конјунктур");
+    }
+
+    #[test]
+    fn test_pluralize() {
+        let result = pluralize("корисник");
+        assert!(result.iter().any(|v| v == "корисници"));
+
+        let result = pluralize("град");
+        assert!(result.iter().any(|v| v == "градови"));
+
+        let result = pluralize("краљ");
+        assert!(result.iter().any(|v| v == "краљеви"));
+
+        let result = pluralize("grad");
+        assert!(result.iter().any(|v| v == "gradovi"));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(singularize(""), "");
+    }
+}
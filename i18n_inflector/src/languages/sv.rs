@@ -1,11 +1,329 @@
 //! Swedish (sv) inflection rules.
+//!
+//! Swedish nouns fall into five plural declensions: `-or` for common nouns in `-a` (`flicka` ->
+//! `flickor`), `-ar` for most native common nouns (`bil` -> `bilar`, `pojke` -> `pojkar`), `-er`
+//! for loan words with final stress and some native nouns (`produkt` -> `produkter`), `-n` for
+//! neuters in a vowel (`äpple` -> `äpplen`), and no ending for neuters in a consonant and agent
+//! nouns (`hus`, `lärare`). The declension cannot always be told from the singular, so the rules
+//! rank the likely candidates.
 
 use crate::language_rules::LanguageRuleSet;
+use crate::lexicon::{Irregulars, Lexicon, irregulars};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use phf::phf_set;
 
-pub(crate) use super::da::{pluralize, singularize};
+use super::{strip, vowel_groups};
+
+/// Irregular Swedish nouns: umlaut plurals, old plurals and nouns in `-e` that take `-ar`.
+static IRREGULARS: Irregulars = irregulars! {
+    // Umlaut plurals
+    "bok" => "böcker",
+    "bonde" => "bönder",
+    "bror" => "bröder",
+    "far" => "fäder",
+    "fot" => "fötter",
+    "gås" => "gäss",
+    "hand" => "händer",
+    "land" => "länder",
+    "lus" => "löss",
+    "man" => "män",
+    "mor" => "mödrar",
+    "mus" => "möss",
+    "natt" => "nätter",
+    "rot" => "rötter",
+    "son" => "söner",
+    "stad" => "städer",
+    "strand" => "stränder",
+    "tand" => "tänder",
+    "tång" => "tänger",
+    // Other irregular plurals
+    "dotter" => "döttrar",
+    "fiende" => "fiender",
+    "kriterium" => "kriterier",
+    "sommar" => "somrar",
+    "studium" => "studier",
+    "syster" => "systrar",
+    "öga" => "ögon",
+    "öra" => "öron",
+    // Nouns in -e which take -ar
+    "hage" => "hagar",
+    "kille" => "killar",
+    "pojke" => "pojkar",
+    "timme" => "timmar",
+    "ände" => "ändar",
+};
+
+/// Swedish neuters with the same form in the singular and plural.
+static LEXICON: Lexicon = Lexicon {
+    uncountables: phf_set! {
+        "bad", "barn", "berg", "blad", "bord", "brev", "bröd", "djur", "exempel", "fall", "fel",
+        "fält", "fönster", "glas", "golv", "hav", "hjul", "hotell", "hus", "kort", "kök", "lejon",
+        "ljud", "ljus", "moln", "mål", "namn", "nummer", "nät", "objekt", "ord", "paket", "papper",
+        "par", "problem", "program", "projekt", "recept", "rum", "skal", "skepp", "slott", "spel",
+        "språk", "steg", "svar", "system", "tak", "tecken", "torn", "träd", "tåg", "vatten", "verk",
+        "år", "ägg",
+    },
+    uncountable_suffixes: &["are", "iker"],
+    invariant_suffixes: &["ment"],
+};
+
+/// Singular Swedish nouns in `-or`, mostly Latin agent nouns, which would otherwise be read as
+/// plurals of nouns in `-a` (`motor`, `dator`, but `gator` -> `gata`).
+static OR_SINGULARS: phf::Set<&'static str> = phf_set! {
+    "ackumulator", "dator", "direktor", "doktor", "faktor", "generator", "humor", "indikator",
+    "junior", "kalkylator", "kompressor", "kondensator", "major", "mentor", "monitor", "motor",
+    "operator", "processor", "professor", "radiator", "reaktor", "rektor", "sektor", "senior",
+    "tenor", "traktor", "transistor", "tumor", "vektor", "ventilator",
+};
+
+/// Swedish neuters in `-e`, which take `-n` in the plural (`äpple` -> `äpplen`) and whose plural
+/// cannot be told apart from a singular in `-en` such as `öken`.
+static NEUTERS_IN_E: phf::Set<&'static str> = phf_set! {
+    "ansikte", "arbete", "bete", "läge", "minne", "märke", "möte", "nöje", "område",
+    "rike", "skede", "stycke", "ställe", "yrke", "öde", "äpple",
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "sv",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    irregulars: Some(&IRREGULARS),
+    lexicon: &LEXICON,
+    ..LanguageRuleSet::new("sv", singularize, pluralize)
 };
+
+/// Endings of nouns in the third declension, which take `-er` (`nation` -> `nationer`).
+const ER_ENDINGS: &[&str] = &[
+    "het", "ion", "tet", "ör", "ist", "ent", "ant", "graf", "log", "ur",
+];
+
+/// Returns `true` if `ch` is a Swedish vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "aeiouyåäöé".contains(ch)
+}
+
+/// Drops the unstressed `e` of a final `-el`, `-en` or `-er` before the `-ar` ending and
+/// simplifies a preceding `mm` (`nyckel` -> `nyckl`, `himmel` -> `himl`), or returns `None` if
+/// the noun does not end in an unstressed syllable.
+fn syncopate(name: &str) -> Option<String> {
+    let stem = ["el", "en", "er"]
+        .iter()
+        .find_map(|&ending| strip(name, ending))?;
+    if vowel_groups(name, is_vowel) < 2 || !stem.ends_with(|ch: char| !is_vowel(ch)) {
+        return None;
+    }
+    let stem = if stem.ends_with("mm") {
+        &stem[..stem.len() - 1]
+    } else {
+        stem
+    };
+    let consonant = &name[name.len() - 1..];
+    Some(format!("{stem}{consonant}"))
+}
+
+/// Converts a plural Swedish noun to its singular form.
+///
+/// Handles irregular nouns (`män` -> `man`, `böcker` -> `bok`), `-or` for nouns in `-a`
+/// (`flickor` -> `flicka`), `-ar` with the unstressed `e` restored (`nycklar` -> `nyckel`), `-er`
+/// (`produkter` -> `produkt`, `linjer` -> `linje`), and `-n` after a vowel (`konton` -> `konto`,
+/// `meddelanden` -> `meddelande`, and known neuters in `-e` such as `äpplen` -> `äpple`). Known
+/// singulars in `-or` such as `motor` and `dator` are kept, and other plurals in `-en` are left
+/// alone since they share their ending with singulars such as `öken` and `vatten`.
+pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(singular) = IRREGULARS.singular(name) {
+        return Cow::Borrowed(singular);
+    }
+    if let Some(stem) = strip(name, "or") {
+        if !stem.contains(is_vowel) {
+            return Cow::Borrowed(&name[..name.len() - 1]);
+        }
+        if OR_SINGULARS.contains(name) {
+            return Cow::Borrowed(name);
+        }
+        return format!("{stem}a").into();
+    }
+    for (plural, singular) in [("anden", "ande"), ("enden", "ende"), ("eer", "eum")] {
+        if let Some(stem) = strip(name, plural) {
+            return format!("{stem}{singular}").into();
+        }
+    }
+    if let Some(stem) = strip(name, "ar") {
+        // Restore the unstressed `e` dropped before `-ar` (`fåglar` -> `fågel`).
+        let mut chars = stem.chars().rev();
+        if let (Some(last @ ('l' | 'n' | 'r')), Some(previous)) = (chars.next(), chars.next())
+            && !is_vowel(previous)
+            && !"jlmnr".contains(previous)
+        {
+            return format!("{}e{last}", &stem[..stem.len() - 1]).into();
+        }
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = strip(name, "jer") {
+        return format!("{stem}je").into();
+    }
+    if let Some(stem) = strip(name, "er") {
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = strip(name, "n")
+        && (stem.ends_with(|ch: char| is_vowel(ch) && ch != 'e') || NEUTERS_IN_E.contains(stem))
+    {
+        return Cow::Borrowed(stem);
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns a list of possible plural forms for a Swedish noun, most likely first.
+///
+/// Irregular nouns are looked up first (`man` -> `män`). Nouns in `-a` take `-or`, nouns in
+/// `-ande` and `-ende` prefer `-n`, Latin nouns in `-eum` and `-ium` take `-er` in place of `-um`,
+/// and nouns in `-é`, `-i` and the endings in [`ER_ENDINGS`] take `-er`. Known neuters in `-e`
+/// take `-n` (`äpple` -> `äpplen`), other nouns in `-e` prefer `-ar` over `-n`, and nouns in an
+/// unstressed `-el`, `-en` or `-er` prefer `-ar` after dropping the `e` (`nyckel` -> `nycklar`).
+/// Monosyllables take `-ar` or no ending (`bil` -> `bilar`), and longer nouns prefer the `-er` of
+/// loan words over `-ar` (`kolumn` -> `kolumner`).
+pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if let Some(plural) = IRREGULARS.plural(name) {
+        return vec![Cow::Borrowed(plural)];
+    }
+    if let Some(stem) = strip(name, "a") {
+        return vec![format!("{stem}or").into()];
+    }
+    if name.ends_with("ande") || name.ends_with("ende") {
+        return vec![format!("{name}n").into(), Cow::Borrowed(name)];
+    }
+    if name.ends_with("else") {
+        return vec![format!("{name}r").into()];
+    }
+    if let Some(stem) = strip(name, "um")
+        && stem.ends_with(['e', 'i'])
+    {
+        return vec![format!("{stem}er").into()];
+    }
+    if name.ends_with(['é', 'i']) || ER_ENDINGS.iter().any(|ending| name.ends_with(ending)) {
+        return vec![format!("{name}er").into()];
+    }
+    if NEUTERS_IN_E.contains(name) {
+        return vec![format!("{name}n").into()];
+    }
+    if let Some(stem) = strip(name, "e") {
+        return vec![format!("{stem}ar").into(), format!("{name}n").into()];
+    }
+    if name.ends_with(is_vowel) {
+        let mut candidates = vec![format!("{name}r").into(), format!("{name}n").into()];
+        if vowel_groups(name, is_vowel) > 1 {
+            candidates.reverse();
+        }
+        return candidates;
+    }
+    if let Some(stem) = syncopate(name) {
+        return vec![format!("{stem}ar").into(), Cow::Borrowed(name)];
+    }
+    if vowel_groups(name, is_vowel) > 1 {
+        return vec![
+            format!("{name}er").into(),
+            format!("{name}ar").into(),
+            Cow::Borrowed(name),
+        ];
+    }
+    vec![format!("{name}ar").into(), Cow::Borrowed(name)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("flickor"), "flicka");
+        assert_eq!(singularize("listor"), "lista");
+        assert_eq!(singularize("bilar"), "bil");
+        assert_eq!(singularize("produkter"), "produkt");
+        assert_eq!(singularize("filer"), "fil");
+        assert_eq!(singularize("linjer"), "linje");
+        assert_eq!(singularize("idéer"), "idé");
+        assert_eq!(singularize("museer"), "museum");
+        assert_eq!(singularize("konton"), "konto");
+        assert_eq!(singularize("meddelanden"), "meddelande");
+        assert_eq!(singularize("skor"), "sko");
+        assert_eq!(singularize("gator"), "gata");
+        assert_eq!(singularize("äpplen"), "äpple");
+        assert_eq!(singularize("ställen"), "ställe");
+    }
+
+    #[test]
+    fn test_singularize_already_singular() {
+        assert_eq!(singularize("produkt"), "produkt");
+        assert_eq!(singularize("motor"), "motor");
+        assert_eq!(singularize("faktor"), "faktor");
+        assert_eq!(singularize("öken"), "öken");
+        assert_eq!(singularize("dator"), "dator");
+        assert_eq!(singularize("vatten"), "vatten");
+    }
+
+    #[test]
+    fn test_singularize_syncope() {
+        assert_eq!(singularize("nycklar"), "nyckel");
+        assert_eq!(singularize("fåglar"), "fågel");
+        assert_eq!(singularize("öknar"), "öken");
+        assert_eq!(singularize("vintrar"), "vinter");
+        assert_eq!(singularize("hamnar"), "hamn");
+    }
+
+    #[test]
+    fn test_irregulars() {
+        assert_eq!(singularize("män"), "man");
+        assert_eq!(singularize("möss"), "mus");
+        assert_eq!(singularize("böcker"), "bok");
+        assert_eq!(singularize("pojkar"), "pojke");
+        assert_eq!(pluralize("man"), vec!["män"]);
+        assert_eq!(pluralize("mus"), vec!["möss"]);
+        assert_eq!(pluralize("hand"), vec!["händer"]);
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("flicka"), vec!["flickor"]);
+        assert_eq!(pluralize("bil"), vec!["bilar", "bil"]);
+        assert_eq!(pluralize("äpple"), vec!["äpplen"]);
+        assert_eq!(pluralize("kolumn")[0], "kolumner");
+        assert_eq!(pluralize("nation"), vec!["nationer"]);
+        assert_eq!(pluralize("enhet"), vec!["enheter"]);
+        assert_eq!(pluralize("idé"), vec!["idéer"]);
+        assert_eq!(pluralize("museum"), vec!["museer"]);
+        assert_eq!(pluralize("händelse"), vec!["händelser"]);
+        assert_eq!(pluralize("meddelande")[0], "meddelanden");
+        assert_eq!(pluralize("gubbe"), vec!["gubbar", "gubben"]);
+        assert_eq!(pluralize("konto")[0], "konton");
+        assert_eq!(pluralize("sko")[0], "skor");
+    }
+
+    #[test]
+    fn test_pluralize_syncope() {
+        assert_eq!(pluralize("nyckel")[0], "nycklar");
+        assert_eq!(pluralize("fågel")[0], "fåglar");
+        assert_eq!(pluralize("vinter")[0], "vintrar");
+        assert_eq!(pluralize("himmel")[0], "himlar");
+        assert_eq!(syncopate("bil"), None);
+    }
+
+    #[test]
+    fn test_rules_invariant() {
+        use crate::language_rules::LanguageRules;
+        assert_eq!(RULES.pluralize("hus"), vec!["hus"]);
+        assert_eq!(RULES.pluralize("lärare"), vec!["lärare"]);
+        assert_eq!(RULES.pluralize("dokument"), vec!["dokument"]);
+        assert_eq!(RULES.pluralize("rum"), vec!["rum"]);
+        assert_eq!(RULES.pluralize("språk"), vec!["språk"]);
+        assert_eq!(RULES.pluralize("bil")[0], "bilar");
+        assert_eq!(RULES.number_of("rum"), crate::Number::Invariant);
+        assert_eq!(RULES.singularize("musiker"), "musiker");
+        assert_eq!(RULES.number_of("gator"), crate::Number::Plural);
+        assert_eq!(RULES.number_of("äpplen"), crate::Number::Plural);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(singularize(""), "");
+    }
+}
@@ -2,56 +2,168 @@
 //!
 //! Also used for Azerbaijani (az), Bashkir (ba), Chuvash (cv), Kazakh (kk), Kyrgyz (ky),
 //! Turkmen (tk), Tatar (tt), Uyghur (ug), and Uzbek (uz).
+//!
+//! Turkish nouns stack their suffixes in a fixed order: stem, plural `-lAr`, possessive and case
+//! (`kitap-lar-ımız-da`, "in our books"). Suffix templates below write the harmonizing vowels
+//! in capitals: `A` is `a` or `e` and `I` is `ı`, `i`, `u` or `ü`.
 
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
 
 use crate::language_rules::LanguageRuleSet;
 
 /// Back vowels in Turkish vowel harmony (including dotless-i).
 const BACK_VOWELS: &[char] = &['a', 'ı', 'o', 'u', 'A', 'I', 'O', 'U'];
-/// Unambiguous front vowels in Turkish vowel harmony.
-const FRONT_VOWELS: &[char] = &['e', 'ö', 'ü', 'E', 'İ', 'Ö', 'Ü'];
-/// Ambiguous vowels that could be front or back in ASCII transliteration.
-const AMBIGUOUS_VOWELS: &[char] = &['i'];
+/// Front vowels in Turkish vowel harmony, including Azerbaijani `ə` and Turkmen `ä`.
+const FRONT_VOWELS: &[char] = &['e', 'i', 'ö', 'ü', 'ə', 'ä', 'E', 'İ', 'Ö', 'Ü', 'Ə', 'Ä'];
+
+/// Case endings that follow the plural marker or a first or second person possessive.
+const CASE_ENDINGS: &[&str] = &["", "I", "A", "dA", "dAki", "dAn", "In", "Inki", "lA"];
+/// Case endings that follow a third person possessive, with the buffer `n` or `y`.
+const THIRD_PERSON_CASE_ENDINGS: &[&str] = &[
+    "", "nI", "nA", "ndA", "ndAki", "ndAn", "nIn", "nInki", "ylA",
+];
+/// First and second person possessive endings after a consonant (`-ım`, `-ın`, `-ımız`,
+/// `-ınız`).
+const POSSESSIVE_ENDINGS: &[&str] = &["Im", "In", "ImIz", "InIz"];
+
+/// Common nouns whose final `p`, `ç`, `t` or `k` softens to `b`, `c`, `d` or `ğ` before a vowel
+/// (`kitap` -> `kitabı`). Softening is not predictable from the word alone, since most
+/// monosyllables and many loan words keep their consonant (`top` -> `topu`).
+static SOFTENING_NOUNS: phf::Set<&'static str> = phf_set! {
+    "ağaç", "amaç", "ayak", "bardak", "bıçak", "boyut", "cep", "çiçek", "çocuk", "dolap", "durak",
+    "ekmek", "gözlük", "ilaç", "inek", "kalp", "kanat", "kazanç", "kâğıt", "kitap", "köpek",
+    "kulak", "mektup", "ortak", "renk", "sokak", "tabak", "yaprak", "yatak", "yurt",
+};
+
+/// Loan words whose last vowel is back but whose suffixes take front harmony, mostly after a
+/// final palatal `l`, `k` or `t` (`saat` -> `saatler`, `gol` -> `goller`).
+static FRONT_HARMONY_NOUNS: phf::Set<&'static str> = phf_set! {
+    "alkol", "basketbol", "dikkat", "futbol", "gol", "hakikat", "hal", "hayal", "ihtimal",
+    "istikbal", "kabul", "kalp", "kontrol", "misal", "petrol", "protokol", "rol", "saat",
+    "sembol", "seyahat", "usul", "voleybol",
+};
+
+/// Returns `true` if `ch` is a harmonizing vowel.
+fn is_vowel(ch: char) -> bool {
+    BACK_VOWELS.contains(&ch) || FRONT_VOWELS.contains(&ch)
+}
 
 /// Determines vowel harmony for a Turkic word.
 /// Returns `Some(true)` for back, `Some(false)` for front, `None` if ambiguous
 /// or no vowels found.
+///
+/// The dotted `i` is a front vowel, but ASCII text often writes the dotless `ı` as `i`
+/// (`kullanici`), so a final `i` in an ASCII word with an earlier back vowel is ambiguous. The
+/// [`FRONT_HARMONY_NOUNS`] are front despite their last vowel.
 fn last_vowel_harmony(s: &str) -> Option<bool> {
-    for ch in s.chars().rev() {
-        if BACK_VOWELS.contains(&ch) {
-            return Some(true);
-        }
-        if FRONT_VOWELS.contains(&ch) {
-            return Some(false);
+    if FRONT_HARMONY_NOUNS.contains(s) {
+        return Some(false);
+    }
+    let mut vowels = s.chars().rev().filter(|&ch| is_vowel(ch));
+    let last = vowels.next()?;
+    if last == 'i' && s.is_ascii() && vowels.any(|ch| BACK_VOWELS.contains(&ch)) {
+        return None;
+    }
+    Some(BACK_VOWELS.contains(&last))
+}
+
+/// Returns `true` if `tail` spells the suffix `template` in the given harmony. ASCII words may
+/// write `ı` as `i` and `ü` as `u`.
+fn matches_template(tail: &str, template: &str, back: bool, folded: bool) -> bool {
+    let mut chars = tail.chars();
+    for expected in template.chars() {
+        let Some(ch) = chars.next() else {
+            return false;
+        };
+        let matched = match expected {
+            'A' => ch == if back { 'a' } else { 'e' },
+            'I' if back => matches!(ch, 'ı' | 'u') || (folded && ch == 'i'),
+            'I' => matches!(ch, 'i' | 'ü') || (folded && ch == 'u'),
+            _ => ch == expected,
+        };
+        if !matched {
+            return false;
         }
-        if AMBIGUOUS_VOWELS.contains(&ch) {
+    }
+    chars.next().is_none()
+}
+
+/// Returns `true` if `tail` is a possessive and case suffix stack in the given harmony, as it
+/// follows a consonant such as the `r` of the plural marker.
+fn is_suffix_stack(tail: &str, back: bool) -> bool {
+    let folded = tail.is_ascii();
+    let matches = |template: &str| matches_template(tail, template, back, folded);
+    CASE_ENDINGS.iter().any(|&case| matches(case))
+        || THIRD_PERSON_CASE_ENDINGS
+            .iter()
+            .any(|&case| matches(&format!("I{case}")))
+        || POSSESSIVE_ENDINGS.iter().any(|&possessive| {
+            CASE_ENDINGS
+                .iter()
+                .any(|&case| matches(&format!("{possessive}{case}")))
+        })
+}
+
+/// Splits a word into its stem and the possessive and case suffixes stacked after the plural
+/// marker (`arabalarımız` -> `araba`), or returns `None` if the word has no plural marker.
+fn strip_plural_stack(name: &str) -> Option<&str> {
+    let mut positions: Vec<(usize, bool)> = name
+        .match_indices("lar")
+        .map(|(index, _)| (index, true))
+        .chain(name.match_indices("ler").map(|(index, _)| (index, false)))
+        .collect();
+    positions.sort_unstable_by(|a, b| b.cmp(a));
+    positions.into_iter().find_map(|(index, back)| {
+        let stem = &name[..index];
+        let tail = &name[index + 3..];
+        (!stem.is_empty() && is_suffix_stack(tail, back)).then_some(stem)
+    })
+}
+
+/// Returns the dictionary form of a singular noun with a vowel-initial suffix stack whose final
+/// consonant has softened (`kitabımızda` -> `kitap`, `çocuğu` -> `çocuk`), provided the noun is
+/// one of the [`SOFTENING_NOUNS`].
+fn unsoften(name: &str) -> Option<&'static str> {
+    name.char_indices().find_map(|(index, ch)| {
+        let hard = match ch {
+            'b' => 'p',
+            'c' => 'ç',
+            'd' => 't',
+            'ğ' | 'g' => 'k',
+            _ => return None,
+        };
+        let end = index + ch.len_utf8();
+        let tail = &name[end..];
+        if !tail.starts_with(is_vowel) {
             return None;
         }
-    }
-    None
+        let mut noun = String::from(&name[..index]);
+        noun.push(hard);
+        let noun = SOFTENING_NOUNS.get_key(noun.as_str())?;
+        let back = last_vowel_harmony(noun).unwrap_or(true);
+        is_suffix_stack(tail, back).then_some(*noun)
+    })
 }
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "tr",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("tr", singularize, pluralize);
 
 /// Converts a plural Turkish noun to its singular form.
 ///
-/// Turkish plurals use `-lar` (back vowel harmony) or `-ler` (front vowel
-/// harmony).
+/// Turkish plurals use `-lar` (back vowel harmony) or `-ler` (front vowel harmony), followed by
+/// optional possessive and case endings, which are removed with the plural marker (`kitapları`
+/// -> `kitap`, `evlerde` -> `ev`, `arabalarımız` -> `araba`). Singular nouns with a softened
+/// final consonant are restored to their dictionary form (`kitabı` -> `kitap`).
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
-    for suffix in &["lar", "ler"] {
-        if let Some(stem) = name.strip_suffix(suffix)
-            && !stem.is_empty()
-        {
-            return Cow::Borrowed(stem);
-        }
+    if let Some(stem) = strip_plural_stack(name) {
+        return Cow::Borrowed(stem);
+    }
+    if let Some(noun) = unsoften(name) {
+        return Cow::Borrowed(noun);
     }
     Cow::Borrowed(name)
 }
@@ -59,7 +171,8 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
 /// Returns the grammatically correct plural form for a Turkish noun based on
 /// vowel harmony.
 ///
-/// Analyzes the stem's last vowel to choose between `-lar` (back) and `-ler` (front).
+/// Analyzes the stem's last vowel to choose between `-lar` (back) and `-ler` (front), except for
+/// the [`FRONT_HARMONY_NOUNS`], which take `-ler` (`saat` -> `saatler`).
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
     match last_vowel_harmony(name) {
         Some(true) => vec![format!("{name}lar").into()],
@@ -86,10 +199,36 @@ mod tests {
         assert_eq!(singularize("siparisler"), "siparis");
     }
 
+    #[test]
+    fn test_singularize_suffix_stack() {
+        assert_eq!(singularize("kitapları"), "kitap");
+        assert_eq!(singularize("evlerde"), "ev");
+        assert_eq!(singularize("arabalarımız"), "araba");
+        assert_eq!(singularize("evlerimizden"), "ev");
+        assert_eq!(singularize("kitaplarının"), "kitap");
+        assert_eq!(singularize("gözlerinde"), "göz");
+        assert_eq!(singularize("okullardaki"), "okul");
+        assert_eq!(singularize("arkadaşlarla"), "arkadaş");
+        assert_eq!(singularize("kullanicilari"), "kullanici");
+    }
+
+    #[test]
+    fn test_singularize_softening() {
+        assert_eq!(singularize("kitabı"), "kitap");
+        assert_eq!(singularize("kitabımızda"), "kitap");
+        assert_eq!(singularize("çocuğu"), "çocuk");
+        assert_eq!(singularize("ağacında"), "ağaç");
+        assert_eq!(singularize("rengi"), "renk");
+        assert_eq!(singularize("kedi"), "kedi");
+        assert_eq!(singularize("kullanıcı"), "kullanıcı");
+    }
+
     #[test]
     fn test_singularize_already_singular() {
         assert_eq!(singularize("kullanici"), "kullanici");
         assert_eq!(singularize("ev"), "ev");
+        assert_eq!(singularize("kalem"), "kalem");
+        assert_eq!(singularize("hilal"), "hilal");
     }
 
     #[test]
@@ -121,6 +260,17 @@ mod tests {
         assert_eq!(result, vec!["gözler"]);
     }
 
+    #[test]
+    fn test_pluralize_front_harmony_nouns() {
+        assert_eq!(pluralize("saat"), vec!["saatler"]);
+        assert_eq!(pluralize("hal"), vec!["haller"]);
+        assert_eq!(pluralize("kalp"), vec!["kalpler"]);
+        assert_eq!(pluralize("gol"), vec!["goller"]);
+        assert_eq!(pluralize("sal"), vec!["sallar"]);
+        assert_eq!(singularize("saatlerimiz"), "saat");
+        assert_eq!(singularize("kalbimizde"), "kalp");
+    }
+
     #[test]
     fn test_pluralize_ambiguous_vowel() {
         let result = pluralize("kullanici");
@@ -161,6 +311,18 @@ mod tests {
         assert_eq!(last_vowel_harmony("kullanici"), None);
     }
 
+    #[test]
+    fn test_last_vowel_harmony_dotted_i() {
+        assert_eq!(last_vowel_harmony("kullanıcı"), Some(true));
+        assert_eq!(last_vowel_harmony("siparis"), None);
+        assert_eq!(last_vowel_harmony("ekip"), Some(false));
+        assert_eq!(last_vowel_harmony("bilgi"), Some(false));
+        assert_eq!(last_vowel_harmony("şehir"), Some(false));
+        assert_eq!(last_vowel_harmony("kəlmə"), Some(false));
+        assert_eq!(pluralize("şehir"), vec!["şehirler"]);
+        assert_eq!(pluralize("kullanıcı"), vec!["kullanıcılar"]);
+    }
+
     #[test]
     fn test_last_vowel_harmony_none() {
         assert_eq!(last_vowel_harmony("xyz"), None);
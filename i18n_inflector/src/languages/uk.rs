@@ -1,23 +1,70 @@
 //! Ukrainian (uk) inflection rules.
 //!
-//! Also used for Belarusian (be).
+//! Words in Cyrillic script are declined natively; words in Latin script are treated as
+//! transliterations. The script is detected per word. The transliteration rules are also used
+//! for Belarusian (be).
 
 use crate::language_rules::LanguageRuleSet;
+use crate::number::{self, Number};
+use crate::plural_rules::PluralCategory;
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
+use phf::phf_set;
+
+use super::strip;
+
+/// Irregular nouns as (singular, nominative plural, genitive plural), including the common
+/// nouns whose `і` alternates with `о` or `е` (`рік` -> `роки`).
+static IRREGULARS: &[(&str, &str, &str)] = &[
+    ("людина", "люди", "людей"),
+    ("дитина", "діти", "дітей"),
+    ("друг", "друзі", "друзів"),
+    ("око", "очі", "очей"),
+    ("вухо", "вуха", "вух"),
+    ("ім'я", "імена", "імен"),
+    ("ім’я", "імена", "імен"),
+    ("мати", "матері", "матерів"),
+    ("день", "дні", "днів"),
+    ("батько", "батьки", "батьків"),
+    ("рік", "роки", "років"),
+    ("кінь", "коні", "коней"),
+    ("ніч", "ночі", "ночей"),
+    ("річ", "речі", "речей"),
+    ("кіт", "коти", "котів"),
+    ("стіл", "столи", "столів"),
+    ("міст", "мости", "мостів"),
+    ("ніж", "ножі", "ножів"),
+    ("вечір", "вечори", "вечорів"),
+];
+
+/// Neuter Ukrainian nouns in `-о` and `-е`, whose nominative plurals in `-а` and `-я` read the
+/// same as feminine singulars (`вікна` -> `вікно`, but `книга` stays `книга`).
+static NEUTERS: phf::Set<&'static str> = phf_set! {
+    "болото", "вино", "відро", "вікно", "гасло", "дерево", "джерело", "добро", "житло", "залізо",
+    "зерно", "золото", "колесо", "коло", "крило", "крісло", "лице", "літо", "мило", "місто",
+    "місце", "море", "озеро", "пиво", "поле", "право", "ребро", "ремесло", "свято", "село", "серце",
+    "сідло", "скло", "слово", "сонце", "срібло", "судно", "тіло", "число", "ядро", "яйце",
+};
 
 pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "uk",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
+    singularize_candidates_fn: Some(singularize_candidates),
+    count_form_fn: Some(count_form),
+    number_fn: Some(number),
+    ..LanguageRuleSet::new("uk", singularize, pluralize)
 };
 
-/// Converts a plural East Slavic noun (Latin transliteration) to its singular form.
+/// Converts a plural Ukrainian noun to its singular form.
 ///
-/// Handles `-y` -> `-a` transformation and `-i` suffix stripping, similar to Russian but with
-/// distinct patterns for Ukrainian and Belarusian.
+/// Cyrillic nouns are handled by [`singularize_cyrillic`]. For Latin transliterations, handles
+/// `-y` -> `-a` transformation and `-i` suffix stripping, similar to Russian but with distinct
+/// patterns for Ukrainian and Belarusian.
 pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if is_cyrillic(name) {
+        return singularize_cyrillic(name);
+    }
     if let Some(stem) = name.strip_suffix('y')
         && !stem.is_empty()
     {
@@ -31,22 +78,301 @@ pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
     Cow::Borrowed(name)
 }
 
-/// Returns a list of possible plural forms for an East Slavic noun (Latin
-/// transliteration).
+/// Returns the possible singular forms of a Ukrainian noun, most likely first.
+///
+/// Starts with the result of [`singularize`]. A Cyrillic word in `-а` or `-я` may also be the
+/// nominative plural of a neuter in `-о` or `-е` (`вікна` -> `вікно`), or a feminine singular
+/// (`книга`); the reading listed in [`NEUTERS`] comes first.
+pub(crate) fn singularize_candidates(name: &str) -> Vec<Cow<'_, str>> {
+    let mut candidates = vec![singularize(name)];
+    if !is_cyrillic(name) || name.ends_with("ння") || name.ends_with("ття") {
+        return candidates;
+    }
+    let neuter = strip(name, "а")
+        .map(|stem| format!("{stem}о"))
+        .or_else(|| strip(name, "я").map(|stem| format!("{stem}е")));
+    if let Some(neuter) = neuter {
+        let other = if candidates[0] == name {
+            Cow::Owned(neuter)
+        } else {
+            Cow::Borrowed(name)
+        };
+        candidates.push(other);
+    }
+    candidates
+}
+
+/// Returns a list of possible plural forms for a Ukrainian noun, most likely first.
+///
+/// Cyrillic nouns take their nominative plural (see [`nominative_plural`]). For Latin
+/// transliterations, stems ending in a velar or hushing consonant (`k`, `g`, `h`, `zh`, `sh`,
+/// `ch`) prefer `-i` by the spelling rule; all other stems prefer `-y`.
 pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if is_cyrillic(name) {
+        return vec![nominative_plural(name)];
+    }
     let mut candidates = Vec::new();
-    if let Some(stem) = name.strip_suffix('a') {
-        candidates.push(format!("{stem}y").into());
-        candidates.push(format!("{stem}i").into());
+    let (stem, feminine) = match name.strip_suffix('a') {
+        Some(stem) => (stem, true),
+        None => (name, false),
+    };
+    let endings = if stem.ends_with(['k', 'g', 'h']) {
+        ["i", "y"]
+    } else {
+        ["y", "i"]
+    };
+    if feminine {
+        for ending in endings {
+            candidates.push(format!("{stem}{ending}").into());
+        }
+    }
+    for ending in endings {
+        candidates.push(format!("{name}{ending}").into());
     }
-    candidates.push(format!("{name}y").into());
-    candidates.push(format!("{name}i").into());
     candidates
 }
 
+/// Returns `true` if the word is written in the Cyrillic script.
+pub(crate) fn is_cyrillic(name: &str) -> bool {
+    name.chars()
+        .any(|ch| ('\u{0400}'..='\u{04FF}').contains(&ch))
+}
+
+/// Returns `true` if the stem ends in a hushing consonant, after which `и` is spelled `і`.
+fn is_hushing(stem: &str) -> bool {
+    stem.ends_with(['ж', 'ч', 'ш', 'щ'])
+}
+
+/// Converts a plural Ukrainian noun in Cyrillic script to its singular form.
+///
+/// Handles irregular nouns (`люди` -> `людина`), the nominative plural endings `-и`, `-і` and
+/// `-ї` (`файли` -> `файл`, `книги` -> `книга`, `користувачі` -> `користувач`, `музеї` ->
+/// `музей`, `категорії` -> `категорія`) and the genitive plural endings `-ів` and `-їв`
+/// (`файлів` -> `файл`, `музеїв` -> `музей`). Neuter plurals in `-а` and `-я` read the same as
+/// feminine singulars; they are recognized for the nouns listed in [`NEUTERS`] (`вікна` ->
+/// `вікно`, `поля` -> `поле`) and returned unchanged otherwise.
+fn singularize_cyrillic(name: &str) -> Cow<'_, str> {
+    if let Some(&(singular, _, _)) = IRREGULARS
+        .iter()
+        .find(|&&(_, plural, genitive)| name == plural || name == genitive)
+    {
+        return Cow::Borrowed(singular);
+    }
+    if let Some(neuter) = strip(name, "а")
+        .map(|stem| format!("{stem}о"))
+        .or_else(|| strip(name, "я").map(|stem| format!("{stem}е")))
+        .filter(|neuter| NEUTERS.contains(neuter.as_str()))
+    {
+        return neuter.into();
+    }
+    if let Some(stem) = strip(name, "ії") {
+        return format!("{stem}ія").into();
+    }
+    if let Some(stem) = strip(name, "иці") {
+        return format!("{stem}иця").into();
+    }
+    if let Some(stem) = strip(name, "ці") {
+        return format!("{stem}ець").into();
+    }
+    if let Some(stem) = strip(name, "їв").or_else(|| strip(name, "ї")) {
+        return format!("{stem}й").into();
+    }
+    if let Some(stem) = strip(name, "ів") {
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = strip(name, "и") {
+        // Feminines in `-ка` (`помилка`) and `-ига`/`-уга` (`книга`, `послуга`) against
+        // masculines with a vowel before the velar (`урок`, `діалог`).
+        if let Some(root) = stem.strip_suffix(['к', 'г', 'х']) {
+            let feminine = if stem.ends_with('г') {
+                root.ends_with(['и', 'у'])
+            } else {
+                !root.is_empty() && !root.ends_with(is_vowel)
+            };
+            if feminine {
+                return format!("{stem}а").into();
+            }
+        }
+        return Cow::Borrowed(stem);
+    }
+    if let Some(stem) = strip(name, "і") {
+        if is_hushing(stem) {
+            return Cow::Borrowed(stem);
+        }
+        return format!("{stem}ь").into();
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns `true` if `ch` is a Ukrainian vowel letter.
+fn is_vowel(ch: char) -> bool {
+    "аеєиіїоуюя".contains(ch)
+}
+
+/// Returns the nominative plural of a Ukrainian noun (`файл` -> `файли`, `пісня` -> `пісні`).
+fn nominative_plural(name: &str) -> Cow<'_, str> {
+    if let Some(&(_, plural, _)) = IRREGULARS
+        .iter()
+        .find(|&&(singular, _, _)| name == singular)
+    {
+        return Cow::Borrowed(plural);
+    }
+    if name.ends_with("ння") || name.ends_with("ття") {
+        return Cow::Borrowed(name);
+    }
+    if let Some(stem) = strip(name, "ець") {
+        return format!("{stem}ці").into();
+    }
+    if let Some(stem) = strip(name, "ень") {
+        return format!("{stem}ні").into();
+    }
+    if let Some(stem) = strip(name, "а") {
+        let ending = if is_hushing(stem) { "і" } else { "и" };
+        return format!("{stem}{ending}").into();
+    }
+    if let Some(stem) = strip(name, "ія") {
+        return format!("{stem}ії").into();
+    }
+    if let Some(stem) = strip(name, "я").or_else(|| strip(name, "ь")) {
+        return format!("{stem}і").into();
+    }
+    if let Some(stem) = strip(name, "о") {
+        return format!("{stem}а").into();
+    }
+    if let Some(stem) = strip(name, "е") {
+        return format!("{stem}я").into();
+    }
+    if let Some(stem) = strip(name, "й") {
+        return format!("{stem}ї").into();
+    }
+    let ending = if is_hushing(name) { "і" } else { "и" };
+    format!("{name}{ending}").into()
+}
+
+/// Returns the genitive singular of a Ukrainian noun (`файл` -> `файла`, `книга` -> `книги`),
+/// dropping the fleeting `е` of masculines in `-ень` and `-ець` (`день` -> `дня`, `продавець` ->
+/// `продавця`).
+fn genitive_singular(name: &str) -> Cow<'_, str> {
+    if name.ends_with("ння") || name.ends_with("ття") {
+        return Cow::Borrowed(name);
+    }
+    if strip(name, "а").is_some() || strip(name, "я").is_some() {
+        return nominative_plural(name);
+    }
+    if let Some(stem) = strip(name, "о") {
+        return format!("{stem}а").into();
+    }
+    if let Some(stem) = strip(name, "ень") {
+        return format!("{stem}ня").into();
+    }
+    if let Some(stem) = strip(name, "ець") {
+        return format!("{stem}ця").into();
+    }
+    if let Some(stem) = strip(name, "е")
+        .or_else(|| strip(name, "й"))
+        .or_else(|| strip(name, "ь"))
+    {
+        return format!("{stem}я").into();
+    }
+    format!("{name}а").into()
+}
+
+/// Inserts the fleeting vowel that breaks up the final consonant cluster of a feminine or neuter
+/// stem in the genitive plural (`сторінк` -> `сторінок`, `вікн` -> `вікон`, `весн` -> `весен`,
+/// `числ` -> `чисел`), or returns `None` if the stem needs none.
+fn fleeting_genitive(stem: &str) -> Option<String> {
+    let mut chars = stem.chars().rev();
+    let (last, previous) = (chars.next()?, chars.next()?);
+    let base = &stem[..stem.len() - last.len_utf8() - previous.len_utf8()];
+    let vowel = match (previous, last) {
+        ('с' | 'т' | 'д' | 'з' | 'р', 'н') | ('с' | 'т' | 'м', 'л') | ('д', 'р') => 'е',
+        (_, 'к') | ('к' | 'г' | 'х', 'н') if !is_vowel(previous) => 'о',
+        _ => return None,
+    };
+    Some(format!("{base}{previous}{vowel}{last}"))
+}
+
+/// Returns the genitive plural of a Ukrainian noun (`файл` -> `файлів`, `книга` -> `книг`), with
+/// a fleeting vowel in the final consonant cluster of feminines and neuters (`сторінка` ->
+/// `сторінок`, `вікно` -> `вікон`).
+fn genitive_plural(name: &str) -> Cow<'_, str> {
+    if let Some(&(_, _, genitive)) = IRREGULARS
+        .iter()
+        .find(|&&(singular, _, _)| name == singular)
+    {
+        return Cow::Borrowed(genitive);
+    }
+    if let Some(stem) = strip(name, "ець") {
+        return format!("{stem}ців").into();
+    }
+    if let Some(stem) = strip(name, "ень") {
+        return format!("{stem}нів").into();
+    }
+    if let Some(stem) = strip(name, "ння").or_else(|| strip(name, "ття")) {
+        let consonant = if name.ends_with("ння") {
+            "нь"
+        } else {
+            "ть"
+        };
+        return format!("{stem}{consonant}").into();
+    }
+    if let Some(stem) = strip(name, "ія") {
+        return format!("{stem}ій").into();
+    }
+    if let Some(stem) = strip(name, "а").or_else(|| strip(name, "о")) {
+        return fleeting_genitive(stem).map_or(Cow::Borrowed(stem), Cow::Owned);
+    }
+    if let Some(stem) = strip(name, "я") {
+        return format!("{stem}ь").into();
+    }
+    if let Some(stem) = strip(name, "й") {
+        return format!("{stem}їв").into();
+    }
+    if let Some(stem) = strip(name, "е").or_else(|| strip(name, "ь")) {
+        return format!("{stem}ів").into();
+    }
+    format!("{name}ів").into()
+}
+
+/// Returns the form of a singular Ukrainian noun (Cyrillic) required by a number.
+///
+/// Numbers ending in `2`-`4` take the nominative plural (`2 файли`), numbers ending in `0` or
+/// `5`-`9` and the teens take the genitive plural (`5 файлів`), and fractions take the genitive
+/// singular (`1,5 файла`). Latin transliterations fall back to the plural.
+pub(crate) fn count_form(name: &str, category: PluralCategory) -> Option<Cow<'_, str>> {
+    if !is_cyrillic(name) {
+        return None;
+    }
+    match category {
+        PluralCategory::Few => Some(nominative_plural(name)),
+        PluralCategory::Many => Some(genitive_plural(name)),
+        PluralCategory::Other => Some(genitive_singular(name)),
+        _ => None,
+    }
+}
+
+/// Detects the number of irregular Cyrillic nouns and the genitive plurals of the others
+/// (`файлів`, `сторінок`), which the suffix rules alone would read as singulars.
+pub(crate) fn number(name: &str) -> Option<Number> {
+    if !is_cyrillic(name) {
+        return None;
+    }
+    if let Some(&(singular, ..)) = IRREGULARS.iter().find(|&&(singular, plural, genitive)| {
+        name == singular || name == plural || name == genitive
+    }) {
+        return Some(if name == singular {
+            Number::Singular
+        } else {
+            Number::Plural
+        });
+    }
+    number::genitive_plural_number(name, singularize_cyrillic, pluralize, genitive_plural)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::language_rules::LanguageRules;
 
     #[test]
     fn test_singularize() {
@@ -70,8 +396,153 @@ mod tests {
         assert!(result.iter().any(|v| v == "tablicy"));
     }
 
+    #[test]
+    fn test_pluralize_ranking() {
+        assert_eq!(pluralize("produkt")[0], "produkty");
+        assert_eq!(pluralize("tablica")[0], "tablicy");
+        assert_eq!(pluralize("kniga")[0], "knigi");
+        assert_eq!(pluralize("učebnik")[0], "učebniki");
+    }
+
+    #[test]
+    fn test_singularize_cyrillic() {
+        assert_eq!(singularize("файли"), "файл");
+        assert_eq!(singularize("книги"), "книга");
+        assert_eq!(singularize("помилки"), "помилка");
+        assert_eq!(singularize("уроки"), "урок");
+        assert_eq!(singularize("користувачі"), "користувач");
+        assert_eq!(singularize("вчителі"), "вчитель");
+        assert_eq!(singularize("музеї"), "музей");
+        assert_eq!(singularize("категорії"), "категорія");
+        assert_eq!(singularize("вулиці"), "вулиця");
+        assert_eq!(singularize("продавці"), "продавець");
+        assert_eq!(singularize("файлів"), "файл");
+        assert_eq!(singularize("музеїв"), "музей");
+        assert_eq!(singularize("люди"), "людина");
+        assert_eq!(singularize("дітей"), "дитина");
+        assert_eq!(singularize("роки"), "рік");
+        assert_eq!(singularize("повідомлення"), "повідомлення");
+        assert_eq!(singularize("вікна"), "вікно");
+        assert_eq!(singularize("поля"), "поле");
+        assert_eq!(singularize("міста"), "місто");
+        assert_eq!(singularize("книга"), "книга");
+    }
+
+    #[test]
+    fn test_singularize_candidates() {
+        assert_eq!(RULES.singularize_candidates("вікна"), ["вікно", "вікна"]);
+        assert_eq!(RULES.singularize_candidates("книга"), ["книга", "книго"]);
+        assert_eq!(RULES.singularize_candidates("файли"), ["файл"]);
+        assert_eq!(
+            RULES.singularize_candidates("повідомлення"),
+            ["повідомлення"]
+        );
+    }
+
+    #[test]
+    fn test_pluralize_cyrillic() {
+        assert_eq!(pluralize("файл"), vec!["файли"]);
+        assert_eq!(pluralize("книга"), vec!["книги"]);
+        assert_eq!(pluralize("задача"), vec!["задачі"]);
+        assert_eq!(pluralize("пісня"), vec!["пісні"]);
+        assert_eq!(pluralize("вікно"), vec!["вікна"]);
+        assert_eq!(pluralize("продавець"), vec!["продавці"]);
+        assert_eq!(pluralize("людина"), vec!["люди"]);
+        assert_eq!(pluralize("дитина"), vec!["діти"]);
+        assert_eq!(pluralize("стіл"), vec!["столи"]);
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(singularize(""), "");
     }
+
+    #[test]
+    fn test_count_form() {
+        assert_eq!(count_form("файл", PluralCategory::Few).unwrap(), "файли");
+        assert_eq!(count_form("файл", PluralCategory::Many).unwrap(), "файлів");
+        assert_eq!(count_form("файл", PluralCategory::Other).unwrap(), "файла");
+        assert_eq!(count_form("книга", PluralCategory::Few).unwrap(), "книги");
+        assert_eq!(count_form("книга", PluralCategory::Many).unwrap(), "книг");
+        assert_eq!(count_form("задача", PluralCategory::Few).unwrap(), "задачі");
+        assert_eq!(
+            count_form("користувач", PluralCategory::Few).unwrap(),
+            "користувачі"
+        );
+        assert_eq!(
+            count_form("користувач", PluralCategory::Many).unwrap(),
+            "користувачів"
+        );
+        assert_eq!(
+            count_form("категорія", PluralCategory::Few).unwrap(),
+            "категорії"
+        );
+        assert_eq!(
+            count_form("категорія", PluralCategory::Many).unwrap(),
+            "категорій"
+        );
+        assert_eq!(
+            count_form("повідомлення", PluralCategory::Few).unwrap(),
+            "повідомлення"
+        );
+        assert_eq!(
+            count_form("повідомлення", PluralCategory::Many).unwrap(),
+            "повідомлень"
+        );
+        assert_eq!(count_form("вікно", PluralCategory::Few).unwrap(), "вікна");
+        assert_eq!(count_form("музей", PluralCategory::Many).unwrap(), "музеїв");
+        assert_eq!(count_form("дитина", PluralCategory::Few).unwrap(), "діти");
+        assert_eq!(count_form("рік", PluralCategory::Many).unwrap(), "років");
+        assert_eq!(
+            count_form("продавець", PluralCategory::Many).unwrap(),
+            "продавців"
+        );
+        assert_eq!(
+            count_form("сторінка", PluralCategory::Many).unwrap(),
+            "сторінок"
+        );
+        assert_eq!(
+            count_form("копійка", PluralCategory::Many).unwrap(),
+            "копійок"
+        );
+        assert_eq!(count_form("ручка", PluralCategory::Many).unwrap(), "ручок");
+        assert_eq!(count_form("рука", PluralCategory::Many).unwrap(), "рук");
+        assert_eq!(count_form("вікно", PluralCategory::Many).unwrap(), "вікон");
+        assert_eq!(count_form("весна", PluralCategory::Many).unwrap(), "весен");
+        assert_eq!(count_form("число", PluralCategory::Many).unwrap(), "чисел");
+        assert_eq!(count_form("карта", PluralCategory::Many).unwrap(), "карт");
+        assert_eq!(count_form("день", PluralCategory::Other).unwrap(), "дня");
+        assert_eq!(count_form("учень", PluralCategory::Few).unwrap(), "учні");
+        assert_eq!(count_form("учень", PluralCategory::Many).unwrap(), "учнів");
+        assert_eq!(
+            count_form("продавець", PluralCategory::Other).unwrap(),
+            "продавця"
+        );
+        assert!(count_form("файл", PluralCategory::One).is_none());
+        assert!(count_form("fajl", PluralCategory::Few).is_none());
+    }
+
+    #[test]
+    fn test_inflect_for_count() {
+        assert_eq!(RULES.inflect_for_count("файл", 1), "файл");
+        assert_eq!(RULES.inflect_for_count("файл", 3), "файли");
+        assert_eq!(RULES.inflect_for_count("файл", 11), "файлів");
+        assert_eq!(RULES.inflect_for_count("файл", 22), "файли");
+        assert_eq!(RULES.inflect_for_count("сторінка", 5), "сторінок");
+        assert_eq!(RULES.inflect_for_count("вікно", 5), "вікон");
+        let half: crate::PluralOperands = "1.5".parse().unwrap();
+        assert_eq!(RULES.inflect_for_count("день", half), "дня");
+    }
+
+    #[test]
+    fn test_number_of() {
+        assert_eq!(RULES.number_of("файл"), Number::Singular);
+        assert_eq!(RULES.number_of("файли"), Number::Plural);
+        assert_eq!(RULES.number_of("файлів"), Number::Plural);
+        assert_eq!(RULES.number_of("стіл"), Number::Singular);
+        assert_eq!(RULES.number_of("людей"), Number::Plural);
+        assert_eq!(RULES.number_of("вікна"), Number::Plural);
+        assert_eq!(RULES.number_of("вікно"), Number::Singular);
+        assert_eq!(RULES.number_of("книга"), Number::Singular);
+    }
 }
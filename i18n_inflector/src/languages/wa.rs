@@ -1,11 +1,57 @@
 //! Walloon (wa) inflection rules.
+//!
+//! Walloon marks the plural of nouns with a written `-s`, which is mostly silent (`tchvå` ->
+//! `tchvås`, `tchapea` -> `tchapeas`). Unlike French, Walloon has no `-aux` or `-x` plurals, and
+//! nouns already ending in `-s`, `-x` or `-z` are invariant.
 
 use crate::language_rules::LanguageRuleSet;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 
-pub(crate) use super::fr::{pluralize, singularize};
+pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet::new("wa", singularize, pluralize);
 
-pub(crate) static RULES: LanguageRuleSet = LanguageRuleSet {
-    language: "wa",
-    singularize_fn: singularize,
-    pluralize_fn: pluralize,
-};
+/// Converts a plural Walloon noun to its singular form by removing `-s` (`tchvås` -> `tchvå`).
+pub(crate) fn singularize(name: &str) -> Cow<'_, str> {
+    if let Some(stem) = name.strip_suffix('s')
+        && !stem.is_empty()
+    {
+        return Cow::Borrowed(stem);
+    }
+    Cow::Borrowed(name)
+}
+
+/// Returns the plural of a Walloon noun, which adds `-s` (`måjhon` -> `måjhons`); nouns ending in
+/// `-s`, `-x` or `-z` are invariant (`pris`).
+pub(crate) fn pluralize(name: &str) -> Vec<Cow<'_, str>> {
+    if name.ends_with(['s', 'x', 'z']) {
+        vec![Cow::Borrowed(name)]
+    } else {
+        vec![format!("{name}s").into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(singularize("tchvås"), "tchvå");
+        assert_eq!(singularize("tchapeas"), "tchapea");
+        assert_eq!(singularize("måjhons"), "måjhon");
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("tchvå"), vec!["tchvås"]);
+        assert_eq!(pluralize("tchapea"), vec!["tchapeas"]);
+        assert_eq!(pluralize("pris"), vec!["pris"]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(singularize(""), "");
+    }
+}
@@ -0,0 +1,236 @@
+//! Per-language word lists consulted before a language's suffix rules.
+
+use crate::number::Number;
+use phf::{phf_map, phf_set};
+
+/// Uncountable and invariant nouns for a language.
+///
+/// Both [`singularize`](crate::LanguageRules::singularize) and
+/// [`pluralize`](crate::LanguageRules::pluralize) consult the lexicon before falling back to the
+/// language's suffix rules.
+#[derive(Debug)]
+pub(crate) struct Lexicon {
+    /// Nouns that have no distinct singular and plural forms (e.g. `news`, `sheep`).
+    pub(crate) uncountables: phf::Set<&'static str>,
+    /// Suffixes of nouns that have no distinct singular and plural forms (e.g. `-ware`).
+    pub(crate) uncountable_suffixes: &'static [&'static str],
+    /// Suffixes of singular nouns whose plural is identical to the singular (e.g. French `-s`,
+    /// `-x` and `-z`). Only consulted when pluralizing, since plural forms commonly share these
+    /// endings.
+    pub(crate) invariant_suffixes: &'static [&'static str],
+}
+
+impl Lexicon {
+    /// A lexicon without any entries.
+    pub(crate) const EMPTY: Lexicon = Lexicon {
+        uncountables: phf_set! {},
+        uncountable_suffixes: &[],
+        invariant_suffixes: &[],
+    };
+
+    /// Returns `true` if the lowercase `word` has no distinct singular and plural forms.
+    #[must_use]
+    pub(crate) fn is_uncountable(&self, word: &str) -> bool {
+        self.uncountables.contains(word)
+            || self
+                .uncountable_suffixes
+                .iter()
+                .any(|suffix| word.ends_with(suffix))
+    }
+
+    /// Returns `true` if the lowercase singular `word` has a plural identical to itself.
+    #[must_use]
+    pub(crate) fn is_invariant(&self, word: &str) -> bool {
+        self.is_uncountable(word)
+            || self
+                .invariant_suffixes
+                .iter()
+                .any(|suffix| word.ends_with(suffix))
+    }
+}
+
+/// Irregular nouns for a language, compiled into maps for both directions.
+///
+/// Language modules consult their table before the suffix rules. Build with [`irregulars!`],
+/// which derives the plural -> singular map from the singular -> plural entries.
+#[derive(Debug)]
+pub(crate) struct Irregulars {
+    /// Singular -> plural mappings.
+    pub(crate) plurals: phf::Map<&'static str, &'static str>,
+    /// Plural -> singular mappings.
+    pub(crate) singulars: phf::Map<&'static str, &'static str>,
+}
+
+impl Irregulars {
+    /// A table without any entries.
+    pub(crate) const EMPTY: Irregulars = Irregulars {
+        plurals: phf_map! {},
+        singulars: phf_map! {},
+    };
+
+    /// Returns the singular of the lowercase irregular plural `word`, or `word` itself if it is
+    /// an irregular singular.
+    #[must_use]
+    pub(crate) fn singular(&self, word: &str) -> Option<&'static str> {
+        self.singulars
+            .get(word)
+            .or_else(|| self.plurals.get_key(word))
+            .copied()
+    }
+
+    /// Returns the plural of the lowercase irregular singular `word`.
+    #[must_use]
+    pub(crate) fn plural(&self, word: &str) -> Option<&'static str> {
+        self.plurals.get(word).copied()
+    }
+
+    /// Returns the number of the lowercase `word` if it is an irregular singular or plural, or
+    /// [`Number::Invariant`] if it is both (`"deer" => "deer"`).
+    #[must_use]
+    pub(crate) fn number(&self, word: &str) -> Option<Number> {
+        match (
+            self.plurals.contains_key(word),
+            self.singulars.contains_key(word),
+        ) {
+            (true, true) => Some(Number::Invariant),
+            (true, false) => Some(Number::Singular),
+            (false, true) => Some(Number::Plural),
+            (false, false) => None,
+        }
+    }
+}
+
+/// Builds an [`Irregulars`] table from `"singular" => "plural"` entries.
+///
+/// Entries in the optional `singular_to_plural:` and `plural_to_singular:` sections only apply
+/// in one direction, for spelling variants (`"ребенок" => "дети"`), variant plurals
+/// (`"persons" => "person"`) and forms shared by several words.
+macro_rules! irregulars {
+    (
+        $($singular:literal => $plural:literal),* $(,)?
+        $(; singular_to_plural: $($only_singular:literal => $only_plural:literal),* $(,)?)?
+        $(; plural_to_singular: $($variant:literal => $base:literal),* $(,)?)?
+    ) => {
+        $crate::lexicon::Irregulars {
+            plurals: ::phf::phf_map! {
+                $($singular => $plural,)*
+                $($($only_singular => $only_plural,)*)?
+            },
+            singulars: ::phf::phf_map! {
+                $($plural => $singular,)*
+                $($($variant => $base,)*)?
+            },
+        }
+    };
+}
+
+pub(crate) use irregulars;
+
+#[cfg(test)]
+impl Irregulars {
+    /// Returns the `(singular, plural)` entries that the suffix rules `singularize` and
+    /// `pluralize` already produce without the table, in every direction the entry applies to.
+    pub(crate) fn regular_entries(
+        &self,
+        singularize: fn(&str) -> alloc::borrow::Cow<'_, str>,
+        pluralize: fn(&str) -> alloc::vec::Vec<alloc::borrow::Cow<'_, str>>,
+    ) -> alloc::vec::Vec<(&'static str, &'static str)> {
+        let pluralizes =
+            |singular, plural| pluralize(singular).first().map(AsRef::as_ref) == Some(plural);
+        let singularizes = |singular, plural| singularize(plural) == singular;
+        let plurals = self.plurals.entries().filter(|&(&singular, &plural)| {
+            pluralizes(singular, plural)
+                && (self.singulars.get(plural) != Some(&singular) || singularizes(singular, plural))
+        });
+        let singulars = self.singulars.entries().filter(|&(&plural, &singular)| {
+            self.plurals.get(singular) != Some(&plural) && singularizes(singular, plural)
+        });
+        plurals
+            .map(|(&singular, &plural)| (singular, plural))
+            .chain(singulars.map(|(&plural, &singular)| (singular, plural)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_LEXICON: Lexicon = Lexicon {
+        uncountables: phf_set! { "news", "sheep" },
+        uncountable_suffixes: &["ware"],
+        invariant_suffixes: &["x"],
+    };
+
+    static TEST_IRREGULARS: Irregulars = irregulars! {
+        "child" => "children",
+        "deer" => "deer",
+        "person" => "people";
+        singular_to_plural:
+        "human" => "humans";
+        plural_to_singular:
+        "persons" => "person",
+    };
+
+    #[test]
+    fn test_empty() {
+        assert!(!Lexicon::EMPTY.is_uncountable("news"));
+        assert!(!Lexicon::EMPTY.is_invariant("news"));
+        assert!(!Lexicon::EMPTY.is_invariant(""));
+    }
+
+    #[test]
+    fn test_is_uncountable() {
+        assert!(TEST_LEXICON.is_uncountable("news"));
+        assert!(TEST_LEXICON.is_uncountable("sheep"));
+        assert!(TEST_LEXICON.is_uncountable("software"));
+        assert!(!TEST_LEXICON.is_uncountable("users"));
+        assert!(!TEST_LEXICON.is_uncountable("prix"));
+    }
+
+    #[test]
+    fn test_is_invariant() {
+        assert!(TEST_LEXICON.is_invariant("news"));
+        assert!(TEST_LEXICON.is_invariant("software"));
+        assert!(TEST_LEXICON.is_invariant("prix"));
+        assert!(!TEST_LEXICON.is_invariant("user"));
+    }
+
+    #[test]
+    fn test_irregulars() {
+        let irregulars = &TEST_IRREGULARS;
+        assert_eq!(irregulars.plural("child"), Some("children"));
+        assert_eq!(irregulars.plural("children"), None);
+        assert_eq!(irregulars.singular("children"), Some("child"));
+        assert_eq!(irregulars.singular("child"), Some("child"));
+        assert_eq!(irregulars.singular("persons"), Some("person"));
+        assert_eq!(irregulars.plural("persons"), None);
+        assert_eq!(irregulars.plural("human"), Some("humans"));
+        assert_eq!(irregulars.singular("humans"), None);
+        assert_eq!(irregulars.singular("user"), None);
+        assert_eq!(Irregulars::EMPTY.singular("children"), None);
+    }
+
+    #[test]
+    fn test_regular_entries() {
+        fn singularize(word: &str) -> alloc::borrow::Cow<'_, str> {
+            word.strip_suffix('s').unwrap_or(word).into()
+        }
+        fn pluralize(word: &str) -> alloc::vec::Vec<alloc::borrow::Cow<'_, str>> {
+            alloc::vec![alloc::format!("{word}s").into()]
+        }
+        let mut regular = TEST_IRREGULARS.regular_entries(singularize, pluralize);
+        regular.sort_unstable();
+        assert_eq!(regular, [("human", "humans"), ("person", "persons")]);
+    }
+
+    #[test]
+    fn test_irregulars_number() {
+        let irregulars = &TEST_IRREGULARS;
+        assert_eq!(irregulars.number("child"), Some(Number::Singular));
+        assert_eq!(irregulars.number("children"), Some(Number::Plural));
+        assert_eq!(irregulars.number("persons"), Some(Number::Plural));
+        assert_eq!(irregulars.number("user"), None);
+        assert_eq!(irregulars.number("deer"), Some(Number::Invariant));
+    }
+}
@@ -6,7 +6,7 @@
 //! # Quick Start
 //!
 //! ```
-//! use i18n_inflector::{language_rules, LanguageRules};
+//! use i18n_inflector::{language_rules, LanguageRules, Number};
 //!
 //! // English
 //! let en = language_rules("en").unwrap();
@@ -14,21 +14,65 @@
 //! assert_eq!(en.singularize("categories"), "category");
 //! assert_eq!(en.singularize("children"), "child");
 //!
+//! // Casing is preserved
+//! assert_eq!(en.singularize("Children"), "Child");
+//! assert_eq!(en.singularize("USERS"), "USER");
+//!
 //! let plurals = en.pluralize("user");
 //! assert!(plurals.iter().any(|v| v == "users"));
 //!
+//! // Single most likely plural
+//! assert_eq!(en.pluralize_best("user"), "users");
+//! assert_eq!(en.pluralize_best("box"), "boxes");
+//!
+//! // Number detection
+//! assert_eq!(en.number_of("users"), Number::Plural);
+//! assert_eq!(en.number_of("status"), Number::Singular);
+//! assert_eq!(en.number_of("news"), Number::Invariant);
+//!
+//! // Compound identifiers keep their naming convention
+//! assert_eq!(en.singularize_identifier("user_accounts"), "user_account");
+//! assert_eq!(en.singularize_identifier("OrderLineItems"), "OrderLineItem");
+//!
 //! // Spanish
 //! assert_eq!(language_rules("es").unwrap().singularize("ciudades"), "ciudad");
 //!
 //! // French
 //! assert_eq!(language_rules("fr").unwrap().singularize("journaux"), "journal");
 //!
+//! // Russian
+//! assert_eq!(language_rules("ru").unwrap().singularize("пользователи"), "пользователь");
+//!
 //! // Japanese
 //! assert_eq!(language_rules("ja").unwrap().singularize("user"), "user");
 //!
 //! // Unsupported locale returns an error
 //! assert!(language_rules("xx").is_err());
 //! ```
+//!
+//! # Plural Categories and Counts
+//!
+//! ```
+//! use i18n_inflector::{
+//!     language_rules, ordinal_category, plural_category, LanguageRules, PluralCategory,
+//!     PluralOperands,
+//! };
+//!
+//! # fn main() -> i18n_inflector::Result<()> {
+//! assert_eq!(plural_category("pl", 1)?, PluralCategory::One);
+//! assert_eq!(plural_category("pl", 3)?, PluralCategory::Few);
+//! assert_eq!(plural_category("pl", 5)?, PluralCategory::Many);
+//! assert_eq!(plural_category("pl", "1.5".parse::<PluralOperands>()?)?, PluralCategory::Other);
+//! assert_eq!(ordinal_category("en", 22)?, PluralCategory::Two);
+//!
+//! // The form of a noun required by a number
+//! let ru = language_rules("ru")?;
+//! assert_eq!(ru.inflect_for_count("файл", 1), "файл");
+//! assert_eq!(ru.inflect_for_count("файл", 2), "файла");
+//! assert_eq!(ru.inflect_for_count("файл", 5), "файлов");
+//! # Ok(())
+//! # }
+//! ```
 
 #![no_std]
 
@@ -37,26 +81,45 @@ extern crate std;
 
 extern crate alloc;
 
+mod candidate;
+mod case;
+mod catalogue;
+mod custom_rules;
 mod error;
+mod explain;
+mod identifier;
 mod language_rules;
 mod languages;
+mod lexicon;
 mod locale;
+mod number;
+mod plural_rules;
 mod registry;
 
-pub use error::{Error, Result};
+pub use candidate::{Candidate, Confidence};
+pub use catalogue::{LanguageInfo, QualityTier};
+pub use custom_rules::{CustomRuleSet, CustomRuleSetBuilder};
+pub use error::{Error, Result, RuleLocation};
+pub use explain::{Explanation, Rule, RuleOrigin, Step};
+pub use identifier::HeadPosition;
 pub use language_rules::{LanguageRuleSet, LanguageRules};
+pub use locale::LanguageTag;
+pub use number::Number;
+pub use plural_rules::{PluralCategory, PluralOperands};
 
-use crate::locale::normalize_locale;
-use alloc::format;
+use alloc::string::ToString;
 
 /// Returns the [`LanguageRuleSet`] for the given locale.
 ///
-/// The locale is normalized (case-insensitive, region suffixes stripped)
-/// before lookup. Returns an error if the base language code is not recognized.
+/// The locale is parsed as a BCP 47 language tag (see [`LanguageTag`]) and resolved through its
+/// [fallback chain](LanguageTag::fallback_chain): regional variants such as `pt-PT` and `de-CH`
+/// have rule sets of their own, and every other tag falls back to its language, so `en-US`, `EN`,
+/// `en_US` and `eng` all resolve to English. Returns an error if the language is not recognized.
 ///
 /// # Errors
 ///
-/// Returns [`Error`] if the locale is not a supported language code.
+/// Returns [`Error::MalformedLocaleTag`] or [`Error::UnknownScript`] if the locale is not a valid
+/// language tag, and [`Error::UnsupportedLanguage`] if its language is not supported.
 ///
 /// # Examples
 ///
@@ -71,18 +134,146 @@ use alloc::format;
 /// // Locale normalization
 /// let rules = language_rules("en-US")?;
 /// assert_eq!(rules.language(), "en");
+/// let rules = language_rules("deu")?;
+/// assert_eq!(rules.language(), "de");
+///
+/// // Regional variants
+/// let rules = language_rules("de-CH")?;
+/// assert_eq!(rules.locale(), "de-CH");
+/// assert_eq!(rules.singularize("Straßen"), "Strasse");
+/// assert_eq!(language_rules("de-AT")?.locale(), "de");
 ///
 /// // Unsupported locale
 /// assert!(language_rules("xx").is_err());
+/// assert!(language_rules("fil").is_err());
 /// # Ok(())
 /// # }
 /// ```
 pub fn language_rules(locale: &str) -> Result<&'static LanguageRuleSet> {
-    let normalized = normalize_locale(locale);
-    registry::LANGUAGE_RULES_MAP
-        .get(normalized.as_str())
-        .copied()
-        .ok_or_else(|| Error::new(format!("unsupported locale: {locale}")))
+    // Registered locales are found without parsing, which allocates the fallback chain
+    if let Some(rules) = registry::get(locale) {
+        return Ok(rules);
+    }
+    let tag = LanguageTag::parse(locale)?;
+    registry::resolve(&tag).ok_or_else(|| unsupported(locale))
+}
+
+/// Returns the CLDR cardinal plural category of `n` for the given locale.
+///
+/// The locale is parsed and resolved the same way as in [`language_rules`]. Decimals are passed as
+/// [`PluralOperands`] parsed from their string representation, so that visible trailing zeros are
+/// taken into account.
+///
+/// # Errors
+///
+/// Returns [`Error::MalformedLocaleTag`] or [`Error::UnknownScript`] if the locale is not a valid
+/// language tag, and [`Error::UnsupportedLanguage`] if its language is not supported.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_inflector::{plural_category, PluralCategory, PluralOperands};
+///
+/// # fn main() -> i18n_inflector::Result<()> {
+/// assert_eq!(plural_category("en", 1)?, PluralCategory::One);
+/// assert_eq!(plural_category("en", "1.0".parse::<PluralOperands>()?)?, PluralCategory::Other);
+/// assert_eq!(plural_category("ru", 21)?, PluralCategory::One);
+/// assert_eq!(plural_category("ar", 0)?, PluralCategory::Zero);
+/// assert_eq!(plural_category("pt-BR", 0)?, PluralCategory::One);
+/// assert_eq!(plural_category("pt-PT", 0)?, PluralCategory::Other);
+/// assert_eq!(plural_category("pt-AO", 0)?, PluralCategory::Other);
+/// # Ok(())
+/// # }
+/// ```
+pub fn plural_category<N: Into<PluralOperands>>(locale: &str, n: N) -> Result<PluralCategory> {
+    let tag = LanguageTag::parse(locale)?;
+    plural_rules::cardinal(&tag.to_string(), &n.into()).ok_or_else(|| unsupported(locale))
+}
+
+/// Returns the CLDR ordinal plural category of `n` for the given locale (`1st`, `2nd`, `3rd`,
+/// `4th` in English).
+///
+/// # Errors
+///
+/// Returns [`Error::MalformedLocaleTag`] or [`Error::UnknownScript`] if the locale is not a valid
+/// language tag, and [`Error::UnsupportedLanguage`] if its language is not supported.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_inflector::{ordinal_category, PluralCategory};
+///
+/// # fn main() -> i18n_inflector::Result<()> {
+/// assert_eq!(ordinal_category("en", 1)?, PluralCategory::One);
+/// assert_eq!(ordinal_category("en", 3)?, PluralCategory::Few);
+/// assert_eq!(ordinal_category("en", 11)?, PluralCategory::Other);
+/// # Ok(())
+/// # }
+/// ```
+pub fn ordinal_category<N: Into<PluralOperands>>(locale: &str, n: N) -> Result<PluralCategory> {
+    let tag = LanguageTag::parse(locale)?;
+    plural_rules::ordinal(&tag.to_string(), &n.into()).ok_or_else(|| unsupported(locale))
+}
+
+/// Returns all supported languages, sorted by code.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_inflector::{supported_languages, QualityTier};
+///
+/// let languages = supported_languages();
+/// assert!(languages.iter().any(|info| info.code() == "en"));
+///
+/// // Languages whose rules borrow from a related language
+/// let delegated = languages
+///     .iter()
+///     .filter(|info| info.tier() == QualityTier::Delegated)
+///     .map(|info| (info.code(), info.delegate()));
+/// assert!(delegated.into_iter().any(|entry| entry == ("ur", Some("hi"))));
+/// ```
+#[must_use]
+pub fn supported_languages() -> &'static [LanguageInfo] {
+    catalogue::all()
+}
+
+/// Returns the [`LanguageInfo`] of the language of the given locale.
+///
+/// The locale is parsed the same way as in [`language_rules`], and the entry of its language is
+/// returned: `de-CH` and `deu` both describe German.
+///
+/// # Errors
+///
+/// Returns [`Error::MalformedLocaleTag`] or [`Error::UnknownScript`] if the locale is not a valid
+/// language tag, and [`Error::UnsupportedLanguage`] if its language is not supported.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_inflector::{language_info, QualityTier};
+///
+/// # fn main() -> i18n_inflector::Result<()> {
+/// let info = language_info("pt-BR")?;
+/// assert_eq!(info.name(), "Portuguese");
+/// assert_eq!(info.endonym(), "português");
+/// assert_eq!(info.scripts(), ["Latn"]);
+/// assert_eq!(info.tier(), QualityTier::Native);
+///
+/// let info = language_info("tl")?;
+/// assert_eq!(info.delegate(), Some("ja"));
+/// assert_eq!(info.tier(), QualityTier::Identity);
+/// # Ok(())
+/// # }
+/// ```
+pub fn language_info(locale: &str) -> Result<&'static LanguageInfo> {
+    catalogue::lookup(locale)
+}
+
+/// Returns the error for a well-formed `locale` whose language has no rule set.
+fn unsupported(locale: &str) -> Error {
+    Error::UnsupportedLanguage {
+        locale: locale.into(),
+    }
 }
 
 #[cfg(test)]
@@ -116,6 +307,68 @@ mod tests {
     fn test_language_rules_unsupported() {
         let err = language_rules("xx").unwrap_err();
         assert_eq!(err.to_string(), "unsupported locale: xx");
+        assert_eq!(
+            err,
+            Error::UnsupportedLanguage {
+                locale: "xx".into()
+            }
+        );
+        assert!(matches!(
+            language_rules("en-Abcd"),
+            Err(Error::UnknownScript { script, .. }) if script == "Abcd"
+        ));
+    }
+
+    #[test]
+    fn test_language_rules_bcp47() {
+        assert_eq!(language_rules("zh-Hant-TW").unwrap().language(), "zh");
+        assert_eq!(language_rules("sr-Latn-RS").unwrap().language(), "sr");
+        assert_eq!(language_rules("eng").unwrap().language(), "en");
+        assert_eq!(language_rules("spa").unwrap().language(), "es");
+        assert_eq!(language_rules("ger").unwrap().language(), "de");
+
+        // Three-letter codes must not fall back to a two-letter prefix
+        let err = language_rules("fil").unwrap_err();
+        assert_eq!(err.to_string(), "unsupported locale: fil");
+        assert!(language_rules("haw").is_err());
+        // Cantonese is not Chinese
+        assert!(language_rules("zh-yue").is_err());
+
+        let err = language_rules("en-").unwrap_err();
+        assert_eq!(err.to_string(), r#"malformed locale tag: "en-""#);
+        assert!(matches!(err, Error::MalformedLocaleTag { tag } if tag == "en-"));
+    }
+
+    #[test]
+    fn test_language_rules_regional() {
+        let rules = language_rules("pt-PT").unwrap();
+        assert_eq!(rules.language(), "pt");
+        assert_eq!(rules.locale(), "pt-PT");
+        assert_eq!(rules.pluralize("ténis"), alloc::vec!["ténis"]);
+        assert_eq!(rules.plural_category(0), PluralCategory::Other);
+        assert_eq!(language_rules("pt_BR").unwrap().locale(), "pt");
+        assert_eq!(
+            language_rules("pt-BR").unwrap().plural_category(0),
+            PluralCategory::One
+        );
+        assert_eq!(language_rules("pt-MZ").unwrap().locale(), "pt-PT");
+
+        let rules = language_rules("de_CH").unwrap();
+        assert_eq!(rules.singularize("STRASSEN"), "STRASSE");
+        assert_eq!(rules.singularize("Straßen"), "Strasse");
+        assert_eq!(
+            language_rules("de").unwrap().singularize("Straßen"),
+            "Straße"
+        );
+
+        let rules = language_rules("sr-Cyrl-RS").unwrap();
+        assert_eq!(rules.locale(), "sr");
+        assert_eq!(rules.singularize("Корисници"), "Корисник");
+        let rules = language_rules("sr-Latn").unwrap();
+        assert_eq!(rules.singularize("korisnici"), "korisnik");
+
+        let err = language_rules("xx-CH").unwrap_err();
+        assert_eq!(err.to_string(), "unsupported locale: xx-CH");
     }
 
     #[test]
@@ -125,6 +378,43 @@ mod tests {
         assert_eq!(result, alloc::vec!["children"]);
     }
 
+    #[test]
+    fn test_language_rules_preserves_case() {
+        let rules = language_rules("en").unwrap();
+        assert_eq!(rules.singularize("Children"), "Child");
+        assert_eq!(rules.singularize("USERS"), "USER");
+        assert_eq!(rules.singularize("Categories"), "Category");
+        assert_eq!(rules.singularize("iPhones"), "iPhone");
+        let result = rules.pluralize("Person");
+        assert_eq!(result, alloc::vec!["People"]);
+
+        let rules = language_rules("tr").unwrap();
+        assert_eq!(rules.singularize("KIZLAR"), "KIZ");
+        assert_eq!(rules.singularize("İLLER"), "İL");
+    }
+
+    #[test]
+    fn test_language_rules_identifier() {
+        let rules = language_rules("en").unwrap();
+        assert_eq!(
+            rules.singularize_identifier("UserCategories"),
+            "UserCategory"
+        );
+        assert_eq!(
+            rules.singularize_identifier("user-accounts"),
+            "user-account"
+        );
+        let result = rules.pluralize_identifier("orderLineItem");
+        assert!(result.iter().any(|v| v == "orderLineItems"));
+
+        let rules = language_rules("fr").unwrap();
+        assert_eq!(rules.head_position(), HeadPosition::First);
+        assert_eq!(
+            rules.singularize_identifier("journaux_ventes"),
+            "journal_ventes"
+        );
+    }
+
     #[test]
     fn test_language_rules_delegate() {
         let rules = language_rules("az").unwrap();
@@ -132,4 +422,48 @@ mod tests {
         // Delegates to Turkish
         assert_eq!(rules.singularize("kullanicilar"), "kullanici");
     }
+
+    #[test]
+    fn test_plural_category() {
+        assert_eq!(plural_category("en-US", 1).unwrap(), PluralCategory::One);
+        assert_eq!(plural_category("EN", 2).unwrap(), PluralCategory::Other);
+        assert_eq!(plural_category("cy", 6u8).unwrap(), PluralCategory::Many);
+        let operands: PluralOperands = "2.5".parse().unwrap();
+        assert_eq!(
+            plural_category("ga", operands).unwrap(),
+            PluralCategory::Other
+        );
+        let err = plural_category("xx", 1).unwrap_err();
+        assert_eq!(err.to_string(), "unsupported locale: xx");
+    }
+
+    #[test]
+    fn test_plural_category_parent_locale() {
+        let rules = language_rules("pt-AO").unwrap();
+        for n in ["0", "1", "1.5", "2", "1000000"] {
+            let operands: PluralOperands = n.parse().unwrap();
+            assert_eq!(
+                plural_category("pt-AO", operands).unwrap(),
+                rules.plural_category(operands),
+                "pt-AO cardinal {n}"
+            );
+            assert_eq!(
+                plural_category("pt-AO", operands).unwrap(),
+                plural_category("pt-PT", operands).unwrap(),
+                "pt-AO cardinal {n}"
+            );
+        }
+        assert_eq!(
+            ordinal_category("pt-AO", 1).unwrap(),
+            rules.ordinal_category(1)
+        );
+    }
+
+    #[test]
+    fn test_ordinal_category() {
+        assert_eq!(ordinal_category("en", 2).unwrap(), PluralCategory::Two);
+        assert_eq!(ordinal_category("fr_FR", 1).unwrap(), PluralCategory::One);
+        assert_eq!(ordinal_category("pt-PT", 1).unwrap(), PluralCategory::Other);
+        assert!(ordinal_category("xx", 1).is_err());
+    }
 }
@@ -1,90 +1,732 @@
-//! Locale normalization utilities.
+//! Locale parsing utilities.
 //!
-//! Handles common locale variations like `en-US` -> `en`, `EN` -> `en`.
+//! Parses [BCP 47](https://www.rfc-editor.org/info/bcp47) language tags such as `en`, `pt-BR`,
+//! `zh-Hant-TW` or `sr-Latn-RS` into their language, script, region and variant subtags. Language
+//! subtags are canonicalized to ISO 639-1 two-letter codes where one exists, so that ISO 639-2
+//! (bibliographic and terminology) and ISO 639-3 codes such as `deu`, `ger` or `eng` resolve to
+//! the same language as `de` or `en`.
 
-/// Normalizes a locale string to a two-letter ISO 639-1 language code.
-///
-/// Strips region/country suffixes (e.g., `en-US` -> `en`, `pt-BR` -> `pt`), trims whitespace,
-/// and converts to lowercase. Returns a stack-allocated [`NormalizedLocale`] to avoid heap
-/// allocation.
-/// Maximum length of an ISO 639-1 language code.
-const MAX_LANGUAGE_CODE_LENGTH: usize = 2;
-
-/// A stack-allocated normalized locale string (avoids heap allocation).
-pub(crate) struct NormalizedLocale {
-    buffer: [u8; MAX_LANGUAGE_CODE_LENGTH],
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use phf::{phf_map, phf_set};
+
+use crate::error::{Error, Result};
+
+/// ISO 639-2 (bibliographic and terminology) and ISO 639-3 codes of languages that have an
+/// ISO 639-1 code, together with deprecated ISO 639-1 codes.
+static LANGUAGE_ALIASES: phf::Map<&'static str, &'static str> = phf_map! {
+    // Deprecated ISO 639-1 codes
+    "in" => "id",
+    "iw" => "he",
+    "ji" => "yi",
+    "jw" => "jv",
+    "mo" => "ro",
+    // ISO 639-2/T and ISO 639-3 codes
+    "aar" => "aa",
+    "abk" => "ab",
+    "afr" => "af",
+    "aka" => "ak",
+    "amh" => "am",
+    "ara" => "ar",
+    "arg" => "an",
+    "asm" => "as",
+    "ava" => "av",
+    "ave" => "ae",
+    "aym" => "ay",
+    "aze" => "az",
+    "bak" => "ba",
+    "bam" => "bm",
+    "bel" => "be",
+    "ben" => "bn",
+    "bis" => "bi",
+    "bod" => "bo",
+    "bos" => "bs",
+    "bre" => "br",
+    "bul" => "bg",
+    "cat" => "ca",
+    "ces" => "cs",
+    "cha" => "ch",
+    "che" => "ce",
+    "chu" => "cu",
+    "chv" => "cv",
+    "cor" => "kw",
+    "cos" => "co",
+    "cym" => "cy",
+    "dan" => "da",
+    "deu" => "de",
+    "div" => "dv",
+    "dzo" => "dz",
+    "ell" => "el",
+    "eng" => "en",
+    "epo" => "eo",
+    "est" => "et",
+    "eus" => "eu",
+    "ewe" => "ee",
+    "fao" => "fo",
+    "fas" => "fa",
+    "fij" => "fj",
+    "fin" => "fi",
+    "fra" => "fr",
+    "fry" => "fy",
+    "ful" => "ff",
+    "gla" => "gd",
+    "gle" => "ga",
+    "glg" => "gl",
+    "glv" => "gv",
+    "grn" => "gn",
+    "guj" => "gu",
+    "hat" => "ht",
+    "hau" => "ha",
+    "heb" => "he",
+    "her" => "hz",
+    "hin" => "hi",
+    "hmo" => "ho",
+    "hrv" => "hr",
+    "hun" => "hu",
+    "hye" => "hy",
+    "ibo" => "ig",
+    "ido" => "io",
+    "iii" => "ii",
+    "iku" => "iu",
+    "ile" => "ie",
+    "ina" => "ia",
+    "ind" => "id",
+    "ipk" => "ik",
+    "isl" => "is",
+    "ita" => "it",
+    "jav" => "jv",
+    "jpn" => "ja",
+    "kal" => "kl",
+    "kan" => "kn",
+    "kas" => "ks",
+    "kat" => "ka",
+    "kau" => "kr",
+    "kaz" => "kk",
+    "khm" => "km",
+    "kik" => "ki",
+    "kin" => "rw",
+    "kir" => "ky",
+    "kom" => "kv",
+    "kon" => "kg",
+    "kor" => "ko",
+    "kua" => "kj",
+    "kur" => "ku",
+    "lao" => "lo",
+    "lat" => "la",
+    "lav" => "lv",
+    "lim" => "li",
+    "lin" => "ln",
+    "lit" => "lt",
+    "ltz" => "lb",
+    "lub" => "lu",
+    "lug" => "lg",
+    "mah" => "mh",
+    "mal" => "ml",
+    "mar" => "mr",
+    "mkd" => "mk",
+    "mlg" => "mg",
+    "mlt" => "mt",
+    "mon" => "mn",
+    "mri" => "mi",
+    "msa" => "ms",
+    "mya" => "my",
+    "nau" => "na",
+    "nav" => "nv",
+    "nbl" => "nr",
+    "nde" => "nd",
+    "ndo" => "ng",
+    "nep" => "ne",
+    "nld" => "nl",
+    "nno" => "nn",
+    "nob" => "nb",
+    "nor" => "no",
+    "nya" => "ny",
+    "oci" => "oc",
+    "oji" => "oj",
+    "ori" => "or",
+    "orm" => "om",
+    "oss" => "os",
+    "pan" => "pa",
+    "pli" => "pi",
+    "pol" => "pl",
+    "por" => "pt",
+    "pus" => "ps",
+    "que" => "qu",
+    "roh" => "rm",
+    "ron" => "ro",
+    "run" => "rn",
+    "rus" => "ru",
+    "sag" => "sg",
+    "san" => "sa",
+    "sin" => "si",
+    "slk" => "sk",
+    "slv" => "sl",
+    "sme" => "se",
+    "smo" => "sm",
+    "sna" => "sn",
+    "snd" => "sd",
+    "som" => "so",
+    "sot" => "st",
+    "spa" => "es",
+    "sqi" => "sq",
+    "srd" => "sc",
+    "srp" => "sr",
+    "ssw" => "ss",
+    "sun" => "su",
+    "swa" => "sw",
+    "swe" => "sv",
+    "tah" => "ty",
+    "tam" => "ta",
+    "tat" => "tt",
+    "tel" => "te",
+    "tgk" => "tg",
+    "tgl" => "tl",
+    "tha" => "th",
+    "tir" => "ti",
+    "ton" => "to",
+    "tsn" => "tn",
+    "tso" => "ts",
+    "tuk" => "tk",
+    "tur" => "tr",
+    "twi" => "tw",
+    "uig" => "ug",
+    "ukr" => "uk",
+    "urd" => "ur",
+    "uzb" => "uz",
+    "ven" => "ve",
+    "vie" => "vi",
+    "vol" => "vo",
+    "wln" => "wa",
+    "wol" => "wo",
+    "xho" => "xh",
+    "yid" => "yi",
+    "yor" => "yo",
+    "zha" => "za",
+    "zho" => "zh",
+    "zul" => "zu",
+    // ISO 639-2/B codes
+    "alb" => "sq",
+    "arm" => "hy",
+    "baq" => "eu",
+    "bur" => "my",
+    "chi" => "zh",
+    "cze" => "cs",
+    "dut" => "nl",
+    "fre" => "fr",
+    "geo" => "ka",
+    "ger" => "de",
+    "gre" => "el",
+    "ice" => "is",
+    "mac" => "mk",
+    "mao" => "mi",
+    "may" => "ms",
+    "per" => "fa",
+    "rum" => "ro",
+    "slo" => "sk",
+    "tib" => "bo",
+    "wel" => "cy",
+    // ISO 639-3 codes of the standard variety of a macrolanguage
+    "als" => "sq",
+    "arb" => "ar",
+    "ayr" => "ay",
+    "azj" => "az",
+    "cmn" => "zh",
+    "ekk" => "et",
+    "khk" => "mn",
+    "kmr" => "ku",
+    "lvs" => "lv",
+    "npi" => "ne",
+    "ory" => "or",
+    "pes" => "fa",
+    "plt" => "mg",
+    "swh" => "sw",
+    "uzn" => "uz",
+    "ydd" => "yi",
+    "zsm" => "ms",
+};
+
+/// Locales whose CLDR parent is not the tag with its last subtag removed: the Portuguese of
+/// Africa, Asia and Europe inherits from European rather than Brazilian Portuguese.
+static PARENT_LOCALES: phf::Map<&'static str, &'static str> = phf_map! {
+    "pt-AO" => "pt-PT",
+    "pt-CH" => "pt-PT",
+    "pt-CV" => "pt-PT",
+    "pt-FR" => "pt-PT",
+    "pt-GQ" => "pt-PT",
+    "pt-GW" => "pt-PT",
+    "pt-LU" => "pt-PT",
+    "pt-MO" => "pt-PT",
+    "pt-MZ" => "pt-PT",
+    "pt-ST" => "pt-PT",
+    "pt-TL" => "pt-PT",
+};
+
+/// ISO 15924 script codes, in title case. The private use codes `Qaaa` to `Qabx` are accepted
+/// separately.
+static SCRIPTS: phf::Set<&'static str> = phf_set! {
+    "Adlm", "Afak", "Aghb", "Ahom", "Arab", "Aran", "Armi", "Armn", "Avst", "Bali", "Bamu", "Bass",
+    "Batk", "Beng", "Bhks", "Blis", "Bopo", "Brah", "Brai", "Bugi", "Buhd", "Cakm", "Cans", "Cari",
+    "Cham", "Cher", "Chis", "Chrs", "Cirt", "Copt", "Cpmn", "Cprt", "Cyrl", "Cyrs", "Deva", "Diak",
+    "Dogr", "Dsrt", "Dupl", "Egyd", "Egyh", "Egyp", "Elba", "Elym", "Ethi", "Gara", "Geok", "Geor",
+    "Glag", "Gong", "Gonm", "Goth", "Gran", "Grek", "Gujr", "Gukh", "Guru", "Hanb", "Hang", "Hani",
+    "Hano", "Hans", "Hant", "Hatr", "Hebr", "Hira", "Hluw", "Hmng", "Hmnp", "Hrkt", "Hung", "Inds",
+    "Ital", "Jamo", "Java", "Jpan", "Jurc", "Kali", "Kana", "Kawi", "Khar", "Khmr", "Khoj", "Kitl",
+    "Kits", "Knda", "Kore", "Kpel", "Krai", "Kthi", "Lana", "Laoo", "Latf", "Latg", "Latn", "Leke",
+    "Lepc", "Limb", "Lina", "Linb", "Lisu", "Loma", "Lyci", "Lydi", "Mahj", "Maka", "Mand", "Mani",
+    "Marc", "Maya", "Medf", "Mend", "Merc", "Mero", "Mlym", "Modi", "Mong", "Moon", "Mroo", "Mtei",
+    "Mult", "Mymr", "Nagm", "Nand", "Narb", "Nbat", "Newa", "Nkdb", "Nkgb", "Nkoo", "Nshu", "Ogam",
+    "Olck", "Onao", "Orkh", "Orya", "Osge", "Osma", "Ougr", "Palm", "Pauc", "Pcun", "Pelm", "Perm",
+    "Phag", "Phli", "Phlp", "Phlv", "Phnx", "Piqd", "Plrd", "Prti", "Psin", "Ranj", "Rjng", "Rohg",
+    "Roro", "Runr", "Samr", "Sara", "Sarb", "Saur", "Sgnw", "Shaw", "Shrd", "Shui", "Sidd", "Sidt",
+    "Sind", "Sinh", "Sogd", "Sogo", "Sora", "Soyo", "Sund", "Sunu", "Sylo", "Syrc", "Syre", "Syrj",
+    "Syrn", "Tagb", "Takr", "Tale", "Talu", "Taml", "Tang", "Tavt", "Tayo", "Telu", "Teng", "Tfng",
+    "Tglg", "Thaa", "Thai", "Tibt", "Tirh", "Tnsa", "Todr", "Tols", "Toto", "Tutg", "Ugar", "Vaii",
+    "Visp", "Vith", "Wara", "Wcho", "Wole", "Xpeo", "Xsux", "Yezi", "Yiii", "Zanb", "Zinh", "Zmth",
+    "Zsye", "Zsym", "Zxxx", "Zyyy", "Zzzz",
+};
+
+/// The root locale, which ends every fallback chain.
+const ROOT: &str = "und";
+
+/// A subtag of at most `N` ASCII characters, stored inline to avoid heap allocation.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+struct Subtag<const N: usize> {
+    buffer: [u8; N],
     len: u8,
 }
 
-impl NormalizedLocale {
+impl<const N: usize> Subtag<N> {
+    /// Creates a subtag from an ASCII string of at most `N` bytes.
+    fn new(value: &str) -> Self {
+        debug_assert!(value.is_ascii() && value.len() <= N);
+        let mut buffer = [0u8; N];
+        buffer[..value.len()].copy_from_slice(value.as_bytes());
+        Subtag {
+            buffer,
+            len: u8::try_from(value.len()).unwrap_or(u8::MAX),
+        }
+    }
+
     #[inline]
     #[must_use]
-    pub(crate) fn as_str(&self) -> &str {
-        // SAFETY: The internal buffer is guaranteed to be valid UTF-8 by the constructors of
-        // NormalizedLocale.
+    fn as_str(&self) -> &str {
+        // SAFETY: The buffer only ever holds the ASCII bytes copied in `Subtag::new`.
         unsafe { core::str::from_utf8_unchecked(&self.buffer[..self.len as usize]) }
     }
 }
 
-#[inline]
-#[must_use]
-pub(crate) fn normalize_locale(locale: &str) -> NormalizedLocale {
-    let bytes = locale.as_bytes();
-    let mut buffer = [0u8; MAX_LANGUAGE_CODE_LENGTH];
-    let mut len: u8 = 0;
-
-    for i in 0..bytes.len().min(MAX_LANGUAGE_CODE_LENGTH) {
-        let b = bytes[i];
-        if b == b'-' || b == b'_' {
-            break;
+impl<const N: usize> fmt::Debug for Subtag<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// A parsed [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag.
+///
+/// Parsing is case-insensitive, accepts `_` as well as `-` as a separator and ignores POSIX
+/// encoding and modifier suffixes (`en_US.UTF-8`, `de_DE@euro`). Subtags are normalized to their
+/// canonical case, and the language subtag is mapped to its ISO 639-1 code when one exists.
+/// Extensions (`-u-ca-buddhist`) and private use subtags (`-x-...`) are accepted and ignored.
+///
+/// # Examples
+///
+/// ```
+/// use i18n_inflector::LanguageTag;
+///
+/// # fn main() -> i18n_inflector::Result<()> {
+/// let tag = LanguageTag::parse("sr-latn-rs")?;
+/// assert_eq!(tag.language(), "sr");
+/// assert_eq!(tag.script(), Some("Latn"));
+/// assert_eq!(tag.region(), Some("RS"));
+/// assert_eq!(tag.to_string(), "sr-Latn-RS");
+///
+/// // ISO 639-2 and ISO 639-3 codes map to ISO 639-1
+/// assert_eq!(LanguageTag::parse("deu")?.language(), "de");
+/// assert_eq!(LanguageTag::parse("ger")?.language(), "de");
+///
+/// // Codes without an ISO 639-1 equivalent are kept as is
+/// assert_eq!(LanguageTag::parse("fil")?.language(), "fil");
+///
+/// // Malformed tags are rejected
+/// assert!(LanguageTag::parse("en-").is_err());
+///
+/// // Rule sets are looked up from the most specific tag to the root
+/// let tag = LanguageTag::parse("pt-BR")?;
+/// assert_eq!(tag.fallback_chain(), ["pt-BR", "pt", "und"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LanguageTag {
+    language: Subtag<3>,
+    script: Option<Subtag<4>>,
+    region: Option<Subtag<3>>,
+    variants: Vec<String>,
+}
+
+impl LanguageTag {
+    /// Parses a BCP 47 language tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedLocaleTag`] if the tag is not well-formed, and
+    /// [`Error::UnknownScript`] if its script subtag is not an ISO 15924 code.
+    pub fn parse(tag: &str) -> Result<LanguageTag> {
+        let malformed = || Error::MalformedLocaleTag { tag: tag.into() };
+        let trimmed = tag.trim();
+        let trimmed = trimmed
+            .split_once(['.', '@'])
+            .map_or(trimmed, |(tag, _)| tag);
+        let mut subtags = trimmed.split(['-', '_']).peekable();
+
+        let language = subtags.next().unwrap_or_default();
+        if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic())
+        {
+            return Err(malformed());
+        }
+        // An extended language subtag names the language itself: `zh-yue` is Cantonese (`yue`),
+        // not Chinese. Only one is allowed, as the second and third positions are reserved.
+        let language = match subtags.next_if(|subtag| is_alpha(subtag, 3..=3)) {
+            Some(extlang) => {
+                if subtags.peek().is_some_and(|subtag| is_alpha(subtag, 3..=3)) {
+                    return Err(malformed());
+                }
+                extlang
+            }
+            None => language,
+        };
+        let language = language.to_ascii_lowercase();
+        let language = LANGUAGE_ALIASES
+            .get(language.as_str())
+            .copied()
+            .unwrap_or(language.as_str());
+        let mut parsed = LanguageTag {
+            language: Subtag::new(language),
+            script: None,
+            region: None,
+            variants: Vec::new(),
+        };
+
+        if let Some(subtag) = subtags.peek()
+            && is_alpha(subtag, 4..=4)
+        {
+            let mut script = subtag.to_ascii_lowercase();
+            script[..1].make_ascii_uppercase();
+            if !is_script(&script) {
+                return Err(Error::UnknownScript {
+                    tag: tag.into(),
+                    script,
+                });
+            }
+            parsed.script = Some(Subtag::new(&script));
+            subtags.next();
+        }
+        if let Some(subtag) = subtags.peek()
+            && (is_alpha(subtag, 2..=2)
+                || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit())))
+        {
+            parsed.region = Some(Subtag::new(&subtag.to_ascii_uppercase()));
+            subtags.next();
+        }
+        while let Some(subtag) = subtags.peek() {
+            let is_variant = subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+                && ((5..=8).contains(&subtag.len())
+                    || (subtag.len() == 4 && subtag.as_bytes()[0].is_ascii_digit()));
+            if !is_variant {
+                break;
+            }
+            parsed.variants.push(subtag.to_ascii_lowercase());
+            subtags.next();
+        }
+
+        // Extensions and private use subtags do not affect inflection, but must be well-formed:
+        // each singleton is followed by at least one subtag of up to eight characters.
+        let mut extension_len: Option<usize> = None;
+        for subtag in subtags {
+            if subtag.is_empty()
+                || subtag.len() > 8
+                || !subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+            {
+                return Err(malformed());
+            }
+            extension_len = match extension_len {
+                Some(0) if subtag.len() == 1 => return Err(malformed()),
+                _ if subtag.len() == 1 => Some(0),
+                Some(len) => Some(len + 1),
+                None => return Err(malformed()),
+            };
+        }
+        if extension_len == Some(0) {
+            return Err(malformed());
+        }
+        Ok(parsed)
+    }
+
+    /// Returns the language subtag, as an ISO 639-1 code where one exists.
+    #[must_use]
+    pub fn language(&self) -> &str {
+        self.language.as_str()
+    }
+
+    /// Returns the ISO 15924 script subtag in title case (`Latn`, `Cyrl`, `Hant`), if any.
+    #[must_use]
+    pub fn script(&self) -> Option<&str> {
+        self.script.as_ref().map(Subtag::as_str)
+    }
+
+    /// Returns the ISO 3166-1 or UN M.49 region subtag in uppercase (`US`, `419`), if any.
+    #[must_use]
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_ref().map(Subtag::as_str)
+    }
+
+    /// Returns the variant subtags in lowercase (`1901`, `valencia`).
+    #[must_use]
+    pub fn variants(&self) -> &[String] {
+        &self.variants
+    }
+
+    /// Returns the tags to look up for this locale, from the most specific to the root locale
+    /// `und`.
+    ///
+    /// Each tag is the previous one with its last subtag removed (`sr-Latn-RS`, `sr-Latn`, `sr`),
+    /// except where CLDR defines another parent locale: `pt-AO` falls back to `pt-PT` before
+    /// `pt`.
+    #[must_use]
+    pub fn fallback_chain(&self) -> Vec<String> {
+        let mut chain = Vec::new();
+        let mut locale = self.to_string();
+        loop {
+            let parent = PARENT_LOCALES
+                .get(locale.as_str())
+                .map(|parent| String::from(*parent))
+                .or_else(|| {
+                    locale
+                        .rsplit_once('-')
+                        .map(|(parent, _)| String::from(parent))
+                });
+            chain.push(locale);
+            match parent {
+                Some(parent) => locale = parent,
+                None => break,
+            }
+        }
+        if self.language() != ROOT {
+            chain.push(String::from(ROOT));
         }
-        buffer[i] = b.to_ascii_lowercase();
-        len += 1;
+        chain
     }
+}
+
+/// Returns `true` if the title-cased `script` is an ISO 15924 code, including the private use
+/// codes `Qaaa` to `Qabx`.
+fn is_script(script: &str) -> bool {
+    SCRIPTS.contains(script) || ("Qaaa"..="Qabx").contains(&script)
+}
 
-    NormalizedLocale { buffer, len }
+/// Returns `true` if `subtag` consists of ASCII letters only and its length is within `length`.
+fn is_alpha(subtag: &str, length: core::ops::RangeInclusive<usize>) -> bool {
+    length.contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+impl FromStr for LanguageTag {
+    type Err = Error;
+
+    fn from_str(tag: &str) -> Result<Self> {
+        LanguageTag::parse(tag)
+    }
+}
+
+impl fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.language())?;
+        if let Some(script) = self.script() {
+            write!(f, "-{script}")?;
+        }
+        if let Some(region) = self.region() {
+            write!(f, "-{region}")?;
+        }
+        for variant in &self.variants {
+            write!(f, "-{variant}")?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
+    use alloc::string::ToString;
+
+    fn language(tag: &str) -> String {
+        LanguageTag::parse(tag).unwrap().language().to_string()
+    }
+
+    #[test]
+    fn test_parse_simple() {
+        assert_eq!(language("en"), "en");
+        assert_eq!(language("fr"), "fr");
+        assert_eq!(language("EN"), "en");
+        assert_eq!(language(" en "), "en");
+    }
+
+    #[test]
+    fn test_parse_with_region() {
+        let tag = LanguageTag::parse("en-US").unwrap();
+        assert_eq!(tag.language(), "en");
+        assert_eq!(tag.script(), None);
+        assert_eq!(tag.region(), Some("US"));
+        assert_eq!(language("en_US"), "en");
+        assert_eq!(language("pt-br"), "pt");
+        assert_eq!(LanguageTag::parse("es-419").unwrap().region(), Some("419"));
+    }
+
+    #[test]
+    fn test_parse_with_script() {
+        let tag = LanguageTag::parse("zh-Hant-TW").unwrap();
+        assert_eq!(tag.language(), "zh");
+        assert_eq!(tag.script(), Some("Hant"));
+        assert_eq!(tag.region(), Some("TW"));
+
+        let tag = LanguageTag::parse("SR_LATN_RS").unwrap();
+        assert_eq!(tag.script(), Some("Latn"));
+        assert_eq!(tag.region(), Some("RS"));
+        assert_eq!(tag.to_string(), "sr-Latn-RS");
+
+        let tag = LanguageTag::parse("uz-Cyrl").unwrap();
+        assert_eq!(tag.script(), Some("Cyrl"));
+        assert_eq!(tag.region(), None);
+    }
 
     #[test]
-    fn test_normalize_simple() {
-        assert_eq!(normalize_locale("en").as_str(), "en");
-        assert_eq!(normalize_locale("fr").as_str(), "fr");
+    fn test_parse_unknown_script() {
+        assert_eq!(
+            LanguageTag::parse("en-abcd-US").unwrap_err(),
+            Error::UnknownScript {
+                tag: "en-abcd-US".into(),
+                script: "Abcd".into(),
+            }
+        );
+        assert_eq!(
+            LanguageTag::parse("en-Qaab").unwrap().script(),
+            Some("Qaab")
+        );
+        assert!(LanguageTag::parse("en-Qaby").is_err());
     }
 
     #[test]
-    fn test_normalize_with_region() {
-        assert_eq!(normalize_locale("a_b").as_str(), "a");
-        assert_eq!(normalize_locale("a-b").as_str(), "a");
-        assert_eq!(normalize_locale("en_US").as_str(), "en");
-        assert_eq!(normalize_locale("en-US").as_str(), "en");
-        assert_eq!(normalize_locale("pt-BR").as_str(), "pt");
-        assert_eq!(normalize_locale("zh-TW").as_str(), "zh");
+    fn test_parse_variants_and_extensions() {
+        let tag = LanguageTag::parse("de-CH-1901").unwrap();
+        assert_eq!(tag.region(), Some("CH"));
+        assert_eq!(tag.variants(), ["1901"]);
+
+        let tag = LanguageTag::parse("ca-ES-Valencia").unwrap();
+        assert_eq!(tag.variants(), ["valencia"]);
+        assert_eq!(tag.to_string(), "ca-ES-valencia");
+
+        let tag = LanguageTag::parse("th-TH-u-nu-thai").unwrap();
+        assert_eq!(tag.to_string(), "th-TH");
+        assert_eq!(language("en-x-private"), "en");
+    }
+
+    #[test]
+    fn test_parse_extlang() {
+        // The extended language subtag replaces the primary language
+        assert_eq!(
+            LanguageTag::parse("zh-yue-HK").unwrap().to_string(),
+            "yue-HK"
+        );
+        assert_eq!(language("zh-cmn-Hans"), "zh");
+        assert_eq!(language("ar-arb"), "ar");
     }
 
     #[test]
-    fn test_normalize_with_underscore() {
-        assert_eq!(normalize_locale("en_US").as_str(), "en");
-        assert_eq!(normalize_locale("pt_BR").as_str(), "pt");
+    fn test_parse_posix() {
+        let tag = LanguageTag::parse("en_US.UTF-8").unwrap();
+        assert_eq!(tag.to_string(), "en-US");
+        assert_eq!(language("de_DE@euro"), "de");
     }
 
     #[test]
-    fn test_normalize_uppercase() {
-        assert_eq!(normalize_locale("EN").as_str(), "en");
-        assert_eq!(normalize_locale("EN-US").as_str(), "en");
+    fn test_parse_alpha3() {
+        assert_eq!(language("deu"), "de");
+        assert_eq!(language("ger"), "de");
+        assert_eq!(language("eng"), "en");
+        assert_eq!(language("spa-MX"), "es");
+        assert_eq!(language("fra"), "fr");
+        assert_eq!(language("fre"), "fr");
+        assert_eq!(language("zho-Hant"), "zh");
+        assert_eq!(language("chi"), "zh");
+        assert_eq!(language("cmn-Hans-CN"), "zh");
+        assert_eq!(language("WEL"), "cy");
+        assert_eq!(language("iw"), "he");
     }
 
     #[test]
-    fn test_normalize_unicode() {
-        assert_eq!(normalize_locale("ük").as_str(), "ü");
-        assert_eq!(normalize_locale("EN-ÜS").as_str(), "en");
+    fn test_parse_unknown_alpha3() {
+        // Languages without an ISO 639-1 code must not resolve to a two-letter prefix.
+        assert_eq!(language("fil"), "fil");
+        assert_eq!(language("haw"), "haw");
+        assert_eq!(language("yue-HK"), "yue");
     }
 
     #[test]
-    fn test_normalize_empty() {
-        assert_eq!(normalize_locale("").as_str(), "");
+    fn test_parse_malformed() {
+        for tag in [
+            "",
+            "e",
+            "-",
+            "en-",
+            "english",
+            "12",
+            "ü",
+            "en-ÜS",
+            "en--US",
+            "en-US-a",
+            "en-a-b",
+            "en-toolongsubtag",
+            "en-x",
+            "en-US-abc",
+            "zh-yue-cmn",
+        ] {
+            let error = LanguageTag::parse(tag).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!("malformed locale tag: {tag:?}"),
+                "{tag}"
+            );
+            assert_eq!(error, Error::MalformedLocaleTag { tag: tag.into() });
+        }
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let chain = |tag: &str| LanguageTag::parse(tag).unwrap().fallback_chain();
+        assert_eq!(chain("en"), ["en", "und"]);
+        assert_eq!(chain("pt_BR"), ["pt-BR", "pt", "und"]);
+        assert_eq!(chain("sr-Latn-RS"), ["sr-Latn-RS", "sr-Latn", "sr", "und"]);
+        assert_eq!(chain("de-CH-1901"), ["de-CH-1901", "de-CH", "de", "und"]);
+        assert_eq!(chain("pt-AO"), ["pt-AO", "pt-PT", "pt", "und"]);
+        assert_eq!(chain("und"), ["und"]);
+    }
+
+    #[test]
+    fn test_all_parent_locales_are_canonical() {
+        for (locale, parent) in PARENT_LOCALES.entries() {
+            assert_eq!(LanguageTag::parse(locale).unwrap().to_string(), *locale);
+            assert_eq!(LanguageTag::parse(parent).unwrap().to_string(), *parent);
+        }
+    }
+
+    #[test]
+    fn test_all_aliases_are_canonical() {
+        for (alias, language) in LANGUAGE_ALIASES.entries() {
+            assert_eq!(language.len(), 2, "{alias}");
+            assert!(!LANGUAGE_ALIASES.contains_key(language), "{alias}");
+        }
     }
 }
@@ -0,0 +1,132 @@
+//! Grammatical number detection.
+//!
+//! Decides whether a noun of unknown number is singular or plural by running it through a
+//! language's singularization and pluralization rules in both directions, so that singular nouns
+//! ending in a plural-looking suffix (`status`, `bus`) are not mistaken for plurals. Built-in rule
+//! sets first consult their irregulars and uncountables, and languages whose counted nouns take
+//! the genitive plural (`5 машин`) recognize it with [`genitive_plural_number`].
+
+use crate::language_rules::{LanguageRules, PluralizeFn, SingularizeFn};
+use alloc::borrow::Cow;
+
+/// The grammatical number of a noun.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Number {
+    /// The noun is singular (`user`, `status`).
+    Singular,
+    /// The noun is plural (`users`, `children`).
+    Plural,
+    /// The noun ends in a plural suffix, but pluralizing the resulting singular does not lead
+    /// back to it, so the rules cannot tell whether it is singular or plural.
+    Ambiguous,
+    /// The noun has identical singular and plural forms (`news`, `sheep`).
+    Invariant,
+}
+
+/// Detects the grammatical number of a noun using the singularization and pluralization rules of
+/// `rules`.
+pub(crate) fn number_of<R>(rules: &R, word: &str) -> Number
+where
+    R: LanguageRules + ?Sized,
+{
+    let singular = rules.singularize(word);
+    let plurals = rules.pluralize(word);
+    let invariant = plurals.iter().any(|plural| plural == word);
+    if singular == word {
+        return if invariant {
+            Number::Invariant
+        } else {
+            Number::Singular
+        };
+    }
+
+    // The word changes when singularized: it is plural if pluralizing the singular leads back to
+    // it, otherwise the suffix rules matched a singular that merely looks plural.
+    if rules
+        .pluralize(&singular)
+        .iter()
+        .any(|plural| plural == word)
+    {
+        Number::Plural
+    } else {
+        Number::Ambiguous
+    }
+}
+
+/// Returns [`Number::Plural`] if the lowercase `name` is a genitive plural according to the
+/// `singularize`, `pluralize` and `genitive_plural` functions of a language, or `None` to leave
+/// the decision to the suffix rules.
+///
+/// A genitive plural with an ending (`файлов`) singularizes to a noun whose genitive plural it
+/// is. One without an ending (`машин`, `stron`) looks like a singular, but its nominative plural
+/// singularizes to another noun whose genitive plural it is (`машины` -> `машина`).
+pub(crate) fn genitive_plural_number(
+    name: &str,
+    singularize: SingularizeFn,
+    pluralize: PluralizeFn,
+    genitive_plural: fn(&str) -> Cow<'_, str>,
+) -> Option<Number> {
+    let singular = singularize(name);
+    let lemma = if singular == name {
+        let plural = pluralize(name).into_iter().next()?;
+        Cow::Owned(singularize(&plural).into_owned())
+    } else {
+        singular
+    };
+    (lemma != name && genitive_plural(&lemma) == name).then_some(Number::Plural)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_rules::CustomRuleSet;
+    use crate::languages;
+
+    #[test]
+    fn test_number_of_english() {
+        let rules = &languages::en::RULES;
+        assert_eq!(number_of(rules, "user"), Number::Singular);
+        assert_eq!(number_of(rules, "users"), Number::Plural);
+        assert_eq!(number_of(rules, "boxes"), Number::Plural);
+        assert_eq!(number_of(rules, "categories"), Number::Plural);
+        assert_eq!(number_of(rules, "children"), Number::Plural);
+        assert_eq!(number_of(rules, "child"), Number::Singular);
+        assert_eq!(number_of(rules, "status"), Number::Singular);
+        assert_eq!(number_of(rules, "bus"), Number::Singular);
+        assert_eq!(number_of(rules, "class"), Number::Singular);
+        assert_eq!(number_of(rules, "news"), Number::Invariant);
+        assert_eq!(number_of(rules, "sheep"), Number::Invariant);
+        assert_eq!(number_of(rules, "software"), Number::Invariant);
+    }
+
+    #[test]
+    fn test_number_of_preserves_case() {
+        let rules = &languages::en::RULES;
+        assert_eq!(number_of(rules, "Users"), Number::Plural);
+        assert_eq!(number_of(rules, "STATUS"), Number::Singular);
+        assert_eq!(number_of(rules, "News"), Number::Invariant);
+    }
+
+    #[test]
+    fn test_number_of_ambiguous() {
+        let rules = CustomRuleSet::builder(&languages::en::RULES)
+            .singular_rule("ae", "a")
+            .build()
+            .unwrap();
+        assert_eq!(number_of(&rules, "formulae"), Number::Ambiguous);
+        assert_eq!(number_of(&rules, "formulas"), Number::Plural);
+    }
+
+    #[test]
+    fn test_number_of_other_languages() {
+        assert_eq!(number_of(&languages::de::RULES, "kinder"), Number::Plural);
+        assert_eq!(number_of(&languages::fr::RULES, "prix"), Number::Invariant);
+        assert_eq!(number_of(&languages::fr::RULES, "journaux"), Number::Plural);
+        assert_eq!(number_of(&languages::es::RULES, "ciudades"), Number::Plural);
+        assert_eq!(number_of(&languages::es::RULES, "ciudad"), Number::Singular);
+        assert_eq!(
+            number_of(&languages::ja::RULES, "ユーザー"),
+            Number::Invariant
+        );
+    }
+}
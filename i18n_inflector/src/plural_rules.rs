@@ -0,0 +1,1449 @@
+//! CLDR plural rules.
+//!
+//! Selects the plural category (`zero`, `one`, `two`, `few`, `many` or `other`) of a number for
+//! both cardinal (`1 file`, `2 files`) and ordinal (`1st`, `2nd`) usage. The rules follow the
+//! [Unicode CLDR plural rules](https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html)
+//! and are evaluated against the operands `n`, `i`, `v`, `w`, `f` and `t`, so decimals such as
+//! `1.5` or `1.0` are categorized correctly.
+//!
+//! Languages without CLDR data fall back to `one` for `n = 1` when they inflect nouns for number,
+//! and to `other` for every number when they do not.
+
+use core::fmt;
+use core::ops::RangeInclusive;
+use core::str::FromStr;
+
+use phf::phf_map;
+
+use crate::error::{Error, Result};
+use crate::locale::LanguageTag;
+use PluralCategory::{Few, Many, One, Other, Two, Zero};
+
+/// A CLDR plural category.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PluralCategory {
+    /// Used for zero in languages with a dedicated form (Arabic, Latvian, Welsh).
+    Zero,
+    /// Used for one, and numbers that behave like it (Russian `21`).
+    One,
+    /// Used for two, and numbers that behave like it (Slovenian `102`).
+    Two,
+    /// Used for small numbers (Polish `2`-`4`, Arabic `3`-`10`).
+    Few,
+    /// Used for large numbers or fractions (Russian `5`-`20`, Arabic `11`-`99`).
+    Many,
+    /// The general plural form; required by every language.
+    Other,
+}
+
+impl PluralCategory {
+    /// Returns the CLDR keyword of the category (`zero`, `one`, `two`, `few`, `many` or `other`).
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Maximum number of visible fraction digits supported by [`PluralOperands`].
+const MAX_FRACTION_DIGITS: usize = 18;
+
+/// The CLDR plural operands of a number.
+///
+/// Integers convert with [`From`]. Decimals are parsed from their string representation so that
+/// visible trailing zeros are kept (`"1.0"` and `"1"` belong to different categories in English):
+///
+/// ```
+/// use i18n_inflector::PluralOperands;
+///
+/// # fn main() -> i18n_inflector::Result<()> {
+/// let operands: PluralOperands = "1.50".parse()?;
+/// assert_eq!(operands.i(), 1);
+/// assert_eq!(operands.v(), 2);
+/// assert_eq!(operands.f(), 50);
+/// assert_eq!(operands.t(), 5);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PluralOperands {
+    i: u64,
+    v: usize,
+    w: usize,
+    f: u64,
+    t: u64,
+}
+
+impl PluralOperands {
+    /// Returns the integer digits of the absolute value (`i`).
+    #[must_use]
+    pub fn i(&self) -> u64 {
+        self.i
+    }
+
+    /// Returns the number of visible fraction digits, with trailing zeros (`v`).
+    #[must_use]
+    pub fn v(&self) -> usize {
+        self.v
+    }
+
+    /// Returns the number of visible fraction digits, without trailing zeros (`w`).
+    #[must_use]
+    pub fn w(&self) -> usize {
+        self.w
+    }
+
+    /// Returns the visible fraction digits, with trailing zeros (`f`).
+    #[must_use]
+    pub fn f(&self) -> u64 {
+        self.f
+    }
+
+    /// Returns the visible fraction digits, without trailing zeros (`t`).
+    #[must_use]
+    pub fn t(&self) -> u64 {
+        self.t
+    }
+
+    /// Returns `true` if the absolute value `n` equals `value`.
+    fn n_is(&self, value: u64) -> bool {
+        self.f == 0 && self.i == value
+    }
+
+    /// Returns `true` if the absolute value `n` is an integer within `range`.
+    fn n_in(&self, range: RangeInclusive<u64>) -> bool {
+        self.f == 0 && range.contains(&self.i)
+    }
+
+    /// Returns `n` if it is an integer.
+    fn n_integer(&self) -> Option<u64> {
+        (self.f == 0).then_some(self.i)
+    }
+
+    /// Returns `n % modulus`, or `None` when `n` is not an integer and so never equals one.
+    fn n_mod(&self, modulus: u64) -> Option<u64> {
+        (self.f == 0).then_some(self.i % modulus)
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for PluralOperands {
+                fn from(value: $t) -> Self {
+                    PluralOperands {
+                        i: u64::from(value),
+                        ..PluralOperands::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for PluralOperands {
+                fn from(value: $t) -> Self {
+                    PluralOperands::from(value.unsigned_abs())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64);
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+impl From<usize> for PluralOperands {
+    fn from(value: usize) -> Self {
+        PluralOperands {
+            i: value as u64,
+            ..PluralOperands::default()
+        }
+    }
+}
+
+impl FromStr for PluralOperands {
+    type Err = Error;
+
+    /// Parses a decimal number such as `3`, `-1.5` or `1.00`.
+    fn from_str(value: &str) -> Result<Self> {
+        let invalid = || Error::InvalidPluralOperand {
+            value: value.into(),
+        };
+        let number = value.trim();
+        let number = number
+            .strip_prefix('-')
+            .or_else(|| number.strip_prefix('+'))
+            .unwrap_or(number);
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if integer.is_empty()
+            || !integer.bytes().all(|b| b.is_ascii_digit())
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+            || fraction.len() > MAX_FRACTION_DIGITS
+            || (number.contains('.') && fraction.is_empty())
+        {
+            return Err(invalid());
+        }
+
+        let i = integer.parse().map_err(|_| invalid())?;
+        let trimmed = fraction.trim_end_matches('0');
+        let f = if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse().map_err(|_| invalid())?
+        };
+        let t = if trimmed.is_empty() {
+            0
+        } else {
+            trimmed.parse().map_err(|_| invalid())?
+        };
+        Ok(PluralOperands {
+            i,
+            v: fraction.len(),
+            w: trimmed.len(),
+            f,
+            t,
+        })
+    }
+}
+
+impl TryFrom<&str> for PluralOperands {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+/// Function type for plural category selection.
+pub(crate) type PluralRuleFn = fn(&PluralOperands) -> PluralCategory;
+
+/// Returns `true` if `value` is present and within `range`.
+fn within(value: Option<u64>, range: RangeInclusive<u64>) -> bool {
+    value.is_some_and(|value| range.contains(&value))
+}
+
+/// Returns `true` if `value` is present and one of `values`.
+fn one_of(value: Option<u64>, values: &[u64]) -> bool {
+    value.is_some_and(|value| values.contains(&value))
+}
+
+/// Returns the category for `condition`, or `other`.
+fn one_if(condition: bool) -> PluralCategory {
+    if condition { One } else { Other }
+}
+
+/// Selects `many` for millions in Romance languages (`1 000 000 de fichiers`).
+fn is_million(op: &PluralOperands) -> bool {
+    op.v == 0 && op.i != 0 && op.i.is_multiple_of(1_000_000)
+}
+
+// Cardinal rules.
+
+/// `other` for every number.
+fn other(_: &PluralOperands) -> PluralCategory {
+    Other
+}
+
+/// `one: n = 1`
+fn one_n1(op: &PluralOperands) -> PluralCategory {
+    one_if(op.n_is(1))
+}
+
+/// `one: n = 0..1`
+fn one_n0_1(op: &PluralOperands) -> PluralCategory {
+    one_if(op.n_in(0..=1))
+}
+
+/// `one: i = 0,1`
+fn one_i0_1(op: &PluralOperands) -> PluralCategory {
+    one_if(op.i <= 1)
+}
+
+/// `one: i = 0 or n = 1`
+fn one_i0_or_n1(op: &PluralOperands) -> PluralCategory {
+    one_if(op.i == 0 || op.n_is(1))
+}
+
+/// `one: i = 1 and v = 0`
+fn one_i1_v0(op: &PluralOperands) -> PluralCategory {
+    one_if(op.i == 1 && op.v == 0)
+}
+
+/// `one: n = 1`, `two: n = 2`
+fn one_two(op: &PluralOperands) -> PluralCategory {
+    if op.n_is(1) {
+        One
+    } else if op.n_is(2) {
+        Two
+    } else {
+        Other
+    }
+}
+
+fn da(op: &PluralOperands) -> PluralCategory {
+    one_if(op.n_is(1) || (op.t != 0 && op.i <= 1))
+}
+
+fn is(op: &PluralOperands) -> PluralCategory {
+    one_if(
+        (op.t == 0 && op.i % 10 == 1 && op.i % 100 != 11) || (op.t % 10 == 1 && op.t % 100 != 11),
+    )
+}
+
+fn mk(op: &PluralOperands) -> PluralCategory {
+    one_if(
+        (op.v == 0 && op.i % 10 == 1 && op.i % 100 != 11) || (op.f % 10 == 1 && op.f % 100 != 11),
+    )
+}
+
+fn si(op: &PluralOperands) -> PluralCategory {
+    one_if(op.n_in(0..=1) || (op.i == 0 && op.f == 1))
+}
+
+fn fil(op: &PluralOperands) -> PluralCategory {
+    if op.v == 0 {
+        one_if((1..=3).contains(&op.i) || ![4, 6, 9].contains(&(op.i % 10)))
+    } else {
+        one_if(![4, 6, 9].contains(&(op.f % 10)))
+    }
+}
+
+fn lv(op: &PluralOperands) -> PluralCategory {
+    if op.n_mod(10) == Some(0)
+        || within(op.n_mod(100), 11..=19)
+        || (op.v == 2 && (11..=19).contains(&(op.f % 100)))
+    {
+        Zero
+    } else if (op.n_mod(10) == Some(1) && op.n_mod(100) != Some(11))
+        || (op.f % 10 == 1 && (op.v != 2 || op.f % 100 != 11))
+    {
+        One
+    } else {
+        Other
+    }
+}
+
+fn ro(op: &PluralOperands) -> PluralCategory {
+    if op.i == 1 && op.v == 0 {
+        One
+    } else if op.v != 0 || op.n_is(0) || (!op.n_is(1) && within(op.n_mod(100), 1..=19)) {
+        Few
+    } else {
+        Other
+    }
+}
+
+/// Bosnian, Croatian and Serbian.
+fn hr(op: &PluralOperands) -> PluralCategory {
+    if (op.v == 0 && op.i % 10 == 1 && op.i % 100 != 11) || (op.f % 10 == 1 && op.f % 100 != 11) {
+        One
+    } else if (op.v == 0 && (2..=4).contains(&(op.i % 10)) && !(12..=14).contains(&(op.i % 100)))
+        || ((2..=4).contains(&(op.f % 10)) && !(12..=14).contains(&(op.f % 100)))
+    {
+        Few
+    } else {
+        Other
+    }
+}
+
+fn sl(op: &PluralOperands) -> PluralCategory {
+    if op.v != 0 {
+        return Few;
+    }
+    match op.i % 100 {
+        1 => One,
+        2 => Two,
+        3 | 4 => Few,
+        _ => Other,
+    }
+}
+
+/// Russian and Ukrainian.
+fn ru(op: &PluralOperands) -> PluralCategory {
+    if op.v != 0 {
+        return Other;
+    }
+    let (units, tens) = (op.i % 10, op.i % 100);
+    if units == 1 && tens != 11 {
+        One
+    } else if (2..=4).contains(&units) && !(12..=14).contains(&tens) {
+        Few
+    } else {
+        Many
+    }
+}
+
+fn be(op: &PluralOperands) -> PluralCategory {
+    let (units, tens) = (op.n_mod(10), op.n_mod(100));
+    if units == Some(1) && tens != Some(11) {
+        One
+    } else if within(units, 2..=4) && !within(tens, 12..=14) {
+        Few
+    } else if units == Some(0) || within(units, 5..=9) || within(tens, 11..=14) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn pl(op: &PluralOperands) -> PluralCategory {
+    if op.v != 0 {
+        return Other;
+    }
+    let (units, tens) = (op.i % 10, op.i % 100);
+    if op.i == 1 {
+        One
+    } else if (2..=4).contains(&units) && !(12..=14).contains(&tens) {
+        Few
+    } else {
+        Many
+    }
+}
+
+/// Czech and Slovak.
+fn cs(op: &PluralOperands) -> PluralCategory {
+    if op.v != 0 {
+        Many
+    } else if op.i == 1 {
+        One
+    } else if (2..=4).contains(&op.i) {
+        Few
+    } else {
+        Other
+    }
+}
+
+fn lt(op: &PluralOperands) -> PluralCategory {
+    let (units, tens) = (op.n_mod(10), op.n_mod(100));
+    if units == Some(1) && !within(tens, 11..=19) {
+        One
+    } else if within(units, 2..=9) && !within(tens, 11..=19) {
+        Few
+    } else if op.f != 0 {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn mt(op: &PluralOperands) -> PluralCategory {
+    if op.n_is(1) {
+        One
+    } else if op.n_is(2) {
+        Two
+    } else if op.n_is(0) || within(op.n_mod(100), 3..=10) {
+        Few
+    } else if within(op.n_mod(100), 11..=19) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn ar(op: &PluralOperands) -> PluralCategory {
+    if op.n_is(0) {
+        Zero
+    } else if op.n_is(1) {
+        One
+    } else if op.n_is(2) {
+        Two
+    } else if within(op.n_mod(100), 3..=10) {
+        Few
+    } else if within(op.n_mod(100), 11..=99) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn cy(op: &PluralOperands) -> PluralCategory {
+    match op.n_integer() {
+        Some(0) => Zero,
+        Some(1) => One,
+        Some(2) => Two,
+        Some(3) => Few,
+        Some(6) => Many,
+        _ => Other,
+    }
+}
+
+fn ga(op: &PluralOperands) -> PluralCategory {
+    if op.n_is(1) {
+        One
+    } else if op.n_is(2) {
+        Two
+    } else if op.n_in(3..=6) {
+        Few
+    } else if op.n_in(7..=10) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn gd(op: &PluralOperands) -> PluralCategory {
+    if op.n_is(1) || op.n_is(11) {
+        One
+    } else if op.n_is(2) || op.n_is(12) {
+        Two
+    } else if op.n_in(3..=10) || op.n_in(13..=19) {
+        Few
+    } else {
+        Other
+    }
+}
+
+fn gv(op: &PluralOperands) -> PluralCategory {
+    if op.v != 0 {
+        Many
+    } else if op.i % 10 == 1 {
+        One
+    } else if op.i % 10 == 2 {
+        Two
+    } else if [0, 20, 40, 60, 80].contains(&(op.i % 100)) {
+        Few
+    } else {
+        Other
+    }
+}
+
+fn br(op: &PluralOperands) -> PluralCategory {
+    let (units, tens) = (op.n_mod(10), op.n_mod(100));
+    if units == Some(1) && !one_of(tens, &[11, 71, 91]) {
+        One
+    } else if units == Some(2) && !one_of(tens, &[12, 72, 92]) {
+        Two
+    } else if one_of(units, &[3, 4, 9])
+        && !(within(tens, 10..=19) || within(tens, 70..=79) || within(tens, 90..=99))
+    {
+        Few
+    } else if !op.n_is(0) && op.n_mod(1_000_000) == Some(0) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn kw(op: &PluralOperands) -> PluralCategory {
+    let hundreds = op.n_mod(100);
+    if op.n_is(0) {
+        Zero
+    } else if op.n_is(1) {
+        One
+    } else if one_of(hundreds, &[2, 22, 42, 62, 82])
+        || (op.n_mod(1_000) == Some(0)
+            && (within(op.n_mod(100_000), 1_000..=20_000)
+                || one_of(op.n_mod(100_000), &[40_000, 60_000, 80_000])))
+        || (!op.n_is(0) && op.n_mod(1_000_000) == Some(100_000))
+    {
+        Two
+    } else if one_of(hundreds, &[3, 23, 43, 63, 83]) {
+        Few
+    } else if !op.n_is(1) && one_of(hundreds, &[1, 21, 41, 61, 81]) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn he(op: &PluralOperands) -> PluralCategory {
+    if (op.i == 1 && op.v == 0) || (op.i == 0 && op.v != 0) {
+        One
+    } else if op.i == 2 && op.v == 0 {
+        Two
+    } else {
+        Other
+    }
+}
+
+fn fr(op: &PluralOperands) -> PluralCategory {
+    if op.i <= 1 {
+        One
+    } else if is_million(op) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn es(op: &PluralOperands) -> PluralCategory {
+    if op.n_is(1) {
+        One
+    } else if is_million(op) {
+        Many
+    } else {
+        Other
+    }
+}
+
+/// Catalan and Italian.
+fn it(op: &PluralOperands) -> PluralCategory {
+    if op.i == 1 && op.v == 0 {
+        One
+    } else if is_million(op) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn pt(op: &PluralOperands) -> PluralCategory {
+    fr(op)
+}
+
+// Ordinal rules.
+
+fn ordinal_en(op: &PluralOperands) -> PluralCategory {
+    let (units, tens) = (op.n_mod(10), op.n_mod(100));
+    if units == Some(1) && tens != Some(11) {
+        One
+    } else if units == Some(2) && tens != Some(12) {
+        Two
+    } else if units == Some(3) && tens != Some(13) {
+        Few
+    } else {
+        Other
+    }
+}
+
+fn ordinal_hu(op: &PluralOperands) -> PluralCategory {
+    one_if(op.n_is(1) || op.n_is(5))
+}
+
+fn ordinal_ne(op: &PluralOperands) -> PluralCategory {
+    one_if(op.n_in(1..=4))
+}
+
+fn ordinal_be(op: &PluralOperands) -> PluralCategory {
+    if one_of(op.n_mod(10), &[2, 3]) && !one_of(op.n_mod(100), &[12, 13]) {
+        Few
+    } else {
+        Other
+    }
+}
+
+fn ordinal_uk(op: &PluralOperands) -> PluralCategory {
+    if op.n_mod(10) == Some(3) && op.n_mod(100) != Some(13) {
+        Few
+    } else {
+        Other
+    }
+}
+
+fn ordinal_tk(op: &PluralOperands) -> PluralCategory {
+    if one_of(op.n_mod(10), &[6, 9]) || op.n_is(10) {
+        Few
+    } else {
+        Other
+    }
+}
+
+fn ordinal_kk(op: &PluralOperands) -> PluralCategory {
+    let units = op.n_mod(10);
+    if one_of(units, &[6, 9]) || (units == Some(0) && !op.n_is(0)) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn ordinal_it(op: &PluralOperands) -> PluralCategory {
+    if one_of(op.n_integer(), &[8, 11, 80, 800]) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn ordinal_ka(op: &PluralOperands) -> PluralCategory {
+    let tens = op.i % 100;
+    if op.i == 1 {
+        One
+    } else if op.i == 0 || (2..=20).contains(&tens) || [40, 60, 80].contains(&tens) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn ordinal_sq(op: &PluralOperands) -> PluralCategory {
+    if op.n_is(1) {
+        One
+    } else if op.n_mod(10) == Some(4) && op.n_mod(100) != Some(14) {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn ordinal_sv(op: &PluralOperands) -> PluralCategory {
+    one_if(one_of(op.n_mod(10), &[1, 2]) && !one_of(op.n_mod(100), &[11, 12]))
+}
+
+fn ordinal_mr(op: &PluralOperands) -> PluralCategory {
+    match op.n_integer() {
+        Some(1) => One,
+        Some(2 | 3) => Two,
+        Some(4) => Few,
+        _ => Other,
+    }
+}
+
+fn ordinal_ca(op: &PluralOperands) -> PluralCategory {
+    match op.n_integer() {
+        Some(1 | 3) => One,
+        Some(2) => Two,
+        Some(4) => Few,
+        _ => Other,
+    }
+}
+
+fn ordinal_mk(op: &PluralOperands) -> PluralCategory {
+    let (units, tens) = (op.i % 10, op.i % 100);
+    if units == 1 && tens != 11 {
+        One
+    } else if units == 2 && tens != 12 {
+        Two
+    } else if (units == 7 || units == 8) && tens != 17 && tens != 18 {
+        Many
+    } else {
+        Other
+    }
+}
+
+fn ordinal_az(op: &PluralOperands) -> PluralCategory {
+    let (units, tens, thousands) = (op.i % 10, op.i % 100, op.i % 1000);
+    if [1, 2, 5, 7, 8].contains(&units) || [20, 50, 70, 80].contains(&tens) {
+        One
+    } else if [3, 4].contains(&units) || (thousands != 0 && thousands % 100 == 0) {
+        Few
+    } else if op.i == 0 || units == 6 || [40, 60, 90].contains(&tens) {
+        Many
+    } else {
+        Other
+    }
+}
+
+/// Gujarati and Hindi.
+fn ordinal_hi(op: &PluralOperands) -> PluralCategory {
+    match op.n_integer() {
+        Some(1) => One,
+        Some(2 | 3) => Two,
+        Some(4) => Few,
+        Some(6) => Many,
+        _ => Other,
+    }
+}
+
+/// Assamese and Bengali.
+fn ordinal_bn(op: &PluralOperands) -> PluralCategory {
+    match op.n_integer() {
+        Some(1 | 5 | 7..=10) => One,
+        Some(2 | 3) => Two,
+        Some(4) => Few,
+        Some(6) => Many,
+        _ => Other,
+    }
+}
+
+fn ordinal_or(op: &PluralOperands) -> PluralCategory {
+    match op.n_integer() {
+        Some(1 | 5 | 7..=9) => One,
+        Some(2 | 3) => Two,
+        Some(4) => Few,
+        Some(6) => Many,
+        _ => Other,
+    }
+}
+
+fn ordinal_cy(op: &PluralOperands) -> PluralCategory {
+    match op.n_integer() {
+        Some(0 | 7..=9) => Zero,
+        Some(1) => One,
+        Some(2) => Two,
+        Some(3 | 4) => Few,
+        Some(5 | 6) => Many,
+        _ => Other,
+    }
+}
+
+fn ordinal_gd(op: &PluralOperands) -> PluralCategory {
+    match op.n_integer() {
+        Some(1 | 11) => One,
+        Some(2 | 12) => Two,
+        Some(3 | 13) => Few,
+        _ => Other,
+    }
+}
+
+fn ordinal_kw(op: &PluralOperands) -> PluralCategory {
+    let hundreds = op.n_mod(100);
+    if op.n_in(1..=4)
+        || [1..=4, 21..=24, 41..=44, 61..=64, 81..=84]
+            .into_iter()
+            .any(|range| within(hundreds, range))
+    {
+        One
+    } else if op.n_is(5) || hundreds == Some(5) {
+        Many
+    } else {
+        Other
+    }
+}
+
+/// Cardinal plural rules for every supported language, and for the regional variants whose rules
+/// differ from those of their language.
+static CARDINAL_RULES_MAP: phf::Map<&'static str, PluralRuleFn> = phf_map! {
+    "aa" => other,
+    "ab" => other,
+    "ae" => other,
+    "af" => one_n1,
+    "ak" => one_n0_1,
+    "am" => one_i0_or_n1,
+    "an" => one_n1,
+    "ar" => ar,
+    "as" => one_i0_or_n1,
+    "av" => other,
+    "ay" => one_n1,
+    "az" => one_n1,
+    "ba" => one_n1,
+    "be" => be,
+    "bg" => one_n1,
+    "bi" => other,
+    "bm" => other,
+    "bn" => one_i0_or_n1,
+    "bo" => other,
+    "br" => br,
+    "bs" => hr,
+    "ca" => it,
+    "ce" => one_n1,
+    "ch" => other,
+    "co" => it,
+    "cs" => cs,
+    "cu" => other,
+    "cv" => one_n1,
+    "cy" => cy,
+    "da" => da,
+    "de" => one_i1_v0,
+    "dv" => one_n1,
+    "dz" => other,
+    "ee" => one_n1,
+    "el" => one_n1,
+    "en" => one_i1_v0,
+    "eo" => one_n1,
+    "es" => es,
+    "et" => one_i1_v0,
+    "eu" => one_n1,
+    "fa" => one_i0_or_n1,
+    "ff" => one_i0_1,
+    "fi" => one_i1_v0,
+    "fj" => other,
+    "fo" => one_n1,
+    "fr" => fr,
+    "fy" => one_i1_v0,
+    "ga" => ga,
+    "gd" => gd,
+    "gl" => one_i1_v0,
+    "gn" => one_n1,
+    "gu" => one_i0_or_n1,
+    "gv" => gv,
+    "ha" => one_n1,
+    "he" => he,
+    "hi" => one_i0_or_n1,
+    "ho" => other,
+    "hr" => hr,
+    "ht" => other,
+    "hu" => one_n1,
+    "hy" => one_i0_1,
+    "ia" => one_i1_v0,
+    "id" => other,
+    "ie" => one_i1_v0,
+    "ig" => other,
+    "ii" => other,
+    "ik" => other,
+    "is" => is,
+    "it" => it,
+    "iu" => one_two,
+    "ja" => other,
+    "jv" => other,
+    "ka" => one_n1,
+    "kg" => one_n1,
+    "ki" => one_n1,
+    "kj" => one_n1,
+    "kk" => one_n1,
+    "km" => other,
+    "kn" => one_i0_or_n1,
+    "ko" => other,
+    "ku" => one_n1,
+    "kv" => other,
+    "kw" => kw,
+    "ky" => one_n1,
+    "la" => one_n1,
+    "lb" => one_n1,
+    "lg" => one_n1,
+    "li" => one_i1_v0,
+    "lo" => other,
+    "lt" => lt,
+    "lu" => one_n1,
+    "lv" => lv,
+    "mg" => one_n0_1,
+    "mi" => other,
+    "mk" => mk,
+    "ml" => one_n1,
+    "mn" => one_n1,
+    "mr" => one_n1,
+    "ms" => other,
+    "mt" => mt,
+    "my" => other,
+    "nb" => one_n1,
+    "nd" => one_n1,
+    "ne" => one_n1,
+    "nl" => one_i1_v0,
+    "nn" => one_n1,
+    "no" => one_n1,
+    "nr" => one_n1,
+    "nv" => other,
+    "ny" => one_n1,
+    "oc" => one_n1,
+    "oj" => other,
+    "om" => one_n1,
+    "or" => one_n1,
+    "os" => one_n1,
+    "pa" => one_n0_1,
+    "pi" => other,
+    "pl" => pl,
+    "ps" => one_n1,
+    "pt" => pt,
+    "pt-PT" => it,
+    "qu" => one_n1,
+    "rm" => one_n1,
+    "ro" => ro,
+    "ru" => ru,
+    "rw" => one_n1,
+    "sa" => other,
+    "sc" => one_i1_v0,
+    "sd" => one_n1,
+    "se" => one_two,
+    "sg" => other,
+    "si" => si,
+    "sk" => cs,
+    "sl" => sl,
+    "sm" => other,
+    "sn" => one_n1,
+    "so" => one_n1,
+    "sq" => one_n1,
+    "sr" => hr,
+    "ss" => one_n1,
+    "st" => one_n1,
+    "su" => other,
+    "sv" => one_i1_v0,
+    "sw" => one_i1_v0,
+    "ta" => one_n1,
+    "te" => one_n1,
+    "tg" => one_n1,
+    "th" => other,
+    "ti" => one_n0_1,
+    "tk" => one_n1,
+    "tl" => fil,
+    "tn" => one_n1,
+    "tr" => one_n1,
+    "ts" => one_n1,
+    "tt" => one_n1,
+    "ug" => one_n1,
+    "uk" => ru,
+    "ur" => one_i1_v0,
+    "uz" => one_n1,
+    "ve" => one_n1,
+    "vi" => other,
+    "wa" => one_n0_1,
+    "wo" => other,
+    "xh" => one_n1,
+    "yi" => one_i1_v0,
+    "yo" => other,
+    "zh" => other,
+    "zu" => one_i0_or_n1,
+};
+
+/// Ordinal plural rules for every supported language.
+static ORDINAL_RULES_MAP: phf::Map<&'static str, PluralRuleFn> = phf_map! {
+    "aa" => other,
+    "ab" => other,
+    "ae" => other,
+    "af" => other,
+    "ak" => other,
+    "am" => other,
+    "an" => other,
+    "ar" => other,
+    "as" => ordinal_bn,
+    "av" => other,
+    "ay" => other,
+    "az" => ordinal_az,
+    "ba" => other,
+    "be" => ordinal_be,
+    "bg" => other,
+    "bi" => other,
+    "bm" => other,
+    "bn" => ordinal_bn,
+    "bo" => other,
+    "br" => other,
+    "bs" => other,
+    "ca" => ordinal_ca,
+    "ce" => other,
+    "ch" => other,
+    "co" => ordinal_it,
+    "cs" => other,
+    "cu" => other,
+    "cv" => other,
+    "cy" => ordinal_cy,
+    "da" => other,
+    "de" => other,
+    "dv" => other,
+    "dz" => other,
+    "ee" => other,
+    "el" => other,
+    "en" => ordinal_en,
+    "eo" => other,
+    "es" => other,
+    "et" => other,
+    "eu" => other,
+    "fa" => other,
+    "ff" => other,
+    "fi" => other,
+    "fj" => other,
+    "fo" => other,
+    "fr" => one_n1,
+    "fy" => other,
+    "ga" => one_n1,
+    "gd" => ordinal_gd,
+    "gl" => other,
+    "gn" => other,
+    "gu" => ordinal_hi,
+    "gv" => other,
+    "ha" => other,
+    "he" => other,
+    "hi" => ordinal_hi,
+    "ho" => other,
+    "hr" => other,
+    "ht" => other,
+    "hu" => ordinal_hu,
+    "hy" => one_n1,
+    "ia" => other,
+    "id" => other,
+    "ie" => other,
+    "ig" => other,
+    "ii" => other,
+    "ik" => other,
+    "is" => other,
+    "it" => ordinal_it,
+    "iu" => other,
+    "ja" => other,
+    "jv" => other,
+    "ka" => ordinal_ka,
+    "kg" => other,
+    "ki" => other,
+    "kj" => other,
+    "kk" => ordinal_kk,
+    "km" => other,
+    "kn" => other,
+    "ko" => other,
+    "ku" => other,
+    "kv" => other,
+    "kw" => ordinal_kw,
+    "ky" => other,
+    "la" => other,
+    "lb" => other,
+    "lg" => other,
+    "li" => other,
+    "lo" => one_n1,
+    "lt" => other,
+    "lu" => other,
+    "lv" => other,
+    "mg" => other,
+    "mi" => other,
+    "mk" => ordinal_mk,
+    "ml" => other,
+    "mn" => other,
+    "mr" => ordinal_mr,
+    "ms" => one_n1,
+    "mt" => other,
+    "my" => other,
+    "nb" => other,
+    "nd" => other,
+    "ne" => ordinal_ne,
+    "nl" => other,
+    "nn" => other,
+    "no" => other,
+    "nr" => other,
+    "nv" => other,
+    "ny" => other,
+    "oc" => other,
+    "oj" => other,
+    "om" => other,
+    "or" => ordinal_or,
+    "os" => other,
+    "pa" => other,
+    "pi" => other,
+    "pl" => other,
+    "ps" => other,
+    "pt" => other,
+    "qu" => other,
+    "rm" => other,
+    "ro" => one_n1,
+    "ru" => other,
+    "rw" => other,
+    "sa" => other,
+    "sc" => ordinal_it,
+    "sd" => other,
+    "se" => other,
+    "sg" => other,
+    "si" => other,
+    "sk" => other,
+    "sl" => other,
+    "sm" => other,
+    "sn" => other,
+    "so" => other,
+    "sq" => ordinal_sq,
+    "sr" => other,
+    "ss" => other,
+    "st" => other,
+    "su" => other,
+    "sv" => ordinal_sv,
+    "sw" => other,
+    "ta" => other,
+    "te" => other,
+    "tg" => other,
+    "th" => other,
+    "ti" => other,
+    "tk" => ordinal_tk,
+    "tl" => one_n1,
+    "tn" => other,
+    "tr" => other,
+    "ts" => other,
+    "tt" => other,
+    "ug" => other,
+    "uk" => ordinal_uk,
+    "ur" => other,
+    "uz" => other,
+    "ve" => other,
+    "vi" => one_n1,
+    "wa" => other,
+    "wo" => other,
+    "xh" => other,
+    "yi" => other,
+    "yo" => other,
+    "zh" => other,
+    "zu" => other,
+};
+
+/// Looks up the rule for `locale`, walking its [fallback chain](LanguageTag::fallback_chain) so
+/// that `pt-PT-x-private` uses the `pt-PT` rules, `pt-BR` those of `pt` and `pt-AO` those of
+/// `pt-PT`.
+fn lookup(map: &phf::Map<&'static str, PluralRuleFn>, locale: &str) -> Option<PluralRuleFn> {
+    // Languages with rules are found without parsing, which allocates the fallback chain
+    if let Some(rule) = map.get(locale) {
+        return Some(*rule);
+    }
+    LanguageTag::parse(locale)
+        .ok()?
+        .fallback_chain()
+        .iter()
+        .find_map(|locale| map.get(locale.as_str()).copied())
+}
+
+/// Returns the cardinal plural category of `operands` in `locale`, or `None` if its language is
+/// not supported.
+#[must_use]
+pub(crate) fn cardinal(locale: &str, operands: &PluralOperands) -> Option<PluralCategory> {
+    lookup(&CARDINAL_RULES_MAP, locale).map(|rule| rule(operands))
+}
+
+/// Returns the ordinal plural category of `operands` in `locale`, or `None` if its language is
+/// not supported.
+#[must_use]
+pub(crate) fn ordinal(locale: &str, operands: &PluralOperands) -> Option<PluralCategory> {
+    lookup(&ORDINAL_RULES_MAP, locale).map(|rule| rule(operands))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::LANGUAGE_RULES_MAP;
+
+    fn cardinal_of(language: &str, number: &str) -> PluralCategory {
+        cardinal(language, &number.parse().unwrap()).unwrap()
+    }
+
+    fn ordinal_of(language: &str, number: u64) -> PluralCategory {
+        ordinal(language, &number.into()).unwrap()
+    }
+
+    fn assert_cardinal(language: &str, category: PluralCategory, numbers: &[&str]) {
+        for number in numbers {
+            assert_eq!(
+                cardinal_of(language, number),
+                category,
+                "{language} cardinal {number}"
+            );
+        }
+    }
+
+    fn assert_ordinal(language: &str, category: PluralCategory, numbers: &[u64]) {
+        for &number in numbers {
+            assert_eq!(
+                ordinal_of(language, number),
+                category,
+                "{language} ordinal {number}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_language_has_rules() {
+        for language in LANGUAGE_RULES_MAP.keys() {
+            assert!(CARDINAL_RULES_MAP.contains_key(language), "{language}");
+            assert!(ORDINAL_RULES_MAP.contains_key(language), "{language}");
+        }
+        let languages = CARDINAL_RULES_MAP.keys().filter(|key| !key.contains('-'));
+        assert_eq!(languages.count(), LANGUAGE_RULES_MAP.len());
+        assert_eq!(ORDINAL_RULES_MAP.len(), LANGUAGE_RULES_MAP.len());
+    }
+
+    #[test]
+    fn test_operands() {
+        let operands: PluralOperands = "1.230".parse().unwrap();
+        assert_eq!(
+            (
+                operands.i(),
+                operands.v(),
+                operands.w(),
+                operands.f(),
+                operands.t()
+            ),
+            (1, 3, 2, 230, 23)
+        );
+        assert_eq!(
+            "-7".parse::<PluralOperands>().unwrap(),
+            PluralOperands::from(7u8)
+        );
+        assert_eq!(PluralOperands::from(-3i32), PluralOperands::from(3usize));
+        assert_eq!(PluralOperands::try_from(" 0.0 ").unwrap().v(), 1);
+    }
+
+    #[test]
+    fn test_operands_invalid() {
+        for value in [
+            "",
+            "-",
+            "1.",
+            ".5",
+            "1,5",
+            "1e3",
+            "abc",
+            "1.0000000000000000000",
+        ] {
+            assert_eq!(
+                value.parse::<PluralOperands>().unwrap_err(),
+                Error::InvalidPluralOperand {
+                    value: value.into()
+                },
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_category_as_str() {
+        assert_eq!(PluralCategory::Zero.as_str(), "zero");
+        assert_eq!(
+            alloc::string::ToString::to_string(&PluralCategory::Few),
+            "few"
+        );
+    }
+
+    #[test]
+    fn test_cardinal_english() {
+        assert_cardinal("en", One, &["1"]);
+        assert_cardinal("en", Other, &["0", "2", "1.0", "1.5", "21"]);
+    }
+
+    #[test]
+    fn test_cardinal_french() {
+        assert_cardinal("fr", One, &["0", "1", "1.5"]);
+        assert_cardinal("fr", Many, &["1000000", "2000000"]);
+        assert_cardinal("fr", Other, &["2", "100", "1000000.5"]);
+    }
+
+    #[test]
+    fn test_cardinal_polish() {
+        assert_cardinal("pl", One, &["1"]);
+        assert_cardinal("pl", Few, &["2", "3", "4", "22", "104"]);
+        assert_cardinal("pl", Many, &["0", "5", "11", "12", "14", "21", "25"]);
+        assert_cardinal("pl", Other, &["1.5", "2.0"]);
+    }
+
+    #[test]
+    fn test_cardinal_russian() {
+        assert_cardinal("ru", One, &["1", "21", "101"]);
+        assert_cardinal("ru", Few, &["2", "4", "23"]);
+        assert_cardinal("ru", Many, &["0", "5", "11", "14", "100"]);
+        assert_cardinal("ru", Other, &["1.5"]);
+        assert_cardinal("uk", Few, &["3"]);
+        assert_cardinal("be", One, &["1", "1.0", "21"]);
+        assert_cardinal("be", Other, &["1.5"]);
+    }
+
+    #[test]
+    fn test_cardinal_arabic() {
+        assert_cardinal("ar", Zero, &["0"]);
+        assert_cardinal("ar", One, &["1"]);
+        assert_cardinal("ar", Two, &["2"]);
+        assert_cardinal("ar", Few, &["3", "10", "103"]);
+        assert_cardinal("ar", Many, &["11", "99", "111"]);
+        assert_cardinal("ar", Other, &["100", "102", "0.5"]);
+    }
+
+    #[test]
+    fn test_cardinal_welsh() {
+        assert_cardinal("cy", Zero, &["0"]);
+        assert_cardinal("cy", One, &["1"]);
+        assert_cardinal("cy", Two, &["2"]);
+        assert_cardinal("cy", Few, &["3"]);
+        assert_cardinal("cy", Many, &["6"]);
+        assert_cardinal("cy", Other, &["4", "5", "7", "1.5"]);
+    }
+
+    #[test]
+    fn test_cardinal_irish() {
+        assert_cardinal("ga", One, &["1"]);
+        assert_cardinal("ga", Two, &["2"]);
+        assert_cardinal("ga", Few, &["3", "6"]);
+        assert_cardinal("ga", Many, &["7", "10"]);
+        assert_cardinal("ga", Other, &["0", "11", "2.5"]);
+    }
+
+    #[test]
+    fn test_cardinal_decimals() {
+        assert_cardinal("lv", Zero, &["0", "10", "11", "0.11"]);
+        assert_cardinal("lv", One, &["1", "21", "0.1", "1.1"]);
+        assert_cardinal("lv", Other, &["2", "0.2"]);
+        assert_cardinal("hr", One, &["1", "0.1", "21"]);
+        assert_cardinal("hr", Few, &["2", "0.2", "1.3"]);
+        assert_cardinal("hr", Other, &["5", "11", "0.5"]);
+        assert_cardinal("cs", Many, &["0.5", "1.0"]);
+        assert_cardinal("is", One, &["1", "21", "0.1", "1.1"]);
+        assert_cardinal("is", One, &["1.0"]);
+        assert_cardinal("is", Other, &["11", "2", "0.11"]);
+        assert_cardinal("da", One, &["1", "0.1", "1.5"]);
+        assert_cardinal("da", Other, &["0", "2", "2.5"]);
+        assert_cardinal("si", One, &["0", "1", "0.1"]);
+        assert_cardinal("si", Other, &["2", "0.2"]);
+    }
+
+    #[test]
+    fn test_cardinal_other_rules() {
+        assert_cardinal("ja", Other, &["0", "1", "2"]);
+        assert_cardinal("hi", One, &["0", "1", "0.5"]);
+        assert_cardinal("pt", One, &["0", "1", "1.5"]);
+        assert_cardinal("es", Many, &["1000000"]);
+        assert_cardinal("it", Many, &["1000000"]);
+        assert_cardinal("it", Other, &["1.0"]);
+        assert_cardinal("he", One, &["1", "0.5"]);
+        assert_cardinal("he", Two, &["2"]);
+        assert_cardinal("sl", One, &["1", "101"]);
+        assert_cardinal("sl", Two, &["2", "102"]);
+        assert_cardinal("sl", Few, &["3", "4", "0.5"]);
+        assert_cardinal("ro", Few, &["0", "2", "19", "101", "1.5"]);
+        assert_cardinal("ro", Other, &["20", "100"]);
+        assert_cardinal("lt", One, &["1", "21"]);
+        assert_cardinal("lt", Few, &["2", "9"]);
+        assert_cardinal("lt", Many, &["0.5"]);
+        assert_cardinal("lt", Other, &["10", "11"]);
+        assert_cardinal("mt", Few, &["0", "3", "10"]);
+        assert_cardinal("mt", Many, &["11", "19"]);
+        assert_cardinal("br", One, &["1", "21"]);
+        assert_cardinal("br", Two, &["2", "22"]);
+        assert_cardinal("br", Few, &["3", "9"]);
+        assert_cardinal("br", Many, &["1000000"]);
+        assert_cardinal("br", Other, &["11", "71"]);
+        assert_cardinal("gv", Few, &["0", "20"]);
+        assert_cardinal("gd", One, &["11"]);
+        assert_cardinal("gd", Few, &["13"]);
+        assert_cardinal("kw", Two, &["2", "22", "1000", "100000"]);
+        assert_cardinal("kw", Many, &["21", "41"]);
+        assert_cardinal("mk", One, &["1", "21", "0.1"]);
+        assert_cardinal("mk", Other, &["11", "2"]);
+        assert_cardinal("tl", One, &["1", "2", "3", "5", "0.5"]);
+        assert_cardinal("tl", Other, &["4", "6", "9", "0.4"]);
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_ordinal("en", One, &[1, 21, 101]);
+        assert_ordinal("en", Two, &[2, 22]);
+        assert_ordinal("en", Few, &[3, 23]);
+        assert_ordinal("en", Other, &[0, 4, 11, 12, 13, 111]);
+        assert_ordinal("fr", One, &[1]);
+        assert_ordinal("fr", Other, &[2, 21]);
+        assert_ordinal("it", Many, &[8, 11, 80, 800]);
+        assert_ordinal("it", Other, &[1, 18]);
+        assert_ordinal("sv", One, &[1, 2, 21, 22]);
+        assert_ordinal("sv", Other, &[3, 11, 12]);
+        assert_ordinal("cy", Zero, &[0, 7]);
+        assert_ordinal("cy", Many, &[5, 6]);
+        assert_ordinal("ca", One, &[1, 3]);
+        assert_ordinal("ca", Two, &[2]);
+        assert_ordinal("ca", Few, &[4]);
+        assert_ordinal("hi", Many, &[6]);
+        assert_ordinal("bn", One, &[1, 5, 10]);
+        assert_ordinal("az", One, &[1, 20]);
+        assert_ordinal("az", Few, &[3, 100]);
+        assert_ordinal("az", Many, &[0, 6, 40]);
+        assert_ordinal("ka", One, &[1]);
+        assert_ordinal("ka", Many, &[0, 2, 20, 40]);
+        assert_ordinal("ka", Other, &[21]);
+        assert_ordinal("kk", Many, &[6, 9, 10]);
+        assert_ordinal("kk", Other, &[0, 1]);
+        assert_ordinal("kw", One, &[1, 4, 21]);
+        assert_ordinal("kw", Many, &[5, 105]);
+        assert_ordinal("uk", Few, &[3, 23]);
+        assert_ordinal("uk", Other, &[13]);
+        assert_ordinal("ru", Other, &[1, 2, 5]);
+    }
+
+    #[test]
+    fn test_regional_rules() {
+        assert_cardinal("pt", One, &["0", "1", "1.5"]);
+        assert_cardinal("pt-BR", One, &["0", "1", "1.5"]);
+        assert_cardinal("pt-PT", One, &["1"]);
+        assert_cardinal("pt-PT", Other, &["0", "1.5", "2"]);
+        assert_cardinal("pt-PT", Many, &["1000000"]);
+        assert_cardinal("pt-PT-x-private", Other, &["0"]);
+        assert_cardinal("pt-AO", One, &["1"]);
+        assert_cardinal("pt-AO", Other, &["0", "1.5"]);
+        assert_cardinal("pt-MZ", Many, &["1000000"]);
+        assert_ordinal("pt-PT", Other, &[1]);
+        assert_eq!(cardinal("xx-PT", &PluralOperands::from(1u8)), None);
+    }
+
+    #[test]
+    fn test_unsupported() {
+        assert_eq!(cardinal("xx", &PluralOperands::from(1u8)), None);
+        assert_eq!(ordinal("xx", &PluralOperands::from(1u8)), None);
+    }
+}
@@ -1,9 +1,11 @@
-//! Registry of all language rules, keyed by ISO 639-1 language code.
+//! Registry of all language rules, keyed by ISO 639-1 language code, and of the regional and
+//! script variants of those rules, keyed by BCP 47 language tag.
 
 use phf::phf_map;
 
 use crate::language_rules::LanguageRuleSet;
 use crate::languages;
+use crate::locale::LanguageTag;
 
 /// A compile-time map of ISO 639-1 language codes to their [`LanguageRuleSet`].
 pub(crate) static LANGUAGE_RULES_MAP: phf::Map<&'static str, &'static LanguageRuleSet> = phf_map! {
@@ -176,6 +178,31 @@ pub(crate) static LANGUAGE_RULES_MAP: phf::Map<&'static str, &'static LanguageRu
     "zu" => &languages::zu::RULES,
 };
 
+/// A compile-time map of BCP 47 tags of regional and script variants to their
+/// [`LanguageRuleSet`].
+pub(crate) static REGIONAL_RULES_MAP: phf::Map<&'static str, &'static LanguageRuleSet> = phf_map! {
+    "de-CH" => &languages::de_ch::RULES,
+    "de-LI" => &languages::de_ch::LI_RULES,
+    "pt-PT" => &languages::pt_pt::RULES,
+};
+
+/// Returns the rule set registered under exactly `locale`, such as `en` or `pt-PT`, without
+/// parsing it or walking a fallback chain.
+pub(crate) fn get(locale: &str) -> Option<&'static LanguageRuleSet> {
+    REGIONAL_RULES_MAP
+        .get(locale)
+        .or_else(|| LANGUAGE_RULES_MAP.get(locale))
+        .copied()
+}
+
+/// Resolves the most specific rule set for `tag` by walking its fallback chain, so that `pt-PT`
+/// finds the European Portuguese rules while `pt-BR` falls back to those of `pt`.
+pub(crate) fn resolve(tag: &LanguageTag) -> Option<&'static LanguageRuleSet> {
+    tag.fallback_chain()
+        .iter()
+        .find_map(|locale| get(locale.as_str()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,6 +243,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_all_variants_have_correct_tag() {
+        for (tag, rules) in REGIONAL_RULES_MAP.entries() {
+            assert_eq!(*tag, rules.locale());
+            assert!(LANGUAGE_RULES_MAP.contains_key(rules.language()), "{tag}");
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get("en").unwrap().locale(), "en");
+        assert_eq!(get("pt-PT").unwrap().locale(), "pt-PT");
+        assert!(get("sr-Latn").is_none());
+        assert!(get("pt-BR").is_none());
+        assert!(get("EN").is_none());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let resolve = |locale: &str| resolve(&LanguageTag::parse(locale).unwrap()).unwrap();
+        assert_eq!(resolve("pt").locale(), "pt");
+        assert_eq!(resolve("pt-BR").locale(), "pt");
+        assert_eq!(resolve("pt-PT").locale(), "pt-PT");
+        assert_eq!(resolve("pt-AO").locale(), "pt-PT");
+        assert_eq!(resolve("de-CH-1901").locale(), "de-CH");
+        assert_eq!(resolve("de-LI").locale(), "de-LI");
+        assert_eq!(resolve("de-AT").locale(), "de");
+        assert_eq!(resolve("sr").locale(), "sr");
+        assert_eq!(resolve("sr-Cyrl-RS").locale(), "sr");
+        assert_eq!(resolve("sr-Latn-ME").locale(), "sr");
+        assert!(super::resolve(&LanguageTag::parse("xx-CH").unwrap()).is_none());
+    }
+
     #[test]
     fn test_singularize_via_registry() {
         let rules = LANGUAGE_RULES_MAP.get("en").unwrap();